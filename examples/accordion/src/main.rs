@@ -0,0 +1,91 @@
+use iced::widget::{checkbox, column, container, text};
+use iced::{Element, Fill};
+
+use iced_palace::widget::accordion;
+
+fn main() -> iced::Result {
+    iced::application(Example::default, Example::update, Example::view).run()
+}
+
+#[derive(Default)]
+struct Example {
+    expanded: [bool; 4],
+    exclusive: bool,
+    notifications: bool,
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    Toggled(usize, bool),
+    ToggleExclusive(bool),
+    ToggleNotifications(bool),
+}
+
+impl Example {
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Toggled(index, is_expanded) => {
+                self.expanded[index] = is_expanded;
+            }
+            Message::ToggleExclusive(exclusive) => {
+                self.exclusive = exclusive;
+            }
+            Message::ToggleNotifications(notifications) => {
+                self.notifications = notifications;
+            }
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let sections = [
+            accordion::section(
+                text("Profile"),
+                text("Your name, avatar and a few words about yourself."),
+            )
+            .icon(text("👤")),
+            accordion::section(
+                text("Notifications"),
+                checkbox(self.notifications)
+                    .label("Send me an email on every mention")
+                    .on_toggle(Message::ToggleNotifications),
+            )
+            .icon(text("🔔")),
+            accordion::section(
+                text("Billing"),
+                text("Billing is managed by your organization."),
+            )
+            .icon(text("💳"))
+            .disabled(true),
+            accordion::section(
+                text("About"),
+                column![
+                    text("iced_palace"),
+                    text("A collection of widgets for iced.").size(14),
+                ]
+                .spacing(5),
+            ),
+        ]
+        .into_iter()
+        .zip(self.expanded)
+        .map(|(section, is_expanded)| section.expanded(is_expanded));
+
+        let accordion = accordion(sections)
+            .on_toggle(Message::Toggled)
+            .exclusive(self.exclusive)
+            .spacing(8);
+
+        container(
+            column![
+                checkbox(self.exclusive)
+                    .label("Only one section at a time")
+                    .on_toggle(Message::ToggleExclusive),
+                accordion,
+            ]
+            .spacing(20)
+            .max_width(500),
+        )
+        .center_x(Fill)
+        .padding(20)
+        .into()
+    }
+}
@@ -0,0 +1,137 @@
+use iced::widget::{button, center, column, container, row, stack, text};
+use iced::{Color, Element, Fill};
+
+use iced_palace::widget::annotation_layer;
+use iced_palace::widget::annotation_layer::{Action, Annotations, Tool};
+
+fn main() -> iced::Result {
+    iced::run(Example::update, Example::view)
+}
+
+#[derive(Default)]
+struct Example {
+    annotations: Annotations,
+    tool: Tool,
+    color: usize,
+    saved: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    Annotate(Action),
+    ToolSelected(Tool),
+    ColorSelected(usize),
+    Undo,
+    Redo,
+    Clear,
+    Save,
+    Restore,
+}
+
+const COLORS: &[Color] = &[
+    Color::from_rgb(0.88, 0.24, 0.24),
+    Color::from_rgb(0.2, 0.6, 0.3),
+    Color::from_rgb(0.2, 0.4, 0.9),
+];
+
+impl Example {
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Annotate(action) => {
+                self.annotations.perform(action);
+            }
+            Message::ToolSelected(tool) => {
+                self.tool = tool;
+            }
+            Message::ColorSelected(color) => {
+                self.color = color;
+            }
+            Message::Undo => {
+                self.annotations.undo();
+            }
+            Message::Redo => {
+                self.annotations.redo();
+            }
+            Message::Clear => {
+                self.annotations.clear();
+            }
+            Message::Save => {
+                self.saved = Some(self.annotations.to_string());
+            }
+            Message::Restore => {
+                if let Some(annotations) =
+                    self.saved.as_deref().and_then(|saved| saved.parse().ok())
+                {
+                    self.annotations = annotations;
+                }
+            }
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let tools = row([
+            ("Pen", Tool::Pen),
+            ("Arrow", Tool::Arrow),
+            ("Text", Tool::Text),
+            ("Eraser", Tool::Eraser),
+        ]
+        .into_iter()
+        .map(|(label, tool)| {
+            button(label)
+                .on_press_maybe((tool != self.tool).then_some(Message::ToolSelected(tool)))
+                .into()
+        }))
+        .spacing(5);
+
+        let colors = row(COLORS.iter().enumerate().map(|(i, color)| {
+            let color = *color;
+
+            button(" ")
+                .style(move |_theme, _status| button::Style {
+                    background: Some(color.into()),
+                    border: iced::border::rounded(10).width(if i == self.color { 3 } else { 0 }),
+                    ..button::Style::default()
+                })
+                .on_press(Message::ColorSelected(i))
+                .into()
+        }))
+        .spacing(5);
+
+        let history = row![
+            button("Undo").on_press_maybe(self.annotations.can_undo().then_some(Message::Undo)),
+            button("Redo").on_press_maybe(self.annotations.can_redo().then_some(Message::Redo)),
+            button("Clear").on_press(Message::Clear),
+            button("Save").on_press(Message::Save),
+            button("Restore").on_press_maybe(self.saved.as_ref().map(|_| Message::Restore)),
+        ]
+        .spacing(5);
+
+        let document = container(center(
+            column![
+                text("Quarterly review").size(40),
+                text("Revenue grew 12% while costs stayed flat."),
+                text("Mark anything that needs another look!"),
+            ]
+            .spacing(10),
+        ))
+        .style(container::bordered_box);
+
+        let canvas = stack![
+            document,
+            annotation_layer(&self.annotations)
+                .tool(self.tool)
+                .color(COLORS[self.color])
+                .on_action(Message::Annotate),
+        ];
+
+        column![
+            row![tools, colors, history].spacing(20),
+            canvas,
+            text!("{} annotation(s)", self.annotations.len()).size(12),
+        ]
+        .spacing(10)
+        .padding(10)
+        .height(Fill)
+        .into()
+    }
+}
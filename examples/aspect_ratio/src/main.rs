@@ -0,0 +1,138 @@
+use iced::widget::{button, center, column, container, row, text};
+use iced::{Element, Fill, Theme};
+use iced_palace::widget::aspect_ratio::Fit;
+use iced_palace::widget::{aspect_ratio, letterbox};
+
+fn main() -> iced::Result {
+    iced::application(Example::new, Example::update, Example::view).run()
+}
+
+struct Example {
+    ratio: Ratio,
+    fit: Fit,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Ratio {
+    Widescreen,
+    Classic,
+    Square,
+    Portrait,
+}
+
+impl Ratio {
+    const ALL: [Ratio; 4] = [
+        Ratio::Widescreen,
+        Ratio::Classic,
+        Ratio::Square,
+        Ratio::Portrait,
+    ];
+
+    fn value(self) -> f32 {
+        match self {
+            Ratio::Widescreen => 16.0 / 9.0,
+            Ratio::Classic => 4.0 / 3.0,
+            Ratio::Square => 1.0,
+            Ratio::Portrait => 9.0 / 16.0,
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            Ratio::Widescreen => "16:9",
+            Ratio::Classic => "4:3",
+            Ratio::Square => "1:1",
+            Ratio::Portrait => "9:16",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    RatioSelected(Ratio),
+    FitSelected(Fit),
+}
+
+impl Example {
+    fn new() -> Self {
+        Self {
+            ratio: Ratio::Widescreen,
+            fit: Fit::Contain,
+        }
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::RatioSelected(ratio) => {
+                self.ratio = ratio;
+            }
+            Message::FitSelected(fit) => {
+                self.fit = fit;
+            }
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let ratios = row(Ratio::ALL.into_iter().map(|ratio| {
+            toggle(
+                ratio.label(),
+                self.ratio == ratio,
+                Message::RatioSelected(ratio),
+            )
+        }))
+        .spacing(5);
+
+        let fits = row![
+            toggle(
+                "Contain",
+                self.fit == Fit::Contain,
+                Message::FitSelected(Fit::Contain)
+            ),
+            toggle(
+                "Cover",
+                self.fit == Fit::Cover,
+                Message::FitSelected(Fit::Cover)
+            ),
+        ]
+        .spacing(5);
+
+        let screen = || {
+            center(text(self.ratio.label()).size(30)).style(|theme: &Theme| {
+                let palette = theme.extended_palette();
+
+                container::Style::default()
+                    .background(palette.primary.weak.color)
+                    .color(palette.primary.weak.text)
+            })
+        };
+
+        let preview = row![
+            container(
+                aspect_ratio(self.ratio.value(), screen())
+                    .fit(self.fit)
+                    .height(Fill)
+            )
+            .style(container::bordered_box)
+            .width(Fill)
+            .height(Fill),
+            letterbox(self.ratio.value(), screen()),
+        ]
+        .spacing(20);
+
+        column![row![ratios, fits].spacing(20), preview]
+            .spacing(20)
+            .padding(20)
+            .into()
+    }
+}
+
+fn toggle(label: &str, active: bool, on_press: Message) -> Element<'_, Message> {
+    button(text(label).size(14))
+        .style(if active {
+            button::primary
+        } else {
+            button::secondary
+        })
+        .on_press(on_press)
+        .into()
+}
@@ -0,0 +1,75 @@
+use iced::widget::{center, column, image, row, text};
+use iced::{Center, Element};
+
+use iced_palace::widget::avatar::Presence;
+use iced_palace::widget::{avatar, avatar_stack};
+
+fn main() -> iced::Result {
+    iced::run(Example::update, Example::view)
+}
+
+struct Example {
+    photo: image::Handle,
+}
+
+#[derive(Debug, Clone)]
+enum Message {}
+
+const TEAM: &[(&str, Presence)] = &[
+    ("Ada Lovelace", Presence::Online),
+    ("Grace Hopper", Presence::Away),
+    ("Alan Turing", Presence::Busy),
+    ("Edsger Dijkstra", Presence::Offline),
+    ("Barbara Liskov", Presence::Online),
+    ("Donald Knuth", Presence::Away),
+    ("Margaret Hamilton", Presence::Online),
+];
+
+impl Example {
+    fn update(&mut self, message: Message) {
+        match message {}
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let sizes = row![
+            avatar("Ada Lovelace").small(),
+            avatar("Ada Lovelace"),
+            avatar("Ada Lovelace").large(),
+            avatar("Ada Lovelace")
+                .image(self.photo.clone())
+                .presence(Presence::Online)
+                .large(),
+        ]
+        .spacing(10)
+        .align_y(Center);
+
+        let team = column(TEAM.iter().map(|(name, presence)| {
+            row![avatar(*name).presence(*presence), text(*name)]
+                .spacing(10)
+                .align_y(Center)
+                .into()
+        }))
+        .spacing(10);
+
+        let stack = avatar_stack(TEAM.iter().map(|(name, _)| avatar(*name))).max(5);
+
+        center(column![sizes, team, stack].spacing(30)).into()
+    }
+}
+
+impl Default for Example {
+    fn default() -> Self {
+        Self {
+            photo: gradient(128),
+        }
+    }
+}
+
+fn gradient(size: u32) -> image::Handle {
+    let pixels = (0..size)
+        .flat_map(|y| (0..size).map(move |x| (x, y)))
+        .flat_map(|(x, y)| [(x * 255 / size) as u8, 120, (y * 255 / size) as u8, 255])
+        .collect::<Vec<u8>>();
+
+    image::Handle::from_rgba(size, size, pixels)
+}
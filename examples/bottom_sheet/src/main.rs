@@ -0,0 +1,72 @@
+use iced::widget::{button, center, column, row, scrollable, text};
+use iced::{Center, Element, Fill};
+
+use iced_palace::widget::bottom_sheet;
+use iced_palace::widget::bottom_sheet::Detent;
+
+fn main() -> iced::Result {
+    iced::run(Example::update, Example::view)
+}
+
+#[derive(Default)]
+struct Example {
+    detent: Detent,
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    DetentChanged(Detent),
+}
+
+const DETENTS: &[(&str, Detent)] = &[
+    ("Hide", Detent::Hidden),
+    ("Peek", Detent::Peek),
+    ("Half", Detent::Half),
+    ("Full", Detent::Full),
+];
+
+impl Example {
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::DetentChanged(detent) => {
+                self.detent = detent;
+            }
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let content = center(
+            column![
+                text("Drag the handle of the sheet").size(20),
+                row(DETENTS.iter().map(|(label, detent)| {
+                    button(*label)
+                        .on_press_maybe(
+                            (*detent != self.detent).then_some(Message::DetentChanged(*detent)),
+                        )
+                        .into()
+                }))
+                .spacing(10),
+            ]
+            .spacing(20)
+            .align_x(Center),
+        );
+
+        let sheet = column![
+            text("Nearby places").size(24),
+            scrollable(
+                column((1..=30).map(|i| text!("Place #{i}").into()))
+                    .spacing(10)
+                    .width(Fill)
+            )
+            .height(Fill),
+        ]
+        .spacing(20)
+        .padding([0, 20]);
+
+        bottom_sheet(content, sheet)
+            .detent(self.detent)
+            .detents([Detent::Hidden, Detent::Peek, Detent::Half, Detent::Full])
+            .on_detent_change(Message::DetentChanged)
+            .into()
+    }
+}
@@ -0,0 +1,96 @@
+use iced::time::seconds;
+use iced::widget::{button, center, column, container, row, text, toggler};
+use iced::{Center, Color, Element, Fill};
+
+use iced_palace::widget::carousel;
+
+fn main() -> iced::Result {
+    iced::application(Example::default, Example::update, Example::view).run()
+}
+
+#[derive(Default)]
+struct Example {
+    page: usize,
+    autoplay: bool,
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    PageChanged(usize),
+    Previous,
+    Next,
+    ToggleAutoplay(bool),
+}
+
+const PAGES: [(&str, Color); 5] = [
+    ("Welcome", Color::from_rgb(0.35, 0.45, 0.85)),
+    (
+        "Swipe or drag to move around",
+        Color::from_rgb(0.25, 0.65, 0.55),
+    ),
+    ("Click the dots to jump", Color::from_rgb(0.85, 0.55, 0.25)),
+    (
+        "Hover to pause the autoplay",
+        Color::from_rgb(0.75, 0.3, 0.45),
+    ),
+    ("That's all!", Color::from_rgb(0.45, 0.45, 0.5)),
+];
+
+impl Example {
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::PageChanged(page) => {
+                self.page = page;
+            }
+            Message::Previous => {
+                self.page = self.page.saturating_sub(1);
+            }
+            Message::Next => {
+                self.page = (self.page + 1).min(PAGES.len() - 1);
+            }
+            Message::ToggleAutoplay(autoplay) => {
+                self.autoplay = autoplay;
+            }
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let pages = PAGES.iter().map(|(title, color)| {
+            let color = *color;
+
+            container(text(*title).size(30).color(Color::WHITE))
+                .center(Fill)
+                .style(move |_| container::Style::default().background(color))
+                .into()
+        });
+
+        let mut carousel = carousel(pages)
+            .page(self.page)
+            .on_page_change(Message::PageChanged)
+            .height(300);
+
+        if self.autoplay {
+            carousel = carousel.autoplay(seconds(3));
+        }
+
+        center(
+            column![
+                carousel,
+                row![
+                    button("Previous").on_press(Message::Previous),
+                    text!("{} / {}", self.page + 1, PAGES.len()),
+                    button("Next").on_press(Message::Next),
+                    toggler(self.autoplay)
+                        .label("Autoplay")
+                        .on_toggle(Message::ToggleAutoplay),
+                ]
+                .spacing(20)
+                .align_y(Center),
+            ]
+            .spacing(20)
+            .align_x(Center),
+        )
+        .padding(20)
+        .into()
+    }
+}
@@ -0,0 +1,147 @@
+use iced::time::{Duration, Instant};
+use iced::widget::{button, column, row, text_input};
+use iced::window;
+use iced::{Element, Fill, Subscription};
+
+use iced_palace::widget::chat_view;
+use iced_palace::widget::chat_view::{Chat, Sender};
+
+fn main() -> iced::Result {
+    iced::application(Example::new, Example::update, Example::view)
+        .subscription(Example::subscription)
+        .run()
+}
+
+struct Example {
+    chat: Chat,
+    draft: String,
+    reply: Option<Reply>,
+    replies: usize,
+}
+
+struct Reply {
+    words: Vec<&'static str>,
+    next: Instant,
+    is_typing: bool,
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    DraftChanged(String),
+    Send,
+    Tick(Instant),
+}
+
+const THINKING: Duration = Duration::from_millis(1200);
+const WORD: Duration = Duration::from_millis(60);
+
+const REPLIES: &[&str] = &[
+    "That is a great question! Widgets in iced are built from a handful of \
+    small traits, and most of them only need to care about layout, events \
+    and drawing.",
+    "Streaming text works by appending chunks to the last message. The view \
+    reveals them gradually, so the conversation feels alive even when the \
+    chunks arrive in bursts.",
+    "Sure. Try scrolling up while I am answering; the view will stop \
+    following the conversation until you scroll back to the bottom.",
+];
+
+impl Example {
+    fn new() -> Self {
+        let mut chat = Chat::new();
+
+        chat.push(
+            Sender::Remote(String::from("Assistant")),
+            "Hello! Ask me anything.",
+        );
+
+        Self {
+            chat,
+            draft: String::new(),
+            reply: None,
+            replies: 0,
+        }
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::DraftChanged(draft) => {
+                self.draft = draft;
+            }
+            Message::Send => {
+                if self.draft.trim().is_empty() || self.reply.is_some() {
+                    return;
+                }
+
+                self.chat
+                    .push(Sender::Local, std::mem::take(&mut self.draft));
+
+                self.reply = Some(Reply {
+                    words: REPLIES[self.replies % REPLIES.len()]
+                        .split_inclusive(' ')
+                        .rev()
+                        .collect(),
+                    next: Instant::now() + THINKING,
+                    is_typing: true,
+                });
+
+                self.replies += 1;
+            }
+            Message::Tick(now) => {
+                let Some(reply) = &mut self.reply else {
+                    return;
+                };
+
+                if now < reply.next {
+                    return;
+                }
+
+                if reply.is_typing {
+                    reply.is_typing = false;
+                    self.chat.stream(Sender::Remote(String::from("Assistant")));
+                }
+
+                while reply.next <= now {
+                    reply.next += WORD;
+
+                    match reply.words.pop() {
+                        Some(word) => self.chat.append(word),
+                        None => {
+                            self.chat.finish();
+                            self.reply = None;
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let is_typing = self.reply.as_ref().is_some_and(|reply| reply.is_typing);
+
+        let input = row![
+            text_input("Type a message...", &self.draft)
+                .on_input(Message::DraftChanged)
+                .on_submit(Message::Send)
+                .padding(10),
+            button("Send")
+                .on_press_maybe(self.reply.is_none().then_some(Message::Send))
+                .padding(10),
+        ]
+        .spacing(10);
+
+        column![chat_view(&self.chat).typing(is_typing).height(Fill), input]
+            .spacing(10)
+            .padding(10)
+            .into()
+    }
+
+    fn subscription(&self) -> Subscription<Message> {
+        if self.reply.is_some() {
+            window::frames().map(Message::Tick)
+        } else {
+            Subscription::none()
+        }
+    }
+}
@@ -0,0 +1,123 @@
+use iced::widget::{column, pick_list, row, scrollable, text, toggler};
+use iced::{Element, Fill, Theme};
+
+use iced_palace::widget::code_block;
+
+fn main() -> iced::Result {
+    iced::application(Example::default, Example::update, Example::view)
+        .theme(Example::theme)
+        .run()
+}
+
+#[derive(Default)]
+struct Example {
+    theme: Option<Theme>,
+    wrap: bool,
+    hide_line_numbers: bool,
+    copies: usize,
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    ThemeSelected(Theme),
+    WrapToggled(bool),
+    LineNumbersToggled(bool),
+    Copied,
+}
+
+const RUST: &str = r#"use iced::widget::{button, column, text};
+
+#[derive(Default)]
+struct Counter {
+    value: i64,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Message {
+    Increment,
+    Decrement,
+}
+
+impl Counter {
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Increment => self.value += 1,
+            Message::Decrement => self.value -= 1, // Negative numbers are allowed, for a very long time, in this particular counter
+        }
+    }
+
+    fn view(&self) -> iced::Element<'_, Message> {
+        column![
+            button("+").on_press(Message::Increment),
+            text(self.value).size(50),
+            button("-").on_press(Message::Decrement),
+        ]
+        .into()
+    }
+}
+"#;
+
+const TOML: &str = r#"[package]
+name = "counter"
+version = "0.1.0"
+edition = "2024"
+
+[dependencies]
+iced = "0.14"
+"#;
+
+impl Example {
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::ThemeSelected(theme) => {
+                self.theme = Some(theme);
+            }
+            Message::WrapToggled(wrap) => {
+                self.wrap = wrap;
+            }
+            Message::LineNumbersToggled(line_numbers) => {
+                self.hide_line_numbers = !line_numbers;
+            }
+            Message::Copied => {
+                self.copies += 1;
+            }
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let controls = row![
+            pick_list(Theme::ALL, self.theme.as_ref(), Message::ThemeSelected)
+                .placeholder("System theme"),
+            toggler(self.wrap)
+                .label("Wrap lines")
+                .on_toggle(Message::WrapToggled),
+            toggler(!self.hide_line_numbers)
+                .label("Line numbers")
+                .on_toggle(Message::LineNumbersToggled),
+            text!("Copied {} times", self.copies),
+        ]
+        .spacing(20)
+        .align_y(iced::Center);
+
+        let code = column![
+            code_block(TOML, "toml")
+                .line_numbers(!self.hide_line_numbers)
+                .wrap(self.wrap)
+                .on_copy(Message::Copied),
+            code_block(RUST, "rs")
+                .line_numbers(!self.hide_line_numbers)
+                .wrap(self.wrap)
+                .on_copy(Message::Copied),
+        ]
+        .spacing(20);
+
+        column![controls, scrollable(code).height(Fill).spacing(10)]
+            .spacing(20)
+            .padding(20)
+            .into()
+    }
+
+    fn theme(&self) -> Option<Theme> {
+        self.theme.clone()
+    }
+}
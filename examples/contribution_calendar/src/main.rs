@@ -0,0 +1,87 @@
+use iced::widget::{center, column, text};
+use iced::{Center, Element};
+
+use iced_palace::widget::contribution_calendar;
+use iced_palace::widget::contribution_calendar::Date;
+
+use std::collections::BTreeMap;
+
+fn main() -> iced::Result {
+    iced::application(Example::new, Example::update, Example::view).run()
+}
+
+struct Example {
+    contributions: BTreeMap<Date, f32>,
+    selected: Option<Date>,
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    DayClicked(Date),
+}
+
+impl Example {
+    fn new() -> Self {
+        let start = Date::new(2025, 1, 1);
+
+        let contributions = (0..365)
+            .map(|day| {
+                let date = start.add_days(day);
+
+                // Busy weekdays, quiet weekends
+                let activity = ((day * 7919) % 13) as f32 - 4.0;
+                let activity = if date.weekday() == 0 || date.weekday() == 6 {
+                    activity - 6.0
+                } else {
+                    activity
+                };
+
+                (date, activity.max(0.0))
+            })
+            .collect();
+
+        Self {
+            contributions,
+            selected: None,
+        }
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::DayClicked(date) => {
+                self.selected = Some(date);
+            }
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let total: f32 = self.contributions.values().sum();
+
+        let calendar = contribution_calendar(&self.contributions)
+            .tooltip(|date, value| match value as u32 {
+                0 => format!("No contributions on {date}"),
+                1 => format!("1 contribution on {date}"),
+                count => format!("{count} contributions on {date}"),
+            })
+            .on_day_click(Message::DayClicked);
+
+        let selected = match self.selected {
+            Some(date) => text!(
+                "{date}: {} contributions",
+                self.contributions.get(&date).copied().unwrap_or_default()
+            ),
+            None => text("Click a day to select it"),
+        };
+
+        center(
+            column![
+                text!("{total} contributions in 2025").size(20),
+                calendar,
+                selected,
+            ]
+            .spacing(10)
+            .align_x(Center),
+        )
+        .into()
+    }
+}
@@ -0,0 +1,68 @@
+use iced::time::{Instant, seconds};
+use iced::widget::{button, center, column, row, text};
+use iced::{Center, Element, Font};
+
+use iced_palace::widget::countdown;
+
+fn main() -> iced::Result {
+    iced::application(Example::new, Example::update, Example::view).run()
+}
+
+struct Example {
+    deadline: Instant,
+    paused: bool,
+    finished: bool,
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    TogglePause,
+    Restart,
+    Finished,
+}
+
+impl Example {
+    fn new() -> Self {
+        Self {
+            deadline: Instant::now() + seconds(90),
+            paused: false,
+            finished: false,
+        }
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::TogglePause => {
+                self.paused = !self.paused;
+            }
+            Message::Restart => {
+                *self = Self::new();
+            }
+            Message::Finished => {
+                self.finished = true;
+            }
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        center(
+            column![
+                countdown(self.deadline)
+                    .paused(self.paused)
+                    .on_finish(Message::Finished)
+                    .size(60)
+                    .font(Font::MONOSPACE),
+                row![
+                    button(if self.paused { "Resume" } else { "Pause" })
+                        .on_press_maybe((!self.finished).then_some(Message::TogglePause)),
+                    button("Restart").on_press(Message::Restart),
+                ]
+                .spacing(10),
+                text(if self.finished { "Time's up!" } else { "" }),
+            ]
+            .align_x(Center)
+            .spacing(20),
+        )
+        .into()
+    }
+}
@@ -0,0 +1,116 @@
+use iced::widget::{column, container, row, scrollable, text, toggler};
+use iced::{Center, Element, Fill, Font};
+
+use iced_palace::widget::diff_text;
+
+fn main() -> iced::Result {
+    iced::run(Example::update, Example::view)
+}
+
+#[derive(Default)]
+struct Example {
+    side_by_side: bool,
+    expanded: bool,
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    SideBySideToggled(bool),
+    ExpandedToggled(bool),
+}
+
+impl Example {
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::SideBySideToggled(side_by_side) => {
+                self.side_by_side = side_by_side;
+            }
+            Message::ExpandedToggled(expanded) => {
+                self.expanded = expanded;
+            }
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let controls = row![
+            text("Diff"),
+            toggler(self.side_by_side)
+                .label("Side by side")
+                .on_toggle(Message::SideBySideToggled),
+            toggler(self.expanded)
+                .label("Show unchanged lines")
+                .on_toggle(Message::ExpandedToggled),
+        ]
+        .spacing(20)
+        .align_y(Center);
+
+        let diff = diff_text(OLD, NEW)
+            .side_by_side(self.side_by_side)
+            .font(Font::MONOSPACE)
+            .size(14);
+
+        let diff = if self.expanded { diff.expanded() } else { diff };
+
+        column![
+            controls,
+            container(scrollable(diff).width(Fill).height(Fill))
+                .style(container::bordered_box)
+                .padding(5),
+        ]
+        .spacing(10)
+        .padding(10)
+        .into()
+    }
+}
+
+const OLD: &str = r#"use std::collections::HashMap;
+
+fn main() {
+    let mut counts = HashMap::new();
+
+    for word in INPUT.split_whitespace() {
+        *counts.entry(word).or_insert(0) += 1;
+    }
+
+    let mut counts: Vec<_> = counts.into_iter().collect();
+    counts.sort_by_key(|(_, count)| *count);
+
+    for (word, count) in counts {
+        println!("{word}: {count}");
+    }
+}
+
+const INPUT: &str = "the quick brown fox jumps over the lazy dog";
+
+fn unused() {
+    // This function is never called
+}
+
+fn helper(a: i32, b: i32) -> i32 {
+    a + b
+}
+"#;
+
+const NEW: &str = r#"use std::collections::BTreeMap;
+
+fn main() {
+    let mut counts = BTreeMap::new();
+
+    for word in INPUT.split_whitespace() {
+        *counts.entry(word.to_lowercase()).or_insert(0) += 1;
+    }
+
+    let mut counts: Vec<_> = counts.into_iter().collect();
+    counts.sort_by_key(|(_, count)| std::cmp::Reverse(*count));
+
+    for (word, count) in counts {
+        println!("{word}: {count}");
+    }
+}
+
+const INPUT: &str = "The quick brown fox jumps over the lazy dog";
+
+fn helper(a: i64, b: i64) -> i64 {
+    a + b
+}
+"#;
@@ -1,8 +1,9 @@
 use iced::time::milliseconds;
-use iced::widget::{center, column};
+use iced::widget::{button, center, column};
 use iced::{Center, Element, Font};
 
 use iced_palace::widget::diffused_text;
+use iced_palace::widget::diffused_text::Play;
 
 fn main() -> iced::Result {
     iced::run(Example::update, Example::view)
@@ -10,14 +11,21 @@ fn main() -> iced::Result {
 
 struct Example {
     text: String,
+    replays: u64,
 }
 
-#[derive(Debug)]
-enum Message {}
+#[derive(Debug, Clone)]
+enum Message {
+    Replay,
+}
 
 impl Example {
     fn update(&mut self, message: Message) {
-        match message {}
+        match message {
+            Message::Replay => {
+                self.replays += 1;
+            }
+        }
     }
 
     fn view(&self) -> Element<'_, Message> {
@@ -30,7 +38,11 @@ impl Example {
                     .duration(milliseconds(20) * self.text.len() as u32)
                     .font(Font::MONOSPACE)
                     .width(400)
-                    .center()
+                    .center(),
+                diffused_text("Press the button to replay")
+                    .play_on(Play::Signal(self.replays))
+                    .font(Font::MONOSPACE),
+                button("Replay").on_press(Message::Replay),
             ]
             .align_x(Center)
             .spacing(20),
@@ -47,6 +59,7 @@ impl Default for Example {
             then 'real' is simply electrical signals interpreted by your brain.\n\
             — Morpheus, The Matrix"
                 .to_owned(),
+            replays: 0,
         }
     }
 }
@@ -0,0 +1,90 @@
+use iced::widget::{button, center, column, row, text, toggler};
+use iced::{Center, Element, Fill};
+
+use iced_palace::widget::drawer;
+use iced_palace::widget::drawer::Edge;
+
+fn main() -> iced::Result {
+    iced::run(Example::update, Example::view)
+}
+
+#[derive(Default)]
+struct Example {
+    is_open: bool,
+    edge: Edge,
+    notifications: bool,
+    dark_mode: bool,
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    Toggle(bool),
+    EdgeSelected(Edge),
+    NotificationsToggled(bool),
+    DarkModeToggled(bool),
+}
+
+const EDGES: &[(&str, Edge)] = &[
+    ("Left", Edge::Left),
+    ("Right", Edge::Right),
+    ("Top", Edge::Top),
+    ("Bottom", Edge::Bottom),
+];
+
+impl Example {
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Toggle(is_open) => {
+                self.is_open = is_open;
+            }
+            Message::EdgeSelected(edge) => {
+                self.edge = edge;
+            }
+            Message::NotificationsToggled(notifications) => {
+                self.notifications = notifications;
+            }
+            Message::DarkModeToggled(dark_mode) => {
+                self.dark_mode = dark_mode;
+            }
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let content = center(
+            column![
+                row(EDGES.iter().map(|(label, edge)| {
+                    button(*label)
+                        .on_press_maybe(
+                            (*edge != self.edge).then_some(Message::EdgeSelected(*edge)),
+                        )
+                        .into()
+                }))
+                .spacing(10),
+                button("Open settings").on_press(Message::Toggle(true)),
+            ]
+            .spacing(20)
+            .align_x(Center),
+        );
+
+        let panel = column![
+            text("Settings").size(24),
+            toggler(self.notifications)
+                .label("Notifications")
+                .on_toggle(Message::NotificationsToggled),
+            toggler(self.dark_mode)
+                .label("Dark mode")
+                .on_toggle(Message::DarkModeToggled),
+            button("Close").on_press(Message::Toggle(false)),
+        ]
+        .spacing(20)
+        .padding(20)
+        .width(Fill);
+
+        drawer(content, panel)
+            .open(self.is_open)
+            .edge(self.edge)
+            .size(280)
+            .on_toggle(Message::Toggle)
+            .into()
+    }
+}
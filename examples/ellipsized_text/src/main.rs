@@ -1,6 +1,8 @@
-use iced::widget::{center, center_x, column, container, row, text, toggler};
-use iced::{Element, Font};
+use iced::advanced::widget::operate;
+use iced::widget::{button, center, center_x, column, container, row, text, toggler};
+use iced::{Center, Element, Font, Task};
 use iced_palace::widget::ellipsized_text;
+use iced_palace::widget::ellipsized_text::Contents;
 
 fn main() -> iced::Result {
     iced::application(Example::new, Example::update, Example::view).run()
@@ -9,12 +11,15 @@ fn main() -> iced::Result {
 struct Example {
     use_monospace: bool,
     wrap: bool,
+    contents: Option<Contents>,
 }
 
 #[derive(Debug, Clone)]
 enum Message {
     ToggleMonospace(bool),
     ToggleWrap(bool),
+    Inspect,
+    Inspected(Contents),
 }
 
 impl Example {
@@ -22,16 +27,27 @@ impl Example {
         Self {
             use_monospace: true,
             wrap: true,
+            contents: None,
         }
     }
 
-    fn update(&mut self, message: Message) {
+    fn update(&mut self, message: Message) -> Task<Message> {
         match message {
             Message::ToggleMonospace(use_monospace) => {
                 self.use_monospace = use_monospace;
+
+                Task::none()
             }
             Message::ToggleWrap(wrap) => {
                 self.wrap = wrap;
+
+                Task::none()
+            }
+            Message::Inspect => operate(ellipsized_text::contents("quote")).map(Message::Inspected),
+            Message::Inspected(contents) => {
+                self.contents = Some(contents);
+
+                Task::none()
             }
         }
     }
@@ -54,13 +70,26 @@ impl Example {
                 .on_toggle(Message::ToggleMonospace),
             toggler(self.wrap)
                 .label("Wrap")
-                .on_toggle(Message::ToggleWrap)
+                .on_toggle(Message::ToggleWrap),
+            button("Inspect").on_press(Message::Inspect),
+            text(match &self.contents {
+                Some(contents) if contents.is_truncated() => format!(
+                    "Showing {} of {} characters",
+                    contents.displayed.chars().count(),
+                    contents.full.chars().count()
+                ),
+                Some(_) => "Showing the full text".to_owned(),
+                None => "Click the text and copy it to get the full quote".to_owned(),
+            }),
         ]
-        .spacing(20);
+        .spacing(20)
+        .align_y(Center);
 
         column![
             center(
                 ellipsized_text(FROMM)
+                    .id("quote")
+                    .selectable(true)
                     .font(if self.use_monospace {
                         Font::MONOSPACE
                     } else {
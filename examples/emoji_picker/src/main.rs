@@ -0,0 +1,73 @@
+use iced::widget::{button, center, column, container, row, text};
+use iced::{Center, Element};
+
+use iced_palace::widget::emoji_picker;
+
+fn main() -> iced::Result {
+    iced::application(Example::default, Example::update, Example::view).run()
+}
+
+#[derive(Default)]
+struct Example {
+    message: String,
+    recent: Vec<String>,
+    is_open: bool,
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    Picked(String),
+    Toggled(bool),
+    Clear,
+}
+
+impl Example {
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Picked(emoji) => {
+                self.message.push_str(&emoji);
+
+                self.recent.retain(|recent| *recent != emoji);
+                self.recent.insert(0, emoji);
+                self.recent.truncate(16);
+
+                self.is_open = false;
+            }
+            Message::Toggled(is_open) => {
+                self.is_open = is_open;
+            }
+            Message::Clear => {
+                self.message.clear();
+            }
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let picker = emoji_picker(
+            container(text("🙂 Add emoji"))
+                .padding(10)
+                .style(container::rounded_box),
+        )
+        .on_pick(Message::Picked)
+        .recent(&self.recent)
+        .open(self.is_open)
+        .on_toggle(Message::Toggled);
+
+        center(
+            column![
+                text(if self.message.is_empty() {
+                    "Pick some emoji!"
+                } else {
+                    &self.message
+                })
+                .size(24),
+                row![picker, button("Clear").on_press(Message::Clear)]
+                    .spacing(10)
+                    .align_y(Center),
+            ]
+            .spacing(20)
+            .align_x(Center),
+        )
+        .into()
+    }
+}
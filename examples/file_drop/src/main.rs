@@ -0,0 +1,77 @@
+use iced::widget::{center, column, container, row, text};
+use iced::{Element, Fill};
+
+use iced_palace::widget::file_drop;
+
+use std::path::PathBuf;
+
+fn main() -> iced::Result {
+    iced::run(Example::update, Example::view)
+}
+
+#[derive(Default)]
+struct Example {
+    images: Vec<PathBuf>,
+    files: Vec<PathBuf>,
+    rejected: Vec<PathBuf>,
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    ImagesDropped(Vec<PathBuf>),
+    FilesDropped(Vec<PathBuf>),
+    Rejected(Vec<PathBuf>),
+}
+
+impl Example {
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::ImagesDropped(paths) => {
+                self.images.extend(paths);
+            }
+            Message::FilesDropped(paths) => {
+                self.files.extend(paths);
+            }
+            Message::Rejected(paths) => {
+                self.rejected = paths;
+            }
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let images = file_drop(zone("Drop images here", &self.images))
+            .extensions(["png", "jpg", "jpeg", "gif"])
+            .on_drop(Message::ImagesDropped)
+            .on_reject(Message::Rejected);
+
+        let files =
+            file_drop(zone("Drop anything here", &self.files)).on_drop(Message::FilesDropped);
+
+        let rejected = if self.rejected.is_empty() {
+            text("")
+        } else {
+            text!("Rejected {} file(s): not an image!", self.rejected.len())
+        };
+
+        column![row![images, files].spacing(20).height(Fill), rejected]
+            .spacing(10)
+            .padding(20)
+            .into()
+    }
+}
+
+fn zone<'a>(label: &'a str, paths: &'a [PathBuf]) -> Element<'a, Message> {
+    let paths = column(
+        paths
+            .iter()
+            .map(|path| text(path.display().to_string()).size(12).into()),
+    )
+    .spacing(5);
+
+    container(column![center(text(label).size(20)).height(100), paths].spacing(10))
+        .padding(10)
+        .width(Fill)
+        .height(Fill)
+        .style(container::bordered_box)
+        .into()
+}
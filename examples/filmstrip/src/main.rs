@@ -0,0 +1,79 @@
+use iced::widget::{center, column, container, text};
+use iced::{Center, Color, Element, Fill};
+
+use iced_palace::widget::filmstrip;
+
+fn main() -> iced::Result {
+    iced::application(Example::new, Example::update, Example::view).run()
+}
+
+struct Example {
+    pages: Vec<Page>,
+    selected: Option<usize>,
+}
+
+struct Page {
+    number: usize,
+    color: Color,
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    Selected(usize),
+}
+
+impl Example {
+    fn new() -> Self {
+        let pages = (1..=500)
+            .map(|number| {
+                let hue = (number * 37 % 360) as f32 / 360.0;
+
+                Page {
+                    number,
+                    color: Color::from_rgb(hue, 0.6, 1.0 - hue * 0.5),
+                }
+            })
+            .collect();
+
+        Self {
+            pages,
+            selected: Some(0),
+        }
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Selected(index) => {
+                self.selected = Some(index);
+            }
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let page: Element<'_, _> = match self.selected.and_then(|index| self.pages.get(index)) {
+            Some(page) => thumbnail(page, 48),
+            None => text("No page selected").into(),
+        };
+
+        let strip = filmstrip(&self.pages, |page| thumbnail(page, 16))
+            .selected(self.selected)
+            .on_select(Message::Selected);
+
+        column![
+            center(container(page).width(300).height(400)).height(Fill),
+            text("Click the strip and use the arrow keys to navigate").size(12),
+            strip,
+        ]
+        .align_x(Center)
+        .spacing(10)
+        .into()
+    }
+}
+
+fn thumbnail(page: &Page, size: u32) -> Element<'_, Message> {
+    let color = page.color;
+
+    center(text(page.number).size(size).color(Color::WHITE))
+        .style(move |_theme| container::background(color))
+        .into()
+}
@@ -0,0 +1,116 @@
+use iced::alignment::Vertical;
+use iced::widget::{button, column, container, row, scrollable, text};
+use iced::{Element, Fill};
+use iced_palace::widget::flow;
+use iced_palace::widget::flow::Justify;
+
+fn main() -> iced::Result {
+    iced::application(Example::new, Example::update, Example::view).run()
+}
+
+struct Example {
+    justify: Justify,
+    align: Vertical,
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    Justify(Justify),
+    Align(Vertical),
+}
+
+impl Example {
+    fn new() -> Self {
+        Self {
+            justify: Justify::Start,
+            align: Vertical::Center,
+        }
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Justify(justify) => {
+                self.justify = justify;
+            }
+            Message::Align(align) => {
+                self.align = align;
+            }
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        const TAGS: &[&str] = &[
+            "rust",
+            "gui",
+            "iced",
+            "widgets",
+            "layout",
+            "elm",
+            "reactive",
+            "wgpu",
+            "tiny-skia",
+            "cross-platform",
+            "async",
+            "retained",
+            "themes",
+            "animations",
+            "text",
+            "canvas",
+            "accessibility",
+            "desktop",
+            "web",
+            "wasm",
+        ];
+
+        let justify = flow(
+            [
+                ("Start", Justify::Start),
+                ("Center", Justify::Center),
+                ("End", Justify::End),
+                ("Space between", Justify::SpaceBetween),
+                ("Space around", Justify::SpaceAround),
+                ("Space evenly", Justify::SpaceEvenly),
+            ]
+            .into_iter()
+            .map(|(label, justify)| {
+                toggle(label, self.justify == justify, Message::Justify(justify))
+            }),
+        )
+        .spacing(5);
+
+        let align = row([
+            ("Top", Vertical::Top),
+            ("Center", Vertical::Center),
+            ("Bottom", Vertical::Bottom),
+        ]
+        .into_iter()
+        .map(|(label, align)| toggle(label, self.align == align, Message::Align(align))))
+        .spacing(5);
+
+        let tags = flow(TAGS.iter().enumerate().map(|(i, tag)| {
+            container(text(*tag).size(12 + (i % 3) as u32 * 4))
+                .padding([4, 10])
+                .style(container::rounded_box)
+                .into()
+        }))
+        .spacing(8)
+        .row_spacing(10)
+        .justify(self.justify)
+        .align_y(self.align);
+
+        scrollable(column![justify, align, tags].spacing(20).padding(20))
+            .height(Fill)
+            .into()
+    }
+}
+
+fn toggle(label: &str, active: bool, on_press: Message) -> Element<'_, Message> {
+    button(text(label).size(14))
+        .style(if active {
+            button::primary
+        } else {
+            button::secondary
+        })
+        .on_press(on_press)
+        .into()
+}
@@ -0,0 +1,113 @@
+use iced::widget::{center, column, container, row, slider, text};
+use iced::{Color, Element, Fill, Radians, gradient};
+
+use iced_palace::widget::gradient_editor;
+
+fn main() -> iced::Result {
+    iced::application(Example::new, Example::update, Example::view).run()
+}
+
+struct Example {
+    stops: Vec<(f32, Color)>,
+    selected: Option<usize>,
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    StopsChanged(Vec<(f32, Color)>),
+    StopSelected(usize),
+    ChannelChanged(usize, f32),
+}
+
+impl Example {
+    fn new() -> Self {
+        Self {
+            stops: vec![
+                (0.0, Color::from_rgb8(0x23, 0x15, 0x57)),
+                (0.45, Color::from_rgb8(0x44, 0x10, 0x7a)),
+                (0.75, Color::from_rgb8(0xff, 0x13, 0x61)),
+                (1.0, Color::from_rgb8(0xff, 0xf8, 0x00)),
+            ],
+            selected: None,
+        }
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::StopsChanged(stops) => {
+                self.stops = stops;
+                self.selected = self.selected.filter(|index| *index < self.stops.len());
+            }
+            Message::StopSelected(index) => {
+                self.selected = Some(index);
+            }
+            Message::ChannelChanged(channel, value) => {
+                let Some((_, color)) = self.selected.and_then(|index| self.stops.get_mut(index))
+                else {
+                    return;
+                };
+
+                match channel {
+                    0 => color.r = value,
+                    1 => color.g = value,
+                    _ => color.b = value,
+                }
+            }
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let editor = gradient_editor(&self.stops, Message::StopsChanged)
+            .on_select(Message::StopSelected)
+            .selected(self.selected)
+            .height(32);
+
+        let color = match self.selected.and_then(|index| self.stops.get(index)) {
+            Some((offset, color)) => {
+                let channel = |label, channel, value| {
+                    row![
+                        text(label).width(20),
+                        slider(0.0..=1.0, value, move |value| {
+                            Message::ChannelChanged(channel, value)
+                        })
+                        .step(0.01),
+                    ]
+                    .spacing(10)
+                };
+
+                column![
+                    text!("Stop at {:.0}%", offset * 100.0),
+                    channel("R", 0, color.r),
+                    channel("G", 1, color.g),
+                    channel("B", 2, color.b),
+                ]
+                .spacing(10)
+            }
+            None => column![text("Grab a stop to edit its color")],
+        };
+
+        let preview = container("").width(Fill).height(120).style(|_| {
+            container::Style::default().background(
+                gradient::Linear::new(Radians::PI / 4.0).add_stops(self.stops.iter().map(
+                    |(offset, color)| gradient::ColorStop {
+                        offset: *offset,
+                        color: *color,
+                    },
+                )),
+            )
+        });
+
+        center(
+            column![
+                editor,
+                text("Double-click to add a stop, drag a stop away to remove it").size(14),
+                color,
+                preview,
+            ]
+            .spacing(20)
+            .max_width(500),
+        )
+        .padding(20)
+        .into()
+    }
+}
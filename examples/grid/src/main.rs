@@ -0,0 +1,71 @@
+use iced::widget::{center, container, scrollable, text};
+use iced::{Element, Fill, Theme};
+use iced_palace::widget::grid::{Placement, Template};
+use iced_palace::widget::{Grid, grid};
+
+fn main() -> iced::Result {
+    iced::run(Example::update, Example::view)
+}
+
+#[derive(Default)]
+struct Example;
+
+#[derive(Debug, Clone)]
+enum Message {}
+
+impl Example {
+    fn update(&mut self, message: Message) {
+        match message {}
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let cards = grid((1..=9).map(|i| card(format!("Card {i}"), 100)))
+            .columns([Fill, Fill, Fill])
+            .place(Placement::cell(0, 0).span(2, 2), card("Featured", 210))
+            .spacing(10)
+            .breakpoint(
+                1000,
+                Template::new().columns([Fill, Fill, Fill, Fill, Fill]),
+            );
+
+        let layout = Grid::new()
+            .areas(["header", "main", "sidebar", "footer"])
+            .breakpoint(
+                700,
+                Template::new().columns([200.into(), Fill]).areas([
+                    "header header",
+                    "sidebar main",
+                    "footer footer",
+                ]),
+            )
+            .place(Placement::area("header"), panel("Header"))
+            .place(Placement::area("sidebar"), panel("Sidebar").height(Fill))
+            .place(Placement::area("main"), cards)
+            .place(Placement::area("footer"), panel("Footer"))
+            .spacing(10)
+            .padding(10);
+
+        scrollable(layout).into()
+    }
+}
+
+fn panel(label: &str) -> container::Container<'_, Message> {
+    container(text(label).size(20))
+        .padding(20)
+        .width(Fill)
+        .style(container::rounded_box)
+}
+
+fn card<'a>(label: impl text::IntoFragment<'a>, height: u32) -> Element<'a, Message> {
+    center(text(label))
+        .height(height)
+        .style(|theme: &Theme| {
+            let palette = theme.extended_palette();
+
+            container::Style::default()
+                .background(palette.primary.weak.color)
+                .color(palette.primary.weak.text)
+                .border(iced::border::rounded(5))
+        })
+        .into()
+}
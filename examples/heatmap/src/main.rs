@@ -0,0 +1,108 @@
+use iced::widget::{column, pick_list, row, text, toggler};
+use iced::{Element, Fill, Theme};
+
+use iced_palace::widget::heatmap;
+
+const DAYS: [&str; 7] = ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"];
+const HOURS: usize = 24;
+
+fn main() -> iced::Result {
+    iced::application(Example::new, Example::update, Example::view)
+        .theme(Example::theme)
+        .run()
+}
+
+struct Example {
+    load: Vec<f32>,
+    selected: Option<(usize, usize)>,
+    diverging: bool,
+    theme: Option<Theme>,
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    CellClicked(usize, usize),
+    DivergingToggled(bool),
+    ThemeSelected(Theme),
+}
+
+impl Example {
+    fn new() -> Self {
+        // A made-up weekly load, busier during working hours
+        let load = (0..DAYS.len() * HOURS)
+            .map(|i| {
+                let (day, hour) = (i / HOURS, (i % HOURS) as f32);
+                let daytime = (-(hour - 14.0).powi(2) / 18.0).exp();
+                let weekday = if day < 5 { 1.0 } else { 0.4 };
+                let noise = ((i * 7919) % 13) as f32 / 13.0;
+
+                (daytime * weekday * 80.0 + noise * 20.0).round()
+            })
+            .collect();
+
+        Self {
+            load,
+            selected: None,
+            diverging: false,
+            theme: None,
+        }
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::CellClicked(row, column) => {
+                self.selected = Some((row, column));
+            }
+            Message::DivergingToggled(diverging) => {
+                self.diverging = diverging;
+            }
+            Message::ThemeSelected(theme) => {
+                self.theme = Some(theme);
+            }
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let controls = row![
+            pick_list(Theme::ALL, self.theme.as_ref(), Message::ThemeSelected)
+                .placeholder("System theme"),
+            toggler(self.diverging)
+                .label("Diverging scale")
+                .on_toggle(Message::DivergingToggled),
+        ]
+        .spacing(20);
+
+        let map = heatmap(DAYS.len(), HOURS, &self.load)
+            .row_labels(DAYS)
+            .column_labels((0..HOURS).map(|hour| format!("{hour:02}")))
+            .range(0.0..=100.0)
+            .tooltip(|day, hour, load| format!("{} {hour:02}:00 — {load}%", DAYS[day]))
+            .on_cell_click(Message::CellClicked)
+            .text_size(12)
+            .spacing(2)
+            .style(if self.diverging {
+                heatmap::diverging
+            } else {
+                heatmap::default
+            });
+
+        let status = match self.selected {
+            Some((day, hour)) => text!(
+                "{} at {hour:02}:00 had a load of {}%",
+                DAYS[day],
+                self.load[day * HOURS + hour]
+            ),
+            None => text("Click a cell to select it"),
+        };
+
+        column![controls, map, status]
+            .height(Fill)
+            .spacing(20)
+            .padding(20)
+            .into()
+    }
+
+    fn theme(&self) -> Option<Theme> {
+        self.theme.clone()
+    }
+}
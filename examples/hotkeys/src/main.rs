@@ -0,0 +1,63 @@
+use iced::widget::{center, column, text, text_input};
+use iced::{Center, Element};
+
+use iced_palace::widget::hotkeys;
+
+fn main() -> iced::Result {
+    iced::run(Example::update, Example::view)
+}
+
+#[derive(Default)]
+struct Example {
+    log: Vec<&'static str>,
+    draft: String,
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    Pressed(&'static str),
+    DraftChanged(String),
+}
+
+const BINDINGS: &[&str] = &["mod+s", "mod+shift+p", "?", "g g", "g i", "escape"];
+
+impl Example {
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Pressed(shortcut) => {
+                self.log.push(shortcut);
+            }
+            Message::DraftChanged(draft) => {
+                self.draft = draft;
+            }
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let content = center(
+            column![
+                text!("Try: {}", BINDINGS.join(", ")),
+                text_input("Typing here won't trigger plain shortcuts", &self.draft)
+                    .on_input(Message::DraftChanged)
+                    .width(400),
+                column(
+                    self.log
+                        .iter()
+                        .rev()
+                        .take(10)
+                        .map(|shortcut| text!("Pressed {shortcut}").into()),
+                ),
+            ]
+            .spacing(20)
+            .align_x(Center),
+        );
+
+        hotkeys(
+            BINDINGS
+                .iter()
+                .map(|shortcut| (*shortcut, Message::Pressed(shortcut))),
+            content,
+        )
+        .into()
+    }
+}
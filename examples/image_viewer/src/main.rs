@@ -0,0 +1,85 @@
+use iced::widget::{button, column, image, row, text};
+use iced::{Center, ContentFit, Degrees, Element};
+
+use iced_palace::widget::image_viewer;
+
+fn main() -> iced::Result {
+    iced::run(Example::update, Example::view)
+}
+
+struct Example {
+    image: image::Handle,
+    rotation: f32,
+    content_fit: ContentFit,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Message {
+    RotateLeft,
+    RotateRight,
+    Fit(ContentFit),
+}
+
+impl Example {
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::RotateLeft => {
+                self.rotation = (self.rotation - 90.0).rem_euclid(360.0);
+            }
+            Message::RotateRight => {
+                self.rotation = (self.rotation + 90.0).rem_euclid(360.0);
+            }
+            Message::Fit(content_fit) => {
+                self.content_fit = content_fit;
+            }
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let viewer = image_viewer(self.image.clone())
+            .rotation(Degrees(self.rotation))
+            .content_fit(self.content_fit);
+
+        let controls = row![
+            button("⟲").on_press(Message::RotateLeft),
+            button("⟳").on_press(Message::RotateRight),
+            button("Fit").on_press(Message::Fit(ContentFit::Contain)),
+            button("Fill").on_press(Message::Fit(ContentFit::Cover)),
+            text("Scroll or pinch to zoom, drag to pan, double-click to reset").size(14),
+        ]
+        .spacing(10)
+        .align_y(Center);
+
+        column![viewer, controls].padding(10).spacing(10).into()
+    }
+}
+
+impl Default for Example {
+    fn default() -> Self {
+        Self {
+            image: checkerboard(640, 400),
+            rotation: 0.0,
+            content_fit: ContentFit::Contain,
+        }
+    }
+}
+
+fn checkerboard(width: u32, height: u32) -> image::Handle {
+    let pixels = (0..height)
+        .flat_map(|y| (0..width).map(move |x| (x, y)))
+        .flat_map(|(x, y)| {
+            let red = (x * 255 / width) as u8;
+            let blue = (y * 255 / height) as u8;
+            let shade = if (x / 40 + y / 40) % 2 == 0 { 255 } else { 160 };
+
+            [
+                (red as u32 * shade / 255) as u8,
+                (shade / 2) as u8,
+                (blue as u32 * shade / 255) as u8,
+                255,
+            ]
+        })
+        .collect::<Vec<u8>>();
+
+    image::Handle::from_rgba(width, height, pixels)
+}
@@ -0,0 +1,104 @@
+use iced::widget::{center, column, scrollable, text};
+use iced::{Element, Fill};
+
+use iced_palace::widget::kanban;
+use iced_palace::widget::kanban::Move;
+
+fn main() -> iced::Result {
+    iced::application(Example::new, Example::update, Example::view).run()
+}
+
+struct Example {
+    columns: Vec<Column>,
+    last_move: Option<String>,
+}
+
+struct Column {
+    title: &'static str,
+    limit: Option<usize>,
+    cards: Vec<&'static str>,
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    Moved(Move),
+}
+
+impl Example {
+    fn new() -> Self {
+        Self {
+            columns: vec![
+                Column {
+                    title: "Backlog",
+                    limit: None,
+                    cards: vec![
+                        "Write the release notes",
+                        "Profile the layout pass",
+                        "Support right-to-left text",
+                        "Add a dark theme toggle",
+                    ],
+                },
+                Column {
+                    title: "In progress",
+                    limit: Some(2),
+                    cards: vec!["Fix the flaky animation test", "Review the kanban widget"],
+                },
+                Column {
+                    title: "Done",
+                    limit: None,
+                    cards: vec!["Set up continuous integration"],
+                },
+            ],
+            last_move: None,
+        }
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Moved(Move {
+                card,
+                from,
+                to,
+                index,
+            }) => {
+                let card = self.columns[from].cards.remove(card);
+                self.columns[to].cards.insert(index, card);
+
+                self.last_move = Some(format!("Moved \"{card}\" to {}", self.columns[to].title));
+            }
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let board = kanban(self.columns.iter().map(|column| {
+            let title = match column.limit {
+                Some(limit) => format!("{} ({}/{limit})", column.title, column.cards.len()),
+                None => format!("{} ({})", column.title, column.cards.len()),
+            };
+
+            let column_ = kanban::column(
+                text(title).size(18),
+                column.cards.iter().map(|card| text(*card).into()),
+            );
+
+            match column.limit {
+                Some(limit) => column_.limit(limit),
+                None => column_,
+            }
+        }))
+        .on_move(Message::Moved);
+
+        center(
+            column![
+                scrollable(board).direction(scrollable::Direction::Horizontal(
+                    scrollable::Scrollbar::default()
+                )),
+                text(self.last_move.as_deref().unwrap_or("Drag the cards around")),
+            ]
+            .spacing(20)
+            .width(Fill),
+        )
+        .padding(20)
+        .into()
+    }
+}
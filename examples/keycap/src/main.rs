@@ -0,0 +1,55 @@
+use iced::widget::{center, column, container, row, text};
+use iced::{Element, Theme};
+
+use iced_palace::widget::{keycap, shortcut_hint};
+
+fn main() -> iced::Result {
+    iced::run(Example::update, Example::view)
+}
+
+#[derive(Default)]
+struct Example;
+
+#[derive(Debug, Clone)]
+enum Message {}
+
+impl Example {
+    fn update(&mut self, message: Message) {
+        match message {}
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let palette = container(
+            column![
+                shortcut_hint("Open file", "mod+o"),
+                shortcut_hint("Command palette", "mod+shift+p"),
+                shortcut_hint("Go to definition", "f12"),
+                shortcut_hint("Move line up", "alt+up"),
+                shortcut_hint("Go to top", "g g"),
+                shortcut_hint("Zoom in", "mod++"),
+            ]
+            .spacing(12)
+            .width(320),
+        )
+        .padding(20)
+        .style(|theme: &Theme| {
+            container::rounded_box(theme).shadow(iced::Shadow {
+                color: iced::Color::BLACK.scale_alpha(0.2),
+                offset: iced::Vector::new(0.0, 4.0),
+                blur_radius: 16.0,
+            })
+        });
+
+        center(
+            column![
+                row![text("Search anything with"), keycap("mod+k")]
+                    .spacing(10)
+                    .align_y(iced::Center),
+                palette,
+            ]
+            .spacing(30)
+            .align_x(iced::Center),
+        )
+        .into()
+    }
+}
@@ -0,0 +1,151 @@
+use iced::time::{Duration, Instant};
+use iced::widget::{button, column, row, text, text_input};
+use iced::window;
+use iced::{Center, Element, Font, Subscription};
+
+use iced_palace::widget::log_view;
+use iced_palace::widget::log_view::{Level, Log};
+
+fn main() -> iced::Result {
+    iced::application(Example::default, Example::update, Example::view)
+        .subscription(Example::subscription)
+        .run()
+}
+
+struct Example {
+    log: Log,
+    level: Level,
+    search: String,
+    is_running: bool,
+    last_line: Instant,
+    lines: usize,
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    Tick(Instant),
+    LevelSelected(Level),
+    SearchChanged(String),
+    ToggleRunning,
+    Clear,
+}
+
+const LEVELS: &[(&str, Level)] = &[
+    ("Trace", Level::Trace),
+    ("Debug", Level::Debug),
+    ("Info", Level::Info),
+    ("Warning", Level::Warning),
+    ("Error", Level::Error),
+];
+
+const INTERVAL: Duration = Duration::from_millis(100);
+
+impl Example {
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Tick(now) => {
+                while now - self.last_line >= INTERVAL {
+                    self.last_line += INTERVAL;
+                    self.lines += 1;
+
+                    let (level, message) = line(self.lines);
+                    self.log.push(level, message);
+                }
+            }
+            Message::LevelSelected(level) => {
+                self.level = level;
+            }
+            Message::SearchChanged(search) => {
+                self.search = search;
+            }
+            Message::ToggleRunning => {
+                self.is_running = !self.is_running;
+                self.last_line = Instant::now();
+            }
+            Message::Clear => {
+                self.log.clear();
+            }
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let levels = row(LEVELS.iter().map(|(label, level)| {
+            button(text(*label).size(14))
+                .on_press_maybe((*level != self.level).then_some(Message::LevelSelected(*level)))
+                .into()
+        }))
+        .spacing(5);
+
+        let controls = row![
+            levels,
+            text_input("Search...", &self.search)
+                .on_input(Message::SearchChanged)
+                .width(200),
+            button(if self.is_running { "Pause" } else { "Resume" })
+                .on_press(Message::ToggleRunning),
+            button("Clear").on_press(Message::Clear),
+            text!("{} lines", self.log.len()),
+        ]
+        .spacing(10)
+        .align_y(Center);
+
+        column![
+            controls,
+            log_view(&self.log)
+                .level(self.level)
+                .search(&self.search)
+                .font(Font::MONOSPACE)
+                .text_size(14),
+        ]
+        .spacing(10)
+        .padding(10)
+        .into()
+    }
+
+    fn subscription(&self) -> Subscription<Message> {
+        if self.is_running {
+            window::frames().map(Message::Tick)
+        } else {
+            Subscription::none()
+        }
+    }
+}
+
+impl Default for Example {
+    fn default() -> Self {
+        Self {
+            log: Log::new(5_000),
+            level: Level::Trace,
+            search: String::new(),
+            is_running: true,
+            last_line: Instant::now(),
+            lines: 0,
+        }
+    }
+}
+
+fn line(n: usize) -> (Level, String) {
+    const COMPONENTS: &[&str] = &["http", "db", "cache", "scheduler", "auth"];
+
+    let component = COMPONENTS[n * 7 % COMPONENTS.len()];
+
+    match n * 13 % 20 {
+        0 => (
+            Level::Error,
+            format!("[{component}] request #{n} failed: connection reset by peer"),
+        ),
+        1 | 2 => (
+            Level::Warning,
+            format!("[{component}] request #{n} took {}ms", 500 + n % 700),
+        ),
+        3..=8 => (
+            Level::Debug,
+            format!("[{component}] cache lookup for key {:x}", n * 2654435761),
+        ),
+        9..=11 => (Level::Trace, format!("[{component}] tick {n}")),
+        _ => (
+            Level::Info,
+            format!("[{component}] handled request #{n} in {}ms", 5 + n % 90),
+        ),
+    }
+}
@@ -0,0 +1,86 @@
+use iced::widget::{center, column, pick_list, row, slider, text};
+use iced::{Center, Element, Fill};
+
+use iced_palace::widget::magnifier;
+use iced_palace::widget::magnifier::Shape;
+
+fn main() -> iced::Result {
+    iced::run(Example::update, Example::view)
+}
+
+struct Example {
+    shape: Shape,
+    zoom: f32,
+}
+
+impl Default for Example {
+    fn default() -> Self {
+        Self {
+            shape: Shape::Circle,
+            zoom: 2.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    ShapeSelected(&'static str),
+    ZoomChanged(f32),
+}
+
+const SHAPES: [&str; 2] = ["Circle", "Rectangle"];
+
+impl Example {
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::ShapeSelected(shape) => {
+                self.shape = if shape == "Circle" {
+                    Shape::Circle
+                } else {
+                    Shape::Rectangle
+                };
+            }
+            Message::ZoomChanged(zoom) => {
+                self.zoom = zoom;
+            }
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let controls = row![
+            pick_list(
+                SHAPES,
+                Some(match self.shape {
+                    Shape::Circle => "Circle",
+                    Shape::Rectangle => "Rectangle",
+                }),
+                Message::ShapeSelected,
+            ),
+            text!("{:.1}×", self.zoom),
+            slider(2.0..=4.0, self.zoom, Message::ZoomChanged).step(0.5),
+        ]
+        .spacing(20)
+        .align_y(Center);
+
+        let fine_print = column((1..=12).map(|i| {
+            text!(
+                "{i}. The quick brown fox jumps over the lazy dog — tiny print worth zooming into."
+            )
+            .size(9)
+            .into()
+        }))
+        .spacing(4);
+
+        column![
+            controls,
+            text("Hold Alt and hover the text below").size(14),
+            magnifier(center(fine_print))
+                .shape(self.shape)
+                .zoom(self.zoom),
+        ]
+        .spacing(20)
+        .padding(20)
+        .height(Fill)
+        .into()
+    }
+}
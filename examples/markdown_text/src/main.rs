@@ -0,0 +1,87 @@
+use iced::time::Duration;
+use iced::widget::{button, column, container, row, scrollable, text};
+use iced::{Element, Fill};
+
+use iced_palace::widget::markdown_text;
+
+fn main() -> iced::Result {
+    iced::run(Example::update, Example::view)
+}
+
+#[derive(Default)]
+struct Example {
+    document: usize,
+    clicked: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    Show(usize),
+    LinkClicked(String),
+}
+
+const DOCUMENTS: &[(&str, &str)] = &[
+    (
+        "Introduction",
+        "**iced** is a cross-platform GUI library for Rust, focused on \
+        *simplicity* and _type-safety_. Inspired by [Elm](https://elm-lang.org).
+
+        Widgets are plain structs built with `builder` methods:
+
+        - `column!` and `row!` for layout
+        - **Bold** and *italic* text via font weights
+        - Links like [the book](https://book.iced.rs) are clickable",
+    ),
+    (
+        "Getting started",
+        "Add the crate to your manifest and write three things:
+
+        1. A `State` that holds your data
+        2. An `update` function that *changes* it
+        3. A `view` function that **describes** it
+
+        Then run `cargo run` and enjoy! Markers that are never closed, \
+        like this lone * or ** pair, are shown as they are.",
+    ),
+];
+
+impl Example {
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Show(document) => {
+                self.document = document;
+            }
+            Message::LinkClicked(url) => {
+                self.clicked = Some(url);
+            }
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let tabs = row(DOCUMENTS.iter().enumerate().map(|(i, (title, _))| {
+            button(*title)
+                .on_press_maybe((i != self.document).then_some(Message::Show(i)))
+                .into()
+        }))
+        .spacing(10);
+
+        let document = markdown_text(DOCUMENTS[self.document].1)
+            .reveal(Duration::from_millis(15))
+            .on_link(Message::LinkClicked)
+            .size(18);
+
+        let clicked = text(match &self.clicked {
+            Some(url) => format!("Clicked: {url}"),
+            None => String::from("Click a link!"),
+        });
+
+        column![
+            tabs,
+            scrollable(container(document).padding(10)).height(Fill),
+            clicked
+        ]
+        .spacing(10)
+        .padding(10)
+        .into()
+    }
+}
@@ -0,0 +1,96 @@
+use iced::widget::{center, column, text};
+use iced::{Element, Fill};
+
+use iced_palace::widget::menu_bar;
+use iced_palace::widget::menu_bar::Item;
+
+fn main() -> iced::Result {
+    iced::run(Example::update, Example::view)
+}
+
+#[derive(Default)]
+struct Example {
+    last_action: Option<&'static str>,
+    word_wrap: bool,
+    line_numbers: bool,
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    Action(&'static str),
+    ToggleWordWrap,
+    ToggleLineNumbers,
+}
+
+impl Example {
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Action(action) => {
+                self.last_action = Some(action);
+            }
+            Message::ToggleWordWrap => {
+                self.word_wrap = !self.word_wrap;
+            }
+            Message::ToggleLineNumbers => {
+                self.line_numbers = !self.line_numbers;
+            }
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let action = |label| Item::new(label).on_press(Message::Action(label));
+
+        let menu = menu_bar([
+            Item::submenu(
+                "File",
+                [
+                    action("New").shortcut("Ctrl+N"),
+                    action("Open...").shortcut("Ctrl+O"),
+                    Item::submenu(
+                        "Open Recent",
+                        [
+                            action("notes.md"),
+                            action("main.rs"),
+                            Item::submenu("More", [action("Cargo.toml"), action("README.md")]),
+                        ],
+                    ),
+                    Item::separator(),
+                    action("Save").shortcut("Ctrl+S"),
+                    Item::new("Save As...").shortcut("Ctrl+Shift+S"),
+                    Item::separator(),
+                    action("Quit").shortcut("Ctrl+Q"),
+                ],
+            ),
+            Item::submenu(
+                "Edit",
+                [
+                    action("Undo").shortcut("Ctrl+Z"),
+                    action("Redo").shortcut("Ctrl+Y"),
+                    Item::separator(),
+                    action("Cut").shortcut("Ctrl+X"),
+                    action("Copy").shortcut("Ctrl+C"),
+                    action("Paste").shortcut("Ctrl+V"),
+                ],
+            ),
+            Item::submenu(
+                "View",
+                [
+                    Item::checkbox("Word Wrap", self.word_wrap).on_press(Message::ToggleWordWrap),
+                    Item::checkbox("Line Numbers", self.line_numbers)
+                        .on_press(Message::ToggleLineNumbers),
+                ],
+            ),
+            Item::submenu("Help", [action("About")]),
+        ]);
+
+        column![
+            menu,
+            center(text(match self.last_action {
+                Some(action) => format!("Last action: {action}"),
+                None => "Pick something from the menu".to_owned(),
+            }))
+            .height(Fill),
+        ]
+        .into()
+    }
+}
@@ -0,0 +1,114 @@
+use iced::Element;
+use iced::widget::{button, center, column, container, row, text, text_input};
+
+use iced_palace::widget::modal;
+use iced_palace::widget::modal::Dialogs;
+
+fn main() -> iced::Result {
+    iced::run(Example::update, Example::view)
+}
+
+#[derive(Default)]
+struct Example {
+    dialogs: Dialogs<Dialog>,
+    name: String,
+    files: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+enum Dialog {
+    NewFile,
+    ConfirmDelete,
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    Open(Dialog),
+    Dismiss,
+    NameChanged(String),
+    Create,
+    Delete,
+}
+
+impl Example {
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Open(dialog) => {
+                self.dialogs.open(dialog);
+            }
+            Message::Dismiss => {
+                let _ = self.dialogs.close();
+            }
+            Message::NameChanged(name) => {
+                self.name = name;
+            }
+            Message::Create => {
+                if !self.name.is_empty() {
+                    self.files.push(std::mem::take(&mut self.name));
+                    let _ = self.dialogs.close();
+                }
+            }
+            Message::Delete => {
+                self.files.clear();
+                self.dialogs.close_all();
+            }
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let base = center(
+            column![
+                text!("{} files", self.files.len()),
+                button("New file...").on_press(Message::Open(Dialog::NewFile)),
+            ]
+            .spacing(10),
+        );
+
+        modal(base, self.dialogs.iter().map(|dialog| self.dialog(dialog)))
+            .on_dismiss(Message::Dismiss)
+            .into()
+    }
+
+    fn dialog(&self, dialog: &Dialog) -> Element<'_, Message> {
+        let content: Element<'_, Message> = match dialog {
+            Dialog::NewFile => column![
+                text("New file").size(20),
+                text_input("Name", &self.name)
+                    .on_input(Message::NameChanged)
+                    .on_submit(Message::Create),
+                row![
+                    button("Delete all...")
+                        .style(button::danger)
+                        .on_press(Message::Open(Dialog::ConfirmDelete)),
+                    button("Cancel")
+                        .style(button::secondary)
+                        .on_press(Message::Dismiss),
+                    button("Create").on_press(Message::Create),
+                ]
+                .spacing(10),
+            ]
+            .spacing(10)
+            .into(),
+            Dialog::ConfirmDelete => column![
+                text("Delete every file?").size(20),
+                row![
+                    button("Cancel")
+                        .style(button::secondary)
+                        .on_press(Message::Dismiss),
+                    button("Delete")
+                        .style(button::danger)
+                        .on_press(Message::Delete),
+                ]
+                .spacing(10),
+            ]
+            .spacing(10)
+            .into(),
+        };
+
+        container(content)
+            .width(300)
+            .padding(20)
+            .style(container::rounded_box)
+            .into()
+    }
+}
@@ -0,0 +1,61 @@
+use iced::widget::{center, column, slider, stack, text};
+use iced::{Center, Element};
+
+use iced_palace::debug::time;
+use iced_palace::widget::perf_hud;
+
+fn main() -> iced::Result {
+    iced::application(Example::new, Example::update, Example::view).run()
+}
+
+struct Example {
+    work: u32,
+    primes: usize,
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    WorkChanged(u32),
+}
+
+impl Example {
+    fn new() -> Self {
+        Self {
+            work: 10_000,
+            primes: count_primes(10_000),
+        }
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::WorkChanged(work) => {
+                self.work = work;
+                self.primes = count_primes(work);
+
+                perf_hud::counter("primes", self.primes as f64);
+            }
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let content = center(
+            column![
+                text("Press F12 to toggle the performance HUD"),
+                text!("There are {} primes below {}", self.primes, self.work),
+                slider(1_000..=2_000_000, self.work, Message::WorkChanged),
+            ]
+            .spacing(10)
+            .align_x(Center)
+            .max_width(400),
+        );
+
+        stack![content, perf_hud().visible(true)].into()
+    }
+}
+
+#[time]
+fn count_primes(limit: u32) -> usize {
+    (2..limit)
+        .filter(|n| (2..).take_while(|d| d * d <= *n).all(|d| n % d != 0))
+        .count()
+}
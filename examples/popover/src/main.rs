@@ -0,0 +1,123 @@
+use iced::widget::{button, center, column, container, pick_list, row, text, toggler};
+use iced::{Element, Fill};
+
+use iced_palace::widget::popover;
+use iced_palace::widget::popover::Placement;
+
+fn main() -> iced::Result {
+    iced::application(Example::default, Example::update, Example::view).run()
+}
+
+#[derive(Default)]
+struct Example {
+    placement: Placement,
+    is_menu_open: bool,
+    notifications: bool,
+    archived: usize,
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    PlacementSelected(Placement),
+    MenuToggled(bool),
+    NotificationsToggled(bool),
+    Archive,
+}
+
+const PLACEMENTS: [Placement; 4] = [
+    Placement::Top,
+    Placement::Bottom,
+    Placement::Left,
+    Placement::Right,
+];
+
+impl Example {
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::PlacementSelected(placement) => {
+                self.placement = placement;
+            }
+            Message::MenuToggled(is_open) => {
+                self.is_menu_open = is_open;
+            }
+            Message::NotificationsToggled(notifications) => {
+                self.notifications = notifications;
+            }
+            Message::Archive => {
+                self.archived += 1;
+                self.is_menu_open = false;
+            }
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let placement = pick_list(
+            PLACEMENTS.map(Name),
+            Some(Name(self.placement)),
+            |Name(placement)| Message::PlacementSelected(placement),
+        );
+
+        let settings = popover(
+            container(text("Settings"))
+                .padding(10)
+                .style(container::rounded_box),
+            column![
+                text("Settings").size(18),
+                toggler(self.notifications)
+                    .label("Notifications")
+                    .on_toggle(Message::NotificationsToggled),
+            ]
+            .spacing(10),
+        )
+        .placement(self.placement);
+
+        let menu = popover(
+            container(text!("Actions ({} archived)", self.archived))
+                .padding(10)
+                .style(container::rounded_box),
+            column![
+                button("Archive")
+                    .on_press(Message::Archive)
+                    .style(button::text),
+                button("Close")
+                    .on_press(Message::MenuToggled(false))
+                    .style(button::text),
+            ],
+        )
+        .open(self.is_menu_open)
+        .on_toggle(Message::MenuToggled)
+        .placement(self.placement)
+        .padding(5);
+
+        let corner = popover(
+            container(text("Near the edge"))
+                .padding(10)
+                .style(container::rounded_box),
+            text("I flip when there is no room!"),
+        )
+        .placement(self.placement)
+        .close_on_click_outside(false);
+
+        column![
+            placement,
+            center(row![settings, menu].spacing(40)),
+            container(corner).align_right(Fill),
+        ]
+        .padding(20)
+        .into()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Name(Placement);
+
+impl std::fmt::Display for Name {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self.0 {
+            Placement::Top => "Top",
+            Placement::Bottom => "Bottom",
+            Placement::Left => "Left",
+            Placement::Right => "Right",
+        })
+    }
+}
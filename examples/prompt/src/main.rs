@@ -0,0 +1,86 @@
+use iced::widget::{column, operation};
+use iced::{Element, Font, Task};
+
+use iced_palace::widget::log_view::{Level, Log};
+use iced_palace::widget::{log_view, prompt};
+
+fn main() -> iced::Result {
+    iced::application(Example::new, Example::update, Example::view).run()
+}
+
+struct Example {
+    log: Log,
+    counter: i64,
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    Submit(String),
+}
+
+const COMMANDS: &[&str] = &["help", "increment", "decrement", "reset", "echo ", "clear"];
+
+impl Example {
+    fn new() -> (Self, Task<Message>) {
+        let mut log = Log::default();
+        log.push(Level::Info, "Type `help` to list the available commands.");
+
+        (Self { log, counter: 0 }, operation::focus("prompt"))
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Submit(input) => {
+                self.log.push(Level::Trace, format!("> {input}"));
+
+                let (command, argument) =
+                    input.trim().split_once(' ').unwrap_or((input.trim(), ""));
+
+                match command {
+                    "" => {}
+                    "help" => {
+                        self.log
+                            .push(Level::Info, format!("Commands: {}", COMMANDS.join(", ")));
+                    }
+                    "increment" => {
+                        self.counter += 1;
+                        self.log.push(Level::Info, self.counter.to_string());
+                    }
+                    "decrement" => {
+                        self.counter -= 1;
+                        self.log.push(Level::Info, self.counter.to_string());
+                    }
+                    "reset" => {
+                        self.counter = 0;
+                        self.log.push(Level::Warning, "Counter reset");
+                    }
+                    "echo" => {
+                        self.log.push(Level::Info, argument);
+                    }
+                    "clear" => {
+                        self.log.clear();
+                    }
+                    _ => {
+                        self.log
+                            .push(Level::Error, format!("Unknown command: {command}"));
+                    }
+                }
+            }
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        column![
+            log_view(&self.log).font(Font::MONOSPACE),
+            prompt()
+                .id("prompt")
+                .placeholder("Enter a command...")
+                .suggestions(COMMANDS.iter().copied())
+                .on_submit(Message::Submit)
+                .font(Font::MONOSPACE),
+        ]
+        .spacing(10)
+        .padding(10)
+        .into()
+    }
+}
@@ -0,0 +1,104 @@
+use iced::widget::{center, column, container, row, text};
+use iced::{Center, Element};
+
+use iced_palace::widget::quantity_input;
+
+use std::fmt;
+
+fn main() -> iced::Result {
+    iced::application(Example::default, Example::update, Example::view).run()
+}
+
+struct Example {
+    width: (f32, Unit),
+    font_size: (f32, Unit),
+}
+
+impl Default for Example {
+    fn default() -> Self {
+        Self {
+            width: (50.0, Unit::Percent),
+            font_size: (1.5, Unit::Em),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    WidthChanged(f32, Unit),
+    FontSizeChanged(f32, Unit),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Unit {
+    Px,
+    Em,
+    Rem,
+    Percent,
+}
+
+impl Unit {
+    const ALL: [Self; 4] = [Self::Px, Self::Em, Self::Rem, Self::Percent];
+}
+
+impl fmt::Display for Unit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Unit::Px => "px",
+            Unit::Em => "em",
+            Unit::Rem => "rem",
+            Unit::Percent => "%",
+        })
+    }
+}
+
+impl Example {
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::WidthChanged(value, unit) => {
+                self.width = (value, unit);
+            }
+            Message::FontSizeChanged(value, unit) => {
+                self.font_size = (value, unit);
+            }
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let (width, width_unit) = self.width;
+        let (font_size, font_size_unit) = self.font_size;
+
+        let field = |label, input| {
+            row![text(label).width(100), input]
+                .spacing(10)
+                .align_y(Center)
+        };
+
+        center(
+            container(
+                column![
+                    field(
+                        "Width",
+                        quantity_input(&Unit::ALL, width, width_unit)
+                            .on_change(Message::WidthChanged)
+                            .range(Unit::Px, 0.0..=4096.0)
+                            .range(Unit::Percent, 0.0..=100.0),
+                    ),
+                    field(
+                        "Font size",
+                        quantity_input(&Unit::ALL, font_size, font_size_unit)
+                            .on_change(Message::FontSizeChanged)
+                            .step(0.1)
+                            .range(Unit::Em, 0.5..=8.0)
+                            .range(Unit::Rem, 0.5..=8.0),
+                    ),
+                    field("Disabled", quantity_input(&Unit::ALL, 12.0, Unit::Px)),
+                    text("Type \"120 px\", scroll over a unit or use the arrow keys.").size(14),
+                ]
+                .spacing(15),
+            )
+            .width(360),
+        )
+        .into()
+    }
+}
@@ -0,0 +1,82 @@
+use iced::widget::{center, column, text};
+use iced::{Center, Element};
+
+use iced_palace::widget::range_slider;
+
+fn main() -> iced::Result {
+    iced::application(Example::new, Example::update, Example::view).run()
+}
+
+struct Example {
+    price: (u32, u32),
+    temperature: (f32, f32),
+    released: usize,
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    PriceChanged((u32, u32)),
+    TemperatureChanged((f32, f32)),
+    Released,
+}
+
+impl Example {
+    fn new() -> Self {
+        Self {
+            price: (200, 800),
+            temperature: (18.0, 24.0),
+            released: 0,
+        }
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::PriceChanged(price) => {
+                self.price = price;
+            }
+            Message::TemperatureChanged(temperature) => {
+                self.temperature = temperature;
+            }
+            Message::Released => {
+                self.released += 1;
+            }
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let price = column![
+            text!("Price: ${} – ${}", self.price.0, self.price.1),
+            range_slider(0..=1000, self.price, Message::PriceChanged)
+                .step(10u32)
+                .shift_step(100u32)
+                .on_release(Message::Released),
+        ]
+        .spacing(10);
+
+        let temperature = column![
+            text!(
+                "Temperature: {:.1}°C – {:.1}°C",
+                self.temperature.0,
+                self.temperature.1
+            ),
+            range_slider(-10.0..=40.0, self.temperature, Message::TemperatureChanged)
+                .step(0.5)
+                .height(24),
+        ]
+        .spacing(10);
+
+        center(
+            column![
+                price,
+                temperature,
+                text!("Released {} times", self.released).size(14),
+                text("Hover and use the arrow keys to nudge the last handle").size(14),
+            ]
+            .spacing(30)
+            .align_x(Center)
+            .max_width(400),
+        )
+        .padding(20)
+        .into()
+    }
+}
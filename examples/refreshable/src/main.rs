@@ -0,0 +1,76 @@
+use iced::advanced::widget::operate;
+use iced::time::{Instant, seconds};
+use iced::widget::{column, container, scrollable, text};
+use iced::window;
+use iced::{Element, Fill, Subscription, Task};
+
+use iced_palace::widget::refreshable;
+
+fn main() -> iced::Result {
+    iced::application(Example::default, Example::update, Example::view)
+        .subscription(Example::subscription)
+        .run()
+}
+
+#[derive(Default)]
+struct Example {
+    items: Vec<String>,
+    refreshing_since: Option<Instant>,
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    Refresh,
+    Tick(Instant),
+}
+
+impl Example {
+    fn update(&mut self, message: Message) -> Task<Message> {
+        match message {
+            Message::Refresh => {
+                self.refreshing_since = Some(Instant::now());
+
+                Task::none()
+            }
+            Message::Tick(now) => {
+                let Some(since) = self.refreshing_since else {
+                    return Task::none();
+                };
+
+                if now.saturating_duration_since(since) < seconds(2) {
+                    return Task::none();
+                }
+
+                self.refreshing_since = None;
+                self.items
+                    .insert(0, format!("Item #{}", self.items.len() + 1));
+
+                operate(refreshable::finish("feed")).discard()
+            }
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let items = column(
+            self.items
+                .iter()
+                .map(|item| container(text(item)).padding(10).width(Fill).into()),
+        )
+        .push(text("Pull down to refresh").width(Fill).center())
+        .spacing(5)
+        .padding(10);
+
+        refreshable(scrollable(items).width(Fill).height(Fill))
+            .id("feed")
+            .on_refresh(Message::Refresh)
+            .into()
+    }
+
+    fn subscription(&self) -> Subscription<Message> {
+        if self.refreshing_since.is_some() {
+            window::frames().map(Message::Tick)
+        } else {
+            Subscription::none()
+        }
+    }
+}
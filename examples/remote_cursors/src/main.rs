@@ -0,0 +1,115 @@
+use iced::time::{Duration, Instant};
+use iced::widget::{center, column, container, stack, text, toggler};
+use iced::window;
+use iced::{Color, Element, Fill, Point, Rectangle, Subscription};
+
+use iced_palace::widget::remote_cursors;
+use iced_palace::widget::remote_cursors::Cursor;
+
+fn main() -> iced::Result {
+    iced::application(Example::default, Example::update, Example::view)
+        .subscription(Example::subscription)
+        .run()
+}
+
+struct Example {
+    cursors: Vec<Cursor>,
+    is_running: bool,
+    last_update: Instant,
+    step: usize,
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    Tick(Instant),
+    ToggleRunning(bool),
+}
+
+impl Example {
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Tick(now) => {
+                // Remote updates arrive in bursts, like they would over a network
+                if now - self.last_update < Duration::from_millis(600) {
+                    return;
+                }
+
+                self.last_update = now;
+                self.step += 1;
+
+                for (i, cursor) in self.cursors.iter_mut().enumerate() {
+                    let seed = (self.step * 7 + i * 13) as f32;
+
+                    cursor.position = Point::new(
+                        60.0 + (seed * 1.7).sin().abs() * 480.0,
+                        60.0 + (seed * 2.3).cos().abs() * 280.0,
+                    );
+
+                    cursor.selection = (self.step % 3 == i).then_some(Rectangle {
+                        x: cursor.position.x - 80.0,
+                        y: cursor.position.y - 40.0,
+                        width: 80.0,
+                        height: 40.0,
+                    });
+                }
+            }
+            Message::ToggleRunning(is_running) => {
+                self.is_running = is_running;
+            }
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let canvas = stack![
+            center(text("Shared canvas").size(24)).style(container::bordered_box),
+            remote_cursors(&self.cursors),
+        ]
+        .width(600)
+        .height(400);
+
+        column![
+            toggler(self.is_running)
+                .label("Simulate remote users")
+                .on_toggle(Message::ToggleRunning),
+            center(canvas).height(Fill),
+        ]
+        .spacing(10)
+        .padding(10)
+        .into()
+    }
+
+    fn subscription(&self) -> Subscription<Message> {
+        if self.is_running {
+            window::frames().map(Message::Tick)
+        } else {
+            Subscription::none()
+        }
+    }
+}
+
+impl Default for Example {
+    fn default() -> Self {
+        Self {
+            cursors: vec![
+                Cursor::new(
+                    "Ada",
+                    Color::from_rgb8(0xE0, 0x4F, 0x5F),
+                    Point::new(100.0, 100.0),
+                ),
+                Cursor::new(
+                    "Grace",
+                    Color::from_rgb8(0x3A, 0x86, 0xFF),
+                    Point::new(300.0, 200.0),
+                ),
+                Cursor::new(
+                    "Linus",
+                    Color::from_rgb8(0x2A, 0x9D, 0x5C),
+                    Point::new(450.0, 300.0),
+                ),
+            ],
+            is_running: true,
+            last_update: Instant::now(),
+            step: 0,
+        }
+    }
+}
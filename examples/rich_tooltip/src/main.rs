@@ -0,0 +1,109 @@
+use iced::time::milliseconds;
+use iced::widget::{button, center, column, container, pick_list, row, text};
+use iced::{Element, Fill, Font};
+
+use iced_palace::widget::rich_tooltip;
+use iced_palace::widget::rich_tooltip::Placement;
+
+fn main() -> iced::Result {
+    iced::application(Example::default, Example::update, Example::view).run()
+}
+
+#[derive(Default)]
+struct Example {
+    placement: Placement,
+    copies: usize,
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    PlacementSelected(Placement),
+    Copy,
+}
+
+const PLACEMENTS: [Placement; 5] = [
+    Placement::Top,
+    Placement::Bottom,
+    Placement::Left,
+    Placement::Right,
+    Placement::FollowCursor,
+];
+
+impl Example {
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::PlacementSelected(placement) => {
+                self.placement = placement;
+            }
+            Message::Copy => {
+                self.copies += 1;
+            }
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let placement = pick_list(
+            PLACEMENTS.map(Name),
+            Some(Name(self.placement)),
+            |Name(placement)| Message::PlacementSelected(placement),
+        );
+
+        let definition = rich_tooltip(
+            container(text("ferris"))
+                .padding(10)
+                .style(container::rounded_box),
+            column![
+                text("Ferris").font(Font::MONOSPACE).size(18),
+                text("The unofficial mascot of the Rust programming language."),
+            ]
+            .spacing(5),
+        )
+        .placement(self.placement);
+
+        let interactive = rich_tooltip(
+            container(text("Share"))
+                .padding(10)
+                .style(container::rounded_box),
+            column![
+                text("https://github.com/hecrj/iced_palace").size(14),
+                button(text!("Copy link ({})", self.copies)).on_press(Message::Copy),
+            ]
+            .spacing(10),
+        )
+        .placement(self.placement)
+        .show_delay(milliseconds(200))
+        .hide_delay(milliseconds(300))
+        .interactive(true);
+
+        let corner = rich_tooltip(
+            container(text("Near the edge"))
+                .padding(10)
+                .style(container::rounded_box),
+            text("I flip when there is no room!"),
+        )
+        .placement(self.placement);
+
+        column![
+            placement,
+            center(row![definition, interactive].spacing(40)),
+            container(corner).align_right(Fill),
+        ]
+        .padding(20)
+        .into()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Name(Placement);
+
+impl std::fmt::Display for Name {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self.0 {
+            Placement::Top => "Top",
+            Placement::Bottom => "Bottom",
+            Placement::Left => "Left",
+            Placement::Right => "Right",
+            Placement::FollowCursor => "Follow cursor",
+        })
+    }
+}
@@ -0,0 +1,49 @@
+use iced::widget::{column, container, scrollable, text};
+use iced::{Element, Fill};
+
+use iced_palace::widget::{reveal_on_scroll, scroll_progress};
+
+fn main() -> iced::Result {
+    iced::run(Example::update, Example::view)
+}
+
+#[derive(Default)]
+struct Example;
+
+#[derive(Debug, Clone)]
+enum Message {}
+
+impl Example {
+    fn update(&mut self, message: Message) {
+        match message {}
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let sections = (1..=20).map(|i| {
+            reveal_on_scroll(
+                container(
+                    column![text!("Section {i}").size(24), text(LOREM_IPSUM).size(16),].spacing(10),
+                )
+                .padding(20)
+                .width(Fill)
+                .style(container::rounded_box),
+            )
+            .into()
+        });
+
+        let article = scrollable(column(sections).spacing(20).padding(20).max_width(700))
+            .id("article")
+            .width(Fill)
+            .height(Fill);
+
+        scroll_progress("article", container(article).center_x(Fill))
+            .thickness(4)
+            .into()
+    }
+}
+
+const LOREM_IPSUM: &str = "Lorem ipsum dolor sit amet, consectetur adipiscing elit, \
+    sed do eiusmod tempor incididunt ut labore et dolore magna aliqua. Ut enim ad minim \
+    veniam, quis nostrud exercitation ullamco laboris nisi ut aliquip ex ea commodo \
+    consequat. Duis aute irure dolor in reprehenderit in voluptate velit esse cillum \
+    dolore eu fugiat nulla pariatur.";
@@ -0,0 +1,44 @@
+use iced::widget::{button, center, row};
+use iced::{Center, Element, Font};
+
+use iced_palace::widget::secret_text;
+
+fn main() -> iced::Result {
+    iced::run(Example::update, Example::view)
+}
+
+#[derive(Default)]
+struct Example {
+    revealed: bool,
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    ToggleReveal,
+}
+
+impl Example {
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::ToggleReveal => {
+                self.revealed = !self.revealed;
+            }
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        center(
+            row![
+                secret_text("sk-8f2a9c41d7e0b3")
+                    .revealed(self.revealed)
+                    .font(Font::MONOSPACE)
+                    .width(220),
+                button(if self.revealed { "Hide" } else { "Reveal" })
+                    .on_press(Message::ToggleReveal),
+            ]
+            .align_y(Center)
+            .spacing(20),
+        )
+        .into()
+    }
+}
@@ -0,0 +1,102 @@
+use iced::widget::{column, row, scrollable, text, toggler};
+use iced::{Center, Element, Fill};
+
+use iced_palace::widget::selection_list;
+
+use std::ops::Range;
+
+fn main() -> iced::Result {
+    iced::application(Example::new, Example::update, Example::view).run()
+}
+
+struct Example {
+    items: Vec<String>,
+    selected: Vec<usize>,
+    visible: Range<usize>,
+    multiple: bool,
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    Selected(Vec<usize>),
+    Scrolled(Range<usize>),
+    ToggleMultiple(bool),
+}
+
+const FRUITS: &[&str] = &[
+    "Apple",
+    "Banana",
+    "Cherry",
+    "Date",
+    "Elderberry",
+    "Fig",
+    "Grape",
+    "Honeydew",
+    "Kiwi",
+    "Lemon",
+    "Mango",
+    "Nectarine",
+    "Orange",
+    "Papaya",
+    "Quince",
+    "Raspberry",
+    "Strawberry",
+    "Tangerine",
+    "Watermelon",
+];
+
+impl Example {
+    fn new() -> Self {
+        Self {
+            items: (0..500)
+                .map(|i| format!("{} #{}", FRUITS[i % FRUITS.len()], i / FRUITS.len() + 1))
+                .collect(),
+            selected: Vec::new(),
+            visible: 0..0,
+            multiple: true,
+        }
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Selected(selected) => {
+                self.selected = selected;
+            }
+            Message::Scrolled(visible) => {
+                self.visible = visible;
+            }
+            Message::ToggleMultiple(multiple) => {
+                self.multiple = multiple;
+                self.selected.truncate(1);
+            }
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let list = selection_list(&self.items, &self.selected)
+            .on_select(Message::Selected)
+            .on_visible(Message::Scrolled)
+            .disabled(|index, _| index % 7 == 6)
+            .multiple(self.multiple);
+
+        column![
+            row![
+                toggler(self.multiple)
+                    .label("Multiple selection")
+                    .on_toggle(Message::ToggleMultiple),
+                text!(
+                    "{} selected, showing rows {} to {}",
+                    self.selected.len(),
+                    self.visible.start + 1,
+                    self.visible.end
+                ),
+            ]
+            .spacing(20)
+            .align_y(Center),
+            scrollable(list).height(Fill),
+        ]
+        .spacing(10)
+        .padding(20)
+        .into()
+    }
+}
@@ -0,0 +1,86 @@
+use iced::widget::{button, center, column, slider, text};
+use iced::{Element, Fill};
+
+use iced_palace::widget::status_bar;
+
+fn main() -> iced::Result {
+    iced::application(Example::new, Example::update, Example::view).run()
+}
+
+struct Example {
+    width: f32,
+    line: usize,
+    errors: usize,
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    WidthChanged(f32),
+    LineChanged(usize),
+    ErrorsCleared,
+}
+
+impl Example {
+    fn new() -> Self {
+        Self {
+            width: 800.0,
+            line: 42,
+            errors: 3,
+        }
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::WidthChanged(width) => {
+                self.width = width;
+            }
+            Message::LineChanged(line) => {
+                self.line = line;
+            }
+            Message::ErrorsCleared => {
+                self.errors = 0;
+            }
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let bar = status_bar(
+            [
+                status_bar::item(text("master")).priority(2),
+                status_bar::item(
+                    button(text!("{} errors", self.errors).size(12))
+                        .padding([0, 4])
+                        .style(button::text)
+                        .on_press(Message::ErrorsCleared),
+                )
+                .priority(3),
+            ],
+            [status_bar::item(text("Indexing…"))],
+            [
+                status_bar::item(text!("Ln {}, Col 1", self.line)).priority(4),
+                status_bar::item(text("Spaces: 4")),
+                status_bar::item(text("UTF-8")).priority(1),
+                status_bar::item(text("Rust")).priority(2),
+            ],
+        )
+        .width(self.width);
+
+        column![
+            center(
+                column![
+                    text!("Status bar width: {:.0}", self.width),
+                    slider(200.0..=800.0, self.width, Message::WidthChanged),
+                    text!("Line: {}", self.line),
+                    slider(1.0..=999.0, self.line as f32, |line| {
+                        Message::LineChanged(line as usize)
+                    }),
+                ]
+                .spacing(10)
+                .max_width(400),
+            )
+            .height(Fill),
+            bar,
+        ]
+        .into()
+    }
+}
@@ -0,0 +1,83 @@
+use iced::Element;
+use iced::widget::{button, center, column, container, row, space, text, text_input};
+
+use iced_palace::widget::steps;
+
+fn main() -> iced::Result {
+    iced::run(Example::update, Example::view)
+}
+
+#[derive(Default)]
+struct Example {
+    current: usize,
+    name: String,
+    email: String,
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    StepChanged(usize),
+    NameChanged(String),
+    EmailChanged(String),
+    Back,
+    Next,
+}
+
+impl Example {
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::StepChanged(step) => {
+                self.current = step;
+            }
+            Message::NameChanged(name) => {
+                self.name = name;
+            }
+            Message::EmailChanged(email) => {
+                self.email = email;
+            }
+            Message::Back => {
+                self.current = self.current.saturating_sub(1);
+            }
+            Message::Next => {
+                self.current = (self.current + 1).min(2);
+            }
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let is_valid = !self.name.is_empty() && self.email.contains('@');
+
+        let wizard = steps(self.current)
+            .push(
+                "Profile",
+                text_input("Your name", &self.name).on_input(Message::NameChanged),
+            )
+            .push(
+                "Contact",
+                text_input("Your email", &self.email).on_input(Message::EmailChanged),
+            )
+            .push_maybe(
+                "Confirm",
+                text!(
+                    "Welcome aboard, {}! We will reach you at {}.",
+                    self.name,
+                    self.email
+                ),
+                is_valid,
+            )
+            .on_step_change(Message::StepChanged)
+            .height(200);
+
+        let controls = row![
+            button("Back").on_press_maybe((self.current > 0).then_some(Message::Back)),
+            space::horizontal(),
+            button("Next").on_press_maybe(
+                (self.current < 1 || self.current == 1 && is_valid).then_some(Message::Next)
+            ),
+        ];
+
+        center(container(column![wizard, controls].spacing(20)).max_width(500))
+            .padding(20)
+            .into()
+    }
+}
@@ -0,0 +1,134 @@
+use iced::widget::{button, center, column, row, text};
+use iced::{Element, Fill};
+
+use iced_palace::widget::tab_bar;
+
+fn main() -> iced::Result {
+    iced::application(Example::new, Example::update, Example::view).run()
+}
+
+struct Example {
+    documents: Vec<Document>,
+    selected: Option<usize>,
+    created: usize,
+}
+
+struct Document {
+    name: String,
+    is_dirty: bool,
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    Selected(usize),
+    Closed(usize),
+    Reordered(usize, usize),
+    New,
+    Edit,
+    Save,
+}
+
+impl Example {
+    fn new() -> Self {
+        let documents = ["main.rs", "widget.rs", "prelude.rs", "Cargo.toml"]
+            .into_iter()
+            .map(|name| Document {
+                name: name.to_owned(),
+                is_dirty: false,
+            })
+            .collect();
+
+        Self {
+            documents,
+            selected: Some(0),
+            created: 0,
+        }
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Selected(index) => {
+                self.selected = Some(index);
+            }
+            Message::Closed(index) => {
+                let _ = self.documents.remove(index);
+
+                self.selected = match self.selected {
+                    _ if self.documents.is_empty() => None,
+                    Some(selected) if selected > index || selected == self.documents.len() => {
+                        Some(selected - 1)
+                    }
+                    selected => selected,
+                };
+            }
+            Message::Reordered(from, to) => {
+                let document = self.documents.remove(from);
+                self.documents.insert(to, document);
+
+                self.selected = self.selected.map(|selected| {
+                    if selected == from {
+                        to
+                    } else if from < selected && selected <= to {
+                        selected - 1
+                    } else if to <= selected && selected < from {
+                        selected + 1
+                    } else {
+                        selected
+                    }
+                });
+            }
+            Message::New => {
+                self.created += 1;
+                self.documents.push(Document {
+                    name: format!("untitled-{}.rs", self.created),
+                    is_dirty: true,
+                });
+                self.selected = Some(self.documents.len() - 1);
+            }
+            Message::Edit | Message::Save => {
+                if let Some(document) = self
+                    .selected
+                    .and_then(|selected| self.documents.get_mut(selected))
+                {
+                    document.is_dirty = matches!(message, Message::Edit);
+                }
+            }
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let tabs = tab_bar(self.documents.iter().map(|document| {
+            tab_bar::tab(&document.name)
+                .dirty(document.is_dirty)
+                .closable(document.name != "Cargo.toml")
+        }))
+        .selected(self.selected)
+        .on_select(Message::Selected)
+        .on_close(Message::Closed)
+        .on_reorder(Message::Reordered);
+
+        let content: Element<'_, _> = match self
+            .selected
+            .and_then(|selected| self.documents.get(selected))
+        {
+            Some(document) => column![
+                text(&document.name).size(24),
+                row![
+                    button("Edit").on_press(Message::Edit),
+                    button("Save").on_press_maybe(document.is_dirty.then_some(Message::Save)),
+                ]
+                .spacing(10),
+            ]
+            .spacing(10)
+            .into(),
+            None => text("No open documents").into(),
+        };
+
+        column![
+            row![tabs, button("+").on_press(Message::New)].spacing(5),
+            center(content).height(Fill),
+        ]
+        .padding(10)
+        .into()
+    }
+}
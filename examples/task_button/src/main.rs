@@ -0,0 +1,123 @@
+use iced::time::{Duration, Instant, seconds};
+use iced::widget::{center, column, row, text, toggler};
+use iced::window;
+use iced::{Center, Element, Subscription};
+
+use iced_palace::widget::task_button;
+use iced_palace::widget::task_button::Progress;
+
+fn main() -> iced::Result {
+    iced::application(Example::default, Example::update, Example::view)
+        .subscription(Example::subscription)
+        .run()
+}
+
+#[derive(Default)]
+struct Example {
+    save: Job,
+    upload: Job,
+    fail: bool,
+}
+
+#[derive(Default)]
+struct Job {
+    progress: Progress,
+    since: Option<Instant>,
+}
+
+impl Job {
+    fn start(&mut self) {
+        self.progress = Progress::Running(None);
+        self.since = Some(Instant::now());
+    }
+
+    fn tick(&mut self, now: Instant, duration: Duration, outcome: Progress, is_measured: bool) {
+        let Some(since) = self.since else {
+            return;
+        };
+
+        let elapsed = now.saturating_duration_since(since);
+
+        match self.progress {
+            Progress::Running(_) if elapsed >= duration => {
+                self.progress = outcome;
+                self.since = Some(now);
+            }
+            Progress::Running(_) if is_measured => {
+                self.progress =
+                    Progress::Running(Some(elapsed.as_secs_f32() / duration.as_secs_f32()));
+            }
+            Progress::Success | Progress::Error if elapsed >= seconds(2) => {
+                *self = Self::default();
+            }
+            _ => {}
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    Tick(Instant),
+    Save,
+    Upload,
+    ToggleFail(bool),
+}
+
+impl Example {
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Tick(now) => {
+                self.save.tick(now, seconds(2), Progress::Success, false);
+                self.upload.tick(
+                    now,
+                    seconds(3),
+                    if self.fail {
+                        Progress::Error
+                    } else {
+                        Progress::Success
+                    },
+                    true,
+                );
+            }
+            Message::Save => {
+                self.save.start();
+            }
+            Message::Upload => {
+                self.upload.start();
+            }
+            Message::ToggleFail(fail) => {
+                self.fail = fail;
+            }
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        center(
+            column![
+                row![
+                    task_button(text("Save").width(80).align_x(Center))
+                        .progress(self.save.progress)
+                        .on_press(Message::Save),
+                    task_button(text("Upload").width(80).align_x(Center))
+                        .progress(self.upload.progress)
+                        .on_press(Message::Upload),
+                ]
+                .spacing(10),
+                toggler(self.fail)
+                    .label("Fail uploads")
+                    .on_toggle(Message::ToggleFail),
+            ]
+            .align_x(Center)
+            .spacing(20),
+        )
+        .into()
+    }
+
+    fn subscription(&self) -> Subscription<Message> {
+        if self.save.since.is_some() || self.upload.since.is_some() {
+            window::frames().map(Message::Tick)
+        } else {
+            Subscription::none()
+        }
+    }
+}
@@ -0,0 +1,68 @@
+use iced::widget::{center, column, text, toggler};
+use iced::{Center, Element, Font};
+
+use iced_palace::widget::time_picker;
+use iced_palace::widget::time_picker::{Clock, Time};
+
+fn main() -> iced::Result {
+    iced::run(Example::update, Example::view)
+}
+
+struct Example {
+    time: Time,
+    use_12h: bool,
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    TimeSelected(Time),
+    Toggle12h(bool),
+}
+
+impl Example {
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::TimeSelected(time) => {
+                self.time = time;
+            }
+            Message::Toggle12h(use_12h) => {
+                self.use_12h = use_12h;
+            }
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let picker = time_picker(self.time)
+            .clock(if self.use_12h { Clock::H12 } else { Clock::H24 })
+            .on_select(Message::TimeSelected)
+            .font(Font::MONOSPACE)
+            .text_size(20);
+
+        center(
+            column![
+                picker,
+                text!(
+                    "Alarm set at {:02}:{:02}:{:02}",
+                    self.time.hour,
+                    self.time.minute,
+                    self.time.second
+                ),
+                toggler(self.use_12h)
+                    .label("12-hour clock")
+                    .on_toggle(Message::Toggle12h),
+            ]
+            .align_x(Center)
+            .spacing(20),
+        )
+        .into()
+    }
+}
+
+impl Default for Example {
+    fn default() -> Self {
+        Self {
+            time: Time::new(7, 30, 0),
+            use_12h: false,
+        }
+    }
+}
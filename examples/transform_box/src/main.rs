@@ -0,0 +1,80 @@
+use iced::widget::{center, column, container, pin, stack, text};
+use iced::{Element, Fill, Point, Size};
+
+use iced_palace::widget::transform_box;
+use iced_palace::widget::transform_box::Transformation;
+
+fn main() -> iced::Result {
+    iced::application(Example::new, Example::update, Example::view).run()
+}
+
+struct Example {
+    center: Point,
+    size: Size,
+    rotation: f32,
+    transforms: usize,
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    Transformed(Transformation),
+    Released,
+}
+
+impl Example {
+    fn new() -> Self {
+        Self {
+            center: Point::new(300.0, 250.0),
+            size: Size::new(200.0, 120.0),
+            rotation: 0.0,
+            transforms: 0,
+        }
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Transformed(transformation) => {
+                self.center += transformation.translation;
+                self.size = Size::new(
+                    self.size.width * transformation.scale.x,
+                    self.size.height * transformation.scale.y,
+                );
+                self.rotation += transformation.rotation.0;
+            }
+            Message::Released => {
+                self.transforms += 1;
+            }
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        // Widgets cannot be rotated, so the item reports its rotation instead
+        let item = transform_box(
+            center(text!("{:.0}°", self.rotation.to_degrees()).size(24))
+                .width(self.size.width)
+                .height(self.size.height)
+                .style(container::rounded_box),
+        )
+        .on_transform(Message::Transformed)
+        .on_release(Message::Released);
+
+        let board = pin(item).position(Point::new(
+            self.center.x - self.size.width / 2.0,
+            self.center.y - self.size.height / 2.0,
+        ));
+
+        stack![
+            board,
+            column![
+                text("Drag to move, use the handles to scale and rotate"),
+                text("Hold Shift to constrain, Alt to scale around the center").size(14),
+                text!("{} transformations so far", self.transforms).size(14),
+            ]
+            .spacing(5)
+            .padding(20),
+        ]
+        .width(Fill)
+        .height(Fill)
+        .into()
+    }
+}
@@ -1,6 +1,8 @@
-use iced::widget::{center, center_x, column, container, toggler};
-use iced::{Element, Font};
+use iced::time::seconds;
+use iced::widget::{center, center_x, column, container, row, text, toggler};
+use iced::{Element, Font, Theme};
 use iced_palace::widget::typewriter;
+use iced_palace::widget::typewriter::Repeat;
 
 fn main() -> iced::Result {
     iced::application(Example::new, Example::update, Example::view).run()
@@ -8,17 +10,23 @@ fn main() -> iced::Result {
 
 struct Example {
     use_monospace: bool,
+    ping_pong: bool,
+    reserve_space: bool,
 }
 
 #[derive(Debug, Clone)]
 enum Message {
     ToggleMonospace(bool),
+    TogglePingPong(bool),
+    ReserveSpace(bool),
 }
 
 impl Example {
     fn new() -> Self {
         Self {
             use_monospace: true,
+            ping_pong: false,
+            reserve_space: true,
         }
     }
 
@@ -27,6 +35,12 @@ impl Example {
             Message::ToggleMonospace(use_monospace) => {
                 self.use_monospace = use_monospace;
             }
+            Message::TogglePingPong(ping_pong) => {
+                self.ping_pong = ping_pong;
+            }
+            Message::ReserveSpace(reserve_space) => {
+                self.reserve_space = reserve_space;
+            }
         }
     }
 
@@ -42,6 +56,14 @@ impl Example {
             .label("Monospace")
             .on_toggle(Message::ToggleMonospace);
 
+        let ping_pong_toggle = toggler(self.ping_pong)
+            .label("Ping-pong")
+            .on_toggle(Message::TogglePingPong);
+
+        let reserve_space_toggle = toggler(self.reserve_space)
+            .label("Reserve space")
+            .on_toggle(Message::ReserveSpace);
+
         column![
             center(
                 typewriter(JOI)
@@ -52,8 +74,17 @@ impl Example {
                     })
                     .line_height(1.5)
                     .very_slow()
+                    .reveal_style(|theme: &Theme| text::Style {
+                        color: Some(theme.palette().primary),
+                    })
+                    .reserve_space(self.reserve_space)
+                    .repeat(if self.ping_pong {
+                        Repeat::PingPong { pause: seconds(2) }
+                    } else {
+                        Repeat::Never
+                    })
             ),
-            center_x(monospace_toggle)
+            center_x(row![monospace_toggle, ping_pong_toggle, reserve_space_toggle].spacing(30))
                 .padding(10)
                 .style(container::dark),
         ]
@@ -0,0 +1,87 @@
+use iced::widget::{column, text};
+use iced::{Element, Fill};
+
+use iced_palace::widget::waveform;
+
+use std::ops::Range;
+
+const SAMPLE_RATE: usize = 44_100;
+
+fn main() -> iced::Result {
+    iced::application(Example::new, Example::update, Example::view).run()
+}
+
+struct Example {
+    samples: Vec<f32>,
+    playhead: usize,
+    selection: Option<Range<usize>>,
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    Seeked(usize),
+    Selected(Range<usize>),
+}
+
+impl Example {
+    fn new() -> Self {
+        // A few plucked notes with a decaying envelope
+        let samples = (0..SAMPLE_RATE * 4)
+            .map(|i| {
+                let t = i as f32 / SAMPLE_RATE as f32;
+                let note = (t * 2.0).floor();
+                let elapsed = t - note / 2.0;
+                let frequency = 220.0 * (1.0 + note * 0.25);
+
+                (elapsed * frequency * std::f32::consts::TAU).sin() * (-elapsed * 6.0).exp() * 0.9
+            })
+            .collect();
+
+        Self {
+            samples,
+            playhead: 0,
+            selection: None,
+        }
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Seeked(sample) => {
+                self.playhead = sample;
+                self.selection = None;
+            }
+            Message::Selected(selection) => {
+                self.playhead = selection.start;
+                self.selection = Some(selection);
+            }
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let seconds = |sample: usize| sample as f32 / SAMPLE_RATE as f32;
+
+        let status = match &self.selection {
+            Some(selection) => text!(
+                "Selected {:.2}s to {:.2}s",
+                seconds(selection.start),
+                seconds(selection.end)
+            ),
+            None => text!("Playhead at {:.2}s", seconds(self.playhead)),
+        };
+
+        column![
+            waveform(&self.samples)
+                .playhead(self.playhead)
+                .selection_maybe(self.selection.clone())
+                .on_seek(Message::Seeked)
+                .on_select(Message::Selected)
+                .height(200),
+            status,
+            text("Scroll to zoom, Shift+scroll to pan, drag to select").size(14),
+        ]
+        .width(Fill)
+        .spacing(10)
+        .padding(20)
+        .into()
+    }
+}
@@ -0,0 +1,85 @@
+use iced::widget::{center, column, row, text};
+use iced::{Center, Element, Font};
+
+use iced_palace::widget::xy_pad;
+
+fn main() -> iced::Result {
+    iced::application(Example::default, Example::update, Example::view).run()
+}
+
+struct Example {
+    pan: (f32, f32),
+    filter: (f32, f32),
+    releases: usize,
+}
+
+impl Default for Example {
+    fn default() -> Self {
+        Self {
+            pan: (0.0, 0.0),
+            filter: (1_000.0, 0.7),
+            releases: 0,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    PanChanged((f32, f32)),
+    FilterChanged((f32, f32)),
+    Released,
+}
+
+impl Example {
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::PanChanged(pan) => {
+                self.pan = pan;
+            }
+            Message::FilterChanged(filter) => {
+                self.filter = filter;
+            }
+            Message::Released => {
+                self.releases += 1;
+            }
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let (x, y) = self.pan;
+        let (cutoff, resonance) = self.filter;
+
+        let pan = column![
+            xy_pad(-1.0..=1.0, -1.0..=1.0, self.pan)
+                .on_change(Message::PanChanged)
+                .on_release(Message::Released),
+            text!("Pan {x:+.2} / Depth {y:+.2}").font(Font::MONOSPACE),
+        ]
+        .spacing(10)
+        .align_x(Center);
+
+        let filter = column![
+            xy_pad(20.0..=20_000.0, 0.0..=1.0, self.filter)
+                .on_change(Message::FilterChanged)
+                .on_release(Message::Released)
+                .center(None),
+            text!("Cutoff {cutoff:.0} Hz / Q {resonance:.2}").font(Font::MONOSPACE),
+        ]
+        .spacing(10)
+        .align_x(Center);
+
+        center(
+            column![
+                row![pan, filter].spacing(40),
+                text!(
+                    "Hold Shift for fine adjustments. Double click to center. Released {} times.",
+                    self.releases
+                )
+                .size(14),
+            ]
+            .spacing(20)
+            .align_x(Center),
+        )
+        .into()
+    }
+}
@@ -21,7 +21,9 @@ pub fn time(attr: TokenStream, item: TokenStream) -> TokenStream {
     let expanded = quote! {
         #(#attrs)*
         #vis #sig {
-            ::iced::debug::time_with(#name, || #block)
+            ::iced::debug::time_with(#name, || {
+                ::iced_palace::debug::time_with(#name, || #block)
+            })
         }
     };
 
@@ -1,3 +1,5 @@
+pub mod prelude;
+pub mod testing;
 pub mod widget;
 
 use iced_core as core;
@@ -5,4 +7,7 @@ use iced_core as core;
 #[cfg(feature = "macros")]
 pub mod debug {
     pub use iced_palace_macros::time;
+
+    #[doc(hidden)]
+    pub use crate::widget::perf_hud::time_with;
 }
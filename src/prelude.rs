@@ -0,0 +1,61 @@
+//! The widgets of the palace and their helpers, in a single import.
+//!
+//! ```no_run
+//! use iced_palace::prelude::*;
+//! ```
+//!
+//! Widget modules are re-exported alongside their helpers, so their styles
+//! are also in reach—like `heatmap::diverging`.
+pub use crate::widget::{
+    Accordion, AspectRatio, Avatar, AvatarStack, BottomSheet, Carousel, ChatView,
+    ContributionCalendar, Countdown, DiffText, Drawer, EllipsizedText, EmojiPicker, FileDrop,
+    Filmstrip, Flow, GradientEditor, Grid, Heatmap, Hotkeys, ImageViewer, Kanban, Keycap,
+    Letterbox, LogView, Magnifier, MarkdownText, MenuBar, Modal, PerfHud, Popover, Prompt,
+    QuantityInput, RangeSlider, Refreshable, RemoteCursors, RevealOnScroll, RichTooltip,
+    ScrollProgress, SelectionList, StatusBar, Steps, TabBar, TaskButton, TimePicker, TransformBox,
+    Typewriter, XyPad,
+};
+
+pub use crate::widget::{
+    accordion, aspect_ratio, avatar, avatar_stack, bottom_sheet, carousel, chat_view,
+    contribution_calendar, countdown, diff_text, drawer, ellipsized_text, emoji_picker, file_drop,
+    filmstrip, flow, gradient_editor, grid, heatmap, hotkeys, image_viewer, kanban, keycap,
+    labeled_slider, letterbox, log_view, magnifier, markdown_text, menu_bar, modal, perf_hud,
+    popover, prompt, quantity_input, range_slider, refreshable, remote_cursors, reveal_on_scroll,
+    rich_tooltip, scroll_progress, selection_list, shortcut_hint, status_bar, steps, tab_bar,
+    task_button, time_picker, transform_box, typewriter, xy_pad,
+};
+
+pub use crate::widget::aspect_ratio::Fit;
+pub use crate::widget::avatar::Presence;
+pub use crate::widget::bottom_sheet::Detent;
+pub use crate::widget::chat_view::Chat;
+pub use crate::widget::drawer::Edge;
+pub use crate::widget::emoji_picker::SkinTone;
+pub use crate::widget::flow::Justify;
+pub use crate::widget::grid::{Template, Track};
+pub use crate::widget::hotkeys::Shortcut;
+pub use crate::widget::log_view::{Level, Log};
+pub use crate::widget::modal::Dialogs;
+pub use crate::widget::rich_tooltip::Placement;
+pub use crate::widget::task_button::Progress;
+pub use crate::widget::time_picker::{Clock, Time};
+pub use crate::widget::transform_box::Transformation;
+pub use crate::widget::typewriter::Repeat;
+
+#[cfg(feature = "rand")]
+pub use crate::widget::{DiffusedText, SecretText, diffused_text, secret_text};
+
+#[cfg(feature = "syntect")]
+pub use crate::widget::code_block::Highlighting;
+
+#[cfg(feature = "syntect")]
+pub use crate::widget::{CodeBlock, code_block};
+
+#[cfg(feature = "geometry")]
+pub use crate::widget::annotation_layer::{Annotations, Tool};
+
+#[cfg(feature = "geometry")]
+pub use crate::widget::{
+    AnnotationLayer, DynamicText, Waveform, annotation_layer, dynamic_text, waveform,
+};
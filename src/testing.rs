@@ -0,0 +1,321 @@
+//! Drive widgets headlessly with a simulated clock.
+//!
+//! A [`Harness`] lays out an [`Element`] and feeds it events, including
+//! the `RedrawRequested` events that animated widgets use to advance in
+//! time. Time only moves forward when told so, which makes tests of
+//! time-driven widgets deterministic.
+//!
+//! ```
+//! use iced_palace::testing::Harness;
+//! use iced_palace::widget::reveal_on_scroll;
+//! use iced_widget::space;
+//!
+//! use std::time::Duration;
+//!
+//! let mut harness = Harness::<()>::new(reveal_on_scroll(space().width(100).height(100)));
+//!
+//! harness.frame();
+//! assert!(harness.is_animating());
+//!
+//! assert!(harness.settle(Duration::from_secs(1)));
+//! assert!(!harness.is_animating());
+//! ```
+use crate::core;
+use crate::core::clipboard;
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::time::{Duration, Instant};
+use crate::core::widget::Tree;
+use crate::core::widget::operation::{self, Operation};
+use crate::core::window;
+use crate::core::{Element, Event, Point, Rectangle, Shell, Size};
+
+/// The time between two simulated frames, at 60 frames per second.
+pub const FRAME: Duration = Duration::from_nanos(16_666_667);
+
+/// A clock that only moves forward when advanced.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Clock {
+    start: Instant,
+    elapsed: Duration,
+}
+
+impl Clock {
+    /// Creates a new [`Clock`] starting at the current time.
+    pub fn new() -> Self {
+        Self {
+            start: Instant::now(),
+            elapsed: Duration::ZERO,
+        }
+    }
+
+    /// Returns the current simulated time.
+    pub fn now(&self) -> Instant {
+        self.start + self.elapsed
+    }
+
+    /// Returns the time elapsed since the [`Clock`] was created.
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    /// Moves the [`Clock`] forward by the given duration.
+    pub fn advance(&mut self, duration: Duration) {
+        self.elapsed += duration;
+    }
+}
+
+impl Default for Clock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A headless environment that owns the state of an [`Element`].
+///
+/// By default, it uses the null renderer `()`, which performs no text
+/// shaping and draws nothing. Overlays are not supported.
+pub struct Harness<'a, Message, Theme = core::Theme, Renderer = ()> {
+    root: Element<'a, Message, Theme, Renderer>,
+    tree: Tree,
+    layout: layout::Node,
+    size: Size,
+    renderer: Renderer,
+    clock: Clock,
+    cursor: mouse::Cursor,
+    messages: Vec<Message>,
+    redraw_request: window::RedrawRequest,
+}
+
+impl<'a, Message, Theme, Renderer> Harness<'a, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer + Default,
+{
+    /// Creates a new [`Harness`] for the given [`Element`] inside a
+    /// 1024x768 viewport.
+    pub fn new(root: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        let mut root = root.into();
+        let mut tree = Tree::new(&root);
+        let renderer = Renderer::default();
+        let size = Size::new(1024.0, 768.0);
+
+        let layout = root.as_widget_mut().layout(
+            &mut tree,
+            &renderer,
+            &layout::Limits::new(Size::ZERO, size),
+        );
+
+        Self {
+            root,
+            tree,
+            layout,
+            size,
+            renderer,
+            clock: Clock::new(),
+            cursor: mouse::Cursor::Unavailable,
+            messages: Vec::new(),
+            redraw_request: window::RedrawRequest::Wait,
+        }
+    }
+
+    /// Resizes the viewport of the [`Harness`].
+    pub fn resize(&mut self, size: impl Into<Size>) {
+        self.size = size.into();
+        self.relayout();
+    }
+
+    /// Returns the [`Clock`] of the [`Harness`].
+    pub fn clock(&self) -> &Clock {
+        &self.clock
+    }
+
+    /// Returns the current simulated time.
+    pub fn now(&self) -> Instant {
+        self.clock.now()
+    }
+
+    /// Returns the widget [`Tree`] of the root.
+    ///
+    /// The state of a widget can be inspected with
+    /// [`tree::State::downcast_ref`](core::widget::tree::State::downcast_ref).
+    pub fn tree(&self) -> &Tree {
+        &self.tree
+    }
+
+    /// Returns the bounds of the root after the last layout.
+    pub fn bounds(&self) -> Rectangle {
+        self.layout.bounds()
+    }
+
+    /// Returns the earliest redraw requested by the widgets since the
+    /// last frame.
+    pub fn redraw_request(&self) -> window::RedrawRequest {
+        self.redraw_request
+    }
+
+    /// Returns true if the widgets requested a redraw.
+    pub fn is_animating(&self) -> bool {
+        self.redraw_request != window::RedrawRequest::Wait
+    }
+
+    /// Takes the messages produced since the last call.
+    pub fn messages(&mut self) -> Vec<Message> {
+        std::mem::take(&mut self.messages)
+    }
+
+    /// Feeds an [`Event`] to the widgets and returns its [`event::Status`].
+    ///
+    /// [`event::Status`]: core::event::Status
+    pub fn event(&mut self, event: Event) -> core::event::Status {
+        let mut messages = Vec::new();
+        let mut shell = Shell::new(&mut messages);
+        let viewport = Rectangle::with_size(self.size);
+
+        self.root.as_widget_mut().update(
+            &mut self.tree,
+            &event,
+            Layout::new(&self.layout),
+            self.cursor,
+            &self.renderer,
+            &mut clipboard::Null,
+            &mut shell,
+            &viewport,
+        );
+
+        let status = shell.event_status();
+        let redraw_request = shell.redraw_request();
+        let is_layout_invalid = shell.is_layout_invalid() || shell.are_widgets_invalid();
+
+        self.redraw_request = self.redraw_request.min(redraw_request);
+        self.messages.append(&mut messages);
+
+        if is_layout_invalid {
+            self.relayout();
+        }
+
+        status
+    }
+
+    /// Feeds a `RedrawRequested` event at the current time, like the
+    /// runtime does before drawing every frame.
+    pub fn frame(&mut self) {
+        self.redraw_request = window::RedrawRequest::Wait;
+
+        let _ = self.event(Event::Window(window::Event::RedrawRequested(
+            self.clock.now(),
+        )));
+    }
+
+    /// Advances the [`Clock`] by the given duration and produces a single
+    /// frame.
+    pub fn advance(&mut self, duration: Duration) {
+        self.clock.advance(duration);
+        self.frame();
+    }
+
+    /// Advances the [`Clock`] frame by frame for the given duration,
+    /// producing frames only when the widgets request them.
+    pub fn run_for(&mut self, duration: Duration) {
+        let end = self.clock.elapsed() + duration;
+
+        while self.clock.elapsed() < end {
+            self.clock.advance(FRAME.min(end - self.clock.elapsed()));
+
+            if self.is_redraw_due() {
+                self.frame();
+            }
+        }
+    }
+
+    /// Runs frames until the widgets stop requesting redraws, for at most
+    /// the given duration.
+    ///
+    /// Returns true if the widgets settled in time.
+    pub fn settle(&mut self, timeout: Duration) -> bool {
+        let end = self.clock.elapsed() + timeout;
+
+        while self.is_animating() {
+            if self.clock.elapsed() >= end {
+                return false;
+            }
+
+            self.run_for(FRAME.min(end - self.clock.elapsed()));
+        }
+
+        true
+    }
+
+    /// Moves the mouse cursor to the given position.
+    pub fn move_cursor(&mut self, position: impl Into<Point>) -> core::event::Status {
+        let position = position.into();
+        self.cursor = mouse::Cursor::Available(position);
+
+        self.event(Event::Mouse(mouse::Event::CursorMoved { position }))
+    }
+
+    /// Clicks the left mouse button at the given position.
+    pub fn click(&mut self, position: impl Into<Point>) -> core::event::Status {
+        let _ = self.move_cursor(position);
+        let pressed = self.event(Event::Mouse(mouse::Event::ButtonPressed(
+            mouse::Button::Left,
+        )));
+        let released = self.event(Event::Mouse(mouse::Event::ButtonReleased(
+            mouse::Button::Left,
+        )));
+
+        pressed.merge(released)
+    }
+
+    /// Runs an [`Operation`] on the widgets.
+    pub fn operate(&mut self, operation: &mut dyn Operation) {
+        self.root.as_widget_mut().operate(
+            &mut self.tree,
+            Layout::new(&self.layout),
+            &self.renderer,
+            operation,
+        );
+    }
+
+    /// Runs an [`Operation`] on the widgets and returns its output, if any;
+    /// like [`typewriter::progress`](crate::widget::typewriter::progress).
+    pub fn query<T>(&mut self, mut operation: impl Operation<T>) -> Option<T> {
+        self.operate(&mut operation::black_box(&mut operation));
+
+        match operation.finish() {
+            operation::Outcome::Some(output) => Some(output),
+            operation::Outcome::None | operation::Outcome::Chain(_) => None,
+        }
+    }
+
+    /// Draws the widgets with the given theme.
+    pub fn draw(&mut self, theme: &Theme) {
+        let viewport = Rectangle::with_size(self.size);
+
+        self.root.as_widget().draw(
+            &self.tree,
+            &mut self.renderer,
+            theme,
+            &renderer::Style::default(),
+            Layout::new(&self.layout),
+            self.cursor,
+            &viewport,
+        );
+    }
+
+    fn is_redraw_due(&self) -> bool {
+        match self.redraw_request {
+            window::RedrawRequest::NextFrame => true,
+            window::RedrawRequest::At(at) => at <= self.clock.now(),
+            window::RedrawRequest::Wait => false,
+        }
+    }
+
+    fn relayout(&mut self) {
+        self.layout = self.root.as_widget_mut().layout(
+            &mut self.tree,
+            &self.renderer,
+            &layout::Limits::new(Size::ZERO, self.size),
+        );
+    }
+}
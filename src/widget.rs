@@ -1,26 +1,141 @@
-mod ellipsized_text;
-mod typewriter;
+mod countdown;
+mod image_viewer;
+mod reveal_on_scroll;
+
+pub mod accordion;
+pub mod aspect_ratio;
+pub mod avatar;
+pub mod bottom_sheet;
+pub mod carousel;
+pub mod chat_view;
+pub mod contribution_calendar;
+pub mod diff_text;
+pub mod drawer;
+pub mod ellipsized_text;
+pub mod emoji_picker;
+pub mod file_drop;
+pub mod filmstrip;
+pub mod flow;
+pub mod gradient_editor;
+pub mod grid;
+pub mod heatmap;
+pub mod hotkeys;
+pub mod kanban;
+pub mod keycap;
+pub mod log_view;
+pub mod magnifier;
+pub mod markdown_text;
+pub mod menu_bar;
+pub mod modal;
+pub mod perf_hud;
+pub mod popover;
+pub mod prompt;
+pub mod quantity_input;
+pub mod range_slider;
+pub mod refreshable;
+pub mod remote_cursors;
+pub mod rich_tooltip;
+pub mod scroll_progress;
+pub mod selection_list;
+pub mod status_bar;
+pub mod steps;
+pub mod tab_bar;
+pub mod task_button;
+pub mod time_picker;
+pub mod transform_box;
+pub mod typewriter;
+pub mod xy_pad;
+
+#[cfg(feature = "rand")]
+pub mod diffused_text;
 
 #[cfg(feature = "rand")]
-mod diffused_text;
+mod secret_text;
+
+#[cfg(feature = "syntect")]
+pub mod code_block;
+
+#[cfg(feature = "geometry")]
+pub mod annotation_layer;
+
+#[cfg(feature = "geometry")]
+pub mod waveform;
 
 #[cfg(feature = "geometry")]
 mod dynamic_text;
 
+pub use accordion::Accordion;
+pub use aspect_ratio::{AspectRatio, Letterbox};
+pub use avatar::{Avatar, AvatarStack};
+pub use bottom_sheet::BottomSheet;
+pub use carousel::Carousel;
+pub use chat_view::ChatView;
+pub use contribution_calendar::ContributionCalendar;
+pub use countdown::Countdown;
+pub use diff_text::DiffText;
+pub use drawer::Drawer;
 pub use ellipsized_text::EllipsizedText;
+pub use emoji_picker::EmojiPicker;
+pub use file_drop::FileDrop;
+pub use filmstrip::Filmstrip;
+pub use flow::Flow;
+pub use gradient_editor::GradientEditor;
+pub use grid::Grid;
+pub use heatmap::Heatmap;
+pub use hotkeys::Hotkeys;
+pub use image_viewer::ImageViewer;
+pub use kanban::Kanban;
+pub use keycap::Keycap;
+pub use log_view::LogView;
+pub use magnifier::Magnifier;
+pub use markdown_text::MarkdownText;
+pub use menu_bar::MenuBar;
+pub use modal::Modal;
+pub use perf_hud::PerfHud;
+pub use popover::Popover;
+pub use prompt::Prompt;
+pub use quantity_input::QuantityInput;
+pub use range_slider::RangeSlider;
+pub use refreshable::Refreshable;
+pub use remote_cursors::RemoteCursors;
+pub use reveal_on_scroll::RevealOnScroll;
+pub use rich_tooltip::RichTooltip;
+pub use scroll_progress::ScrollProgress;
+pub use selection_list::SelectionList;
+pub use status_bar::StatusBar;
+pub use steps::Steps;
+pub use tab_bar::TabBar;
+pub use task_button::TaskButton;
+pub use time_picker::TimePicker;
+pub use transform_box::TransformBox;
 pub use typewriter::Typewriter;
+pub use xy_pad::XyPad;
 
 #[cfg(feature = "rand")]
 pub use diffused_text::DiffusedText;
 
+#[cfg(feature = "rand")]
+pub use secret_text::SecretText;
+
+#[cfg(feature = "syntect")]
+pub use code_block::CodeBlock;
+
+#[cfg(feature = "geometry")]
+pub use annotation_layer::AnnotationLayer;
+
+#[cfg(feature = "geometry")]
+pub use waveform::Waveform;
+
 #[cfg(feature = "geometry")]
 pub use dynamic_text::DynamicText;
 
 use crate::core;
 use crate::core::border;
+use crate::core::time::Instant;
 use crate::core::{Alignment, Color, Element, Length};
 use iced_widget::{container, row, slider, space, stack, text};
 
+use std::collections::BTreeMap;
 use std::ops::RangeInclusive;
 
 pub fn typewriter<'a, Theme, Renderer>(
@@ -43,6 +158,16 @@ where
     EllipsizedText::new(fragment)
 }
 
+pub fn countdown<'a, Message, Theme, Renderer>(
+    deadline: Instant,
+) -> Countdown<'a, Message, Theme, Renderer>
+where
+    Theme: core::widget::text::Catalog,
+    Renderer: core::text::Renderer,
+{
+    Countdown::new(deadline)
+}
+
 #[cfg(feature = "rand")]
 pub fn diffused_text<'a, Theme, Renderer>(
     fragment: impl core::text::IntoFragment<'a>,
@@ -54,6 +179,29 @@ where
     DiffusedText::new(fragment)
 }
 
+#[cfg(feature = "rand")]
+pub fn secret_text<'a, Theme, Renderer>(
+    fragment: impl core::text::IntoFragment<'a>,
+) -> SecretText<'a, Theme, Renderer>
+where
+    Theme: core::widget::text::Catalog,
+    Renderer: core::text::Renderer,
+{
+    SecretText::new(fragment)
+}
+
+#[cfg(feature = "syntect")]
+pub fn code_block<'a, Message, Theme, Renderer>(
+    source: &'a str,
+    language: &'a str,
+) -> CodeBlock<'a, Message, Theme, Renderer>
+where
+    Theme: code_block::Catalog,
+    Renderer: core::text::Renderer<Font = core::Font>,
+{
+    CodeBlock::new(source, language)
+}
+
 #[cfg(feature = "geometry")]
 pub fn dynamic_text<'a, Theme, Renderer>(
     fragment: impl core::text::IntoFragment<'a>,
@@ -65,6 +213,474 @@ where
     DynamicText::new(fragment)
 }
 
+#[cfg(feature = "geometry")]
+pub fn annotation_layer<'a, Message, Theme, Renderer>(
+    annotations: &'a annotation_layer::Annotations,
+) -> AnnotationLayer<'a, Message, Theme, Renderer>
+where
+    Theme: annotation_layer::Catalog,
+    Renderer: iced_widget::graphics::geometry::Renderer,
+{
+    AnnotationLayer::new(annotations)
+}
+
+#[cfg(feature = "geometry")]
+pub fn waveform<'a, Message, Theme, Renderer>(
+    samples: &'a [f32],
+) -> Waveform<'a, Message, Theme, Renderer>
+where
+    Theme: waveform::Catalog,
+    Renderer: iced_widget::graphics::geometry::Renderer,
+{
+    Waveform::new(samples)
+}
+
+pub fn avatar<'a, Theme, Renderer>(
+    name: impl core::text::IntoFragment<'a>,
+) -> Avatar<'a, Theme, Renderer>
+where
+    Theme: avatar::Catalog,
+    Renderer: core::image::Renderer + core::text::Renderer,
+{
+    Avatar::new(name)
+}
+
+pub fn avatar_stack<'a, Theme, Renderer>(
+    avatars: impl IntoIterator<Item = Avatar<'a, Theme, Renderer>>,
+) -> AvatarStack<'a, Theme, Renderer>
+where
+    Theme: avatar::Catalog,
+    Renderer: core::image::Renderer + core::text::Renderer,
+{
+    AvatarStack::new(avatars)
+}
+
+pub fn hotkeys<'a, Message, Theme, Renderer>(
+    bindings: impl IntoIterator<Item = (&'a str, Message)>,
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> Hotkeys<'a, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    Hotkeys::new(bindings, content)
+}
+
+pub fn keycap<'a, Theme, Renderer>(shortcut: &str) -> Keycap<'a, Theme, Renderer>
+where
+    Theme: keycap::Catalog,
+    Renderer: core::text::Renderer,
+{
+    Keycap::new(shortcut)
+}
+
+pub fn image_viewer<Handle>(handle: impl Into<Handle>) -> ImageViewer<Handle> {
+    ImageViewer::new(handle)
+}
+
+pub fn log_view<'a, Theme, Renderer>(log: &'a log_view::Log) -> LogView<'a, Theme, Renderer>
+where
+    Theme: log_view::Catalog,
+    Renderer: core::text::Renderer,
+{
+    LogView::new(log)
+}
+
+pub fn chat_view<'a, Theme, Renderer>(chat: &'a chat_view::Chat) -> ChatView<'a, Theme, Renderer>
+where
+    Theme: chat_view::Catalog,
+    Renderer: core::text::Renderer,
+{
+    ChatView::new(chat)
+}
+
+pub fn file_drop<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> FileDrop<'a, Message, Theme, Renderer>
+where
+    Theme: file_drop::Catalog,
+    Renderer: core::Renderer,
+{
+    FileDrop::new(content)
+}
+
+pub fn markdown_text<'a, Message, Theme, Renderer>(
+    source: &'a str,
+) -> MarkdownText<'a, Message, Theme, Renderer>
+where
+    Theme: markdown_text::Catalog,
+    Renderer: core::text::Renderer<Font = core::Font>,
+{
+    MarkdownText::new(source)
+}
+
+pub fn prompt<'a, Message, Theme, Renderer>() -> Prompt<'a, Message, Theme, Renderer>
+where
+    Theme: prompt::Catalog,
+    Renderer: core::text::Renderer,
+{
+    Prompt::new()
+}
+
+pub fn scroll_progress<'a, Message, Theme, Renderer>(
+    target: impl Into<core::widget::Id>,
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> ScrollProgress<'a, Message, Theme, Renderer>
+where
+    Theme: scroll_progress::Catalog,
+    Renderer: core::Renderer,
+{
+    ScrollProgress::new(target, content)
+}
+
+pub fn reveal_on_scroll<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> RevealOnScroll<'a, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    RevealOnScroll::new(content)
+}
+
+pub fn steps<'a, Message, Theme, Renderer>(current: usize) -> Steps<'a, Message, Theme, Renderer>
+where
+    Theme: steps::Catalog,
+    Renderer: core::text::Renderer,
+{
+    Steps::new(current)
+}
+
+pub fn time_picker<'a, Message, Theme, Renderer>(
+    time: time_picker::Time,
+) -> TimePicker<'a, Message, Theme, Renderer>
+where
+    Theme: time_picker::Catalog,
+    Renderer: core::text::Renderer,
+{
+    TimePicker::new(time)
+}
+
+pub fn menu_bar<'a, Message, Theme, Renderer>(
+    menus: impl IntoIterator<Item = menu_bar::Item<'a, Message>>,
+) -> MenuBar<'a, Message, Theme, Renderer>
+where
+    Theme: menu_bar::Catalog,
+    Renderer: core::text::Renderer,
+{
+    MenuBar::new(menus)
+}
+
+pub fn modal<'a, Message, Theme, Renderer>(
+    base: impl Into<Element<'a, Message, Theme, Renderer>>,
+    dialogs: impl IntoIterator<Item = Element<'a, Message, Theme, Renderer>>,
+) -> Modal<'a, Message, Theme, Renderer>
+where
+    Theme: modal::Catalog,
+    Renderer: core::Renderer,
+{
+    Modal::new(base, dialogs)
+}
+
+pub fn diff_text<'a, Theme, Renderer>(old: &'a str, new: &'a str) -> DiffText<'a, Theme, Renderer>
+where
+    Theme: diff_text::Catalog,
+    Renderer: core::text::Renderer,
+{
+    DiffText::new(old, new)
+}
+
+pub fn drawer<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    panel: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> Drawer<'a, Message, Theme, Renderer>
+where
+    Theme: drawer::Catalog,
+    Renderer: core::Renderer,
+{
+    Drawer::new(content, panel)
+}
+
+pub fn flow<'a, Message, Theme, Renderer>(
+    children: impl IntoIterator<Item = Element<'a, Message, Theme, Renderer>>,
+) -> Flow<'a, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    Flow::with_children(children)
+}
+
+pub fn grid<'a, Message, Theme, Renderer>(
+    children: impl IntoIterator<Item = Element<'a, Message, Theme, Renderer>>,
+) -> Grid<'a, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    Grid::with_children(children)
+}
+
+pub fn heatmap<'a, Message, Theme, Renderer>(
+    rows: usize,
+    columns: usize,
+    values: &'a [f32],
+) -> Heatmap<'a, Message, Theme, Renderer>
+where
+    Theme: heatmap::Catalog,
+    Renderer: core::text::Renderer,
+{
+    Heatmap::new(rows, columns, values)
+}
+
+pub fn transform_box<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> TransformBox<'a, Message, Theme, Renderer>
+where
+    Theme: transform_box::Catalog,
+    Renderer: core::Renderer,
+{
+    TransformBox::new(content)
+}
+
+pub fn carousel<'a, Message, Theme, Renderer>(
+    pages: impl IntoIterator<Item = Element<'a, Message, Theme, Renderer>>,
+) -> Carousel<'a, Message, Theme, Renderer>
+where
+    Theme: carousel::Catalog,
+    Renderer: core::Renderer,
+{
+    Carousel::new(pages)
+}
+
+pub fn refreshable<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> Refreshable<'a, Message, Theme, Renderer>
+where
+    Theme: refreshable::Catalog,
+    Renderer: core::Renderer,
+{
+    Refreshable::new(content)
+}
+
+pub fn kanban<'a, Message, Theme, Renderer>(
+    columns: impl IntoIterator<Item = kanban::Column<'a, Message, Theme, Renderer>>,
+) -> Kanban<'a, Message, Theme, Renderer>
+where
+    Theme: kanban::Catalog,
+    Renderer: core::Renderer,
+{
+    Kanban::new(columns)
+}
+
+pub fn selection_list<'a, T, Message, Theme, Renderer>(
+    items: &'a [T],
+    selected: &'a [usize],
+) -> SelectionList<'a, T, Message, Theme, Renderer>
+where
+    T: std::fmt::Display,
+    Theme: selection_list::Catalog,
+    Renderer: core::text::Renderer,
+{
+    SelectionList::new(items, selected)
+}
+
+pub fn accordion<'a, Message, Theme, Renderer>(
+    sections: impl IntoIterator<Item = accordion::Section<'a, Message, Theme, Renderer>>,
+) -> Accordion<'a, Message, Theme, Renderer>
+where
+    Theme: accordion::Catalog,
+    Renderer: core::Renderer,
+{
+    Accordion::new(sections)
+}
+
+pub fn rich_tooltip<'a, Message, Theme, Renderer>(
+    target: impl Into<Element<'a, Message, Theme, Renderer>>,
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> RichTooltip<'a, Message, Theme, Renderer>
+where
+    Theme: rich_tooltip::Catalog,
+    Renderer: core::Renderer,
+{
+    RichTooltip::new(target, content)
+}
+
+pub fn tab_bar<'a, Message, Theme, Renderer>(
+    tabs: impl IntoIterator<Item = tab_bar::Tab<'a>>,
+) -> TabBar<'a, Message, Theme, Renderer>
+where
+    Theme: tab_bar::Catalog,
+    Renderer: core::text::Renderer,
+{
+    TabBar::new(tabs)
+}
+
+pub fn bottom_sheet<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    sheet: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> BottomSheet<'a, Message, Theme, Renderer>
+where
+    Theme: bottom_sheet::Catalog,
+    Renderer: core::Renderer,
+{
+    BottomSheet::new(content, sheet)
+}
+
+pub fn magnifier<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> Magnifier<'a, Message, Theme, Renderer>
+where
+    Theme: magnifier::Catalog,
+    Renderer: core::Renderer,
+{
+    Magnifier::new(content)
+}
+
+pub fn status_bar<'a, Message, Theme, Renderer>(
+    left: impl IntoIterator<Item = status_bar::Item<'a, Message, Theme, Renderer>>,
+    center: impl IntoIterator<Item = status_bar::Item<'a, Message, Theme, Renderer>>,
+    right: impl IntoIterator<Item = status_bar::Item<'a, Message, Theme, Renderer>>,
+) -> StatusBar<'a, Message, Theme, Renderer>
+where
+    Theme: status_bar::Catalog,
+    Renderer: core::Renderer,
+{
+    StatusBar::new(left, center, right)
+}
+
+pub fn filmstrip<'a, T, Message, Theme, Renderer>(
+    items: &'a [T],
+    view: impl Fn(&'a T) -> Element<'a, Message, Theme, Renderer> + 'a,
+) -> Filmstrip<'a, T, Message, Theme, Renderer>
+where
+    Theme: filmstrip::Catalog,
+    Renderer: core::Renderer,
+{
+    Filmstrip::new(items, view)
+}
+
+pub fn remote_cursors<'a, Theme, Renderer>(
+    cursors: &'a [remote_cursors::Cursor],
+) -> RemoteCursors<'a, Theme, Renderer>
+where
+    Theme: remote_cursors::Catalog,
+    Renderer: core::text::Renderer,
+{
+    RemoteCursors::new(cursors)
+}
+
+pub fn contribution_calendar<'a, Message, Theme, Renderer>(
+    values_by_date: &'a BTreeMap<contribution_calendar::Date, f32>,
+) -> ContributionCalendar<'a, Message, Theme, Renderer>
+where
+    Theme: contribution_calendar::Catalog,
+    Renderer: core::text::Renderer,
+{
+    ContributionCalendar::new(values_by_date)
+}
+
+pub fn perf_hud<'a, Theme>() -> PerfHud<'a, Theme>
+where
+    Theme: perf_hud::Catalog,
+{
+    PerfHud::new()
+}
+
+pub fn popover<'a, Message, Theme, Renderer>(
+    target: impl Into<Element<'a, Message, Theme, Renderer>>,
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> Popover<'a, Message, Theme, Renderer>
+where
+    Theme: popover::Catalog,
+    Renderer: core::Renderer,
+{
+    Popover::new(target, content)
+}
+
+pub fn emoji_picker<'a, Message, Theme, Renderer>(
+    target: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> EmojiPicker<'a, Message, Theme, Renderer>
+where
+    Theme: emoji_picker::Catalog + popover::Catalog,
+    Renderer: core::text::Renderer,
+{
+    EmojiPicker::new(target)
+}
+
+pub fn quantity_input<'a, U, Message, Theme, Renderer>(
+    units: &'a [U],
+    value: f32,
+    unit: U,
+) -> QuantityInput<'a, U, Message, Theme, Renderer>
+where
+    U: Clone + PartialEq + std::fmt::Display,
+    Theme: quantity_input::Catalog,
+    Renderer: core::text::Renderer,
+{
+    QuantityInput::new(units, value, unit)
+}
+
+pub fn xy_pad<'a, Message, Theme>(
+    range_x: RangeInclusive<f32>,
+    range_y: RangeInclusive<f32>,
+    value: (f32, f32),
+) -> XyPad<'a, Message, Theme>
+where
+    Theme: xy_pad::Catalog,
+{
+    XyPad::new(range_x, range_y, value)
+}
+
+pub fn task_button<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> TaskButton<'a, Message, Theme, Renderer>
+where
+    Theme: task_button::Catalog,
+    Renderer: core::Renderer,
+{
+    TaskButton::new(content)
+}
+
+pub fn aspect_ratio<'a, Message, Theme, Renderer>(
+    ratio: f32,
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> AspectRatio<'a, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    AspectRatio::new(ratio, content)
+}
+
+pub fn letterbox<'a, Message, Theme, Renderer>(
+    ratio: f32,
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> Letterbox<'a, Message, Theme, Renderer>
+where
+    Theme: aspect_ratio::Catalog,
+    Renderer: core::Renderer,
+{
+    Letterbox::new(ratio, content)
+}
+
+pub fn range_slider<'a, T, Message, Theme>(
+    range: RangeInclusive<T>,
+    values: (T, T),
+    on_change: impl Fn((T, T)) -> Message + 'a,
+) -> RangeSlider<'a, T, Message, Theme>
+where
+    T: Copy + From<u8> + PartialOrd,
+    Theme: range_slider::Catalog,
+{
+    RangeSlider::new(range, values, on_change)
+}
+
+pub fn gradient_editor<'a, Message, Theme>(
+    stops: &[gradient_editor::Stop],
+    on_change: impl Fn(Vec<gradient_editor::Stop>) -> Message + 'a,
+) -> GradientEditor<'a, Message, Theme>
+where
+    Theme: gradient_editor::Catalog,
+{
+    GradientEditor::new(stops, on_change)
+}
+
 pub fn labeled_slider<'a, T, Message, Renderer>(
     label: impl text::IntoFragment<'a>,
     (range, step): (RangeInclusive<T>, T),
@@ -128,3 +744,17 @@ where
     ]
     .into()
 }
+
+pub fn shortcut_hint<'a, Message, Renderer>(
+    label: impl text::IntoFragment<'a>,
+    shortcut: &str,
+) -> Element<'a, Message, core::Theme, Renderer>
+where
+    Message: 'a,
+    Renderer: core::text::Renderer + 'a,
+{
+    row![text(label), space::horizontal(), keycap(shortcut).size(12)]
+        .spacing(10)
+        .align_y(Alignment::Center)
+        .into()
+}
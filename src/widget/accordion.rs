@@ -0,0 +1,886 @@
+use crate::core::animation::{Animation, Easing};
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::time::{Duration, Instant, milliseconds};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::{Operation, Widget};
+use crate::core::window;
+use crate::core::{
+    Background, Border, Clipboard, Color, Element, Event, Length, Padding, Pixels, Point,
+    Rectangle, Shell, Size, Theme, Vector,
+};
+
+/// The size of the expansion indicator of a header.
+const INDICATOR_SIZE: f32 = 10.0;
+
+/// A group of sections with headers that expand and collapse their content.
+///
+/// The expansion of every section is owned by the application and changed
+/// through [`Accordion::on_toggle`]. An [`exclusive`](Accordion::exclusive)
+/// accordion also asks to collapse the other sections when one expands.
+pub struct Accordion<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    sections: Vec<Section<'a, Message, Theme, Renderer>>,
+    on_toggle: Option<Box<dyn Fn(usize, bool) -> Message + 'a>>,
+    is_exclusive: bool,
+    width: Length,
+    padding: Padding,
+    spacing: f32,
+    duration: Duration,
+    class: Theme::Class<'a>,
+}
+
+/// A section of an [`Accordion`].
+pub struct Section<'a, Message, Theme, Renderer> {
+    header: Element<'a, Message, Theme, Renderer>,
+    content: Element<'a, Message, Theme, Renderer>,
+    icon: Option<Element<'a, Message, Theme, Renderer>>,
+    is_expanded: bool,
+    is_disabled: bool,
+}
+
+impl<'a, Message, Theme, Renderer> Section<'a, Message, Theme, Renderer> {
+    pub fn new(
+        header: impl Into<Element<'a, Message, Theme, Renderer>>,
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self {
+            header: header.into(),
+            content: content.into(),
+            icon: None,
+            is_expanded: false,
+            is_disabled: false,
+        }
+    }
+
+    /// Sets the icon shown before the header.
+    pub fn icon(mut self, icon: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    pub fn expanded(mut self, is_expanded: bool) -> Self {
+        self.is_expanded = is_expanded;
+        self
+    }
+
+    /// Sets whether the [`Section`] ignores clicks on its header.
+    pub fn disabled(mut self, is_disabled: bool) -> Self {
+        self.is_disabled = is_disabled;
+        self
+    }
+
+    fn elements(&self) -> impl Iterator<Item = &Element<'a, Message, Theme, Renderer>> {
+        [&self.header, &self.content].into_iter().chain(&self.icon)
+    }
+}
+
+/// Creates a new [`Section`] with the given header and content.
+pub fn section<'a, Message, Theme, Renderer>(
+    header: impl Into<Element<'a, Message, Theme, Renderer>>,
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> Section<'a, Message, Theme, Renderer> {
+    Section::new(header, content)
+}
+
+impl<'a, Message, Theme, Renderer> Accordion<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    pub fn new(sections: impl IntoIterator<Item = Section<'a, Message, Theme, Renderer>>) -> Self {
+        Self {
+            sections: sections.into_iter().collect(),
+            on_toggle: None,
+            is_exclusive: false,
+            width: Length::Fill,
+            padding: Padding::new(10.0),
+            spacing: 0.0,
+            duration: milliseconds(200),
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the message produced when a section asks to be expanded or
+    /// collapsed.
+    pub fn on_toggle(mut self, on_toggle: impl Fn(usize, bool) -> Message + 'a) -> Self {
+        self.on_toggle = Some(Box::new(on_toggle));
+        self
+    }
+
+    /// Sets whether expanding a section collapses the others.
+    pub fn exclusive(mut self, is_exclusive: bool) -> Self {
+        self.is_exclusive = is_exclusive;
+        self
+    }
+
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the padding of the headers and the content of the sections.
+    pub fn padding(mut self, padding: impl Into<Padding>) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the spacing between sections.
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.spacing = spacing.into().0;
+        self
+    }
+
+    pub fn duration(mut self, duration: impl Into<Duration>) -> Self {
+        self.duration = duration.into();
+        self
+    }
+
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    fn toggle(&self, index: usize, shell: &mut Shell<'_, Message>) {
+        let Some(on_toggle) = &self.on_toggle else {
+            return;
+        };
+
+        let is_expanded = !self.sections[index].is_expanded;
+
+        if self.is_exclusive && is_expanded {
+            for (other, section) in self.sections.iter().enumerate() {
+                if other != index && section.is_expanded {
+                    shell.publish(on_toggle(other, false));
+                }
+            }
+        }
+
+        shell.publish(on_toggle(index, is_expanded));
+    }
+}
+
+struct State {
+    sections: Vec<Expansion>,
+    hovered: Option<usize>,
+    now: Instant,
+}
+
+struct Expansion {
+    is_expanded: bool,
+    animation: Animation<bool>,
+    /// Whether the animation starts on the next redraw.
+    is_pending: bool,
+}
+
+impl State {
+    fn tick(&mut self, now: Instant) {
+        self.now = now;
+
+        for section in &mut self.sections {
+            if std::mem::take(&mut section.is_pending) {
+                section.animation.go_mut(section.is_expanded, now);
+            }
+        }
+    }
+
+    fn progress(&self, index: usize) -> f32 {
+        self.sections.get(index).map_or(0.0, |section| {
+            section.animation.interpolate(0.0, 1.0, self.now)
+        })
+    }
+
+    fn is_animating(&self) -> bool {
+        self.sections
+            .iter()
+            .any(|section| section.animation.is_animating(self.now))
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Accordion<'_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: renderer::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            sections: self
+                .sections
+                .iter()
+                .map(|section| Expansion {
+                    is_expanded: section.is_expanded,
+                    animation: Animation::new(section.is_expanded)
+                        .duration(self.duration)
+                        .easing(Easing::EaseOut),
+                    is_pending: false,
+                })
+                .collect(),
+            hovered: None,
+            now: Instant::now(),
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.sections
+            .iter()
+            .flat_map(Section::elements)
+            .map(Tree::new)
+            .collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let elements: Vec<_> = self.sections.iter().flat_map(Section::elements).collect();
+
+        tree.diff_children(&elements);
+
+        let state = tree.state.downcast_mut::<State>();
+
+        state.sections.truncate(self.sections.len());
+
+        for section in &self.sections[state.sections.len()..] {
+            state.sections.push(Expansion {
+                is_expanded: section.is_expanded,
+                animation: Animation::new(section.is_expanded)
+                    .duration(self.duration)
+                    .easing(Easing::EaseOut),
+                is_pending: false,
+            });
+        }
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, Length::Shrink)
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let state = tree.state.downcast_mut::<State>();
+
+        for (section, expansion) in self.sections.iter().zip(&mut state.sections) {
+            if section.is_expanded != expansion.is_expanded {
+                expansion.is_expanded = section.is_expanded;
+                expansion.animation = expansion.animation.clone().duration(self.duration);
+                expansion.is_pending = true;
+            }
+        }
+
+        let limits = limits.width(self.width);
+        let width = limits.max().width;
+        let padding = self.padding;
+        let inner = (width - padding.x()).max(0.0);
+
+        let mut trees = tree.children.iter_mut();
+        let mut nodes = Vec::with_capacity(self.sections.len());
+        let mut y = 0.0;
+
+        for (index, section) in self.sections.iter_mut().enumerate() {
+            let header_tree = trees.next().unwrap();
+            let content_tree = trees.next().unwrap();
+
+            let icon = section.icon.as_mut().map(|icon| {
+                icon.as_widget_mut().layout(
+                    trees.next().unwrap(),
+                    renderer,
+                    &layout::Limits::new(Size::ZERO, Size::new(inner, f32::INFINITY)),
+                )
+            });
+
+            let icon_width = icon
+                .as_ref()
+                .map_or(0.0, |icon| icon.size().width + padding.left);
+
+            let header = section.header.as_widget_mut().layout(
+                header_tree,
+                renderer,
+                &layout::Limits::new(
+                    Size::ZERO,
+                    Size::new(
+                        (inner - icon_width - INDICATOR_SIZE - padding.right).max(0.0),
+                        f32::INFINITY,
+                    ),
+                ),
+            );
+
+            let header_height = header
+                .size()
+                .height
+                .max(icon.as_ref().map_or(0.0, |icon| icon.size().height))
+                + padding.y();
+
+            let header = {
+                let height = header.size().height;
+
+                header.move_to(Point::new(
+                    padding.left + icon_width,
+                    (header_height - height) / 2.0,
+                ))
+            };
+
+            let content = section
+                .content
+                .as_widget_mut()
+                .layout(
+                    content_tree,
+                    renderer,
+                    &layout::Limits::new(Size::ZERO, Size::new(inner, f32::INFINITY)).width(inner),
+                )
+                .move_to(Point::new(padding.left, header_height + padding.top));
+
+            let content_height = content.size().height + padding.y();
+            let height = header_height + content_height * state.progress(index);
+
+            let mut children = vec![header, content];
+
+            if let Some(icon) = icon {
+                let height = icon.size().height;
+
+                children
+                    .push(icon.move_to(Point::new(padding.left, (header_height - height) / 2.0)));
+            }
+
+            nodes.push(
+                layout::Node::with_children(Size::new(width, height), children)
+                    .move_to(Point::new(0.0, y)),
+            );
+
+            y += height + self.spacing;
+        }
+
+        let height = (y - self.spacing).max(0.0);
+
+        layout::Node::with_children(
+            limits.resolve(self.width, Length::Shrink, Size::new(width, height)),
+            nodes,
+        )
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        operation.container(None, layout.bounds());
+        operation.traverse(&mut |operation| {
+            let mut trees = tree.children.iter_mut();
+
+            for (section, layout) in self.sections.iter_mut().zip(layout.children()) {
+                let mut children = layout.children();
+                let (header, content) = (children.next().unwrap(), children.next().unwrap());
+
+                section.header.as_widget_mut().operate(
+                    trees.next().unwrap(),
+                    header,
+                    renderer,
+                    operation,
+                );
+
+                let content_tree = trees.next().unwrap();
+
+                if section.is_expanded {
+                    section.content.as_widget_mut().operate(
+                        content_tree,
+                        content,
+                        renderer,
+                        operation,
+                    );
+                }
+
+                if let Some(icon) = &mut section.icon {
+                    icon.as_widget_mut().operate(
+                        trees.next().unwrap(),
+                        children.next().unwrap(),
+                        renderer,
+                        operation,
+                    );
+                }
+            }
+        });
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        {
+            let state = tree.state.downcast_mut::<State>();
+
+            if let Event::Window(window::Event::RedrawRequested(now)) = event {
+                state.tick(*now);
+
+                if state.is_animating() {
+                    shell.invalidate_layout();
+                    shell.request_redraw();
+                }
+            }
+        }
+
+        let progress: Vec<f32> = {
+            let state = tree.state.downcast_ref::<State>();
+
+            (0..self.sections.len())
+                .map(|index| state.progress(index))
+                .collect()
+        };
+
+        let mut trees = tree.children.iter_mut();
+        let mut clicked = None;
+        let mut hovered = None;
+
+        for (index, (section, layout)) in
+            self.sections.iter_mut().zip(layout.children()).enumerate()
+        {
+            let bounds = layout.bounds();
+            let mut children = layout.children();
+            let (header, content) = (children.next().unwrap(), children.next().unwrap());
+            let header_bounds = Rectangle {
+                height: content.position().y - bounds.y - self.padding.top,
+                ..bounds
+            };
+
+            section.header.as_widget_mut().update(
+                trees.next().unwrap(),
+                event,
+                header,
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+                viewport,
+            );
+
+            let content_tree = trees.next().unwrap();
+
+            // The content only takes input once fully expanded
+            if progress[index] >= 1.0 || matches!(event, Event::Window(_)) {
+                section.content.as_widget_mut().update(
+                    content_tree,
+                    event,
+                    content,
+                    cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    viewport,
+                );
+            }
+
+            if let Some(icon) = &mut section.icon {
+                icon.as_widget_mut().update(
+                    trees.next().unwrap(),
+                    event,
+                    children.next().unwrap(),
+                    cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    viewport,
+                );
+            }
+
+            if !section.is_disabled && cursor.is_over(header_bounds) {
+                hovered = Some(index);
+
+                if let Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) = event {
+                    clicked = Some(index);
+                }
+            }
+        }
+
+        let state = tree.state.downcast_mut::<State>();
+
+        if matches!(event, Event::Mouse(mouse::Event::CursorMoved { .. }))
+            && state.hovered != hovered
+        {
+            state.hovered = hovered;
+            shell.request_redraw();
+        }
+
+        if let Some(index) = clicked.filter(|_| !shell.is_event_captured()) {
+            self.toggle(index, shell);
+            shell.capture_event();
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+        let mut trees = tree.children.iter();
+
+        for (index, (section, layout)) in self.sections.iter().zip(layout.children()).enumerate() {
+            let mut children = layout.children();
+            let (header, content) = (children.next().unwrap(), children.next().unwrap());
+            let (header_tree, content_tree) = (trees.next().unwrap(), trees.next().unwrap());
+            let icon_tree = section.icon.as_ref().and_then(|_| trees.next());
+
+            if !cursor.is_over(layout.bounds()) {
+                continue;
+            }
+
+            let interaction = section.header.as_widget().mouse_interaction(
+                header_tree,
+                header,
+                cursor,
+                viewport,
+                renderer,
+            );
+
+            if interaction != mouse::Interaction::None {
+                return interaction;
+            }
+
+            if state.progress(index) >= 1.0 && cursor.is_over(content.bounds()) {
+                return section.content.as_widget().mouse_interaction(
+                    content_tree,
+                    content,
+                    cursor,
+                    viewport,
+                    renderer,
+                );
+            }
+
+            if let Some((icon, tree)) = section.icon.as_ref().zip(icon_tree) {
+                let interaction = icon.as_widget().mouse_interaction(
+                    tree,
+                    children.next().unwrap(),
+                    cursor,
+                    viewport,
+                    renderer,
+                );
+
+                if interaction != mouse::Interaction::None {
+                    return interaction;
+                }
+            }
+
+            return if section.is_disabled {
+                mouse::Interaction::NotAllowed
+            } else if cursor
+                .position()
+                .is_some_and(|position| position.y < content.position().y)
+            {
+                mouse::Interaction::Pointer
+            } else {
+                mouse::Interaction::None
+            };
+        }
+
+        mouse::Interaction::None
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _defaults: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let style = theme.style(&self.class);
+        let mut trees = tree.children.iter();
+
+        for (index, (section, layout)) in self.sections.iter().zip(layout.children()).enumerate() {
+            let bounds = layout.bounds();
+            let mut children = layout.children();
+            let (header, content) = (children.next().unwrap(), children.next().unwrap());
+            let (header_tree, content_tree) = (trees.next().unwrap(), trees.next().unwrap());
+
+            let header_bounds = Rectangle {
+                height: content.position().y - bounds.y - self.padding.top,
+                ..bounds
+            };
+
+            let progress = state.progress(index);
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border: style.border,
+                    ..renderer::Quad::default()
+                },
+                style.content,
+            );
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: header_bounds,
+                    border: style.border,
+                    ..renderer::Quad::default()
+                },
+                if state.hovered == Some(index) {
+                    style.hovered_header
+                } else {
+                    style.header
+                },
+            );
+
+            let defaults = renderer::Style {
+                text_color: if section.is_disabled {
+                    style.text_color.scale_alpha(0.5)
+                } else {
+                    style.text_color
+                },
+            };
+
+            section.header.as_widget().draw(
+                header_tree,
+                renderer,
+                theme,
+                &defaults,
+                header,
+                cursor,
+                viewport,
+            );
+
+            let icon_tree = section.icon.as_ref().and_then(|_| trees.next());
+
+            if let Some((icon, tree)) = section.icon.as_ref().zip(icon_tree) {
+                icon.as_widget().draw(
+                    tree,
+                    renderer,
+                    theme,
+                    &defaults,
+                    children.next().unwrap(),
+                    cursor,
+                    viewport,
+                );
+            }
+
+            // A plus turning into a minus as the section expands
+            let center = Point::new(
+                bounds.x + bounds.width - self.padding.right - INDICATOR_SIZE / 2.0,
+                header_bounds.center_y(),
+            );
+
+            let color = if section.is_disabled {
+                style.indicator.scale_alpha(0.5)
+            } else {
+                style.indicator
+            };
+
+            for (width, height) in [
+                (INDICATOR_SIZE, 2.0),
+                (2.0, INDICATOR_SIZE * (1.0 - progress)),
+            ] {
+                if height <= 0.0 {
+                    continue;
+                }
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: center.x - width / 2.0,
+                            y: center.y - height / 2.0,
+                            width,
+                            height,
+                        },
+                        border: Border::default().rounded(1),
+                        ..renderer::Quad::default()
+                    },
+                    color,
+                );
+            }
+
+            if progress <= 0.0 {
+                continue;
+            }
+
+            let body = Rectangle {
+                y: header_bounds.y + header_bounds.height,
+                height: bounds.height - header_bounds.height,
+                ..bounds
+            };
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        height: 1.0,
+                        ..body
+                    },
+                    ..renderer::Quad::default()
+                },
+                style.divider,
+            );
+
+            let Some(clip) = body.intersection(viewport) else {
+                continue;
+            };
+
+            renderer.with_layer(clip, |renderer| {
+                section.content.as_widget().draw(
+                    content_tree,
+                    renderer,
+                    theme,
+                    &renderer::Style {
+                        text_color: style.text_color,
+                    },
+                    content,
+                    if progress >= 1.0 {
+                        cursor
+                    } else {
+                        mouse::Cursor::Unavailable
+                    },
+                    &clip,
+                );
+            });
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let mut children = Vec::new();
+        let mut trees = tree.children.iter_mut();
+
+        for (section, layout) in self.sections.iter_mut().zip(layout.children()) {
+            let mut layouts = layout.children();
+            let (header, content) = (layouts.next().unwrap(), layouts.next().unwrap());
+            let (header_tree, content_tree) = (trees.next().unwrap(), trees.next().unwrap());
+            let icon_tree = section.icon.as_ref().and_then(|_| trees.next());
+
+            children.extend(section.header.as_widget_mut().overlay(
+                header_tree,
+                header,
+                renderer,
+                viewport,
+                translation,
+            ));
+
+            if section.is_expanded {
+                children.extend(section.content.as_widget_mut().overlay(
+                    content_tree,
+                    content,
+                    renderer,
+                    viewport,
+                    translation,
+                ));
+            }
+
+            if let Some((icon, tree)) = section.icon.as_mut().zip(icon_tree) {
+                children.extend(icon.as_widget_mut().overlay(
+                    tree,
+                    layouts.next().unwrap(),
+                    renderer,
+                    viewport,
+                    translation,
+                ));
+            }
+        }
+
+        (!children.is_empty()).then(|| overlay::Group::with_children(children).overlay())
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Accordion<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(accordion: Accordion<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(accordion)
+    }
+}
+
+/// The appearance of an [`Accordion`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Background`] of the headers.
+    pub header: Background,
+    /// The [`Background`] of a hovered header.
+    pub hovered_header: Background,
+    /// The [`Background`] of the content of the sections.
+    pub content: Background,
+    /// The [`Border`] of the sections and their headers.
+    pub border: Border,
+    /// The [`Color`] of the line between a header and its content.
+    pub divider: Color,
+    /// The [`Color`] of the expansion indicators.
+    pub indicator: Color,
+    /// The default text [`Color`] of the headers and the content.
+    pub text_color: Color,
+}
+
+/// The theme catalog of an [`Accordion`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for an [`Accordion`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of an [`Accordion`].
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        header: palette.background.weak.color.into(),
+        hovered_header: palette.background.strong.color.into(),
+        content: palette.background.base.color.into(),
+        border: Border::default()
+            .rounded(4)
+            .width(1)
+            .color(palette.background.strong.color),
+        divider: palette.background.strong.color,
+        indicator: palette.background.weak.text,
+        text_color: palette.background.base.text,
+    }
+}
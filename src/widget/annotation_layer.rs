@@ -0,0 +1,890 @@
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::widget::Widget;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::{
+    Clipboard, Color, Element, Event, Length, Pixels, Point, Rectangle, Shell, Size, Theme, Vector,
+};
+
+use iced_widget::canvas;
+use iced_widget::graphics::geometry;
+
+use std::cell::Cell;
+use std::fmt;
+use std::str::FromStr;
+
+/// A set of freehand annotations with an undo history.
+///
+/// It can be saved and restored in a plain text format, one annotation
+/// per line, through its [`Display`](fmt::Display) and [`FromStr`]
+/// implementations.
+#[derive(Debug, Clone, Default)]
+pub struct Annotations {
+    items: Vec<(Id, Annotation)>,
+    history: Vec<Edit>,
+    undone: Vec<Edit>,
+    next_id: u64,
+    generation: u64,
+}
+
+/// The identifier of an annotation in some [`Annotations`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Id(u64);
+
+/// A mark made on an [`AnnotationLayer`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum Annotation {
+    /// A freehand pen stroke.
+    Stroke {
+        points: Vec<Point>,
+        color: Color,
+        thickness: f32,
+    },
+    /// A straight arrow pointing at `to`.
+    Arrow {
+        from: Point,
+        to: Point,
+        color: Color,
+        thickness: f32,
+    },
+    /// A single line of text.
+    Label {
+        position: Point,
+        text: String,
+        color: Color,
+        size: f32,
+    },
+}
+
+/// A change produced by an [`AnnotationLayer`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum Action {
+    Add(Annotation),
+    Erase(Id),
+}
+
+#[derive(Debug, Clone)]
+enum Edit {
+    Insert(usize, Id, Annotation),
+    Remove(Id),
+}
+
+impl Annotations {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Applies an [`Action`], which can then be undone.
+    pub fn perform(&mut self, action: Action) {
+        let edit = match action {
+            Action::Add(annotation) => {
+                let id = Id(self.next_id);
+                self.next_id += 1;
+
+                Edit::Insert(self.items.len(), id, annotation)
+            }
+            Action::Erase(id) => Edit::Remove(id),
+        };
+
+        if let Some(inverse) = self.apply(edit) {
+            self.history.push(inverse);
+            self.undone.clear();
+        }
+    }
+
+    pub fn undo(&mut self) {
+        if let Some(inverse) = self.history.pop().and_then(|edit| self.apply(edit)) {
+            self.undone.push(inverse);
+        }
+    }
+
+    pub fn redo(&mut self) {
+        if let Some(inverse) = self.undone.pop().and_then(|edit| self.apply(edit)) {
+            self.history.push(inverse);
+        }
+    }
+
+    pub fn can_undo(&self) -> bool {
+        !self.history.is_empty()
+    }
+
+    pub fn can_redo(&self) -> bool {
+        !self.undone.is_empty()
+    }
+
+    /// Removes all the annotations and forgets the undo history.
+    pub fn clear(&mut self) {
+        self.items.clear();
+        self.history.clear();
+        self.undone.clear();
+        self.generation += 1;
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = (Id, &Annotation)> {
+        self.items.iter().map(|(id, annotation)| (*id, annotation))
+    }
+
+    /// Applies an [`Edit`] and returns the one that reverts it.
+    fn apply(&mut self, edit: Edit) -> Option<Edit> {
+        let inverse = match edit {
+            Edit::Insert(index, id, annotation) => {
+                self.items
+                    .insert(index.min(self.items.len()), (id, annotation));
+
+                Edit::Remove(id)
+            }
+            Edit::Remove(id) => {
+                let index = self.items.iter().position(|(item, _)| *item == id)?;
+                let (_, annotation) = self.items.remove(index);
+
+                Edit::Insert(index, id, annotation)
+            }
+        };
+
+        self.generation += 1;
+
+        Some(inverse)
+    }
+}
+
+impl fmt::Display for Annotations {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (_, annotation) in &self.items {
+            match annotation {
+                Annotation::Stroke {
+                    points,
+                    color,
+                    thickness,
+                } => {
+                    write!(f, "stroke {color} {thickness}")?;
+
+                    for point in points {
+                        write!(f, " {},{}", point.x, point.y)?;
+                    }
+                }
+                Annotation::Arrow {
+                    from,
+                    to,
+                    color,
+                    thickness,
+                } => {
+                    write!(
+                        f,
+                        "arrow {color} {thickness} {},{} {},{}",
+                        from.x, from.y, to.x, to.y
+                    )?;
+                }
+                Annotation::Label {
+                    position,
+                    text,
+                    color,
+                    size,
+                } => {
+                    write!(
+                        f,
+                        "label {color} {size} {},{} {text}",
+                        position.x, position.y
+                    )?;
+                }
+            }
+
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for Annotations {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut annotations = Self::new();
+
+        for (i, line) in s.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let annotation = parse_line(line).ok_or(ParseError { line: i + 1 })?;
+
+            annotations
+                .items
+                .push((Id(annotations.next_id), annotation));
+            annotations.next_id += 1;
+        }
+
+        Ok(annotations)
+    }
+}
+
+fn parse_line(line: &str) -> Option<Annotation> {
+    let mut parts = line.splitn(5, ' ');
+
+    let kind = parts.next()?;
+    let color = Color::from_str(parts.next()?).ok()?;
+    let number = parts.next()?.parse().ok()?;
+
+    let point = |part: &str| {
+        let (x, y) = part.split_once(',')?;
+
+        Some(Point::new(x.parse().ok()?, y.parse().ok()?))
+    };
+
+    match kind {
+        "stroke" => Some(Annotation::Stroke {
+            points: parts
+                .flat_map(|rest| rest.split(' '))
+                .map(point)
+                .collect::<Option<_>>()?,
+            color,
+            thickness: number,
+        }),
+        "arrow" => Some(Annotation::Arrow {
+            from: point(parts.next()?)?,
+            to: point(parts.next()?)?,
+            color,
+            thickness: number,
+        }),
+        "label" => Some(Annotation::Label {
+            position: point(parts.next()?)?,
+            text: parts.next().unwrap_or_default().to_owned(),
+            color,
+            size: number,
+        }),
+        _ => None,
+    }
+}
+
+/// An error produced when parsing [`Annotations`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseError {
+    /// The line with the invalid annotation, starting at 1.
+    pub line: usize,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid annotation at line {}", self.line)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl Annotation {
+    /// Returns true if the annotation is within the given distance of a point.
+    fn is_near(&self, point: Point, distance: f32) -> bool {
+        match self {
+            Annotation::Stroke {
+                points, thickness, ..
+            } => {
+                let distance = distance + thickness / 2.0;
+
+                match points.as_slice() {
+                    [single] => single.distance(point) <= distance,
+                    points => points
+                        .windows(2)
+                        .any(|segment| to_segment(point, segment[0], segment[1]) <= distance),
+                }
+            }
+            Annotation::Arrow {
+                from,
+                to,
+                thickness,
+                ..
+            } => to_segment(point, *from, *to) <= distance + thickness / 2.0,
+            Annotation::Label {
+                position,
+                text,
+                size,
+                ..
+            } => Rectangle::new(
+                *position,
+                Size::new(text.chars().count() as f32 * size * 0.6, size * 1.2),
+            )
+            .expand(distance)
+            .contains(point),
+        }
+    }
+
+    fn draw<Renderer: geometry::Renderer>(&self, frame: &mut canvas::Frame<Renderer>) {
+        match self {
+            Annotation::Stroke {
+                points,
+                color,
+                thickness,
+            } => {
+                if let [single] = points.as_slice() {
+                    frame.fill(&canvas::Path::circle(*single, thickness / 2.0), *color);
+                    return;
+                }
+
+                let path = canvas::Path::new(|builder| {
+                    for (i, point) in points.iter().enumerate() {
+                        if i == 0 {
+                            builder.move_to(*point);
+                        } else {
+                            builder.line_to(*point);
+                        }
+                    }
+                });
+
+                frame.stroke(&path, stroke(*color, *thickness));
+            }
+            Annotation::Arrow {
+                from,
+                to,
+                color,
+                thickness,
+            } => {
+                let direction = *to - *from;
+                let length = (direction.x.powi(2) + direction.y.powi(2)).sqrt();
+
+                if length == 0.0 {
+                    return;
+                }
+
+                let unit = direction * (1.0 / length);
+                let head = (thickness * 4.0).min(length);
+                let back = *to - unit * head;
+                let side = Vector::new(-unit.y, unit.x) * (head * 0.5);
+
+                let path = canvas::Path::new(|builder| {
+                    builder.move_to(*from);
+                    builder.line_to(*to);
+                    builder.move_to(back + side);
+                    builder.line_to(*to);
+                    builder.line_to(back - side);
+                });
+
+                frame.stroke(&path, stroke(*color, *thickness));
+            }
+            Annotation::Label {
+                position,
+                text,
+                color,
+                size,
+            } => {
+                frame.fill_text(canvas::Text {
+                    content: text.clone(),
+                    position: *position,
+                    color: *color,
+                    size: Pixels(*size),
+                    ..canvas::Text::default()
+                });
+            }
+        }
+    }
+}
+
+fn stroke(color: Color, thickness: f32) -> canvas::Stroke<'static> {
+    canvas::Stroke::default()
+        .with_color(color)
+        .with_width(thickness)
+        .with_line_cap(canvas::LineCap::Round)
+        .with_line_join(canvas::LineJoin::Round)
+}
+
+/// Returns the distance from a point to the segment between `a` and `b`.
+fn to_segment(point: Point, a: Point, b: Point) -> f32 {
+    let segment = b - a;
+    let length = segment.x.powi(2) + segment.y.powi(2);
+
+    if length == 0.0 {
+        return point.distance(a);
+    }
+
+    let offset = point - a;
+    let t = ((offset.x * segment.x + offset.y * segment.y) / length).clamp(0.0, 1.0);
+
+    point.distance(a + segment * t)
+}
+
+/// The tool used to annotate an [`AnnotationLayer`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Tool {
+    #[default]
+    Pen,
+    Arrow,
+    Text,
+    Eraser,
+}
+
+/// A transparent layer to draw [`Annotations`] on, meant to be stacked over
+/// other content.
+///
+/// Annotations are stored in an unbounded space, which can be moved with
+/// [`translation`](Self::translation) to follow the content underneath.
+///
+/// Without [`on_action`](Self::on_action), the layer is read-only and lets
+/// every event through.
+pub struct AnnotationLayer<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    annotations: &'a Annotations,
+    on_action: Option<Box<dyn Fn(Action) -> Message + 'a>>,
+    tool: Tool,
+    color: Option<Color>,
+    thickness: f32,
+    text_size: f32,
+    translation: Vector,
+    width: Length,
+    height: Length,
+    class: Theme::Class<'a>,
+    renderer: std::marker::PhantomData<Renderer>,
+}
+
+impl<'a, Message, Theme, Renderer> AnnotationLayer<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    pub fn new(annotations: &'a Annotations) -> Self {
+        Self {
+            annotations,
+            on_action: None,
+            tool: Tool::default(),
+            color: None,
+            thickness: 3.0,
+            text_size: 18.0,
+            translation: Vector::ZERO,
+            width: Length::Fill,
+            height: Length::Fill,
+            class: Theme::default(),
+            renderer: std::marker::PhantomData,
+        }
+    }
+
+    pub fn on_action(mut self, on_action: impl Fn(Action) -> Message + 'a) -> Self {
+        self.on_action = Some(Box::new(on_action));
+        self
+    }
+
+    pub fn tool(mut self, tool: Tool) -> Self {
+        self.tool = tool;
+        self
+    }
+
+    /// Sets the [`Color`] of new annotations, instead of the ink of the
+    /// [`Style`].
+    pub fn color(mut self, color: impl Into<Color>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Sets the thickness of new strokes and arrows.
+    pub fn thickness(mut self, thickness: impl Into<Pixels>) -> Self {
+        self.thickness = thickness.into().0;
+        self
+    }
+
+    /// Sets the size of new labels.
+    pub fn text_size(mut self, text_size: impl Into<Pixels>) -> Self {
+        self.text_size = text_size.into().0;
+        self
+    }
+
+    /// Sets the position of the origin of the annotations in the layer.
+    pub fn translation(mut self, translation: impl Into<Vector>) -> Self {
+        self.translation = translation.into();
+        self
+    }
+
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    fn ink<R>(&self, state: &State<R>) -> Color
+    where
+        R: geometry::Renderer,
+    {
+        self.color.unwrap_or(state.ink.get())
+    }
+
+    fn label(&self, position: Point, text: String, color: Color) -> Annotation {
+        Annotation::Label {
+            position,
+            text,
+            color,
+            size: self.text_size,
+        }
+    }
+}
+
+struct State<Renderer>
+where
+    Renderer: geometry::Renderer,
+{
+    drawing: Option<Drawing>,
+    label: Option<(Point, String)>,
+    erased: Vec<Id>,
+    cache: canvas::Cache<Renderer>,
+    cached: Cell<Option<(u64, Vector)>>,
+    /// The ink of the last drawn [`Style`], since the theme is only
+    /// available while drawing.
+    ink: Cell<Color>,
+}
+
+enum Drawing {
+    Stroke(Vec<Point>),
+    Arrow(Point, Point),
+    Erasing,
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for AnnotationLayer<'_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: geometry::Renderer + 'static,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<Renderer>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::<Renderer> {
+            drawing: None,
+            label: None,
+            erased: Vec::new(),
+            cache: canvas::Cache::new(),
+            cached: Cell::new(None),
+            ink: Cell::new(Color::BLACK),
+        })
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        layout::atomic(limits, self.width, self.height)
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let Some(on_action) = &self.on_action else {
+            return;
+        };
+
+        let state = tree.state.downcast_mut::<State<Renderer>>();
+        let bounds = layout.bounds();
+        let origin = bounds.position() + self.translation;
+        let ink = self.ink(state);
+
+        let erase = |state: &mut State<Renderer>, point: Point, shell: &mut Shell<'_, Message>| {
+            for (id, annotation) in self.annotations.iter().rev() {
+                if !state.erased.contains(&id) && annotation.is_near(point, self.thickness) {
+                    state.erased.push(id);
+                    shell.publish(on_action(Action::Erase(id)));
+                }
+            }
+        };
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                let Some(position) = cursor.position_over(bounds) else {
+                    return;
+                };
+
+                let point = Point::ORIGIN + (position - origin);
+
+                if let Some((position, text)) =
+                    state.label.take().filter(|(_, text)| !text.is_empty())
+                {
+                    shell.publish(on_action(Action::Add(self.label(position, text, ink))));
+                }
+
+                match self.tool {
+                    Tool::Pen => {
+                        state.drawing = Some(Drawing::Stroke(vec![point]));
+                    }
+                    Tool::Arrow => {
+                        state.drawing = Some(Drawing::Arrow(point, point));
+                    }
+                    Tool::Text => {
+                        state.label = Some((point, String::new()));
+                    }
+                    Tool::Eraser => {
+                        state.drawing = Some(Drawing::Erasing);
+                        erase(state, point, shell);
+                    }
+                }
+
+                shell.request_redraw();
+                shell.capture_event();
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position }) => {
+                let Some(drawing) = &mut state.drawing else {
+                    return;
+                };
+
+                let point = Point::ORIGIN + (*position - origin);
+
+                match drawing {
+                    Drawing::Stroke(points) => {
+                        if points.last().is_none_or(|last| last.distance(point) >= 1.0) {
+                            points.push(point);
+                        }
+                    }
+                    Drawing::Arrow(_, to) => {
+                        *to = point;
+                    }
+                    Drawing::Erasing => {
+                        erase(state, point, shell);
+                    }
+                }
+
+                shell.request_redraw();
+                shell.capture_event();
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                let Some(drawing) = state.drawing.take() else {
+                    return;
+                };
+
+                match drawing {
+                    Drawing::Stroke(points) => {
+                        shell.publish(on_action(Action::Add(Annotation::Stroke {
+                            points,
+                            color: ink,
+                            thickness: self.thickness,
+                        })));
+                    }
+                    Drawing::Arrow(from, to) => {
+                        if from.distance(to) >= self.thickness {
+                            shell.publish(on_action(Action::Add(Annotation::Arrow {
+                                from,
+                                to,
+                                color: ink,
+                                thickness: self.thickness,
+                            })));
+                        }
+                    }
+                    Drawing::Erasing => {
+                        state.erased.clear();
+                    }
+                }
+
+                shell.request_redraw();
+                shell.capture_event();
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key,
+                modifiers,
+                text,
+                ..
+            }) => {
+                let Some((position, label)) = &mut state.label else {
+                    return;
+                };
+
+                match key.as_ref() {
+                    keyboard::Key::Named(key::Named::Enter) => {
+                        let label = std::mem::take(label);
+
+                        if !label.is_empty() {
+                            shell
+                                .publish(on_action(Action::Add(self.label(*position, label, ink))));
+                        }
+
+                        state.label = None;
+                    }
+                    keyboard::Key::Named(key::Named::Escape) => {
+                        state.label = None;
+                    }
+                    keyboard::Key::Named(key::Named::Backspace) => {
+                        let _ = label.pop();
+                    }
+                    _ => {
+                        let Some(text) = text.as_deref() else {
+                            return;
+                        };
+
+                        if modifiers.command() || text.chars().any(char::is_control) {
+                            return;
+                        }
+
+                        label.push_str(text);
+                    }
+                }
+
+                shell.request_redraw();
+                shell.capture_event();
+            }
+            _ => {}
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if self.on_action.is_none() || !cursor.is_over(layout.bounds()) {
+            return mouse::Interaction::None;
+        }
+
+        match self.tool {
+            Tool::Pen | Tool::Arrow | Tool::Eraser => mouse::Interaction::Crosshair,
+            Tool::Text => mouse::Interaction::Text,
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State<Renderer>>();
+        state.ink.set(theme.style(&self.class).ink);
+
+        let ink = self.ink(state);
+        let bounds = layout.bounds();
+        let key = Some((self.annotations.generation, self.translation));
+
+        if state.cached.replace(key) != key {
+            state.cache.clear();
+        }
+
+        let annotations = state.cache.draw(renderer, bounds.size(), |frame| {
+            frame.translate(self.translation);
+
+            for (_, annotation) in self.annotations.iter() {
+                annotation.draw(frame);
+            }
+        });
+
+        let preview = match (&state.drawing, &state.label) {
+            (Some(Drawing::Stroke(points)), _) => Some(Annotation::Stroke {
+                points: points.clone(),
+                color: ink,
+                thickness: self.thickness,
+            }),
+            (Some(Drawing::Arrow(from, to)), _) => Some(Annotation::Arrow {
+                from: *from,
+                to: *to,
+                color: ink,
+                thickness: self.thickness,
+            }),
+            (_, Some((position, text))) => Some(self.label(*position, format!("{text}|"), ink)),
+            _ => None,
+        };
+
+        renderer.with_translation(bounds.position() - Point::ORIGIN, |renderer| {
+            renderer.draw_geometry(annotations);
+
+            if let Some(preview) = preview {
+                let mut frame = canvas::Frame::new(renderer, bounds.size());
+                frame.translate(self.translation);
+
+                preview.draw(&mut frame);
+
+                renderer.draw_geometry(frame.into_geometry());
+            }
+        });
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<AnnotationLayer<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: geometry::Renderer + 'static,
+{
+    fn from(annotation_layer: AnnotationLayer<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(annotation_layer)
+    }
+}
+
+/// The appearance of an [`AnnotationLayer`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Color`] of new annotations, unless one is set explicitly.
+    pub ink: Color,
+}
+
+/// The theme catalog of an [`AnnotationLayer`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for an [`AnnotationLayer`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of an [`AnnotationLayer`].
+pub fn default(theme: &Theme) -> Style {
+    Style {
+        ink: theme.extended_palette().danger.base.color,
+    }
+}
@@ -0,0 +1,586 @@
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::widget::{Operation, Tree, Widget};
+use crate::core::{
+    Background, Clipboard, Color, Element, Event, Length, Point, Rectangle, Shell, Size, Theme,
+    Vector,
+};
+
+/// A widget that sizes its content to a given aspect ratio within the
+/// available space.
+pub struct AspectRatio<'a, Message, Theme, Renderer> {
+    ratio: f32,
+    fit: Fit,
+    width: Length,
+    height: Length,
+    content: Element<'a, Message, Theme, Renderer>,
+}
+
+/// The strategy used to fit some content of a certain aspect ratio inside
+/// of some bounds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Fit {
+    /// The content is as large as possible while staying inside the bounds.
+    #[default]
+    Contain,
+    /// The content is as small as possible while covering the bounds.
+    ///
+    /// The parts that overflow are clipped.
+    Cover,
+}
+
+impl Fit {
+    fn size(self, ratio: f32, bounds: Size) -> Size {
+        let by_width = Size::new(bounds.width, bounds.width / ratio);
+        let by_height = Size::new(bounds.height * ratio, bounds.height);
+
+        if !bounds.height.is_finite() {
+            return by_width;
+        }
+
+        if !bounds.width.is_finite() {
+            return by_height;
+        }
+
+        let fits_width = by_width.height <= bounds.height;
+
+        match self {
+            Fit::Contain if fits_width => by_width,
+            Fit::Contain => by_height,
+            Fit::Cover if fits_width => by_height,
+            Fit::Cover => by_width,
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> AspectRatio<'a, Message, Theme, Renderer> {
+    /// Creates a new [`AspectRatio`] with the given ratio of width to height.
+    pub fn new(ratio: f32, content: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        Self {
+            ratio,
+            fit: Fit::default(),
+            width: Length::Fill,
+            height: Length::Shrink,
+            content: content.into(),
+        }
+    }
+
+    pub fn fit(mut self, fit: Fit) -> Self {
+        self.fit = fit;
+        self
+    }
+
+    /// Makes the content cover the available space, clipping any overflow.
+    pub fn cover(self) -> Self {
+        self.fit(Fit::Cover)
+    }
+
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for AspectRatio<'_, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+        let available = Size::new(
+            if self.width == Length::Shrink {
+                f32::INFINITY
+            } else {
+                limits.max().width
+            },
+            if self.height == Length::Shrink {
+                f32::INFINITY
+            } else {
+                limits.max().height
+            },
+        );
+
+        let content = self.fit.size(self.ratio, available);
+
+        let size = limits.resolve(
+            self.width,
+            self.height,
+            match self.fit {
+                Fit::Contain => content,
+                Fit::Cover => available.min(content),
+            },
+        );
+
+        let content = self.fit.size(self.ratio, size);
+
+        layout::Node::with_children(
+            size,
+            vec![
+                self.content
+                    .as_widget_mut()
+                    .layout(
+                        &mut tree.children[0],
+                        renderer,
+                        &layout::Limits::new(content, content),
+                    )
+                    .move_to(Point::new(
+                        (size.width - content.width) / 2.0,
+                        (size.height - content.height) / 2.0,
+                    )),
+            ],
+        )
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        operation.container(None, layout.bounds());
+        operation.traverse(&mut |operation| {
+            self.content.as_widget_mut().operate(
+                &mut tree.children[0],
+                layout.children().next().unwrap(),
+                renderer,
+                operation,
+            );
+        });
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        let cursor = visible_cursor(cursor, layout.bounds());
+
+        self.content.as_widget_mut().update(
+            &mut tree.children[0],
+            event,
+            layout.children().next().unwrap(),
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout.children().next().unwrap(),
+            visible_cursor(cursor, layout.bounds()),
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let content = layout.children().next().unwrap();
+
+        let Some(clip) = bounds.intersection(viewport) else {
+            return;
+        };
+
+        let draw = |renderer: &mut Renderer| {
+            self.content.as_widget().draw(
+                &tree.children[0],
+                renderer,
+                theme,
+                style,
+                content,
+                visible_cursor(cursor, bounds),
+                &clip,
+            );
+        };
+
+        if self.fit == Fit::Cover {
+            renderer.with_layer(clip, draw);
+        } else {
+            draw(renderer);
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout.children().next().unwrap(),
+            renderer,
+            viewport,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<AspectRatio<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(aspect_ratio: AspectRatio<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(aspect_ratio)
+    }
+}
+
+/// A widget that fills the available space and centers its content at a
+/// given aspect ratio, drawing bars on the remaining space.
+pub struct Letterbox<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    ratio: f32,
+    width: Length,
+    height: Length,
+    content: Element<'a, Message, Theme, Renderer>,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> Letterbox<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    /// Creates a new [`Letterbox`] with the given ratio of width to height.
+    pub fn new(ratio: f32, content: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        Self {
+            ratio,
+            width: Length::Fill,
+            height: Length::Fill,
+            content: content.into(),
+            class: Theme::default(),
+        }
+    }
+
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Letterbox<'_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: renderer::Renderer,
+{
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+        let fitted = Fit::Contain.size(self.ratio, limits.max());
+        let size = limits.resolve(self.width, self.height, fitted);
+        let content = Fit::Contain.size(self.ratio, size);
+
+        layout::Node::with_children(
+            size,
+            vec![
+                self.content
+                    .as_widget_mut()
+                    .layout(
+                        &mut tree.children[0],
+                        renderer,
+                        &layout::Limits::new(content, content),
+                    )
+                    .move_to(Point::new(
+                        (size.width - content.width) / 2.0,
+                        (size.height - content.height) / 2.0,
+                    )),
+            ],
+        )
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        operation.container(None, layout.bounds());
+        operation.traverse(&mut |operation| {
+            self.content.as_widget_mut().operate(
+                &mut tree.children[0],
+                layout.children().next().unwrap(),
+                renderer,
+                operation,
+            );
+        });
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget_mut().update(
+            &mut tree.children[0],
+            event,
+            layout.children().next().unwrap(),
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout.children().next().unwrap(),
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let content = layout.children().next().unwrap();
+        let appearance = theme.style(&self.class);
+
+        let content_bounds = content.bounds();
+
+        // Draw the bars on both sides of the content, either horizontal
+        // or vertical
+        let bars = if content_bounds.width < bounds.width {
+            let width = content_bounds.x - bounds.x;
+
+            [
+                Rectangle { width, ..bounds },
+                Rectangle {
+                    x: content_bounds.x + content_bounds.width,
+                    width,
+                    ..bounds
+                },
+            ]
+        } else {
+            let height = content_bounds.y - bounds.y;
+
+            [
+                Rectangle { height, ..bounds },
+                Rectangle {
+                    y: content_bounds.y + content_bounds.height,
+                    height,
+                    ..bounds
+                },
+            ]
+        };
+
+        for bar in bars {
+            if bar.width > 0.0 && bar.height > 0.0 {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: bar,
+                        ..renderer::Quad::default()
+                    },
+                    appearance.bars,
+                );
+            }
+        }
+
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            content,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout.children().next().unwrap(),
+            renderer,
+            viewport,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Letterbox<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(letterbox: Letterbox<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(letterbox)
+    }
+}
+
+fn visible_cursor(cursor: mouse::Cursor, bounds: Rectangle) -> mouse::Cursor {
+    if cursor.is_over(bounds) {
+        cursor
+    } else {
+        mouse::Cursor::Unavailable
+    }
+}
+
+/// The appearance of a [`Letterbox`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Background`] of the bars.
+    pub bars: Background,
+}
+
+/// The theme catalog of a [`Letterbox`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`Letterbox`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`Letterbox`], with black bars.
+pub fn default(_theme: &Theme) -> Style {
+    Style {
+        bars: Color::BLACK.into(),
+    }
+}
@@ -0,0 +1,561 @@
+use crate::core::alignment;
+use crate::core::border;
+use crate::core::image;
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::text::{self, Fragment, IntoFragment};
+use crate::core::widget::Widget;
+use crate::core::widget::tree::Tree;
+use crate::core::{
+    Background, Border, Color, ContentFit, Element, Image, Length, Pixels, Point, Radians,
+    Rectangle, Size, Theme,
+};
+
+const DEFAULT_SIZE: f32 = 40.0;
+
+pub struct Avatar<'a, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: image::Renderer + text::Renderer,
+{
+    name: Fragment<'a>,
+    image: Option<<Renderer as image::Renderer>::Handle>,
+    presence: Option<Presence>,
+    size: f32,
+    font: Option<<Renderer as text::Renderer>::Font>,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Theme, Renderer> Avatar<'a, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: image::Renderer + text::Renderer,
+{
+    pub fn new(name: impl IntoFragment<'a>) -> Self {
+        Self {
+            name: name.into_fragment(),
+            image: None,
+            presence: None,
+            size: DEFAULT_SIZE,
+            font: None,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the image of the [`Avatar`].
+    ///
+    /// The initials of the name are shown until the image is loaded.
+    /// Only square images are cut into a circle; other images are
+    /// cropped to a square around their center.
+    pub fn image(mut self, image: impl Into<<Renderer as image::Renderer>::Handle>) -> Self {
+        self.image = Some(image.into());
+        self
+    }
+
+    pub fn image_maybe(
+        mut self,
+        image: Option<impl Into<<Renderer as image::Renderer>::Handle>>,
+    ) -> Self {
+        self.image = image.map(Into::into);
+        self
+    }
+
+    pub fn presence(mut self, presence: Presence) -> Self {
+        self.presence = Some(presence);
+        self
+    }
+
+    pub fn presence_maybe(mut self, presence: Option<Presence>) -> Self {
+        self.presence = presence;
+        self
+    }
+
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.size = size.into().0;
+        self
+    }
+
+    pub fn small(self) -> Self {
+        self.size(24)
+    }
+
+    pub fn large(self) -> Self {
+        self.size(64)
+    }
+
+    pub fn font(mut self, font: impl Into<<Renderer as text::Renderer>::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Option<Presence>) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    fn draw_avatar(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        bounds: Rectangle,
+        ring: f32,
+        viewport: &Rectangle,
+    ) {
+        let style = theme.style(&self.class, self.presence);
+        let radius = bounds.width / 2.0;
+
+        if ring > 0.0 {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: bounds.expand(ring),
+                    border: border::rounded(radius + ring),
+                    ..renderer::Quad::default()
+                },
+                style.ring,
+            );
+        }
+
+        let image_size = self
+            .image
+            .as_ref()
+            .and_then(|image| renderer.measure_image(image))
+            .filter(|size| size.width > 0 && size.height > 0);
+
+        match (&self.image, image_size) {
+            (Some(handle), Some(image_size)) => {
+                let image_size = Size::new(image_size.width as f32, image_size.height as f32);
+                let size = ContentFit::Cover.fit(image_size, bounds.size());
+                let center = bounds.center();
+
+                let drawing_bounds = Rectangle::new(
+                    Point::new(center.x - size.width / 2.0, center.y - size.height / 2.0),
+                    size,
+                );
+
+                renderer.draw_image(
+                    Image {
+                        handle: handle.clone(),
+                        filter_method: image::FilterMethod::default(),
+                        rotation: Radians(0.0),
+                        border_radius: border::Radius::from(radius),
+                        opacity: 1.0,
+                        snap: true,
+                    },
+                    drawing_bounds,
+                    bounds,
+                );
+            }
+            _ => {
+                draw_label(
+                    renderer,
+                    bounds,
+                    style
+                        .background
+                        .unwrap_or_else(|| Background::Color(color(&self.name))),
+                    initials(&self.name),
+                    style.text_color,
+                    self.font,
+                    viewport,
+                );
+            }
+        }
+
+        if self.presence.is_some() {
+            let dot = bounds.width * 0.3;
+            let border = (bounds.width * 0.06).max(1.0);
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: bounds.x + bounds.width - dot,
+                        y: bounds.y + bounds.height - dot,
+                        width: dot,
+                        height: dot,
+                    },
+                    border: Border {
+                        color: style.ring,
+                        width: border,
+                        radius: (dot / 2.0).into(),
+                    },
+                    ..renderer::Quad::default()
+                },
+                style.presence,
+            );
+        }
+    }
+}
+
+fn draw_label<Renderer>(
+    renderer: &mut Renderer,
+    bounds: Rectangle,
+    background: Background,
+    content: String,
+    color: Color,
+    font: Option<Renderer::Font>,
+    viewport: &Rectangle,
+) where
+    Renderer: text::Renderer,
+{
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds,
+            border: border::rounded(bounds.width / 2.0),
+            ..renderer::Quad::default()
+        },
+        background,
+    );
+
+    renderer.fill_text(
+        text::Text {
+            content,
+            bounds: bounds.size(),
+            size: Pixels(bounds.width * 0.4),
+            line_height: text::LineHeight::default(),
+            font: font.unwrap_or_else(|| renderer.default_font()),
+            align_x: text::Alignment::Center,
+            align_y: alignment::Vertical::Center,
+            shaping: text::Shaping::Advanced,
+            wrapping: text::Wrapping::None,
+            hint_factor: renderer.scale_factor(),
+        },
+        bounds.center(),
+        color,
+        *viewport,
+    );
+}
+
+/// The presence of the person behind an [`Avatar`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Presence {
+    Online,
+    Away,
+    Busy,
+    Offline,
+}
+
+/// Returns up to two uppercase initials of the given name.
+fn initials(name: &str) -> String {
+    name.split_whitespace()
+        .filter_map(|word| word.chars().next())
+        .take(2)
+        .flat_map(char::to_uppercase)
+        .collect()
+}
+
+/// Returns a color for the given name, always the same for the same name.
+fn color(name: &str) -> Color {
+    // FNV-1a, which is stable across platforms and releases
+    let hash = name.bytes().fold(0x811c9dc5_u32, |hash, byte| {
+        (hash ^ u32::from(byte)).wrapping_mul(0x01000193)
+    });
+
+    let hue = (hash % 360) as f32;
+    let (saturation, lightness) = (0.55, 0.45);
+
+    let chroma = (1.0 - (2.0 * lightness - 1.0_f32).abs()) * saturation;
+    let x = chroma * (1.0 - ((hue / 60.0) % 2.0 - 1.0).abs());
+    let m = lightness - chroma / 2.0;
+
+    let (r, g, b) = match hue as u32 / 60 {
+        0 => (chroma, x, 0.0),
+        1 => (x, chroma, 0.0),
+        2 => (0.0, chroma, x),
+        3 => (0.0, x, chroma),
+        4 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    };
+
+    Color::from_rgb(r + m, g + m, b + m)
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for Avatar<'_, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: image::Renderer + text::Renderer,
+{
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: Length::Fixed(self.size),
+            height: Length::Fixed(self.size),
+        }
+    }
+
+    fn layout(
+        &mut self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        layout::Node::new(limits.resolve(self.size, self.size, Size::ZERO))
+    }
+
+    fn draw(
+        &self,
+        _tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.draw_avatar(renderer, theme, layout.bounds(), 0.0, viewport);
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Avatar<'a, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog + 'a,
+    Renderer: image::Renderer + text::Renderer + 'a,
+{
+    fn from(avatar: Avatar<'a, Theme, Renderer>) -> Self {
+        Element::new(avatar)
+    }
+}
+
+/// A row of overlapping avatars.
+pub struct AvatarStack<'a, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: image::Renderer + text::Renderer,
+{
+    avatars: Vec<Avatar<'a, Theme, Renderer>>,
+    size: Option<f32>,
+    overlap: f32,
+    max: Option<usize>,
+}
+
+impl<'a, Theme, Renderer> AvatarStack<'a, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: image::Renderer + text::Renderer,
+{
+    pub fn new(avatars: impl IntoIterator<Item = Avatar<'a, Theme, Renderer>>) -> Self {
+        Self {
+            avatars: avatars.into_iter().collect(),
+            size: None,
+            overlap: 0.3,
+            max: None,
+        }
+    }
+
+    /// Sets the size of every avatar in the [`AvatarStack`].
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.size = Some(size.into().0);
+        self
+    }
+
+    /// Sets the fraction of each avatar covered by the next one.
+    pub fn overlap(mut self, overlap: f32) -> Self {
+        self.overlap = overlap.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Sets the maximum amount of avatars shown.
+    ///
+    /// The rest are summarized by a last `+N` avatar.
+    pub fn max(mut self, max: usize) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    fn size_of(&self, avatar: &Avatar<'a, Theme, Renderer>) -> f32 {
+        self.size.unwrap_or(avatar.size)
+    }
+
+    fn visible(&self) -> (&[Avatar<'a, Theme, Renderer>], usize) {
+        match self.max {
+            Some(max) if self.avatars.len() > max => {
+                let shown = max.saturating_sub(1);
+
+                (&self.avatars[..shown], self.avatars.len() - shown)
+            }
+            _ => (&self.avatars, 0),
+        }
+    }
+
+    fn slots(&self) -> impl Iterator<Item = Rectangle> + '_ {
+        let (avatars, hidden) = self.visible();
+        let overflow = self
+            .size
+            .unwrap_or_else(|| avatars.last().map_or(DEFAULT_SIZE, |avatar| avatar.size));
+
+        let sizes = avatars
+            .iter()
+            .map(|avatar| self.size_of(avatar))
+            .chain((hidden > 0).then_some(overflow));
+
+        let height = sizes.clone().fold(0.0, f32::max);
+        let mut x = 0.0;
+
+        sizes.map(move |size| {
+            let slot = Rectangle {
+                x,
+                y: (height - size) / 2.0,
+                width: size,
+                height: size,
+            };
+
+            x += size * (1.0 - self.overlap);
+
+            slot
+        })
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for AvatarStack<'_, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: image::Renderer + text::Renderer,
+{
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: Length::Shrink,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let size = self.slots().fold(Size::ZERO, |size, slot| {
+            Size::new(
+                size.width.max(slot.x + slot.width),
+                size.height.max(slot.y + slot.height),
+            )
+        });
+
+        layout::Node::new(limits.resolve(Length::Shrink, Length::Shrink, size))
+    }
+
+    fn draw(
+        &self,
+        _tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let position = layout.position();
+        let (avatars, hidden) = self.visible();
+        let mut slots = self.slots();
+
+        for (avatar, slot) in avatars.iter().zip(&mut slots) {
+            let bounds = slot + (position - Point::ORIGIN);
+            let ring = (bounds.width * 0.05).max(1.0);
+
+            avatar.draw_avatar(renderer, theme, bounds, ring, viewport);
+        }
+
+        if let Some(slot) = slots.next() {
+            let bounds = slot + (position - Point::ORIGIN);
+            let ring = (bounds.width * 0.05).max(1.0);
+
+            let style = theme.style(&<Theme as Catalog>::default(), None);
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: bounds.expand(ring),
+                    border: border::rounded(bounds.width / 2.0 + ring),
+                    ..renderer::Quad::default()
+                },
+                style.ring,
+            );
+
+            draw_label(
+                renderer,
+                bounds,
+                style.overflow_background,
+                format!("+{hidden}"),
+                style.overflow_text_color,
+                avatars.last().and_then(|avatar| avatar.font),
+                viewport,
+            );
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<AvatarStack<'a, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog + 'a,
+    Renderer: image::Renderer + text::Renderer + 'a,
+{
+    fn from(stack: AvatarStack<'a, Theme, Renderer>) -> Self {
+        Element::new(stack)
+    }
+}
+
+/// The appearance of an [`Avatar`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Background`] behind the initials; picked from the name if `None`.
+    pub background: Option<Background>,
+    /// The [`Color`] of the initials.
+    pub text_color: Color,
+    /// The [`Color`] separating stacked avatars and the presence dot.
+    pub ring: Color,
+    /// The [`Color`] of the presence dot.
+    pub presence: Color,
+    /// The [`Background`] of the `+N` avatar of an [`AvatarStack`].
+    pub overflow_background: Background,
+    /// The text [`Color`] of the `+N` avatar of an [`AvatarStack`].
+    pub overflow_text_color: Color,
+}
+
+/// The theme catalog of an [`Avatar`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class with the given presence.
+    fn style(&self, class: &Self::Class<'_>, presence: Option<Presence>) -> Style;
+}
+
+/// A styling function for an [`Avatar`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Option<Presence>) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>, presence: Option<Presence>) -> Style {
+        class(self, presence)
+    }
+}
+
+/// The default style of an [`Avatar`].
+pub fn default(theme: &Theme, presence: Option<Presence>) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        background: None,
+        text_color: Color::WHITE,
+        ring: palette.background.base.color,
+        presence: match presence {
+            Some(Presence::Online) => palette.success.base.color,
+            Some(Presence::Away) => palette.warning.base.color,
+            Some(Presence::Busy) => palette.danger.base.color,
+            Some(Presence::Offline) | None => palette.background.strongest.color,
+        },
+        overflow_background: palette.background.strong.color.into(),
+        overflow_text_color: palette.background.strong.text,
+    }
+}
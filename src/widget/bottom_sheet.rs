@@ -0,0 +1,779 @@
+use crate::core::animation::{Animation, Easing};
+use crate::core::border;
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::time::{Duration, Instant, milliseconds};
+use crate::core::touch;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::{Operation, Widget};
+use crate::core::window;
+use crate::core::{
+    Background, Border, Clipboard, Color, Element, Event, Length, Pixels, Point, Rectangle, Shadow,
+    Shell, Size, Theme, Vector,
+};
+
+/// The height of the grab area at the top of the sheet.
+const HANDLE_HEIGHT: f32 = 24.0;
+
+/// The space left above a fully expanded sheet.
+const FULL_GAP: f32 = 24.0;
+
+/// How far ahead in time the release velocity projects a drag.
+const PROJECTION: f32 = 0.2;
+
+/// A sheet that slides up from the bottom of some content and rests at
+/// a set of [`Detent`] heights.
+///
+/// The handle at the top of the sheet can be dragged between detents; on
+/// release, the sheet settles on the detent closest to where the drag was
+/// heading. The content is dimmed while the sheet rises above its peek
+/// height.
+pub struct BottomSheet<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    content: Element<'a, Message, Theme, Renderer>,
+    sheet: Element<'a, Message, Theme, Renderer>,
+    detent: Detent,
+    detents: Vec<Detent>,
+    on_detent_change: Option<Box<dyn Fn(Detent) -> Message + 'a>>,
+    peek_height: f32,
+    duration: Duration,
+    class: Theme::Class<'a>,
+}
+
+/// A resting height of a [`BottomSheet`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Detent {
+    /// The sheet is out of sight.
+    Hidden,
+    /// Only the top of the sheet is visible.
+    #[default]
+    Peek,
+    /// The sheet covers half of the content.
+    Half,
+    /// The sheet covers almost all of the content.
+    Full,
+}
+
+impl Detent {
+    fn height(self, peek: f32, total: f32) -> f32 {
+        match self {
+            Detent::Hidden => 0.0,
+            Detent::Peek => peek.min(total),
+            Detent::Half => total / 2.0,
+            Detent::Full => (total - FULL_GAP).max(0.0),
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> BottomSheet<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    pub fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        sheet: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            sheet: sheet.into(),
+            detent: Detent::default(),
+            detents: vec![Detent::Peek, Detent::Half, Detent::Full],
+            on_detent_change: None,
+            peek_height: 120.0,
+            duration: milliseconds(300),
+            class: Theme::default(),
+        }
+    }
+
+    pub fn detent(mut self, detent: Detent) -> Self {
+        self.detent = detent;
+        self
+    }
+
+    /// Sets the detents the sheet can be dragged to; peek, half and full
+    /// by default.
+    ///
+    /// Including [`Detent::Hidden`] lets the sheet be dismissed.
+    pub fn detents(mut self, detents: impl IntoIterator<Item = Detent>) -> Self {
+        self.detents = detents.into_iter().collect();
+        self
+    }
+
+    /// Sets the message produced when the sheet asks to rest at another
+    /// detent; after a drag, by clicking the backdrop or pressing Escape.
+    pub fn on_detent_change(mut self, on_detent_change: impl Fn(Detent) -> Message + 'a) -> Self {
+        self.on_detent_change = Some(Box::new(on_detent_change));
+        self
+    }
+
+    pub fn peek_height(mut self, height: impl Into<Pixels>) -> Self {
+        self.peek_height = height.into().0;
+        self
+    }
+
+    pub fn duration(mut self, duration: impl Into<Duration>) -> Self {
+        self.duration = duration.into();
+        self
+    }
+
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    fn change(&self, detent: Detent, shell: &mut Shell<'_, Message>) {
+        if detent == self.detent {
+            return;
+        }
+
+        if let Some(on_detent_change) = &self.on_detent_change {
+            shell.publish(on_detent_change(detent));
+        }
+    }
+
+    /// Returns the lowest detent, where the backdrop is not shown.
+    fn lowest(&self) -> Detent {
+        self.detents
+            .iter()
+            .copied()
+            .min_by_key(|detent| *detent as u8)
+            .unwrap_or(Detent::Peek)
+    }
+
+    /// Returns the detent a drag released at the given height with the
+    /// given velocity settles on.
+    fn settle(&self, height: f32, velocity: f32, total: f32) -> Detent {
+        let projected = height + velocity * PROJECTION;
+
+        self.detents
+            .iter()
+            .copied()
+            .min_by(|a, b| {
+                let a = (a.height(self.peek_height, total) - projected).abs();
+                let b = (b.height(self.peek_height, total) - projected).abs();
+
+                a.total_cmp(&b)
+            })
+            .unwrap_or(self.detent)
+    }
+
+    /// Returns how dimmed the content is, from `0.0` to `1.0`.
+    fn dimming(&self, height: f32, total: f32) -> f32 {
+        let peek = Detent::Peek.height(self.peek_height, total);
+        let half = Detent::Half.height(self.peek_height, total);
+
+        if half <= peek {
+            return 0.0;
+        }
+
+        ((height - peek) / (half - peek)).clamp(0.0, 1.0)
+    }
+}
+
+struct State {
+    detent: Detent,
+    height: f32,
+    from: f32,
+    transition: Animation<bool>,
+    /// Whether the transition starts on the next redraw.
+    is_pending: bool,
+    now: Instant,
+    drag: Option<Drag>,
+}
+
+struct Drag {
+    finger: Option<touch::Finger>,
+    origin: f32,
+    origin_height: f32,
+    height: f32,
+    last: (f32, Instant),
+    velocity: f32,
+}
+
+impl State {
+    fn settle(&mut self, from: f32, duration: Duration) {
+        self.from = from;
+        self.transition = Animation::new(false)
+            .duration(duration)
+            .easing(Easing::EaseOut);
+        self.is_pending = true;
+    }
+
+    fn is_animating(&self) -> bool {
+        self.is_pending || self.transition.is_animating(self.now)
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for BottomSheet<'_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: renderer::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            detent: self.detent,
+            height: 0.0,
+            from: 0.0,
+            transition: Animation::new(true),
+            is_pending: false,
+            now: Instant::now(),
+            drag: None,
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content), Tree::new(&self.sheet)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&[&self.content, &self.sheet]);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.content.as_widget().size_hint()
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let content = self
+            .content
+            .as_widget_mut()
+            .layout(&mut tree.children[0], renderer, limits);
+
+        let size = content.size();
+        let full = Detent::Full.height(self.peek_height, size.height);
+
+        let sheet = self.sheet.as_widget_mut().layout(
+            &mut tree.children[1],
+            renderer,
+            &layout::Limits::new(
+                Size::new(size.width, 0.0),
+                Size::new(size.width, (full - HANDLE_HEIGHT).max(0.0)),
+            ),
+        );
+
+        let state = tree.state.downcast_mut::<State>();
+
+        if state.detent != self.detent {
+            state.detent = self.detent;
+            state.settle(state.height, self.duration);
+        }
+
+        let target = self.detent.height(self.peek_height, size.height);
+
+        state.height = match &state.drag {
+            Some(drag) => drag.height,
+            None => {
+                let progress = state.transition.interpolate(0.0, 1.0, state.now);
+
+                state.from + (target - state.from) * progress
+            }
+        };
+
+        let panel = layout::Node::with_children(
+            Size::new(size.width, full),
+            vec![sheet.move_to(Point::new(0.0, HANDLE_HEIGHT))],
+        )
+        .move_to(Point::new(0.0, size.height - state.height));
+
+        layout::Node::with_children(size, vec![content, panel])
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let mut children = layout.children();
+        let mut trees = tree.children.iter_mut();
+
+        operation.container(None, layout.bounds());
+        operation.traverse(&mut |operation| {
+            self.content.as_widget_mut().operate(
+                trees.next().unwrap(),
+                children.next().unwrap(),
+                renderer,
+                operation,
+            );
+
+            if self.detent != Detent::Hidden {
+                self.sheet.as_widget_mut().operate(
+                    trees.next().unwrap(),
+                    children.next().unwrap().children().next().unwrap(),
+                    renderer,
+                    operation,
+                );
+            }
+        });
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let mut children = layout.children();
+        let (content_layout, panel_layout) = (children.next().unwrap(), children.next().unwrap());
+        let sheet_layout = panel_layout.children().next().unwrap();
+
+        let panel = panel_layout.bounds();
+        let handle = Rectangle {
+            height: HANDLE_HEIGHT,
+            ..panel
+        };
+
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            let state = tree.state.downcast_mut::<State>();
+            state.now = *now;
+
+            if std::mem::take(&mut state.is_pending) {
+                state.transition.go_mut(true, *now);
+            }
+
+            // Dragging keeps the clock running, to measure the velocity
+            if state.transition.is_animating(*now) || state.drag.is_some() {
+                shell.invalidate_layout();
+                shell.request_redraw();
+            }
+        }
+
+        let (height, is_settled) = {
+            let state = tree.state.downcast_ref::<State>();
+
+            (state.height, state.drag.is_none() && !state.is_animating())
+        };
+
+        let is_dimmed = self.dimming(height, bounds.height) > 0.0;
+
+        // The sheet only takes input while resting at a detent
+        if is_settled && height > 0.0 {
+            self.sheet.as_widget_mut().update(
+                &mut tree.children[1],
+                event,
+                sheet_layout,
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+                &panel.intersection(viewport).unwrap_or(panel),
+            );
+        }
+
+        if !is_dimmed && !shell.is_event_captured() {
+            self.content.as_widget_mut().update(
+                &mut tree.children[0],
+                event,
+                content_layout,
+                if cursor.is_over(panel) {
+                    mouse::Cursor::Unavailable
+                } else {
+                    cursor
+                },
+                renderer,
+                clipboard,
+                shell,
+                viewport,
+            );
+        } else if let Event::Window(_) = event {
+            self.content.as_widget_mut().update(
+                &mut tree.children[0],
+                event,
+                content_layout,
+                mouse::Cursor::Unavailable,
+                renderer,
+                clipboard,
+                shell,
+                viewport,
+            );
+        }
+
+        let state = tree.state.downcast_mut::<State>();
+        let now = state.now;
+
+        let start = |finger, y: f32| Drag {
+            finger,
+            origin: y,
+            origin_height: height,
+            height,
+            last: (y, now),
+            velocity: 0.0,
+        };
+
+        let press = match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                cursor.position().map(|position| (None, position))
+            }
+            Event::Touch(touch::Event::FingerPressed { id, position }) => {
+                Some((Some(*id), *position))
+            }
+            _ => None,
+        };
+
+        let movement = match event {
+            Event::Mouse(mouse::Event::CursorMoved { position }) => Some((None, *position)),
+            Event::Touch(touch::Event::FingerMoved { id, position }) => {
+                Some((Some(*id), *position))
+            }
+            _ => None,
+        };
+
+        let release = match event {
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => Some(None),
+            Event::Touch(
+                touch::Event::FingerLifted { id, .. } | touch::Event::FingerLost { id, .. },
+            ) => Some(Some(*id)),
+            _ => None,
+        };
+
+        if let Some((finger, position)) = press {
+            if state.drag.is_none() && height > 0.0 && handle.contains(position) {
+                state.drag = Some(start(finger, position.y));
+                shell.capture_event();
+            } else if is_dimmed && !panel.contains(position) && !shell.is_event_captured() {
+                self.change(self.lowest(), shell);
+                shell.capture_event();
+            }
+        }
+
+        let dragged = movement.and_then(|(finger, position)| {
+            state
+                .drag
+                .as_mut()
+                .filter(|drag| drag.finger == finger)
+                .map(|drag| (drag, position))
+        });
+
+        if let Some((drag, position)) = dragged {
+            let (last, at) = drag.last;
+            let elapsed = now.saturating_duration_since(at).as_secs_f32();
+
+            // Movements within the same frame share its time
+            if elapsed > 0.0 {
+                let velocity = (last - position.y) / elapsed;
+
+                drag.velocity = drag.velocity * 0.2 + velocity * 0.8;
+                drag.last = (position.y, now);
+            }
+
+            drag.height = (drag.origin_height + drag.origin - position.y)
+                .clamp(0.0, Detent::Full.height(self.peek_height, bounds.height));
+
+            shell.invalidate_layout();
+            shell.request_redraw();
+            shell.capture_event();
+        }
+
+        let released = release.and_then(|finger| state.drag.take_if(|drag| drag.finger == finger));
+
+        if let Some(drag) = released {
+            // A pause before releasing stops the sheet in place
+            let velocity = if now.saturating_duration_since(drag.last.1) > milliseconds(100) {
+                0.0
+            } else {
+                drag.velocity
+            };
+
+            state.settle(drag.height, self.duration);
+            self.change(self.settle(drag.height, velocity, bounds.height), shell);
+
+            shell.invalidate_layout();
+            shell.request_redraw();
+            shell.capture_event();
+        }
+
+        let is_escape = matches!(
+            event,
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key::Named::Escape),
+                ..
+            })
+        );
+
+        if is_escape && is_dimmed && !shell.is_event_captured() {
+            self.change(self.lowest(), shell);
+            shell.capture_event();
+        }
+
+        if is_dimmed && matches!(event, Event::Mouse(_) | Event::Touch(_)) {
+            shell.capture_event();
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+        let mut children = layout.children();
+        let (content_layout, panel_layout) = (children.next().unwrap(), children.next().unwrap());
+        let panel = panel_layout.bounds();
+
+        if state.drag.is_some() {
+            return mouse::Interaction::Grabbing;
+        }
+
+        if state.height > 0.0 && cursor.is_over(panel) {
+            let handle = Rectangle {
+                height: HANDLE_HEIGHT,
+                ..panel
+            };
+
+            return if cursor.is_over(handle) {
+                mouse::Interaction::Grab
+            } else {
+                self.sheet.as_widget().mouse_interaction(
+                    &tree.children[1],
+                    panel_layout.children().next().unwrap(),
+                    cursor,
+                    viewport,
+                    renderer,
+                )
+            };
+        }
+
+        if self.dimming(state.height, layout.bounds().height) > 0.0 {
+            return mouse::Interaction::None;
+        }
+
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            content_layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        defaults: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let mut children = layout.children();
+        let (content_layout, panel_layout) = (children.next().unwrap(), children.next().unwrap());
+        let panel = panel_layout.bounds();
+        let dimming = self.dimming(state.height, bounds.height);
+
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            defaults,
+            content_layout,
+            if dimming > 0.0 || cursor.is_over(panel) {
+                mouse::Cursor::Unavailable
+            } else {
+                cursor
+            },
+            viewport,
+        );
+
+        if state.height <= 0.0 {
+            return;
+        }
+
+        let style = theme.style(&self.class);
+
+        renderer.with_layer(bounds, |renderer| {
+            if dimming > 0.0 {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        ..renderer::Quad::default()
+                    },
+                    style.backdrop.scale_alpha(dimming),
+                );
+            }
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: panel,
+                    border: style.border,
+                    shadow: style.shadow,
+                    ..renderer::Quad::default()
+                },
+                style.background,
+            );
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: panel.center_x() - 18.0,
+                        y: panel.y + HANDLE_HEIGHT / 2.0 - 2.0,
+                        width: 36.0,
+                        height: 4.0,
+                    },
+                    border: Border::default().rounded(2),
+                    ..renderer::Quad::default()
+                },
+                style.handle,
+            );
+
+            let Some(visible) = panel.intersection(&bounds) else {
+                return;
+            };
+
+            self.sheet.as_widget().draw(
+                &tree.children[1],
+                renderer,
+                theme,
+                defaults,
+                panel_layout.children().next().unwrap(),
+                cursor,
+                &visible,
+            );
+        });
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let mut children = layout.children();
+        let (content_layout, panel_layout) = (children.next()?, children.next()?);
+        let mut trees = tree.children.iter_mut();
+        let (content_tree, sheet_tree) = (trees.next()?, trees.next()?);
+
+        let content = self.content.as_widget_mut().overlay(
+            content_tree,
+            content_layout,
+            renderer,
+            viewport,
+            translation,
+        );
+
+        let sheet = if self.detent == Detent::Hidden {
+            None
+        } else {
+            self.sheet.as_widget_mut().overlay(
+                sheet_tree,
+                panel_layout.children().next()?,
+                renderer,
+                viewport,
+                translation,
+            )
+        };
+
+        let children: Vec<_> = content.into_iter().chain(sheet).collect();
+
+        (!children.is_empty()).then(|| overlay::Group::with_children(children).overlay())
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<BottomSheet<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(bottom_sheet: BottomSheet<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(bottom_sheet)
+    }
+}
+
+/// The appearance of a [`BottomSheet`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Background`] drawn over the content while the sheet is raised.
+    pub backdrop: Background,
+    /// The [`Background`] of the sheet.
+    pub background: Background,
+    /// The [`Border`] of the sheet.
+    pub border: Border,
+    /// The [`Shadow`] of the sheet.
+    pub shadow: Shadow,
+    /// The [`Color`] of the drag handle.
+    pub handle: Color,
+}
+
+/// The theme catalog of a [`BottomSheet`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`BottomSheet`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`BottomSheet`].
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        backdrop: Color::BLACK.scale_alpha(0.4).into(),
+        background: palette.background.base.color.into(),
+        border: Border::default().rounded(border::top(12)),
+        shadow: Shadow {
+            color: Color::BLACK.scale_alpha(0.3),
+            offset: Vector::ZERO,
+            blur_radius: 16.0,
+        },
+        handle: palette.background.strong.color,
+    }
+}
@@ -0,0 +1,664 @@
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::time::{Duration, Instant, milliseconds};
+use crate::core::touch;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::{Operation, Widget};
+use crate::core::window;
+use crate::core::{
+    Border, Clipboard, Color, Element, Event, Length, Point, Rectangle, Shell, Size, Theme, Vector,
+};
+
+/// The distance the pointer needs to travel before a drag starts.
+const DRAG_THRESHOLD: f32 = 10.0;
+
+/// The diameter of the page indicators.
+const DOT_SIZE: f32 = 8.0;
+
+/// A pager that shows one page at a time and slides between them.
+///
+/// The current page is owned by the application; swiping, clicking the
+/// page indicators and autoplay only produce [`Carousel::on_page_change`]
+/// messages.
+pub struct Carousel<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    pages: Vec<Element<'a, Message, Theme, Renderer>>,
+    page: usize,
+    on_page_change: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    autoplay: Option<Duration>,
+    indicators: bool,
+    width: Length,
+    height: Length,
+    duration: Duration,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> Carousel<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    pub fn new(pages: impl IntoIterator<Item = Element<'a, Message, Theme, Renderer>>) -> Self {
+        Self {
+            pages: pages.into_iter().collect(),
+            page: 0,
+            on_page_change: None,
+            autoplay: None,
+            indicators: true,
+            width: Length::Fill,
+            height: Length::Shrink,
+            duration: milliseconds(300),
+            class: Theme::default(),
+        }
+    }
+
+    pub fn page(mut self, page: usize) -> Self {
+        self.page = page;
+        self
+    }
+
+    /// Sets the message produced when the [`Carousel`] asks to show
+    /// another page.
+    ///
+    /// A [`Carousel`] without it can't be navigated.
+    pub fn on_page_change(mut self, on_page_change: impl Fn(usize) -> Message + 'a) -> Self {
+        self.on_page_change = Some(Box::new(on_page_change));
+        self
+    }
+
+    /// Advances to the next page every `interval`, wrapping around at the
+    /// end; paused while hovered.
+    pub fn autoplay(mut self, interval: impl Into<Duration>) -> Self {
+        self.autoplay = Some(interval.into());
+        self
+    }
+
+    /// Sets whether the page indicators are shown; `true` by default.
+    pub fn indicators(mut self, indicators: bool) -> Self {
+        self.indicators = indicators;
+        self
+    }
+
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    pub fn duration(mut self, duration: impl Into<Duration>) -> Self {
+        self.duration = duration.into();
+        self
+    }
+
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    fn current(&self) -> usize {
+        self.page.min(self.pages.len().saturating_sub(1))
+    }
+
+    fn go_to(&self, page: usize, shell: &mut Shell<'_, Message>) {
+        if let Some(on_page_change) = self
+            .on_page_change
+            .as_ref()
+            .filter(|_| page != self.current())
+        {
+            shell.publish(on_page_change(page));
+        }
+    }
+}
+
+struct State {
+    page: usize,
+    /// The position the current transition started from.
+    from: f32,
+    /// The start of the current transition; or `None` to start it on the
+    /// next redraw.
+    started_at: Option<Instant>,
+    last_advance: Option<Instant>,
+    now: Instant,
+    is_hovered: bool,
+    drag: Option<Drag>,
+}
+
+struct Drag {
+    finger: Option<touch::Finger>,
+    origin: Point,
+    from: f32,
+    distance: f32,
+    is_active: bool,
+}
+
+impl State {
+    /// Returns the page being shown, with fractions while sliding.
+    fn position(&self, width: f32, duration: Duration) -> f32 {
+        if let Some(drag) = self.drag.as_ref().filter(|drag| drag.is_active) {
+            return drag.from - drag.distance / width.max(1.0);
+        }
+
+        let t = (self.elapsed().as_secs_f32() / duration.as_secs_f32().max(f32::EPSILON)).min(1.0);
+        let eased = 1.0 - (1.0 - t).powi(3);
+
+        self.from + (self.page as f32 - self.from) * eased
+    }
+
+    fn is_settled(&self, duration: Duration) -> bool {
+        self.drag.as_ref().is_none_or(|drag| !drag.is_active)
+            && (self.from == self.page as f32 || self.elapsed() >= duration)
+    }
+
+    fn elapsed(&self) -> Duration {
+        self.started_at.map_or(Duration::ZERO, |started_at| {
+            self.now.saturating_duration_since(started_at)
+        })
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Carousel<'_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: renderer::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        let page = self.current();
+
+        tree::State::new(State {
+            page,
+            from: page as f32,
+            started_at: None,
+            last_advance: None,
+            now: Instant::now(),
+            is_hovered: false,
+            drag: None,
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.pages.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&self.pages);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, self.height)
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let page = self.current();
+        let state = tree.state.downcast_mut::<State>();
+
+        if state.page != page {
+            // The width only matters while dragging
+            state.drag = None;
+            state.from = state.position(1.0, self.duration);
+            state.page = page;
+            state.started_at = None;
+            state.last_advance = None;
+        }
+
+        let limits = limits.width(self.width).height(self.height);
+
+        let pages: Vec<_> = self
+            .pages
+            .iter_mut()
+            .zip(&mut tree.children)
+            .map(|(page, tree)| page.as_widget_mut().layout(tree, renderer, &limits))
+            .collect();
+
+        let intrinsic = pages.iter().fold(Size::ZERO, |size, page| {
+            let page = page.size();
+
+            Size::new(size.width.max(page.width), size.height.max(page.height))
+        });
+
+        let size = limits.resolve(self.width, self.height, intrinsic);
+
+        layout::Node::with_children(size, pages)
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let page = self.current();
+
+        operation.container(None, layout.bounds());
+        operation.traverse(&mut |operation| {
+            if let (Some(element), Some(layout)) =
+                (self.pages.get_mut(page), layout.children().nth(page))
+            {
+                element.as_widget_mut().operate(
+                    &mut tree.children[page],
+                    layout,
+                    renderer,
+                    operation,
+                );
+            }
+        });
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        if self.pages.is_empty() {
+            return;
+        }
+
+        let bounds = layout.bounds();
+        let page = self.current();
+
+        {
+            let state = tree.state.downcast_mut::<State>();
+
+            if let Event::Window(window::Event::RedrawRequested(now)) = event {
+                state.now = *now;
+                let _ = state.started_at.get_or_insert(*now);
+
+                if !state.is_settled(self.duration) {
+                    shell.request_redraw();
+                }
+            }
+        }
+
+        let is_settled = tree.state.downcast_ref::<State>().is_settled(self.duration);
+
+        // Off-screen pages keep receiving window events to stay up to date
+        for (index, ((element, tree), layout)) in self
+            .pages
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .enumerate()
+        {
+            if index == page && is_settled {
+                element.as_widget_mut().update(
+                    tree, event, layout, cursor, renderer, clipboard, shell, viewport,
+                );
+            } else if let Event::Window(_) = event {
+                element.as_widget_mut().update(
+                    tree,
+                    event,
+                    layout,
+                    mouse::Cursor::Unavailable,
+                    renderer,
+                    clipboard,
+                    shell,
+                    viewport,
+                );
+            }
+        }
+
+        let state = tree.state.downcast_mut::<State>();
+
+        if let (Event::Window(window::Event::RedrawRequested(now)), Some(interval)) =
+            (event, self.autoplay)
+        {
+            let last_advance = *state.last_advance.get_or_insert(*now);
+
+            if state.is_hovered || state.drag.is_some() || !is_settled {
+                state.last_advance = Some(*now);
+            } else if *now >= last_advance + interval {
+                state.last_advance = Some(*now);
+
+                self.go_to((page + 1) % self.pages.len(), shell);
+            }
+
+            shell.request_redraw_at(state.last_advance.unwrap_or(*now) + interval);
+        }
+
+        if let Event::Mouse(mouse::Event::CursorMoved { .. }) = event {
+            let is_hovered = cursor.is_over(bounds);
+
+            if state.is_hovered != is_hovered {
+                state.is_hovered = is_hovered;
+
+                if self.autoplay.is_some() {
+                    shell.request_redraw();
+                }
+            }
+        }
+
+        if self.on_page_change.is_none() {
+            return;
+        }
+
+        let width = bounds.width;
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. })
+                if !shell.is_event_captured() =>
+            {
+                let (finger, position) = match event {
+                    Event::Touch(touch::Event::FingerPressed { id, position }) => {
+                        (Some(*id), Some(*position))
+                    }
+                    _ => (None, cursor.position()),
+                };
+
+                let Some(position) = position.filter(|position| bounds.contains(*position)) else {
+                    return;
+                };
+
+                if let Some(index) =
+                    indicator_at(bounds, self.pages.len(), position).filter(|_| self.indicators)
+                {
+                    self.go_to(index, shell);
+                    shell.capture_event();
+
+                    return;
+                }
+
+                state.drag = Some(Drag {
+                    finger,
+                    origin: position,
+                    from: state.position(width, self.duration),
+                    distance: 0.0,
+                    is_active: false,
+                });
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+                let finger = match event {
+                    Event::Touch(touch::Event::FingerMoved { id, .. }) => Some(*id),
+                    _ => None,
+                };
+
+                if let Some(drag) = state.drag.as_mut().filter(|drag| drag.finger == finger) {
+                    drag.distance = position.x - drag.origin.x;
+                    drag.is_active |= drag.distance.abs() > DRAG_THRESHOLD;
+
+                    if drag.is_active {
+                        shell.request_redraw();
+                        shell.capture_event();
+                    }
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. }) => {
+                let finger = match event {
+                    Event::Touch(
+                        touch::Event::FingerLifted { id, .. } | touch::Event::FingerLost { id, .. },
+                    ) => Some(*id),
+                    _ => None,
+                };
+
+                let position = state.position(width, self.duration);
+
+                let Some(drag) = state.drag.take_if(|drag| drag.finger == finger) else {
+                    return;
+                };
+
+                if !drag.is_active {
+                    return;
+                }
+
+                // A fifth of the width is enough to flip the page
+                let target = if drag.distance < -width / 5.0 {
+                    (page + 1).min(self.pages.len() - 1)
+                } else if drag.distance > width / 5.0 {
+                    page.saturating_sub(1)
+                } else {
+                    page
+                };
+
+                state.from = position;
+                state.started_at = None;
+
+                self.go_to(target, shell);
+
+                shell.request_redraw();
+                shell.capture_event();
+            }
+            _ => {}
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+
+        if state.drag.as_ref().is_some_and(|drag| drag.is_active) {
+            return mouse::Interaction::Grabbing;
+        }
+
+        let is_over_indicator = cursor
+            .position_over(bounds)
+            .and_then(|position| indicator_at(bounds, self.pages.len(), position))
+            .is_some();
+
+        if self.on_page_change.is_some() && self.indicators && is_over_indicator {
+            return mouse::Interaction::Pointer;
+        }
+
+        let page = self.current();
+
+        match (self.pages.get(page), layout.children().nth(page)) {
+            (Some(element), Some(layout)) if state.is_settled(self.duration) => element
+                .as_widget()
+                .mouse_interaction(&tree.children[page], layout, cursor, viewport, renderer),
+            _ => mouse::Interaction::None,
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        defaults: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let position = state.position(bounds.width, self.duration);
+        let is_settled = state.is_settled(self.duration);
+
+        let Some(clip) = bounds.intersection(viewport) else {
+            return;
+        };
+
+        renderer.with_layer(clip, |renderer| {
+            for (index, ((element, tree), layout)) in self
+                .pages
+                .iter()
+                .zip(&tree.children)
+                .zip(layout.children())
+                .enumerate()
+            {
+                let offset = index as f32 - position;
+
+                if offset.abs() >= 1.0 {
+                    continue;
+                }
+
+                let cursor = if is_settled && index == state.page {
+                    cursor
+                } else {
+                    mouse::Cursor::Unavailable
+                };
+
+                renderer.with_translation(Vector::new(offset * bounds.width, 0.0), |renderer| {
+                    element
+                        .as_widget()
+                        .draw(tree, renderer, theme, defaults, layout, cursor, &clip);
+                });
+            }
+        });
+
+        if !self.indicators || self.pages.len() < 2 {
+            return;
+        }
+
+        let style = theme.style(&self.class);
+
+        for index in 0..self.pages.len() {
+            let closeness = (1.0 - (index as f32 - position).abs()).max(0.0);
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: indicator_bounds(bounds, self.pages.len(), index),
+                    border: Border::default().rounded(DOT_SIZE / 2.0),
+                    ..renderer::Quad::default()
+                },
+                mix(style.indicator, style.active_indicator, closeness),
+            );
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let page = self.current();
+
+        self.pages.get_mut(page)?.as_widget_mut().overlay(
+            &mut tree.children[page],
+            layout.children().nth(page)?,
+            renderer,
+            viewport,
+            translation,
+        )
+    }
+}
+
+/// Returns the bounds of the indicator of a page, centered at the bottom.
+fn indicator_bounds(bounds: Rectangle, pages: usize, index: usize) -> Rectangle {
+    let spacing = DOT_SIZE;
+    let total = pages as f32 * DOT_SIZE + (pages - 1) as f32 * spacing;
+
+    Rectangle {
+        x: bounds.center_x() - total / 2.0 + index as f32 * (DOT_SIZE + spacing),
+        y: bounds.y + bounds.height - DOT_SIZE * 2.0,
+        width: DOT_SIZE,
+        height: DOT_SIZE,
+    }
+}
+
+fn indicator_at(bounds: Rectangle, pages: usize, position: Point) -> Option<usize> {
+    if pages < 2 {
+        return None;
+    }
+
+    (0..pages).find(|index| {
+        indicator_bounds(bounds, pages, *index)
+            .expand(DOT_SIZE / 2.0)
+            .contains(position)
+    })
+}
+
+fn mix(a: Color, b: Color, t: f32) -> Color {
+    Color {
+        r: a.r + (b.r - a.r) * t,
+        g: a.g + (b.g - a.g) * t,
+        b: a.b + (b.b - a.b) * t,
+        a: a.a + (b.a - a.a) * t,
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Carousel<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(carousel: Carousel<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(carousel)
+    }
+}
+
+/// The appearance of a [`Carousel`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Color`] of the page indicators.
+    pub indicator: Color,
+    /// The [`Color`] of the indicator of the current page.
+    pub active_indicator: Color,
+}
+
+/// The theme catalog of a [`Carousel`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`Carousel`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`Carousel`].
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        indicator: palette.background.strong.color,
+        active_indicator: palette.primary.base.color,
+    }
+}
@@ -0,0 +1,1065 @@
+use crate::core::alignment;
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::text::{self, Paragraph as _};
+use crate::core::time::{Duration, Instant};
+use crate::core::widget::Widget;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::window;
+use crate::core::{
+    Background, Border, Clipboard, Color, Element, Event, Length, Padding, Pixels, Point,
+    Rectangle, Shell, Size, Theme, Vector,
+};
+
+use std::sync::atomic::{self, AtomicU64};
+use std::time::SystemTime;
+
+const SCROLLBAR_WIDTH: f32 = 6.0;
+const SCROLLER_MIN_HEIGHT: f32 = 20.0;
+const BUBBLE_PADDING: Padding = Padding {
+    top: 8.0,
+    right: 12.0,
+    bottom: 8.0,
+    left: 12.0,
+};
+const BUBBLE_RADIUS: f32 = 12.0;
+const DOTS_WIDTH: f32 = 28.0;
+
+/// The history of a conversation.
+#[derive(Debug)]
+pub struct Chat {
+    /// Unique to every [`Chat`], including clones; so a [`ChatView`] can
+    /// tell different histories apart.
+    id: u64,
+    entries: Vec<Entry>,
+    generation: u64,
+}
+
+fn next_id() -> u64 {
+    static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+
+    NEXT_ID.fetch_add(1, atomic::Ordering::Relaxed)
+}
+
+/// A message of a [`Chat`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    pub sender: Sender,
+    pub text: String,
+    pub time: SystemTime,
+    /// Whether more text is still being appended to the message.
+    pub is_streaming: bool,
+}
+
+/// The author of an [`Entry`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Sender {
+    /// The local user, whose messages are aligned to the right.
+    Local,
+    /// Someone else, whose messages are aligned to the left under
+    /// their name.
+    Remote(String),
+}
+
+impl Chat {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&mut self, sender: Sender, text: impl Into<String>) {
+        self.push_at(sender, text, SystemTime::now());
+    }
+
+    pub fn push_at(&mut self, sender: Sender, text: impl Into<String>, time: SystemTime) {
+        self.entries.push(Entry {
+            sender,
+            text: text.into(),
+            time,
+            is_streaming: false,
+        });
+    }
+
+    /// Starts a new empty message that can be extended with [`append`].
+    ///
+    /// [`append`]: Self::append
+    pub fn stream(&mut self, sender: Sender) {
+        self.entries.push(Entry {
+            sender,
+            text: String::new(),
+            time: SystemTime::now(),
+            is_streaming: true,
+        });
+    }
+
+    /// Appends some text to the last message, if it is still streaming.
+    pub fn append(&mut self, chunk: &str) {
+        if let Some(entry) = self.entries.last_mut().filter(|entry| entry.is_streaming) {
+            entry.text.push_str(chunk);
+        }
+    }
+
+    /// Marks the last message as complete.
+    pub fn finish(&mut self) {
+        if let Some(entry) = self.entries.last_mut() {
+            entry.is_streaming = false;
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.generation += 1;
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn last(&self) -> Option<&Entry> {
+        self.entries.last()
+    }
+
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &Entry> {
+        self.entries.iter()
+    }
+}
+
+impl Default for Chat {
+    fn default() -> Self {
+        Self {
+            id: next_id(),
+            entries: Vec::new(),
+            generation: 0,
+        }
+    }
+}
+
+impl Clone for Chat {
+    fn clone(&self) -> Self {
+        Self {
+            id: next_id(),
+            entries: self.entries.clone(),
+            generation: self.generation,
+        }
+    }
+}
+
+impl PartialEq for Chat {
+    fn eq(&self, other: &Self) -> bool {
+        self.entries == other.entries
+    }
+}
+
+impl Eq for Chat {}
+
+/// A scrollable list of the messages of a [`Chat`], drawn as bubbles.
+///
+/// Consecutive messages of the same sender are grouped, and a timestamp is
+/// shown whenever there is a long enough pause in the conversation.
+///
+/// Streaming messages are revealed progressively, like a [`Typewriter`].
+/// The view follows the latest message until the user scrolls up, and only
+/// the visible bubbles are drawn.
+///
+/// [`Typewriter`]: crate::widget::Typewriter
+pub struct ChatView<'a, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    chat: &'a Chat,
+    is_typing: bool,
+    speed: Duration,
+    group_interval: Duration,
+    timestamp: Box<dyn Fn(SystemTime) -> String + 'a>,
+    width: Length,
+    height: Length,
+    padding: Padding,
+    spacing: f32,
+    text_size: Option<Pixels>,
+    line_height: text::LineHeight,
+    font: Option<Renderer::Font>,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Theme, Renderer> ChatView<'a, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    pub fn new(chat: &'a Chat) -> Self {
+        Self {
+            chat,
+            is_typing: false,
+            speed: Duration::from_millis(20),
+            group_interval: Duration::from_secs(5 * 60),
+            timestamp: Box::new(relative),
+            width: Length::Fill,
+            height: Length::Fill,
+            padding: Padding::new(10.0),
+            spacing: 4.0,
+            text_size: None,
+            line_height: text::LineHeight::default(),
+            font: None,
+            class: Theme::default(),
+        }
+    }
+
+    /// Shows a typing indicator after the last message.
+    pub fn typing(mut self, is_typing: bool) -> Self {
+        self.is_typing = is_typing;
+        self
+    }
+
+    /// Sets the time it takes to reveal every character of a streaming
+    /// message.
+    pub fn speed(mut self, char_rate: impl Into<Duration>) -> Self {
+        self.speed = char_rate.into();
+        self
+    }
+
+    /// Sets the pause between messages that starts a new group with a
+    /// timestamp.
+    pub fn group_interval(mut self, interval: impl Into<Duration>) -> Self {
+        self.group_interval = interval.into();
+        self
+    }
+
+    /// Sets the function used to format the timestamps of the groups.
+    ///
+    /// By default, the time is shown relative to now; like "5 minutes ago".
+    pub fn timestamp(mut self, format: impl Fn(SystemTime) -> String + 'a) -> Self {
+        self.timestamp = Box::new(format);
+        self
+    }
+
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    pub fn padding(mut self, padding: impl Into<Padding>) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the spacing between the bubbles of a group.
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.spacing = spacing.into().0;
+        self
+    }
+
+    pub fn text_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(size.into());
+        self
+    }
+
+    pub fn line_height(mut self, line_height: impl Into<text::LineHeight>) -> Self {
+        self.line_height = line_height.into();
+        self
+    }
+
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    fn size(&self, renderer: &Renderer) -> Pixels {
+        self.text_size.unwrap_or_else(|| renderer.default_size())
+    }
+
+    fn label_height(&self, renderer: &Renderer) -> f32 {
+        self.line_height.to_absolute(self.size(renderer) * 0.8).0
+    }
+
+    fn is_new_group(&self, index: usize) -> (bool, bool) {
+        let Some(previous) = index.checked_sub(1).map(|i| &self.chat.entries[i]) else {
+            return (true, true);
+        };
+
+        let entry = &self.chat.entries[index];
+
+        let is_pause = entry
+            .time
+            .duration_since(previous.time)
+            .is_ok_and(|pause| pause > self.group_interval);
+
+        (is_pause || entry.sender != previous.sender, is_pause)
+    }
+
+    /// Measures the visible text of every message and stacks the items
+    /// of the list.
+    fn measure(&self, state: &mut State<Renderer::Paragraph>, renderer: &Renderer, width: f32) {
+        let content_width = (width - self.padding.x()).max(0.0);
+        let max_width = (content_width * 0.75 - BUBBLE_PADDING.x()).max(1.0);
+
+        let size = self.size(renderer);
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
+
+        let key = Key {
+            chat: self.chat.id,
+            generation: self.chat.generation,
+            max_width,
+            size,
+            line_height: self.line_height,
+            font,
+        };
+
+        if state.key != Some(key) {
+            state.key = Some(key);
+            state.bubbles.clear();
+
+            if state
+                .reveal
+                .is_some_and(|reveal| reveal.index >= self.chat.len())
+            {
+                state.reveal = None;
+            }
+        }
+
+        state.bubbles.truncate(self.chat.len());
+
+        let line_height = self.line_height.to_absolute(size).0;
+        let hint_factor = renderer.scale_factor();
+
+        for (i, entry) in self.chat.iter().enumerate() {
+            let shown = state.shown(i, entry);
+
+            if state
+                .bubbles
+                .get(i)
+                .is_some_and(|bubble| bubble.shown == shown)
+            {
+                continue;
+            }
+
+            let paragraph = Renderer::Paragraph::with_text(text::Text {
+                content: &entry.text[..shown],
+                bounds: Size::new(max_width, f32::INFINITY),
+                size,
+                line_height: self.line_height,
+                font,
+                align_x: text::Alignment::Left,
+                align_y: alignment::Vertical::Top,
+                shaping: text::Shaping::Advanced,
+                wrapping: text::Wrapping::WordOrGlyph,
+                hint_factor,
+            });
+
+            let bounds = paragraph.min_bounds();
+
+            let size = Size::new(
+                if shown == 0 { DOTS_WIDTH } else { bounds.width },
+                bounds.height.max(line_height),
+            )
+            .expand(BUBBLE_PADDING);
+
+            let bubble = Bubble {
+                shown,
+                paragraph,
+                size,
+            };
+
+            if i < state.bubbles.len() {
+                state.bubbles[i] = bubble;
+            } else {
+                state.bubbles.push(bubble);
+            }
+        }
+
+        let label_height = self.label_height(renderer);
+        let group_spacing = self.spacing * 3.0;
+
+        state.items.clear();
+
+        let mut y = 0.0;
+
+        for (i, entry) in self.chat.iter().enumerate() {
+            let (is_new_group, is_pause) = self.is_new_group(i);
+
+            if i > 0 && is_new_group {
+                y += group_spacing;
+            }
+
+            if is_pause {
+                state.items.push(Item {
+                    kind: Kind::Timestamp(i),
+                    y,
+                    height: label_height,
+                });
+
+                y += label_height + self.spacing;
+            }
+
+            if is_new_group && matches!(entry.sender, Sender::Remote(_)) {
+                state.items.push(Item {
+                    kind: Kind::Name(i),
+                    y,
+                    height: label_height,
+                });
+
+                y += label_height + self.spacing;
+            }
+
+            let height = state.bubbles[i].size.height;
+
+            state.items.push(Item {
+                kind: Kind::Bubble(i),
+                y,
+                height,
+            });
+
+            y += height + self.spacing;
+        }
+
+        if self.is_typing {
+            if !self.chat.is_empty() {
+                y += group_spacing;
+            }
+
+            let height = line_height + BUBBLE_PADDING.y();
+
+            state.items.push(Item {
+                kind: Kind::Typing,
+                y,
+                height,
+            });
+
+            y += height + self.spacing;
+        }
+
+        state.height = (y - self.spacing).max(0.0);
+    }
+
+    fn viewport(&self, state: &State<Renderer::Paragraph>, bounds: Rectangle) -> Viewport {
+        let content = bounds.shrink(self.padding);
+        let max_offset = (state.height - content.height).max(0.0);
+
+        let offset = if state.is_following {
+            max_offset
+        } else {
+            state.offset.min(max_offset)
+        };
+
+        Viewport {
+            bounds,
+            content,
+            total: state.height,
+            offset,
+            max_offset,
+        }
+    }
+}
+
+struct State<P: text::Paragraph> {
+    offset: f32,
+    is_following: bool,
+    drag: Option<f32>,
+    key: Option<Key<P::Font>>,
+    bubbles: Vec<Bubble<P>>,
+    items: Vec<Item>,
+    height: f32,
+    reveal: Option<Reveal>,
+    started: Option<Instant>,
+    now: Instant,
+}
+
+impl<P: text::Paragraph> State<P> {
+    fn scroll_to(&mut self, offset: f32, viewport: &Viewport) {
+        self.offset = offset.clamp(0.0, viewport.max_offset);
+        self.is_following = self.offset >= viewport.max_offset;
+    }
+
+    /// Returns the amount of bytes of the [`Entry`] that are visible.
+    fn shown(&self, index: usize, entry: &Entry) -> usize {
+        match self.reveal {
+            Some(reveal) if reveal.index == index => entry
+                .text
+                .char_indices()
+                .nth(reveal.chars as usize)
+                .map_or(entry.text.len(), |(i, _)| i),
+            _ => entry.text.len(),
+        }
+    }
+}
+
+/// Everything the measured bubbles depend on, besides the messages.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Key<Font> {
+    chat: u64,
+    generation: u64,
+    max_width: f32,
+    size: Pixels,
+    line_height: text::LineHeight,
+    font: Font,
+}
+
+struct Bubble<P> {
+    shown: usize,
+    paragraph: P,
+    size: Size,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Reveal {
+    index: usize,
+    chars: f32,
+    /// The last frame that revealed some text; or `None` if all of it
+    /// had been revealed by then.
+    last: Option<Instant>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Item {
+    kind: Kind,
+    y: f32,
+    height: f32,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Kind {
+    Timestamp(usize),
+    Name(usize),
+    Bubble(usize),
+    Typing,
+}
+
+struct Viewport {
+    bounds: Rectangle,
+    content: Rectangle,
+    total: f32,
+    offset: f32,
+    max_offset: f32,
+}
+
+impl Viewport {
+    fn scrollbar(&self) -> Option<(Rectangle, Rectangle)> {
+        if self.max_offset <= 0.0 {
+            return None;
+        }
+
+        let track = Rectangle {
+            x: self.bounds.x + self.bounds.width - SCROLLBAR_WIDTH - 2.0,
+            y: self.bounds.y + 2.0,
+            width: SCROLLBAR_WIDTH,
+            height: self.bounds.height - 4.0,
+        };
+
+        let height = (track.height * self.content.height / self.total)
+            .max(SCROLLER_MIN_HEIGHT)
+            .min(track.height);
+
+        let scroller = Rectangle {
+            y: track.y + (track.height - height) * self.offset / self.max_offset,
+            height,
+            ..track
+        };
+
+        Some((track, scroller))
+    }
+
+    fn offset_at(&self, track: Rectangle, scroller: Rectangle, y: f32) -> f32 {
+        let range = track.height - scroller.height;
+
+        if range <= 0.0 {
+            return 0.0;
+        }
+
+        (y - track.y) / range * self.max_offset
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for ChatView<'a, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<Renderer::Paragraph>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::<Renderer::Paragraph> {
+            offset: 0.0,
+            is_following: true,
+            drag: None,
+            key: None,
+            bubbles: Vec::new(),
+            items: Vec::new(),
+            height: 0.0,
+            reveal: None,
+            started: None,
+            now: Instant::now(),
+        })
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let node = layout::atomic(limits, self.width, self.height);
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        self.measure(state, renderer, node.size().width);
+
+        node
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+        let bounds = layout.bounds();
+
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            state.now = *now;
+            let _ = state.started.get_or_insert(*now);
+
+            if let Some((index, entry)) = self.chat.iter().enumerate().last() {
+                if entry.is_streaming && state.reveal.is_none_or(|reveal| reveal.index != index) {
+                    state.reveal = Some(Reveal {
+                        index,
+                        chars: 0.0,
+                        last: None,
+                    });
+                }
+
+                if let Some(reveal) = state.reveal.as_mut().filter(|reveal| reveal.index == index) {
+                    let total = entry.text.chars().count() as f32;
+                    let elapsed = now.saturating_duration_since(reveal.last.unwrap_or(*now));
+                    let speed = self.speed.max(Duration::from_millis(1));
+
+                    reveal.chars =
+                        (reveal.chars + elapsed.as_secs_f32() / speed.as_secs_f32()).min(total);
+                    // Once caught up, new text is revealed from the frame it arrives in
+                    reveal.last = (reveal.chars < total).then_some(*now);
+
+                    if reveal.chars < total {
+                        shell.request_redraw();
+                    } else if !entry.is_streaming {
+                        state.reveal = None;
+                    }
+
+                    self.measure(state, renderer, bounds.width);
+                }
+            }
+
+            if self.is_typing && bounds.intersects(viewport) {
+                shell.request_redraw();
+            }
+
+            return;
+        }
+
+        let viewport = self.viewport(state, bounds);
+
+        match event {
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
+                if !cursor.is_over(viewport.bounds) || viewport.max_offset <= 0.0 {
+                    return;
+                }
+
+                let delta = match delta {
+                    mouse::ScrollDelta::Lines { y, .. } => {
+                        y * self.line_height.to_absolute(self.size(renderer)).0 * 3.0
+                    }
+                    mouse::ScrollDelta::Pixels { y, .. } => *y,
+                };
+
+                state.scroll_to(viewport.offset - delta, &viewport);
+
+                shell.request_redraw();
+                shell.capture_event();
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                let Some((track, scroller)) = viewport.scrollbar() else {
+                    return;
+                };
+
+                let Some(position) = cursor.position_over(track.expand(2.0)) else {
+                    return;
+                };
+
+                let grab = if scroller.contains(position) {
+                    position.y - scroller.y
+                } else {
+                    let grab = scroller.height / 2.0;
+
+                    state.scroll_to(
+                        viewport.offset_at(track, scroller, position.y - grab),
+                        &viewport,
+                    );
+                    shell.request_redraw();
+
+                    grab
+                };
+
+                state.drag = Some(grab);
+                shell.capture_event();
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position }) => {
+                let Some(grab) = state.drag else {
+                    return;
+                };
+
+                let Some((track, scroller)) = viewport.scrollbar() else {
+                    return;
+                };
+
+                state.scroll_to(
+                    viewport.offset_at(track, scroller, position.y - grab),
+                    &viewport,
+                );
+
+                shell.request_redraw();
+                shell.capture_event();
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+                if state.drag.take().is_some() =>
+            {
+                shell.capture_event();
+            }
+            _ => {}
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+
+        if state.drag.is_some() {
+            return mouse::Interaction::Grabbing;
+        }
+
+        let viewport = self.viewport(state, layout.bounds());
+
+        match viewport.scrollbar() {
+            Some((_, scroller)) if cursor.is_over(scroller) => mouse::Interaction::Grab,
+            _ => mouse::Interaction::None,
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _defaults: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+        let view = self.viewport(state, layout.bounds());
+        let style = theme.style(&self.class);
+
+        let Some(clip) = view.bounds.intersection(viewport) else {
+            return;
+        };
+
+        if let Some(background) = style.background {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: view.bounds,
+                    border: style.border,
+                    ..renderer::Quad::default()
+                },
+                background,
+            );
+        }
+
+        let Some(content) = view.content.intersection(&clip) else {
+            return;
+        };
+
+        let label_size = self.size(renderer) * 0.8;
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
+        let hint_factor = renderer.scale_factor();
+        let phase = state
+            .now
+            .saturating_duration_since(state.started.unwrap_or(state.now))
+            .as_secs_f32();
+
+        let label = |content: String, align_x| text::Text {
+            content,
+            bounds: Size::new(view.content.width, f32::INFINITY),
+            size: label_size,
+            line_height: self.line_height,
+            font,
+            align_x,
+            align_y: alignment::Vertical::Top,
+            shaping: text::Shaping::Advanced,
+            wrapping: text::Wrapping::None,
+            hint_factor,
+        };
+
+        let bubble = |renderer: &mut Renderer, bounds: Rectangle, background: Background| {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border: Border::default().rounded(BUBBLE_RADIUS),
+                    ..renderer::Quad::default()
+                },
+                background,
+            );
+        };
+
+        let dots = |renderer: &mut Renderer, bounds: Rectangle, color: Color| {
+            let center = bounds.center();
+
+            for i in 0..3 {
+                let wave = ((phase * 6.0 - i as f32 * 0.8).sin() + 1.0) / 2.0;
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: center.x - 3.0 + (i as f32 - 1.0) * 9.0,
+                            y: center.y - 3.0,
+                            width: 6.0,
+                            height: 6.0,
+                        },
+                        border: Border::default().rounded(3),
+                        ..renderer::Quad::default()
+                    },
+                    color.scale_alpha(0.3 + 0.7 * wave),
+                );
+            }
+        };
+
+        let first = state
+            .items
+            .partition_point(|item| item.y + item.height < view.offset);
+
+        let origin = view.content.position() - Vector::new(0.0, view.offset);
+
+        renderer.with_layer(content, |renderer| {
+            for item in state.items[first..]
+                .iter()
+                .take_while(|item| item.y <= view.offset + view.content.height)
+            {
+                let y = origin.y + item.y;
+
+                match item.kind {
+                    Kind::Timestamp(index) => {
+                        renderer.fill_text(
+                            label(
+                                (self.timestamp)(self.chat.entries[index].time),
+                                text::Alignment::Center,
+                            ),
+                            Point::new(view.content.center_x(), y),
+                            style.timestamp,
+                            content,
+                        );
+                    }
+                    Kind::Name(index) => {
+                        let Sender::Remote(name) = &self.chat.entries[index].sender else {
+                            continue;
+                        };
+
+                        renderer.fill_text(
+                            label(name.clone(), text::Alignment::Left),
+                            Point::new(origin.x + BUBBLE_PADDING.left, y),
+                            style.name,
+                            content,
+                        );
+                    }
+                    Kind::Bubble(index) => {
+                        let entry = &self.chat.entries[index];
+                        let Some(measured) = state.bubbles.get(index) else {
+                            continue;
+                        };
+
+                        let (x, background, color) = match entry.sender {
+                            Sender::Local => (
+                                view.content.x + view.content.width - measured.size.width,
+                                style.local,
+                                style.local_text,
+                            ),
+                            Sender::Remote(_) => (origin.x, style.remote, style.remote_text),
+                        };
+
+                        let bounds = Rectangle::new(Point::new(x, y), measured.size);
+
+                        bubble(renderer, bounds, background);
+
+                        if measured.shown == 0 && entry.is_streaming {
+                            dots(renderer, bounds, color);
+                        } else {
+                            renderer.fill_paragraph(
+                                &measured.paragraph,
+                                Point::new(x + BUBBLE_PADDING.left, y + BUBBLE_PADDING.top),
+                                color,
+                                content,
+                            );
+                        }
+                    }
+                    Kind::Typing => {
+                        let bounds = Rectangle {
+                            x: origin.x,
+                            y,
+                            width: DOTS_WIDTH + BUBBLE_PADDING.x(),
+                            height: item.height,
+                        };
+
+                        bubble(renderer, bounds, style.remote);
+                        dots(renderer, bounds, style.remote_text);
+                    }
+                }
+            }
+        });
+
+        if let Some((_, scroller)) = view.scrollbar() {
+            renderer.with_layer(clip, |renderer| {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: scroller,
+                        border: Border::default().rounded(SCROLLBAR_WIDTH / 2.0),
+                        ..renderer::Quad::default()
+                    },
+                    if state.is_following {
+                        style.scroller.scale_alpha(0.5)
+                    } else {
+                        style.scroller
+                    },
+                );
+            });
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<ChatView<'a, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(chat_view: ChatView<'a, Theme, Renderer>) -> Self {
+        Element::new(chat_view)
+    }
+}
+
+/// Formats the given time relative to now; like "5 minutes ago".
+pub fn relative(time: SystemTime) -> String {
+    let elapsed = SystemTime::now()
+        .duration_since(time)
+        .unwrap_or_default()
+        .as_secs();
+
+    let (amount, unit) = match elapsed {
+        0..60 => return String::from("Just now"),
+        60..3_600 => (elapsed / 60, "minute"),
+        3_600..86_400 => (elapsed / 3_600, "hour"),
+        _ => (elapsed / 86_400, "day"),
+    };
+
+    format!("{amount} {unit}{} ago", if amount == 1 { "" } else { "s" })
+}
+
+/// The appearance of a [`ChatView`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Background`] of the list.
+    pub background: Option<Background>,
+    /// The [`Border`] of the list.
+    pub border: Border,
+    /// The [`Background`] of the bubbles of [`Sender::Local`].
+    pub local: Background,
+    /// The text [`Color`] of the bubbles of [`Sender::Local`].
+    pub local_text: Color,
+    /// The [`Background`] of the bubbles of [`Sender::Remote`].
+    pub remote: Background,
+    /// The text [`Color`] of the bubbles of [`Sender::Remote`].
+    pub remote_text: Color,
+    /// The [`Color`] of the names of the senders.
+    pub name: Color,
+    /// The [`Color`] of the timestamps.
+    pub timestamp: Color,
+    /// The [`Color`] of the scroller.
+    pub scroller: Color,
+}
+
+/// The theme catalog of a [`ChatView`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`ChatView`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`ChatView`].
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        background: None,
+        border: Border::default(),
+        local: palette.primary.base.color.into(),
+        local_text: palette.primary.base.text,
+        remote: palette.background.weak.color.into(),
+        remote_text: palette.background.weak.text,
+        name: palette.secondary.base.color,
+        timestamp: palette.background.strong.color,
+        scroller: palette.background.strong.color,
+    }
+}
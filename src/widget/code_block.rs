@@ -0,0 +1,692 @@
+use crate::core::alignment;
+use crate::core::clipboard;
+use crate::core::font;
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::text::{self, Paragraph as _, Span};
+use crate::core::time::{Duration, Instant};
+use crate::core::widget::Widget;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::window;
+use crate::core::{
+    Background, Border, Clipboard, Color, Element, Event, Font, Length, Padding, Pixels, Point,
+    Rectangle, Shell, Size, Theme, Vector,
+};
+
+use std::cell::{Cell, RefCell};
+use std::sync::LazyLock;
+
+use syntect::easy::HighlightLines;
+use syntect::highlighting::{FontStyle, ThemeSet};
+use syntect::parsing::SyntaxSet;
+use syntect::util::LinesWithEndings;
+
+static SYNTAXES: LazyLock<SyntaxSet> = LazyLock::new(SyntaxSet::load_defaults_newlines);
+static THEMES: LazyLock<ThemeSet> = LazyLock::new(ThemeSet::load_defaults);
+
+const COPIED: Duration = Duration::from_secs(2);
+
+/// A block of source code with syntax highlighting.
+///
+/// The language is looked up by name or file extension (e.g. `"rust"` or
+/// `"rs"`). Unknown languages are shown as plain text.
+///
+/// A button to copy the code to the clipboard appears when hovered.
+pub struct CodeBlock<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer<Font = Font>,
+{
+    source: &'a str,
+    language: &'a str,
+    highlighting: Option<Highlighting>,
+    line_numbers: bool,
+    wrap: bool,
+    on_copy: Option<Message>,
+    width: Length,
+    padding: Padding,
+    size: Option<Pixels>,
+    line_height: text::LineHeight,
+    font: Font,
+    class: Theme::Class<'a>,
+    renderer: std::marker::PhantomData<Renderer>,
+}
+
+impl<'a, Message, Theme, Renderer> CodeBlock<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer<Font = Font>,
+{
+    pub fn new(source: &'a str, language: &'a str) -> Self {
+        Self {
+            source,
+            language,
+            highlighting: None,
+            line_numbers: true,
+            wrap: false,
+            on_copy: None,
+            width: Length::Fill,
+            padding: Padding::new(10.0),
+            size: None,
+            line_height: text::LineHeight::default(),
+            font: Font::MONOSPACE,
+            class: Theme::default(),
+            renderer: std::marker::PhantomData,
+        }
+    }
+
+    /// Sets the [`Highlighting`] of the [`CodeBlock`].
+    ///
+    /// By default, it is chosen by the [`Style`] of the current theme.
+    pub fn highlighting(mut self, highlighting: Highlighting) -> Self {
+        self.highlighting = Some(highlighting);
+        self
+    }
+
+    pub fn line_numbers(mut self, line_numbers: bool) -> Self {
+        self.line_numbers = line_numbers;
+        self
+    }
+
+    /// Sets whether long lines should wrap instead of being clipped.
+    pub fn wrap(mut self, wrap: bool) -> Self {
+        self.wrap = wrap;
+        self
+    }
+
+    /// Sets the message produced when the code is copied to the clipboard.
+    pub fn on_copy(mut self, on_copy: Message) -> Self {
+        self.on_copy = Some(on_copy);
+        self
+    }
+
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    pub fn padding(mut self, padding: impl Into<Padding>) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.size = Some(size.into());
+        self
+    }
+
+    pub fn line_height(mut self, line_height: impl Into<text::LineHeight>) -> Self {
+        self.line_height = line_height.into();
+        self
+    }
+
+    pub fn font(mut self, font: impl Into<Font>) -> Self {
+        self.font = font.into();
+        self
+    }
+
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    fn text(&self, renderer: &Renderer) -> text::Text<(), Font> {
+        text::Text {
+            content: (),
+            bounds: Size::INFINITE,
+            size: self.size.unwrap_or_else(|| renderer.default_size()),
+            line_height: self.line_height,
+            font: self.font,
+            align_x: text::Alignment::Left,
+            align_y: alignment::Vertical::Top,
+            shaping: text::Shaping::Advanced,
+            wrapping: text::Wrapping::None,
+            hint_factor: renderer.scale_factor(),
+        }
+    }
+
+    fn lines(
+        &self,
+        highlighting: Highlighting,
+        width: f32,
+        renderer: &Renderer,
+    ) -> Vec<Renderer::Paragraph> {
+        let text = self.text(renderer);
+
+        highlight(self.source, self.language, highlighting)
+            .iter()
+            .map(|tokens| {
+                let spans: Vec<Span<'_, (), Font>> = tokens
+                    .iter()
+                    .map(|token| {
+                        Span::new(token.text.as_str())
+                            .color(token.color)
+                            .font(Font {
+                                weight: if token.is_bold {
+                                    font::Weight::Bold
+                                } else {
+                                    self.font.weight
+                                },
+                                style: if token.is_italic {
+                                    font::Style::Italic
+                                } else {
+                                    self.font.style
+                                },
+                                ..self.font
+                            })
+                    })
+                    .collect();
+
+                Renderer::Paragraph::with_spans(text::Text {
+                    bounds: Size::new(width, f32::INFINITY),
+                    wrapping: if self.wrap {
+                        text::Wrapping::WordOrGlyph
+                    } else {
+                        text::Wrapping::None
+                    },
+                    ..text.with_content(spans.as_slice())
+                })
+            })
+            .collect()
+    }
+}
+
+/// The color scheme used to highlight a [`CodeBlock`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Highlighting {
+    #[default]
+    OceanDark,
+    OceanLight,
+    EightiesDark,
+    MochaDark,
+    InspiredGitHub,
+    SolarizedDark,
+    SolarizedLight,
+}
+
+impl Highlighting {
+    fn key(self) -> &'static str {
+        match self {
+            Self::OceanDark => "base16-ocean.dark",
+            Self::OceanLight => "base16-ocean.light",
+            Self::EightiesDark => "base16-eighties.dark",
+            Self::MochaDark => "base16-mocha.dark",
+            Self::InspiredGitHub => "InspiredGitHub",
+            Self::SolarizedDark => "Solarized (dark)",
+            Self::SolarizedLight => "Solarized (light)",
+        }
+    }
+}
+
+/// A highlighted piece of a line.
+struct Token {
+    text: String,
+    color: Color,
+    is_bold: bool,
+    is_italic: bool,
+}
+
+/// Highlights the given source code, line by line.
+fn highlight(source: &str, language: &str, highlighting: Highlighting) -> Vec<Vec<Token>> {
+    let syntax = SYNTAXES
+        .find_syntax_by_token(language)
+        .unwrap_or_else(|| SYNTAXES.find_syntax_plain_text());
+
+    let mut highlighter = HighlightLines::new(syntax, &THEMES.themes[highlighting.key()]);
+
+    LinesWithEndings::from(source)
+        .map(|line| {
+            let ranges = highlighter
+                .highlight_line(line, &SYNTAXES)
+                .unwrap_or_default();
+
+            ranges
+                .into_iter()
+                .map(|(style, text)| Token {
+                    text: text.trim_end_matches(['\n', '\r']).to_owned(),
+                    color: Color::from_rgba8(
+                        style.foreground.r,
+                        style.foreground.g,
+                        style.foreground.b,
+                        f32::from(style.foreground.a) / 255.0,
+                    ),
+                    is_bold: style.font_style.contains(FontStyle::BOLD),
+                    is_italic: style.font_style.contains(FontStyle::ITALIC),
+                })
+                .filter(|token| !token.text.is_empty())
+                .collect()
+        })
+        .collect()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Key {
+    width: f32,
+    wrap: bool,
+    line_numbers: bool,
+    size: Pixels,
+    line_height: text::LineHeight,
+    font: Font,
+}
+
+struct State<P> {
+    source: String,
+    language: String,
+    key: Option<Key>,
+    highlighting: Cell<Highlighting>,
+    lines: RefCell<Vec<P>>,
+    numbers: Vec<P>,
+    offsets: Vec<f32>,
+    gutter: f32,
+    labels: Option<(P, P)>,
+    is_copied: bool,
+    copied_at: Option<Instant>,
+    is_hovered: bool,
+}
+
+impl<P: text::Paragraph> State<P> {
+    fn button(&self, bounds: Rectangle, padding: Padding) -> Option<Rectangle> {
+        let (copy, copied) = self.labels.as_ref()?;
+        let label = if self.is_copied { copied } else { copy };
+
+        let size = label.min_bounds().expand(Size::new(16.0, 8.0));
+
+        Some(Rectangle {
+            x: bounds.x + bounds.width - padding.right - size.width,
+            y: bounds.y + padding.top,
+            width: size.width,
+            height: size.height,
+        })
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for CodeBlock<'_, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: text::Renderer<Font = Font>,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<Renderer::Paragraph>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::<Renderer::Paragraph> {
+            source: String::new(),
+            language: String::new(),
+            key: None,
+            highlighting: Cell::new(self.highlighting.unwrap_or_default()),
+            lines: RefCell::new(Vec::new()),
+            numbers: Vec::new(),
+            offsets: Vec::new(),
+            gutter: 0.0,
+            labels: None,
+            is_copied: false,
+            copied_at: None,
+            is_hovered: false,
+        })
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+        let limits = limits.width(self.width).height(Length::Shrink);
+        let text = self.text(renderer);
+        let line_height = self.line_height.to_absolute(text.size).0;
+
+        let numbers = if self.line_numbers {
+            self.source.lines().count().max(1)
+        } else {
+            0
+        };
+
+        if state.numbers.len() != numbers || state.labels.is_none() {
+            state.numbers = (1..=numbers)
+                .map(|number| {
+                    Renderer::Paragraph::with_text(text.with_content(&number.to_string()))
+                })
+                .collect();
+
+            state.gutter = state
+                .numbers
+                .last()
+                .map(|number| number.min_width() + text.size.0)
+                .unwrap_or_default();
+
+            state.labels = Some((
+                Renderer::Paragraph::with_text(text::Text {
+                    font: Font::default(),
+                    size: text.size * 0.8,
+                    ..text.with_content("Copy")
+                }),
+                Renderer::Paragraph::with_text(text::Text {
+                    font: Font::default(),
+                    size: text.size * 0.8,
+                    ..text.with_content("Copied!")
+                }),
+            ));
+        }
+
+        let key = Key {
+            width: if self.wrap {
+                (limits.max().width - self.padding.x() - state.gutter).max(0.0)
+            } else {
+                f32::INFINITY
+            },
+            wrap: self.wrap,
+            line_numbers: self.line_numbers,
+            size: text.size,
+            line_height: self.line_height,
+            font: self.font,
+        };
+
+        if state.source != self.source || state.language != self.language || state.key != Some(key)
+        {
+            let highlighting = self.highlighting.unwrap_or(state.highlighting.get());
+
+            state.source = self.source.to_owned();
+            state.language = self.language.to_owned();
+            state.key = Some(key);
+            state.highlighting.set(highlighting);
+            state.lines = RefCell::new(self.lines(highlighting, key.width, renderer));
+        }
+
+        let lines = state.lines.borrow();
+        let mut y = 0.0;
+        let mut width: f32 = 0.0;
+
+        state.offsets = lines
+            .iter()
+            .map(|line| {
+                let offset = y;
+                let bounds = line.min_bounds();
+
+                y += bounds.height.max(line_height);
+                width = width.max(bounds.width);
+
+                offset
+            })
+            .collect();
+
+        let intrinsic = Size::new(state.gutter + width, y).expand(self.padding);
+
+        layout::Node::new(limits.resolve(self.width, Length::Shrink, intrinsic))
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { .. } | mouse::Event::CursorLeft) => {
+                let is_hovered = cursor.is_over(bounds);
+
+                if is_hovered != state.is_hovered {
+                    state.is_hovered = is_hovered;
+                    shell.request_redraw();
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                let Some(button) = state.button(bounds, self.padding) else {
+                    return;
+                };
+
+                if cursor.is_over(button) {
+                    clipboard.write(clipboard::Kind::Standard, self.source.to_owned());
+                    state.is_copied = true;
+                    state.copied_at = None;
+
+                    if let Some(on_copy) = &self.on_copy {
+                        shell.publish(on_copy.clone());
+                    }
+
+                    shell.capture_event();
+                    shell.request_redraw();
+                }
+            }
+            Event::Window(window::Event::RedrawRequested(now)) => {
+                if !state.is_copied {
+                    return;
+                }
+
+                let copied_at = *state.copied_at.get_or_insert(*now);
+
+                if *now >= copied_at + COPIED {
+                    state.is_copied = false;
+                    state.copied_at = None;
+                    shell.request_redraw();
+                } else {
+                    shell.request_redraw_at(copied_at + COPIED);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+
+        if state
+            .button(layout.bounds(), self.padding)
+            .is_some_and(|button| cursor.is_over(button))
+        {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::None
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        defaults: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+
+        let Some(visible) = bounds.intersection(viewport) else {
+            return;
+        };
+
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+        let style = theme.style(&self.class);
+        let size = self.size.unwrap_or_else(|| renderer.default_size());
+
+        let highlighting = self.highlighting.unwrap_or(style.highlighting);
+
+        if highlighting != state.highlighting.get() {
+            if let Some(key) = state.key {
+                *state.lines.borrow_mut() = self.lines(highlighting, key.width, renderer);
+            }
+
+            state.highlighting.set(highlighting);
+        }
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: style.border,
+                ..renderer::Quad::default()
+            },
+            style.background,
+        );
+
+        let origin = Point::new(bounds.x + self.padding.left, bounds.y + self.padding.top);
+
+        let code = Rectangle {
+            x: origin.x + state.gutter,
+            width: (bounds.width - self.padding.x() - state.gutter).max(0.0),
+            ..bounds
+        };
+
+        let lines = state.lines.borrow();
+
+        for (i, (line, offset)) in lines.iter().zip(&state.offsets).enumerate() {
+            let y = origin.y + offset;
+
+            if y > visible.y + visible.height {
+                break;
+            }
+
+            if y + line.min_height() < visible.y {
+                continue;
+            }
+
+            if let Some(number) = state.numbers.get(i) {
+                renderer.fill_paragraph(
+                    number,
+                    Point::new(origin.x + state.gutter - size.0 - number.min_width(), y),
+                    style.line_number,
+                    visible,
+                );
+            }
+
+            if let Some(clip) = code.intersection(&visible) {
+                renderer.fill_paragraph(line, Point::new(code.x, y), defaults.text_color, clip);
+            }
+        }
+
+        if !(state.is_copied || state.is_hovered && cursor.is_over(bounds)) {
+            return;
+        }
+
+        let (Some(button), Some((copy, copied))) =
+            (state.button(bounds, self.padding), &state.labels)
+        else {
+            return;
+        };
+
+        let label = if state.is_copied { copied } else { copy };
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: button,
+                border: Border::default().rounded(4),
+                ..renderer::Quad::default()
+            },
+            style.button,
+        );
+
+        renderer.fill_paragraph(
+            label,
+            button.position() + Vector::new(8.0, 4.0),
+            style.button_text,
+            visible,
+        );
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<CodeBlock<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer<Font = Font> + 'a,
+{
+    fn from(code_block: CodeBlock<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(code_block)
+    }
+}
+
+/// The appearance of a [`CodeBlock`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Background`] of the block.
+    pub background: Background,
+    /// The [`Border`] of the block.
+    pub border: Border,
+    /// The [`Color`] of the line numbers.
+    pub line_number: Color,
+    /// The [`Background`] of the copy button.
+    pub button: Background,
+    /// The [`Color`] of the label of the copy button.
+    pub button_text: Color,
+    /// The [`Highlighting`] of the code.
+    pub highlighting: Highlighting,
+}
+
+/// The theme catalog of a [`CodeBlock`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`CodeBlock`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`CodeBlock`].
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        background: palette.background.weakest.color.into(),
+        border: Border::default()
+            .rounded(6)
+            .width(1)
+            .color(palette.background.weak.color),
+        line_number: palette.background.strong.color,
+        button: palette.background.weak.color.into(),
+        button_text: palette.background.weak.text,
+        highlighting: if palette.is_dark {
+            Highlighting::OceanDark
+        } else {
+            Highlighting::InspiredGitHub
+        },
+    }
+}
@@ -0,0 +1,656 @@
+use crate::core::alignment;
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::text::{self, paragraph};
+use crate::core::widget::Widget;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::{
+    Background, Border, Clipboard, Color, Element, Event, Length, Padding, Pixels, Point,
+    Rectangle, Shell, Size, Theme, Vector,
+};
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+const MONTHS: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+const WEEKDAYS: [(usize, &str); 3] = [(1, "Mon"), (3, "Wed"), (5, "Fri")];
+
+/// A calendar of days, one column per week, colored by their value.
+///
+/// Hovering a day shows its value in a tooltip.
+pub struct ContributionCalendar<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    values: &'a BTreeMap<Date, f32>,
+    period: Option<(Date, Date)>,
+    max: f32,
+    tooltip: Box<dyn Fn(Date, f32) -> String + 'a>,
+    on_day_click: Option<Box<dyn Fn(Date) -> Message + 'a>>,
+    cell_size: f32,
+    spacing: f32,
+    text_size: Option<Pixels>,
+    font: Option<Renderer::Font>,
+    class: Theme::Class<'a>,
+}
+
+/// A day of the Gregorian calendar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Date {
+    pub year: i32,
+    pub month: u8,
+    pub day: u8,
+}
+
+impl Date {
+    /// Creates a new [`Date`], with the month and day starting at `1`.
+    pub fn new(year: i32, month: u8, day: u8) -> Self {
+        Self {
+            year,
+            month: month.clamp(1, 12),
+            day: day.clamp(1, 31),
+        }
+    }
+
+    /// Returns the [`Date`] the given amount of days after this one.
+    pub fn add_days(self, days: i64) -> Self {
+        Self::from_days(self.days() + days)
+    }
+
+    /// Returns the day of the week, starting at `0` for Sunday.
+    pub fn weekday(self) -> usize {
+        (self.days() + 4).rem_euclid(7) as usize
+    }
+
+    /// The days since the Unix epoch.
+    fn days(self) -> i64 {
+        let month = i64::from(self.month);
+        let year = i64::from(self.year) - i64::from(month <= 2);
+        let era = year.div_euclid(400);
+        let year_of_era = year - era * 400;
+        let day_of_year =
+            (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + i64::from(self.day) - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+        era * 146_097 + day_of_era - 719_468
+    }
+
+    fn from_days(days: i64) -> Self {
+        let days = days + 719_468;
+        let era = days.div_euclid(146_097);
+        let day_of_era = days - era * 146_097;
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let shifted_month = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+        let month = if shifted_month < 10 {
+            shifted_month + 3
+        } else {
+            shifted_month - 9
+        };
+
+        Self {
+            year: (year_of_era + era * 400 + i64::from(month <= 2)) as i32,
+            month: month as u8,
+            day: day as u8,
+        }
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+impl<'a, Message, Theme, Renderer> ContributionCalendar<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    pub fn new(values: &'a BTreeMap<Date, f32>) -> Self {
+        let max = values
+            .values()
+            .filter(|value| value.is_finite())
+            .fold(0.0, |max: f32, value| max.max(*value));
+
+        Self {
+            values,
+            period: None,
+            max,
+            tooltip: Box::new(|date, value| format!("{value} on {date}")),
+            on_day_click: None,
+            cell_size: 11.0,
+            spacing: 3.0,
+            text_size: None,
+            font: None,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the first and last days shown.
+    ///
+    /// By default, the calendar spans from the first to the last day with
+    /// a value.
+    pub fn period(mut self, start: Date, end: Date) -> Self {
+        self.period = Some((start.min(end), start.max(end)));
+        self
+    }
+
+    /// Sets the value of the most intense color.
+    ///
+    /// By default, it is the largest value.
+    pub fn max(mut self, max: f32) -> Self {
+        self.max = max;
+        self
+    }
+
+    /// Sets the contents of the tooltip of a day, given its date and value.
+    ///
+    /// Days without a value have a value of `0.0`.
+    pub fn tooltip(mut self, tooltip: impl Fn(Date, f32) -> String + 'a) -> Self {
+        self.tooltip = Box::new(tooltip);
+        self
+    }
+
+    pub fn on_day_click(mut self, on_day_click: impl Fn(Date) -> Message + 'a) -> Self {
+        self.on_day_click = Some(Box::new(on_day_click));
+        self
+    }
+
+    /// Sets the side of the square of a day.
+    pub fn cell_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.cell_size = size.into().0;
+        self
+    }
+
+    /// Sets the spacing between days.
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.spacing = spacing.into().0;
+        self
+    }
+
+    pub fn text_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(size.into());
+        self
+    }
+
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    fn text<'b>(
+        &self,
+        content: &'b str,
+        renderer: &Renderer,
+    ) -> text::Text<&'b str, Renderer::Font> {
+        text::Text {
+            content,
+            bounds: Size::INFINITE,
+            size: self
+                .text_size
+                .unwrap_or_else(|| renderer.default_size() * 0.75),
+            line_height: text::LineHeight::default(),
+            font: self.font.unwrap_or_else(|| renderer.default_font()),
+            align_x: text::Alignment::Default,
+            align_y: alignment::Vertical::Top,
+            shaping: text::Shaping::Advanced,
+            wrapping: text::Wrapping::None,
+            hint_factor: renderer.scale_factor(),
+        }
+    }
+
+    fn span(&self) -> Option<(Date, Date)> {
+        self.period.or_else(|| {
+            let first = self.values.keys().next()?;
+            let last = self.values.keys().next_back()?;
+
+            Some((*first, *last))
+        })
+    }
+
+    /// Returns the first day of the first week and the amount of weeks.
+    fn weeks(&self) -> Option<(Date, usize)> {
+        let (start, end) = self.span()?;
+        let first = start.add_days(-(start.weekday() as i64));
+        let weeks = (end.days() - first.days()) / 7 + 1;
+
+        Some((first, weeks as usize))
+    }
+
+    fn stride(&self) -> f32 {
+        self.cell_size + self.spacing
+    }
+
+    /// Returns the bounds of the grid of days, without labels.
+    fn grid(&self, state: &State<Renderer::Paragraph>, bounds: Rectangle) -> Rectangle {
+        Rectangle {
+            x: bounds.x + state.gutter.width,
+            y: bounds.y + state.gutter.height,
+            width: (bounds.width - state.gutter.width).max(0.0),
+            height: (bounds.height - state.gutter.height).max(0.0),
+        }
+    }
+
+    fn day_at(&self, grid: Rectangle, position: Point) -> Option<Date> {
+        let (start, end) = self.span()?;
+        let (first, _) = self.weeks()?;
+
+        if !grid.contains(position) {
+            return None;
+        }
+
+        let column = ((position.x - grid.x) / self.stride()) as i64;
+        let row = ((position.y - grid.y) / self.stride()).min(6.0) as i64;
+        let date = first.add_days(column * 7 + row);
+
+        (start <= date && date <= end).then_some(date)
+    }
+
+    fn cell(&self, grid: Rectangle, first: Date, date: Date) -> Rectangle {
+        let days = date.days() - first.days();
+
+        Rectangle {
+            x: grid.x + (days / 7) as f32 * self.stride(),
+            y: grid.y + (days % 7) as f32 * self.stride(),
+            width: self.cell_size,
+            height: self.cell_size,
+        }
+    }
+
+    fn value(&self, date: Date) -> f32 {
+        self.values
+            .get(&date)
+            .copied()
+            .filter(|value| value.is_finite())
+            .unwrap_or(0.0)
+    }
+}
+
+struct State<P: text::Paragraph> {
+    months: Vec<(usize, paragraph::Plain<P>)>,
+    weekdays: Vec<paragraph::Plain<P>>,
+    gutter: Size,
+    hovered: Option<Date>,
+    tooltip: paragraph::Plain<P>,
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for ContributionCalendar<'_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<Renderer::Paragraph>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::<Renderer::Paragraph> {
+            months: Vec::new(),
+            weekdays: Vec::new(),
+            gutter: Size::ZERO,
+            hovered: None,
+            tooltip: paragraph::Plain::default(),
+        })
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(Length::Shrink, Length::Shrink)
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+        let (first, weeks) = self.weeks().unwrap_or((Date::new(1970, 1, 1), 0));
+
+        // A month is labeled above the first week starting in it
+        let mut months: Vec<(usize, &str)> = (0..weeks)
+            .filter_map(|week| {
+                let date = first.add_days(week as i64 * 7);
+                let previous = date.add_days(-7);
+
+                (week == 0 || date.month != previous.month)
+                    .then_some((week, MONTHS[usize::from(date.month) - 1]))
+            })
+            .collect();
+
+        // The first month has no room for its label when it ends right away
+        if months.get(1).is_some_and(|(week, _)| *week < 3) {
+            let _ = months.remove(0);
+        }
+
+        state
+            .months
+            .resize_with(months.len(), || (0, paragraph::Plain::default()));
+
+        let mut label = Size::ZERO;
+
+        for ((week, month), (column, paragraph)) in months.into_iter().zip(&mut state.months) {
+            *column = week;
+            let _ = paragraph.update(self.text(month, renderer));
+
+            label.height = label.height.max(paragraph.min_bounds().height);
+        }
+
+        state
+            .weekdays
+            .resize_with(WEEKDAYS.len(), paragraph::Plain::default);
+
+        for ((_, weekday), paragraph) in WEEKDAYS.iter().zip(&mut state.weekdays) {
+            let _ = paragraph.update(self.text(weekday, renderer));
+
+            label.width = label.width.max(paragraph.min_bounds().width);
+        }
+
+        let gap = self.cell_size / 2.0;
+
+        state.gutter = Size::new(label.width + gap, label.height + gap / 2.0);
+
+        let intrinsic = Size::new(
+            state.gutter.width + weeks as f32 * self.stride() - self.spacing,
+            state.gutter.height + 7.0 * self.stride() - self.spacing,
+        );
+
+        layout::Node::new(limits.resolve(Length::Shrink, Length::Shrink, intrinsic))
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+        let grid = self.grid(state, layout.bounds());
+
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { .. } | mouse::Event::CursorLeft) => {
+                let hovered = cursor
+                    .position()
+                    .and_then(|position| self.day_at(grid, position));
+
+                if hovered != state.hovered {
+                    state.hovered = hovered;
+
+                    if let Some(date) = hovered {
+                        let tooltip = (self.tooltip)(date, self.value(date));
+
+                        let _ = state.tooltip.update(self.text(&tooltip, renderer));
+                    }
+
+                    shell.request_redraw();
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                let Some(on_day_click) = &self.on_day_click else {
+                    return;
+                };
+
+                if let Some(date) = cursor
+                    .position()
+                    .and_then(|position| self.day_at(grid, position))
+                {
+                    shell.publish(on_day_click(date));
+                    shell.capture_event();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+        let grid = self.grid(state, layout.bounds());
+
+        if self.on_day_click.is_some()
+            && cursor
+                .position()
+                .and_then(|position| self.day_at(grid, position))
+                .is_some()
+        {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::None
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _defaults: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+
+        if !bounds.intersects(viewport) {
+            return;
+        }
+
+        let Some(((start, end), (first, _))) = self.span().zip(self.weeks()) else {
+            return;
+        };
+
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+        let style = theme.style(&self.class);
+        let grid = self.grid(state, bounds);
+
+        for days in start.days()..=end.days() {
+            let date = Date::from_days(days);
+            let value = self.value(date);
+
+            let level = if value > 0.0 && self.max > 0.0 {
+                ((value / self.max).min(1.0) * style.levels.len() as f32).ceil() as usize
+            } else {
+                0
+            };
+
+            // Without levels, every day falls back to the empty color
+            let color = level
+                .checked_sub(1)
+                .and_then(|level| style.levels.get(level))
+                .copied()
+                .unwrap_or(style.empty);
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: self.cell(grid, first, date),
+                    border: style.cell_border,
+                    ..renderer::Quad::default()
+                },
+                color,
+            );
+        }
+
+        for (week, label) in &state.months {
+            renderer.fill_paragraph(
+                label.raw(),
+                Point::new(grid.x + *week as f32 * self.stride(), bounds.y),
+                style.label,
+                bounds,
+            );
+        }
+
+        for ((weekday, _), label) in WEEKDAYS.iter().zip(&state.weekdays) {
+            let size = label.min_bounds();
+
+            renderer.fill_paragraph(
+                label.raw(),
+                Point::new(
+                    bounds.x,
+                    grid.y + *weekday as f32 * self.stride() + (self.cell_size - size.height) / 2.0,
+                ),
+                style.label,
+                bounds,
+            );
+        }
+
+        let Some(date) = state.hovered else {
+            return;
+        };
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: self.cell(grid, first, date),
+                border: style.hovered_border,
+                ..renderer::Quad::default()
+            },
+            Color::TRANSPARENT,
+        );
+
+        let Some(position) = cursor.position() else {
+            return;
+        };
+
+        let padding = Padding::from([4, 8]);
+        let size = state.tooltip.min_bounds().expand(padding);
+        let offset = Vector::new(12.0, 12.0);
+
+        // Flip the tooltip when it would overflow the viewport
+        let x = if position.x + offset.x + size.width > viewport.x + viewport.width {
+            position.x - offset.x - size.width
+        } else {
+            position.x + offset.x
+        };
+
+        let y = if position.y + offset.y + size.height > viewport.y + viewport.height {
+            position.y - offset.y - size.height
+        } else {
+            position.y + offset.y
+        };
+
+        let tooltip = Rectangle::new(Point::new(x, y), size);
+
+        renderer.with_layer(*viewport, |renderer| {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: tooltip,
+                    border: Border::default().rounded(4),
+                    ..renderer::Quad::default()
+                },
+                style.tooltip_background,
+            );
+
+            renderer.fill_paragraph(
+                state.tooltip.raw(),
+                Point::new(tooltip.x + padding.left, tooltip.y + padding.top),
+                style.tooltip_text,
+                *viewport,
+            );
+        });
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<ContributionCalendar<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(calendar: ContributionCalendar<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(calendar)
+    }
+}
+
+/// The appearance of a [`ContributionCalendar`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Color`] of the days without a value.
+    pub empty: Color,
+    /// The [`Color`] of the days with a value, from the smallest to the
+    /// largest values.
+    pub levels: [Color; 4],
+    /// The [`Border`] of the days.
+    pub cell_border: Border,
+    /// The [`Border`] of the hovered day.
+    pub hovered_border: Border,
+    /// The [`Color`] of the month and weekday labels.
+    pub label: Color,
+    /// The [`Background`] of the tooltip.
+    pub tooltip_background: Background,
+    /// The [`Color`] of the tooltip text.
+    pub tooltip_text: Color,
+}
+
+/// The theme catalog of a [`ContributionCalendar`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`ContributionCalendar`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`ContributionCalendar`].
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+    let success = palette.success.base.color;
+
+    Style {
+        empty: palette.background.weak.color,
+        levels: [0.3, 0.55, 0.8, 1.0].map(|alpha| success.scale_alpha(alpha)),
+        cell_border: Border::default().rounded(2),
+        hovered_border: Border::default()
+            .rounded(2)
+            .width(1)
+            .color(palette.background.base.text),
+        label: palette.background.strong.text,
+        tooltip_background: palette.background.strongest.color.into(),
+        tooltip_text: palette.background.strongest.text,
+    }
+}
@@ -0,0 +1,309 @@
+use crate::core::alignment;
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::text;
+use crate::core::time::{Duration, Instant};
+use crate::core::widget;
+use crate::core::widget::text::{Catalog, Format, Style, StyleFn};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::window;
+use crate::core::{
+    Alignment, Clipboard, Color, Element, Event, Length, Pixels, Rectangle, Shell, Size, Widget,
+};
+
+pub struct Countdown<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    deadline: Instant,
+    paused: bool,
+    on_finish: Option<Message>,
+    to_string: Box<dyn Fn(Duration) -> String + 'a>,
+    format: Format<Renderer::Font>,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> Countdown<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    pub fn new(deadline: Instant) -> Self {
+        Self {
+            deadline,
+            paused: false,
+            on_finish: None,
+            to_string: Box::new(clock),
+            format: Format::default(),
+            class: Theme::default(),
+        }
+    }
+
+    pub fn paused(mut self, paused: bool) -> Self {
+        self.paused = paused;
+        self
+    }
+
+    pub fn on_finish(mut self, on_finish: Message) -> Self {
+        self.on_finish = Some(on_finish);
+        self
+    }
+
+    pub fn format(mut self, to_string: impl Fn(Duration) -> String + 'a) -> Self {
+        self.to_string = Box::new(to_string);
+        self
+    }
+
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.format.size = Some(size.into());
+        self
+    }
+
+    pub fn line_height(mut self, line_height: impl Into<text::LineHeight>) -> Self {
+        self.format.line_height = line_height.into();
+        self
+    }
+
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.format.font = Some(font.into());
+        self
+    }
+
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.format.width = width.into();
+        self
+    }
+
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.format.height = height.into();
+        self
+    }
+
+    pub fn align_x(mut self, alignment: impl Into<text::Alignment>) -> Self {
+        self.format.align_x = alignment.into();
+        self
+    }
+
+    pub fn align_y(mut self, alignment: impl Into<alignment::Vertical>) -> Self {
+        self.format.align_y = alignment.into();
+        self
+    }
+
+    pub fn center(self) -> Self {
+        self.align_x(Alignment::Center).align_y(Alignment::Center)
+    }
+
+    pub fn shaping(mut self, shaping: text::Shaping) -> Self {
+        self.format.shaping = shaping;
+        self
+    }
+
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    pub fn color(self, color: impl Into<Color>) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.color_maybe(Some(color))
+    }
+
+    pub fn color_maybe(self, color: Option<impl Into<Color>>) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        let color = color.map(Into::into);
+
+        self.style(move |_theme| Style { color })
+    }
+}
+
+struct State<P: text::Paragraph> {
+    deadline: Instant,
+    content: String,
+    delay: Duration,
+    paused_at: Option<Instant>,
+    is_finished: bool,
+    internal: widget::text::State<P>,
+}
+
+impl<P: text::Paragraph> State<P> {
+    fn remaining(&self, now: Instant) -> Duration {
+        let now = self.paused_at.unwrap_or(now);
+
+        (self.deadline + self.delay).saturating_duration_since(now)
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Countdown<'_, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<Renderer::Paragraph>>()
+    }
+
+    fn state(&self) -> tree::State {
+        // The content is filled in on the first redraw
+        tree::State::new(State {
+            deadline: self.deadline,
+            content: String::new(),
+            delay: Duration::ZERO,
+            paused_at: None,
+            is_finished: false,
+            internal: widget::text::State::<Renderer::Paragraph>::default(),
+        })
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.format.width,
+            height: self.format.height,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        if state.deadline != self.deadline {
+            state.deadline = self.deadline;
+            state.delay = Duration::ZERO;
+            state.is_finished = false;
+        }
+
+        widget::text::layout(
+            &mut state.internal,
+            renderer,
+            limits,
+            &state.content,
+            self.format,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        defaults: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor_position: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+        let style = theme.style(&self.class);
+
+        widget::text::draw(
+            renderer,
+            defaults,
+            layout.bounds(),
+            state.internal.raw(),
+            style,
+            viewport,
+        );
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        _layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let Event::Window(window::Event::RedrawRequested(now)) = event else {
+            return;
+        };
+
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        match (self.paused, state.paused_at) {
+            (true, None) => {
+                state.paused_at = Some(*now);
+            }
+            (false, Some(paused_at)) => {
+                state.delay += now.saturating_duration_since(paused_at);
+                state.paused_at = None;
+            }
+            _ => {}
+        }
+
+        let remaining = state.remaining(*now);
+        let content = (self.to_string)(remaining);
+
+        if content != state.content {
+            state.content = content;
+            shell.invalidate_layout();
+        }
+
+        if remaining.is_zero() {
+            if !state.is_finished {
+                state.is_finished = true;
+
+                if let Some(on_finish) = self.on_finish.clone() {
+                    shell.publish(on_finish);
+                }
+            }
+        } else if !self.paused {
+            let subsecond = Duration::from_nanos(remaining.subsec_nanos().into());
+
+            shell.request_redraw_at(
+                *now + if subsecond.is_zero() {
+                    Duration::from_secs(1)
+                } else {
+                    subsecond
+                },
+            );
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Countdown<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(
+        countdown: Countdown<'a, Message, Theme, Renderer>,
+    ) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(countdown)
+    }
+}
+
+/// Formats the remaining time as `mm:ss`, or `h:mm:ss` when over an hour.
+///
+/// Seconds are rounded up, so the countdown only shows `00:00` once finished.
+fn clock(remaining: Duration) -> String {
+    let total = remaining.as_secs() + u64::from(remaining.subsec_nanos() > 0);
+
+    let hours = total / 3600;
+    let minutes = (total % 3600) / 60;
+    let seconds = total % 60;
+
+    if hours > 0 {
+        format!("{hours}:{minutes:02}:{seconds:02}")
+    } else {
+        format!("{minutes:02}:{seconds:02}")
+    }
+}
@@ -0,0 +1,906 @@
+use crate::core::alignment;
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::text::{self, Paragraph as _};
+use crate::core::widget::Widget;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::{
+    Background, Clipboard, Color, Element, Event, Length, Pixels, Point, Rectangle, Shell, Size,
+    Theme,
+};
+
+use std::collections::HashSet;
+use std::ops::Range;
+
+const GAP: f32 = 8.0;
+
+/// A widget displaying the differences between two texts.
+///
+/// Lines are compared first; changed lines are then compared word by word.
+/// Long runs of unchanged lines are collapsed and can be expanded by
+/// clicking on them.
+pub struct DiffText<'a, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    old: &'a str,
+    new: &'a str,
+    side_by_side: bool,
+    context: Option<usize>,
+    width: Length,
+    size: Option<Pixels>,
+    line_height: text::LineHeight,
+    font: Option<Renderer::Font>,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Theme, Renderer> DiffText<'a, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    pub fn new(old: &'a str, new: &'a str) -> Self {
+        Self {
+            old,
+            new,
+            side_by_side: false,
+            context: Some(3),
+            width: Length::Fill,
+            size: None,
+            line_height: text::LineHeight::default(),
+            font: None,
+            class: Theme::default(),
+        }
+    }
+
+    /// Shows the old and new texts in two columns, instead of interleaving
+    /// removed and added lines.
+    pub fn side_by_side(mut self, side_by_side: bool) -> Self {
+        self.side_by_side = side_by_side;
+        self
+    }
+
+    /// Sets the amount of unchanged lines kept around each change when
+    /// collapsing; `3` by default.
+    pub fn context(mut self, context: usize) -> Self {
+        self.context = Some(context);
+        self
+    }
+
+    /// Shows every unchanged line, instead of collapsing them.
+    pub fn expanded(mut self) -> Self {
+        self.context = None;
+        self
+    }
+
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.size = Some(size.into());
+        self
+    }
+
+    pub fn line_height(mut self, line_height: impl Into<text::LineHeight>) -> Self {
+        self.line_height = line_height.into();
+        self
+    }
+
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    fn row_height(&self, renderer: &Renderer) -> f32 {
+        self.line_height
+            .to_absolute(self.size.unwrap_or_else(|| renderer.default_size()))
+            .0
+    }
+
+    fn text<'b>(
+        &self,
+        content: &'b str,
+        line_height: f32,
+        renderer: &Renderer,
+    ) -> text::Text<&'b str, Renderer::Font> {
+        text::Text {
+            content,
+            bounds: Size::new(f32::INFINITY, line_height),
+            size: self.size.unwrap_or_else(|| renderer.default_size()),
+            line_height: self.line_height,
+            font: self.font.unwrap_or_else(|| renderer.default_font()),
+            align_x: text::Alignment::Left,
+            align_y: alignment::Vertical::Top,
+            shaping: text::Shaping::Advanced,
+            wrapping: text::Wrapping::None,
+            hint_factor: renderer.scale_factor(),
+        }
+    }
+
+    fn measure(&self, content: &str, line_height: f32, renderer: &Renderer) -> f32 {
+        if content.is_empty() {
+            return 0.0;
+        }
+
+        Renderer::Paragraph::with_text(self.text(content, line_height, renderer)).min_width()
+    }
+}
+
+#[derive(Default)]
+struct State {
+    old: String,
+    new: String,
+    old_lines: Vec<Range<usize>>,
+    new_lines: Vec<Range<usize>>,
+    blocks: Vec<Block>,
+    rows: Vec<Row>,
+    expanded: HashSet<usize>,
+}
+
+impl State {
+    fn update(&mut self, old: &str, new: &str) {
+        if self.old == old && self.new == new {
+            return;
+        }
+
+        self.old = old.to_owned();
+        self.new = new.to_owned();
+        self.expanded.clear();
+
+        self.old_lines = lines(&self.old);
+        self.new_lines = lines(&self.new);
+
+        let old_lines: Vec<_> = self
+            .old_lines
+            .iter()
+            .map(|range| &old[range.clone()])
+            .collect();
+        let new_lines: Vec<_> = self
+            .new_lines
+            .iter()
+            .map(|range| &new[range.clone()])
+            .collect();
+
+        self.blocks = blocks(&old_lines, &new_lines);
+    }
+
+    fn rows(&mut self, side_by_side: bool, context: Option<usize>) {
+        let has_changes = self
+            .blocks
+            .iter()
+            .any(|block| matches!(block, Block::Change(_)));
+
+        self.rows.clear();
+
+        for (index, block) in self.blocks.iter().enumerate() {
+            match block {
+                Block::Equal { old, new, len } => {
+                    let (head, tail) = match context {
+                        Some(context) if has_changes && !self.expanded.contains(&index) => (
+                            if index == 0 { 0 } else { context },
+                            if index == self.blocks.len() - 1 {
+                                0
+                            } else {
+                                context
+                            },
+                        ),
+                        _ => (*len, 0),
+                    };
+
+                    let equal = |i| Row::Equal {
+                        old: old + i,
+                        new: new + i,
+                    };
+
+                    if *len > head + tail + 1 {
+                        self.rows.extend((0..head).map(equal));
+                        self.rows.push(Row::Collapsed {
+                            region: index,
+                            lines: len - head - tail,
+                        });
+                        self.rows.extend((len - tail..*len).map(equal));
+                    } else {
+                        self.rows.extend((0..*len).map(equal));
+                    }
+                }
+                Block::Change(pairs) if side_by_side => {
+                    self.rows
+                        .extend(pairs.iter().cloned().map(|(old, new)| Row::Pair(old, new)));
+                }
+                Block::Change(pairs) => {
+                    self.rows.extend(
+                        pairs
+                            .iter()
+                            .filter_map(|(old, _)| old.clone())
+                            .map(Row::Removed),
+                    );
+                    self.rows.extend(
+                        pairs
+                            .iter()
+                            .filter_map(|(_, new)| new.clone())
+                            .map(Row::Added),
+                    );
+                }
+            }
+        }
+    }
+
+    fn line(&self, side: Side, line: usize) -> &str {
+        let (text, lines) = match side {
+            Side::Old => (&self.old, &self.old_lines),
+            Side::New => (&self.new, &self.new_lines),
+        };
+
+        lines.get(line).map_or("", |range| &text[range.clone()])
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Side {
+    Old,
+    New,
+}
+
+#[derive(Debug, Clone)]
+enum Block {
+    Equal { old: usize, new: usize, len: usize },
+    Change(Vec<(Option<Line>, Option<Line>)>),
+}
+
+#[derive(Debug, Clone)]
+struct Line {
+    number: usize,
+    changes: Vec<Range<usize>>,
+}
+
+#[derive(Debug, Clone)]
+enum Row {
+    Equal { old: usize, new: usize },
+    Removed(Line),
+    Added(Line),
+    Pair(Option<Line>, Option<Line>),
+    Collapsed { region: usize, lines: usize },
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for DiffText<'_, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let state = tree.state.downcast_mut::<State>();
+
+        state.update(self.old, self.new);
+        state.rows(self.side_by_side, self.context);
+
+        let height = state.rows.len() as f32 * self.row_height(renderer);
+
+        layout::Node::new(limits.resolve(self.width, Length::Shrink, Size::new(0.0, height)))
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        if let Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) = event {
+            let state = tree.state.downcast_mut::<State>();
+
+            let Some(position) = cursor.position_in(layout.bounds()) else {
+                return;
+            };
+
+            let row = (position.y / self.row_height(renderer)) as usize;
+
+            if let Some(Row::Collapsed { region, .. }) = state.rows.get(row) {
+                let _ = state.expanded.insert(*region);
+
+                shell.invalidate_layout();
+                shell.request_redraw();
+                shell.capture_event();
+            }
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+
+        let Some(position) = cursor.position_in(layout.bounds()) else {
+            return mouse::Interaction::None;
+        };
+
+        let row = (position.y / self.row_height(renderer)) as usize;
+
+        match state.rows.get(row) {
+            Some(Row::Collapsed { .. }) => mouse::Interaction::Pointer,
+            _ => mouse::Interaction::None,
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _defaults: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let style = theme.style(&self.class);
+
+        let Some(clip) = bounds.intersection(viewport) else {
+            return;
+        };
+
+        if let Some(background) = style.background {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    ..renderer::Quad::default()
+                },
+                background,
+            );
+        }
+
+        let line_height = self.row_height(renderer);
+        let lines = state.old_lines.len().max(state.new_lines.len()).max(1);
+        let number_width = self.measure(
+            &"0".repeat(lines.ilog10() as usize + 1),
+            line_height,
+            renderer,
+        );
+        let marker_width = self.measure("+", line_height, renderer);
+
+        let first = ((clip.y - bounds.y) / line_height).floor().max(0.0) as usize;
+        let last = ((clip.y + clip.height - bounds.y) / line_height).ceil() as usize;
+
+        let column = |x: f32, width: f32, y: f32| Rectangle {
+            x,
+            y,
+            width,
+            height: line_height,
+        };
+
+        // Draws a single line of one of the texts, starting at `bounds.x`
+        let draw_line = |renderer: &mut Renderer,
+                         bounds: Rectangle,
+                         numbers: &[Option<usize>],
+                         marker: &str,
+                         content: &str,
+                         changes: &[Range<usize>],
+                         background: Option<Background>,
+                         highlight: Background,
+                         color: Color| {
+            let Some(clip) = bounds.intersection(&clip) else {
+                return;
+            };
+
+            if let Some(background) = background {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        ..renderer::Quad::default()
+                    },
+                    background,
+                );
+            }
+
+            let mut x = bounds.x + GAP;
+
+            for number in numbers {
+                if let Some(number) = number {
+                    let number = (number + 1).to_string();
+                    let width = self.measure(&number, line_height, renderer);
+
+                    renderer.fill_text(
+                        self.text(&number, line_height, renderer)
+                            .with_content(number.clone()),
+                        Point::new(x + number_width - width, bounds.y),
+                        style.gutter,
+                        clip,
+                    );
+                }
+
+                x += number_width + GAP;
+            }
+
+            renderer.fill_text(
+                self.text(marker, line_height, renderer)
+                    .with_content(marker.to_owned()),
+                Point::new(x, bounds.y),
+                color,
+                clip,
+            );
+
+            x += marker_width + GAP / 2.0;
+
+            for change in changes {
+                let start = self.measure(&content[..change.start], line_height, renderer);
+                let width = self.measure(&content[change.clone()], line_height, renderer);
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: column(x + start, width, bounds.y),
+                        ..renderer::Quad::default()
+                    },
+                    highlight,
+                );
+            }
+
+            renderer.fill_text(
+                self.text(content, line_height, renderer)
+                    .with_content(content.to_owned()),
+                Point::new(x, bounds.y),
+                style.text_color,
+                clip,
+            );
+        };
+
+        renderer.with_layer(clip, |renderer| {
+            let half = bounds.width / 2.0;
+
+            for (i, row) in state.rows.iter().enumerate().take(last).skip(first) {
+                let y = bounds.y + i as f32 * line_height;
+                let full = column(bounds.x, bounds.width, y);
+                let (left, right) = (column(bounds.x, half, y), column(bounds.x + half, half, y));
+
+                match row {
+                    Row::Equal { old, new } if self.side_by_side => {
+                        let content = state.line(Side::Old, *old);
+
+                        draw_line(
+                            renderer,
+                            left,
+                            &[Some(*old)],
+                            " ",
+                            content,
+                            &[],
+                            None,
+                            style.removed_word,
+                            style.text_color,
+                        );
+                        draw_line(
+                            renderer,
+                            right,
+                            &[Some(*new)],
+                            " ",
+                            content,
+                            &[],
+                            None,
+                            style.added_word,
+                            style.text_color,
+                        );
+                    }
+                    Row::Equal { old, new } => {
+                        draw_line(
+                            renderer,
+                            full,
+                            &[Some(*old), Some(*new)],
+                            " ",
+                            state.line(Side::Old, *old),
+                            &[],
+                            None,
+                            style.added_word,
+                            style.text_color,
+                        );
+                    }
+                    Row::Removed(line) => {
+                        draw_line(
+                            renderer,
+                            full,
+                            &[Some(line.number), None],
+                            "-",
+                            state.line(Side::Old, line.number),
+                            &line.changes,
+                            Some(style.removed),
+                            style.removed_word,
+                            style.removed_marker,
+                        );
+                    }
+                    Row::Added(line) => {
+                        draw_line(
+                            renderer,
+                            full,
+                            &[None, Some(line.number)],
+                            "+",
+                            state.line(Side::New, line.number),
+                            &line.changes,
+                            Some(style.added),
+                            style.added_word,
+                            style.added_marker,
+                        );
+                    }
+                    Row::Pair(old, new) => {
+                        if let Some(line) = old {
+                            draw_line(
+                                renderer,
+                                left,
+                                &[Some(line.number)],
+                                "-",
+                                state.line(Side::Old, line.number),
+                                &line.changes,
+                                Some(style.removed),
+                                style.removed_word,
+                                style.removed_marker,
+                            );
+                        }
+
+                        if let Some(line) = new {
+                            draw_line(
+                                renderer,
+                                right,
+                                &[Some(line.number)],
+                                "+",
+                                state.line(Side::New, line.number),
+                                &line.changes,
+                                Some(style.added),
+                                style.added_word,
+                                style.added_marker,
+                            );
+                        }
+                    }
+                    Row::Collapsed { lines, .. } => {
+                        renderer.fill_quad(
+                            renderer::Quad {
+                                bounds: full,
+                                ..renderer::Quad::default()
+                            },
+                            style.collapsed_background,
+                        );
+
+                        let label = format!(
+                            "{lines} unchanged line{}",
+                            if *lines == 1 { "" } else { "s" }
+                        );
+
+                        renderer.fill_text(
+                            text::Text {
+                                align_x: text::Alignment::Center,
+                                ..self.text(&label, line_height, renderer)
+                            }
+                            .with_content(label.clone()),
+                            Point::new(full.center_x(), y),
+                            style.collapsed_text,
+                            clip,
+                        );
+                    }
+                }
+            }
+        });
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<DiffText<'a, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(diff_text: DiffText<'a, Theme, Renderer>) -> Self {
+        Element::new(diff_text)
+    }
+}
+
+/// Returns the byte range of every line of a text.
+fn lines(text: &str) -> Vec<Range<usize>> {
+    text.lines()
+        .map(|line| {
+            let start = line.as_ptr() as usize - text.as_ptr() as usize;
+
+            start..start + line.len()
+        })
+        .collect()
+}
+
+/// Groups the lines of two texts into unchanged and changed blocks.
+fn blocks(old: &[&str], new: &[&str]) -> Vec<Block> {
+    let mut blocks = Vec::new();
+    let mut removed = Vec::new();
+    let mut added = Vec::new();
+
+    let flush = |blocks: &mut Vec<Block>, removed: &mut Vec<usize>, added: &mut Vec<usize>| {
+        if removed.is_empty() && added.is_empty() {
+            return;
+        }
+
+        let pairs = (0..removed.len().max(added.len()))
+            .map(|i| match (removed.get(i), added.get(i)) {
+                (Some(&a), Some(&b)) => {
+                    let (old_changes, new_changes) = words(old[a], new[b]);
+
+                    (
+                        Some(Line {
+                            number: a,
+                            changes: old_changes,
+                        }),
+                        Some(Line {
+                            number: b,
+                            changes: new_changes,
+                        }),
+                    )
+                }
+                (a, b) => (
+                    a.map(|&number| Line {
+                        number,
+                        changes: Vec::new(),
+                    }),
+                    b.map(|&number| Line {
+                        number,
+                        changes: Vec::new(),
+                    }),
+                ),
+            })
+            .collect();
+
+        blocks.push(Block::Change(pairs));
+        removed.clear();
+        added.clear();
+    };
+
+    for op in diff(old, new) {
+        match op {
+            Op::Equal(a, b) => {
+                flush(&mut blocks, &mut removed, &mut added);
+
+                match blocks.last_mut() {
+                    Some(Block::Equal { len, .. }) => *len += 1,
+                    _ => blocks.push(Block::Equal {
+                        old: a,
+                        new: b,
+                        len: 1,
+                    }),
+                }
+            }
+            Op::Delete(a) => removed.push(a),
+            Op::Insert(b) => added.push(b),
+        }
+    }
+
+    flush(&mut blocks, &mut removed, &mut added);
+
+    blocks
+}
+
+/// Computes the changed byte ranges between two lines, word by word.
+fn words(old: &str, new: &str) -> (Vec<Range<usize>>, Vec<Range<usize>>) {
+    let (old_words, old_offsets) = tokens(old);
+    let (new_words, new_offsets) = tokens(new);
+
+    let mut old_changes: Vec<Range<usize>> = Vec::new();
+    let mut new_changes: Vec<Range<usize>> = Vec::new();
+
+    let push = |changes: &mut Vec<Range<usize>>, range: Range<usize>| match changes.last_mut() {
+        Some(last) if last.end == range.start => last.end = range.end,
+        _ => changes.push(range),
+    };
+
+    for op in diff(&old_words, &new_words) {
+        match op {
+            Op::Equal(..) => {}
+            Op::Delete(i) => push(
+                &mut old_changes,
+                old_offsets[i]..old_offsets[i] + old_words[i].len(),
+            ),
+            Op::Insert(i) => push(
+                &mut new_changes,
+                new_offsets[i]..new_offsets[i] + new_words[i].len(),
+            ),
+        }
+    }
+
+    (old_changes, new_changes)
+}
+
+/// Splits a line into words, whitespace runs and single punctuation marks.
+fn tokens(line: &str) -> (Vec<&str>, Vec<usize>) {
+    let class = |c: char| {
+        if c.is_whitespace() {
+            0
+        } else if c.is_alphanumeric() || c == '_' {
+            1
+        } else {
+            2
+        }
+    };
+
+    let mut words = Vec::new();
+    let mut offsets = Vec::new();
+    let mut start = 0;
+
+    for (index, c) in line.char_indices().skip(1) {
+        let previous = line[..index].chars().next_back().map(class);
+
+        if previous != Some(class(c)) || class(c) == 2 {
+            words.push(&line[start..index]);
+            offsets.push(start);
+            start = index;
+        }
+    }
+
+    if start < line.len() {
+        words.push(&line[start..]);
+        offsets.push(start);
+    }
+
+    (words, offsets)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Op {
+    Equal(usize, usize),
+    Delete(usize),
+    Insert(usize),
+}
+
+/// Computes the longest common subsequence of two slices, as a list of
+/// operations turning the first one into the second one.
+fn diff<T: PartialEq>(old: &[T], new: &[T]) -> Vec<Op> {
+    let prefix = old.iter().zip(new).take_while(|(a, b)| a == b).count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+
+    let a = &old[prefix..old.len() - suffix];
+    let b = &new[prefix..new.len() - suffix];
+
+    let width = b.len() + 1;
+    let mut table = vec![0u32; (a.len() + 1) * width];
+
+    for i in (0..a.len()).rev() {
+        for j in (0..b.len()).rev() {
+            table[i * width + j] = if a[i] == b[j] {
+                table[(i + 1) * width + j + 1] + 1
+            } else {
+                table[(i + 1) * width + j].max(table[i * width + j + 1])
+            };
+        }
+    }
+
+    let mut ops: Vec<_> = (0..prefix).map(|i| Op::Equal(i, i)).collect();
+    let (mut i, mut j) = (0, 0);
+
+    while i < a.len() || j < b.len() {
+        if i < a.len() && j < b.len() && a[i] == b[j] {
+            ops.push(Op::Equal(prefix + i, prefix + j));
+            i += 1;
+            j += 1;
+        } else if i < a.len()
+            && (j == b.len() || table[(i + 1) * width + j] >= table[i * width + j + 1])
+        {
+            ops.push(Op::Delete(prefix + i));
+            i += 1;
+        } else {
+            ops.push(Op::Insert(prefix + j));
+            j += 1;
+        }
+    }
+
+    ops.extend((0..suffix).map(|k| Op::Equal(old.len() - suffix + k, new.len() - suffix + k)));
+
+    ops
+}
+
+/// The appearance of a [`DiffText`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Background`] of the diff.
+    pub background: Option<Background>,
+    /// The [`Color`] of the text.
+    pub text_color: Color,
+    /// The [`Color`] of the line numbers.
+    pub gutter: Color,
+    /// The [`Background`] of added lines.
+    pub added: Background,
+    /// The [`Background`] of the added words in a changed line.
+    pub added_word: Background,
+    /// The [`Color`] of the `+` marker of added lines.
+    pub added_marker: Color,
+    /// The [`Background`] of removed lines.
+    pub removed: Background,
+    /// The [`Background`] of the removed words in a changed line.
+    pub removed_word: Background,
+    /// The [`Color`] of the `-` marker of removed lines.
+    pub removed_marker: Color,
+    /// The [`Background`] of collapsed unchanged lines.
+    pub collapsed_background: Background,
+    /// The [`Color`] of the label of collapsed unchanged lines.
+    pub collapsed_text: Color,
+}
+
+/// The theme catalog of a [`DiffText`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`DiffText`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`DiffText`].
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        background: None,
+        text_color: palette.background.base.text,
+        gutter: palette.background.strong.color,
+        added: palette.success.weak.color.scale_alpha(0.25).into(),
+        added_word: palette.success.base.color.scale_alpha(0.45).into(),
+        added_marker: palette.success.base.color,
+        removed: palette.danger.weak.color.scale_alpha(0.25).into(),
+        removed_word: palette.danger.base.color.scale_alpha(0.45).into(),
+        removed_marker: palette.danger.base.color,
+        collapsed_background: palette.background.weak.color.into(),
+        collapsed_text: palette.background.weak.text,
+    }
+}
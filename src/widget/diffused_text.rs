@@ -6,6 +6,8 @@ use crate::core::renderer;
 use crate::core::text;
 use crate::core::time::{Duration, Instant, milliseconds};
 use crate::core::widget;
+use crate::core::widget::Id;
+use crate::core::widget::operation::Operation;
 use crate::core::widget::text::{Catalog, Format, Style, StyleFn};
 use crate::core::widget::tree::{self, Tree};
 use crate::core::window;
@@ -13,6 +15,8 @@ use crate::core::{
     Alignment, Clipboard, Color, Element, Event, Length, Pixels, Rectangle, Shell, Size, Widget,
 };
 
+use std::any::Any;
+
 #[derive(Debug)]
 pub struct DiffusedText<'a, Theme, Renderer>
 where
@@ -22,8 +26,27 @@ where
     fragment: core::text::Fragment<'a>,
     format: Format<Renderer::Font>,
     class: Theme::Class<'a>,
+    id: Option<Id>,
     duration: Duration,
     tick_rate: u64,
+    play: Play,
+    pause_when_hidden: bool,
+}
+
+/// The trigger of the animation of a [`DiffusedText`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Play {
+    /// Plays the animation when mounted and every time the content changes.
+    #[default]
+    ContentChange,
+    /// Plays the animation only once, when mounted.
+    Mount,
+    /// Plays the animation every time the signal changes, but not when
+    /// mounted.
+    ///
+    /// An incrementing counter in the application state can be used to
+    /// replay the animation without changing the content.
+    Signal(u64),
 }
 
 impl<'a, Theme, Renderer> DiffusedText<'a, Theme, Renderer>
@@ -36,11 +59,21 @@ where
             fragment: fragment.into_fragment(),
             format: Format::default(),
             class: Theme::default(),
+            id: None,
             duration: Duration::from_millis(200),
             tick_rate: 50,
+            play: Play::default(),
+            pause_when_hidden: true,
         }
     }
 
+    /// Sets the [`Id`] of the [`DiffusedText`], used to [`replay`] its
+    /// animation.
+    pub fn id(mut self, id: impl Into<Id>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
     pub fn size(mut self, size: impl Into<Pixels>) -> Self {
         self.format.size = Some(size.into());
         self
@@ -135,12 +168,57 @@ where
         self.tick_rate = tick_rate.into().as_millis() as u64;
         self
     }
+
+    /// Sets what triggers the animation; [`Play::ContentChange`] by default.
+    pub fn play_on(mut self, play: Play) -> Self {
+        self.play = play;
+        self
+    }
+
+    /// Pauses the animation while the window is unfocused, resuming where
+    /// it left off. Enabled by default.
+    pub fn pause_when_hidden(mut self, pause_when_hidden: bool) -> Self {
+        self.pause_when_hidden = pause_when_hidden;
+        self
+    }
+}
+
+/// Replays the animation of the [`DiffusedText`] with the given [`Id`],
+/// regardless of its [`Play`] trigger.
+pub fn replay(id: impl Into<Id>) -> impl Operation {
+    struct Replay {
+        id: Id,
+    }
+
+    impl Operation for Replay {
+        fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation)) {
+            operate(self);
+        }
+
+        fn custom(&mut self, id: Option<&Id>, _bounds: Rectangle, state: &mut dyn Any) {
+            let request = state.downcast_mut::<Request>();
+
+            if let Some(request) = request.filter(|_| id == Some(&self.id)) {
+                request.replay = true;
+            }
+        }
+    }
+
+    Replay { id: id.into() }
+}
+
+/// The request a [`DiffusedText`] exposes to operations.
+struct Request {
+    replay: bool,
 }
 
 /// The internal state of a [`Text`] widget.
 #[derive(Debug)]
 pub struct State<P: text::Paragraph> {
     content: String,
+    signal: Option<u64>,
+    is_mounted: bool,
+    is_window_focused: bool,
     internal: widget::text::State<P>,
     animation: Animation,
 }
@@ -150,7 +228,8 @@ enum Animation {
     Ticking {
         fragment: String,
         ticks: u64,
-        next_redraw: Instant,
+        /// The time of the next tick; or `None` to tick on the next redraw.
+        next_redraw: Option<Instant>,
     },
     Done,
 }
@@ -168,11 +247,14 @@ where
     fn state(&self) -> tree::State {
         tree::State::new(State {
             content: String::new(),
+            signal: None,
+            is_mounted: false,
+            is_window_focused: true,
             internal: widget::text::State::<Renderer::Paragraph>::default(),
             animation: Animation::Ticking {
                 fragment: String::new(),
                 ticks: 0,
-                next_redraw: Instant::now(),
+                next_redraw: None,
             },
         })
     }
@@ -192,14 +274,31 @@ where
     ) -> layout::Node {
         let state = &mut tree.state.downcast_mut::<State<Renderer::Paragraph>>();
 
-        if state.content != self.fragment {
+        let has_changed = state.content != self.fragment;
+        let was_mounted = std::mem::replace(&mut state.is_mounted, true);
+
+        let should_play = match self.play {
+            Play::ContentChange => has_changed,
+            Play::Mount => !was_mounted,
+            Play::Signal(signal) => state.signal.is_some_and(|last| last != signal),
+        };
+
+        if let Play::Signal(signal) = self.play {
+            state.signal = Some(signal);
+        }
+
+        if has_changed {
             state.content = self.fragment.clone().into_owned();
+        }
 
+        if should_play {
             state.animation = Animation::Ticking {
                 fragment: String::from("-"),
                 ticks: 0,
-                next_redraw: Instant::now(),
+                next_redraw: None,
             };
+        } else if !was_mounted {
+            state.animation = Animation::Done;
         }
 
         let fragment = match &state.animation {
@@ -210,6 +309,30 @@ where
         widget::text::layout(&mut state.internal, renderer, limits, fragment, self.format)
     }
 
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+        let bounds = layout.bounds();
+
+        let mut request = Request { replay: false };
+
+        operation.text(self.id.as_ref(), bounds, &self.fragment);
+        operation.custom(self.id.as_ref(), bounds, &mut request);
+
+        if request.replay {
+            state.animation = Animation::Ticking {
+                fragment: String::from("-"),
+                ticks: 0,
+                next_redraw: None,
+            };
+        }
+    }
+
     fn draw(
         &self,
         tree: &Tree,
@@ -244,56 +367,60 @@ where
         shell: &mut Shell<'_, Message>,
         viewport: &Rectangle,
     ) {
-        use rand::Rng;
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
 
+        match event {
+            Event::Window(window::Event::Unfocused) => {
+                state.is_window_focused = false;
+            }
+            Event::Window(window::Event::Focused) => {
+                state.is_window_focused = true;
+                shell.request_redraw();
+            }
+            _ => {}
+        }
+
+        // Out of sight, ticks stop until the text is visible again
         if layout.bounds().intersection(viewport).is_none() {
             return;
         }
 
-        if let Event::Window(window::Event::RedrawRequested(now)) = event {
-            let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+        if self.pause_when_hidden && !state.is_window_focused {
+            return;
+        }
 
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
             match &mut state.animation {
                 Animation::Ticking {
                     fragment,
                     next_redraw,
                     ticks,
                 } => {
-                    if *next_redraw <= *now {
+                    if next_redraw.is_none_or(|next_redraw| next_redraw <= *now) {
                         *ticks += 1;
 
                         let mut rng = rand::rng();
-                        let progress = (self.fragment.len() as f32
-                            / self.duration.as_millis() as f32
+                        let total = self.fragment.chars().count();
+                        let progress = (total as f32 / self.duration.as_millis() as f32
                             * (*ticks * self.tick_rate) as f32)
                             as usize;
 
-                        if progress >= self.fragment.len() {
+                        if progress >= total {
                             state.animation = Animation::Done;
                             shell.invalidate_layout();
 
                             return;
                         }
 
-                        *fragment = self
-                            .fragment
-                            .chars()
-                            .take(progress)
-                            .chain(self.fragment.chars().skip(progress).map(|c| {
-                                if c.is_whitespace() || c == '-' {
-                                    c
-                                } else {
-                                    rng.random_range('a'..='z')
-                                }
-                            }))
-                            .collect::<String>();
-
-                        *next_redraw = *now + Duration::from_millis(self.tick_rate);
+                        *fragment =
+                            diffuse(self.fragment.chars(), &self.fragment, progress, &mut rng);
+
+                        *next_redraw = Some(*now + Duration::from_millis(self.tick_rate));
 
                         shell.invalidate_layout();
                     }
 
-                    shell.request_redraw_at(*next_redraw);
+                    shell.request_redraw_at(next_redraw.unwrap_or(*now));
                 }
                 Animation::Done => {}
             }
@@ -311,3 +438,92 @@ where
         Element::new(text)
     }
 }
+
+/// Takes the first `progress` characters of `target` and scrambles the
+/// remaining characters of `source`.
+fn diffuse(
+    target: impl Iterator<Item = char>,
+    source: &str,
+    progress: usize,
+    rng: &mut impl rand::Rng,
+) -> String {
+    target
+        .take(progress)
+        .chain(source.chars().skip(progress).map(|c| scramble(c, rng)))
+        .collect()
+}
+
+/// Groups of Latin letters with similar advances in common proportional
+/// fonts, from the narrowest to the widest.
+const WIDTHS: &[&str] = &[
+    "ijl",
+    "frt",
+    "cksvxyz",
+    "abdeghnopqu",
+    "mw",
+    "IJL",
+    "EFTZ",
+    "ABKPSVXY",
+    "CDHNRU",
+    "GOQ",
+    "MW",
+];
+
+/// Replaces the given character with a random one of the same width.
+///
+/// Digits become digits, Latin letters are replaced by letters of a
+/// similar advance, and wide characters (like CJK ideographs) are replaced
+/// by other wide characters; so the scrambled text keeps its width while
+/// animating, even with proportional fonts. Any other letter is replaced
+/// by a Latin letter of the same case.
+fn scramble(c: char, rng: &mut impl rand::Rng) -> char {
+    if c.is_whitespace() || c.is_ascii_punctuation() {
+        c
+    } else if c.is_ascii_digit() {
+        rng.random_range('0'..='9')
+    } else if let Some(group) = WIDTHS.iter().find(|group| group.contains(c)) {
+        let i = rng.random_range(0..group.len());
+
+        group[i..].chars().next().unwrap_or(c)
+    } else if is_wide(c) {
+        rng.random_range('\u{4E00}'..='\u{9FA5}')
+    } else if c.is_uppercase() {
+        rng.random_range('A'..='Z')
+    } else if c.is_alphabetic() {
+        rng.random_range('a'..='z')
+    } else {
+        c
+    }
+}
+
+fn is_wide(c: char) -> bool {
+    matches!(
+        c,
+        '\u{1100}'..='\u{115F}'
+            | '\u{2E80}'..='\u{A4CF}'
+            | '\u{AC00}'..='\u{D7A3}'
+            | '\u{F900}'..='\u{FAFF}'
+            | '\u{FE30}'..='\u{FE4F}'
+            | '\u{FF00}'..='\u{FF60}'
+            | '\u{FFE0}'..='\u{FFE6}'
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scrambles_every_letter() {
+        let mut rng = rand::rng();
+
+        for c in "IÀéÎõПриветΩμέγα".chars() {
+            let scrambled = scramble(c, &mut rng);
+
+            assert!(scrambled.is_ascii_alphabetic(), "{c} became {scrambled}");
+            assert_eq!(scrambled.is_uppercase(), c.is_uppercase());
+        }
+
+        assert!("IJL".contains(scramble('I', &mut rng)));
+    }
+}
@@ -0,0 +1,626 @@
+use crate::core::animation::{Animation, Easing};
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::time::{Duration, Instant, milliseconds};
+use crate::core::touch;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::{Operation, Widget};
+use crate::core::window;
+use crate::core::{
+    Background, Clipboard, Color, Element, Event, Length, Point, Rectangle, Shadow, Shell, Size,
+    Theme, Vector,
+};
+
+/// The distance a finger needs to travel before a swipe starts.
+const SWIPE_THRESHOLD: f32 = 10.0;
+
+/// A widget that slides a panel in from an edge, on top of some content.
+pub struct Drawer<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    content: Element<'a, Message, Theme, Renderer>,
+    panel: Element<'a, Message, Theme, Renderer>,
+    is_open: bool,
+    edge: Edge,
+    size: Length,
+    on_toggle: Option<Box<dyn Fn(bool) -> Message + 'a>>,
+    duration: Duration,
+    class: Theme::Class<'a>,
+}
+
+/// The edge a [`Drawer`] slides in from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Edge {
+    #[default]
+    Left,
+    Right,
+    Top,
+    Bottom,
+}
+
+impl Edge {
+    fn is_horizontal(self) -> bool {
+        matches!(self, Self::Left | Self::Right)
+    }
+
+    /// Returns the direction the panel moves in when closing.
+    fn direction(self) -> Vector {
+        match self {
+            Self::Left => Vector::new(-1.0, 0.0),
+            Self::Right => Vector::new(1.0, 0.0),
+            Self::Top => Vector::new(0.0, -1.0),
+            Self::Bottom => Vector::new(0.0, 1.0),
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Drawer<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    pub fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        panel: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            panel: panel.into(),
+            is_open: false,
+            edge: Edge::default(),
+            size: Length::Shrink,
+            on_toggle: None,
+            duration: milliseconds(250),
+            class: Theme::default(),
+        }
+    }
+
+    pub fn open(mut self, is_open: bool) -> Self {
+        self.is_open = is_open;
+        self
+    }
+
+    pub fn edge(mut self, edge: Edge) -> Self {
+        self.edge = edge;
+        self
+    }
+
+    /// Sets the width of the panel, or its height when sliding from
+    /// the top or bottom edge.
+    pub fn size(mut self, size: impl Into<Length>) -> Self {
+        self.size = size.into();
+        self
+    }
+
+    /// Sets the message produced when the [`Drawer`] asks to be opened or
+    /// closed; by clicking the backdrop, pressing Escape or swiping the
+    /// panel away.
+    pub fn on_toggle(mut self, on_toggle: impl Fn(bool) -> Message + 'a) -> Self {
+        self.on_toggle = Some(Box::new(on_toggle));
+        self
+    }
+
+    pub fn duration(mut self, duration: impl Into<Duration>) -> Self {
+        self.duration = duration.into();
+        self
+    }
+
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    fn close(&self, shell: &mut Shell<'_, Message>) {
+        if let Some(on_toggle) = &self.on_toggle {
+            shell.publish(on_toggle(false));
+        }
+    }
+}
+
+struct State {
+    is_open: bool,
+    transition: Animation<bool>,
+    /// Whether the transition starts on the next redraw.
+    is_pending: bool,
+    now: Instant,
+    swipe: Option<Swipe>,
+}
+
+struct Swipe {
+    finger: touch::Finger,
+    origin: Point,
+    distance: f32,
+    is_active: bool,
+}
+
+impl State {
+    fn is_animating(&self) -> bool {
+        self.is_pending || self.transition.is_animating(self.now)
+    }
+    /// Returns how much of the panel is visible, from `0.0` to `1.0`.
+    fn progress(&self, extent: f32) -> f32 {
+        let progress = self.transition.interpolate(0.0, 1.0, self.now);
+
+        match &self.swipe {
+            Some(swipe) if swipe.is_active && extent > 0.0 => {
+                (progress - swipe.distance / extent).clamp(0.0, 1.0)
+            }
+            _ => progress,
+        }
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Drawer<'_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: renderer::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            is_open: self.is_open,
+            transition: Animation::new(self.is_open),
+            is_pending: false,
+            now: Instant::now(),
+            swipe: None,
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content), Tree::new(&self.panel)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&[&self.content, &self.panel]);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.content.as_widget().size_hint()
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let state = tree.state.downcast_mut::<State>();
+
+        if state.is_open != self.is_open {
+            state.is_open = self.is_open;
+            state.transition = state
+                .transition
+                .clone()
+                .duration(self.duration)
+                .easing(Easing::EaseOut);
+            state.is_pending = true;
+            state.swipe = None;
+        }
+
+        let content = self
+            .content
+            .as_widget_mut()
+            .layout(&mut tree.children[0], renderer, limits);
+
+        let size = content.size();
+
+        let panel_limits = if self.edge.is_horizontal() {
+            layout::Limits::new(Size::new(0.0, size.height), size).width(self.size)
+        } else {
+            layout::Limits::new(Size::new(size.width, 0.0), size).height(self.size)
+        };
+
+        let panel =
+            self.panel
+                .as_widget_mut()
+                .layout(&mut tree.children[1], renderer, &panel_limits);
+
+        let panel_size = panel.size();
+
+        let panel = panel.move_to(match self.edge {
+            Edge::Left | Edge::Top => Point::ORIGIN,
+            Edge::Right => Point::new(size.width - panel_size.width, 0.0),
+            Edge::Bottom => Point::new(0.0, size.height - panel_size.height),
+        });
+
+        layout::Node::with_children(size, vec![content, panel])
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let (child, index) = if self.is_open {
+            (&mut self.panel, 1)
+        } else {
+            (&mut self.content, 0)
+        };
+
+        operation.container(None, layout.bounds());
+        operation.traverse(&mut |operation| {
+            child.as_widget_mut().operate(
+                &mut tree.children[index],
+                layout.children().nth(index).unwrap(),
+                renderer,
+                operation,
+            );
+        });
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        let mut children = layout.children();
+        let (content_layout, panel_layout) = (children.next().unwrap(), children.next().unwrap());
+        let extent = extent(self.edge, panel_layout.bounds());
+
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            let state = tree.state.downcast_mut::<State>();
+            state.now = *now;
+
+            if std::mem::take(&mut state.is_pending) {
+                state.transition.go_mut(state.is_open, *now);
+            }
+
+            if state.transition.is_animating(*now) {
+                shell.request_redraw();
+            }
+        }
+
+        let is_visible = {
+            let state = tree.state.downcast_ref::<State>();
+
+            state.is_open || state.is_animating()
+        };
+
+        if !is_visible {
+            self.content.as_widget_mut().update(
+                &mut tree.children[0],
+                event,
+                content_layout,
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+                viewport,
+            );
+
+            return;
+        }
+
+        if let Event::Window(_) = event {
+            self.content.as_widget_mut().update(
+                &mut tree.children[0],
+                event,
+                content_layout,
+                mouse::Cursor::Unavailable,
+                renderer,
+                clipboard,
+                shell,
+                viewport,
+            );
+        }
+
+        let state = tree.state.downcast_mut::<State>();
+
+        // The panel only takes input once it has settled in place
+        if state.is_open && !state.is_animating() {
+            self.panel.as_widget_mut().update(
+                &mut tree.children[1],
+                event,
+                panel_layout,
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+                viewport,
+            );
+        }
+
+        let state = tree.state.downcast_mut::<State>();
+
+        match event {
+            Event::Touch(touch::Event::FingerPressed { id, position })
+                if state.is_open && state.swipe.is_none() =>
+            {
+                state.swipe = Some(Swipe {
+                    finger: *id,
+                    origin: *position,
+                    distance: 0.0,
+                    is_active: false,
+                });
+            }
+            Event::Touch(touch::Event::FingerMoved { id, position }) => {
+                if let Some(swipe) = state.swipe.as_mut().filter(|swipe| swipe.finger == *id) {
+                    let delta = *position - swipe.origin;
+                    let direction = self.edge.direction();
+
+                    swipe.distance = (delta.x * direction.x + delta.y * direction.y).max(0.0);
+                    swipe.is_active |= swipe.distance > SWIPE_THRESHOLD;
+
+                    if swipe.is_active {
+                        shell.request_redraw();
+                        shell.capture_event();
+                    }
+                }
+            }
+            Event::Touch(
+                touch::Event::FingerLifted { id, position }
+                | touch::Event::FingerLost { id, position },
+            ) => {
+                let Some(swipe) = state.swipe.take_if(|swipe| swipe.finger == *id) else {
+                    return;
+                };
+
+                if swipe.is_active {
+                    if swipe.distance > extent / 3.0 {
+                        self.close(shell);
+                    }
+
+                    shell.request_redraw();
+                    shell.capture_event();
+                } else if !shell.is_event_captured()
+                    && !panel_layout.bounds().contains(*position)
+                    && state.is_open
+                {
+                    self.close(shell);
+                    shell.capture_event();
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(_)) if !shell.is_event_captured() => {
+                if state.is_open && !cursor.is_over(panel_layout.bounds()) {
+                    self.close(shell);
+                }
+
+                shell.capture_event();
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key::Named::Escape),
+                ..
+            }) if !shell.is_event_captured() => {
+                if state.is_open {
+                    self.close(shell);
+                }
+
+                shell.capture_event();
+            }
+            Event::Mouse(_) | Event::Touch(_) | Event::Keyboard(_) => {
+                shell.capture_event();
+            }
+            _ => {}
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+        let mut children = layout.children();
+        let (content_layout, panel_layout) = (children.next().unwrap(), children.next().unwrap());
+
+        if !state.is_open && !state.is_animating() {
+            return self.content.as_widget().mouse_interaction(
+                &tree.children[0],
+                content_layout,
+                cursor,
+                viewport,
+                renderer,
+            );
+        }
+
+        if cursor.is_over(panel_layout.bounds()) {
+            self.panel.as_widget().mouse_interaction(
+                &tree.children[1],
+                panel_layout,
+                cursor,
+                viewport,
+                renderer,
+            )
+        } else if cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::None
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        defaults: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let mut children = layout.children();
+        let (content_layout, panel_layout) = (children.next().unwrap(), children.next().unwrap());
+
+        let panel_bounds = panel_layout.bounds();
+        let progress = state.progress(extent(self.edge, panel_bounds));
+        let is_visible = progress > 0.0;
+
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            defaults,
+            content_layout,
+            if is_visible {
+                mouse::Cursor::Unavailable
+            } else {
+                cursor
+            },
+            viewport,
+        );
+
+        if !is_visible {
+            return;
+        }
+
+        let style = theme.style(&self.class);
+        let offset = self.edge.direction() * ((1.0 - progress) * extent(self.edge, panel_bounds));
+
+        renderer.with_layer(bounds, |renderer| {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    ..renderer::Quad::default()
+                },
+                style.backdrop.scale_alpha(progress),
+            );
+
+            renderer.with_translation(offset, |renderer| {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: panel_bounds,
+                        shadow: style.shadow,
+                        ..renderer::Quad::default()
+                    },
+                    style.background,
+                );
+
+                self.panel.as_widget().draw(
+                    &tree.children[1],
+                    renderer,
+                    theme,
+                    defaults,
+                    panel_layout,
+                    cursor,
+                    &panel_bounds,
+                );
+            });
+        });
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let (child, index) = if self.is_open {
+            (&mut self.panel, 1)
+        } else {
+            (&mut self.content, 0)
+        };
+
+        child.as_widget_mut().overlay(
+            &mut tree.children[index],
+            layout.children().nth(index)?,
+            renderer,
+            viewport,
+            translation,
+        )
+    }
+}
+
+/// Returns the distance the panel travels when sliding in.
+fn extent(edge: Edge, panel: Rectangle) -> f32 {
+    if edge.is_horizontal() {
+        panel.width
+    } else {
+        panel.height
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Drawer<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(drawer: Drawer<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(drawer)
+    }
+}
+
+/// The appearance of a [`Drawer`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Background`] drawn over the content while the panel is open.
+    pub backdrop: Background,
+    /// The [`Background`] of the panel.
+    pub background: Background,
+    /// The [`Shadow`] of the panel.
+    pub shadow: Shadow,
+}
+
+/// The theme catalog of a [`Drawer`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`Drawer`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`Drawer`].
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        backdrop: Color::BLACK.scale_alpha(0.4).into(),
+        background: palette.background.base.color.into(),
+        shadow: Shadow {
+            color: Color::BLACK.scale_alpha(0.3),
+            offset: Vector::ZERO,
+            blur_radius: 16.0,
+        },
+    }
+}
@@ -4,15 +4,25 @@ use crate::core::layout::{self, Layout};
 use crate::core::mouse;
 use crate::core::renderer;
 use crate::core::text;
+use crate::core::text::Paragraph as _;
 use crate::core::text::paragraph;
 use crate::core::widget;
 use crate::core::widget::tree::{self, Tree};
 use crate::core::{
-    Alignment, Color, Element, Font, Length, Pixels, Point, Rectangle, Size, Widget,
+    Alignment, Color, Element, Font, Length, Pixels, Point, Rectangle, Size, Transformation,
+    Vector, Widget,
 };
 
 use iced_widget::canvas;
 use iced_widget::graphics::geometry;
+use iced_widget::graphics::text::cosmic_text;
+use iced_widget::graphics::text::{self as graphics_text};
+
+use std::cell::Cell;
+use std::collections::{HashMap, HashSet};
+
+/// The font size glyph outlines are tessellated at before being scaled.
+const REFERENCE_SIZE: f32 = 128.0;
 
 #[derive(Debug)]
 pub struct DynamicText<'a, Theme, Renderer>
@@ -135,7 +145,129 @@ where
     Renderer: text::Renderer + geometry::Renderer + 'static,
 {
     text: paragraph::Plain<Renderer::Paragraph>,
-    geometry: canvas::Cache<Renderer>,
+    glyphs: Vec<Glyph>,
+    outlines: HashMap<cosmic_text::CacheKey, Option<Outline<Renderer>>>,
+    swash: cosmic_text::SwashCache,
+    color: Cell<Option<Color>>,
+}
+
+/// The outline of a glyph at [`REFERENCE_SIZE`], tessellated once per
+/// color.
+struct Outline<Renderer>
+where
+    Renderer: geometry::Renderer,
+{
+    commands: Box<[cosmic_text::Command]>,
+    cache: canvas::Cache<Renderer>,
+}
+
+/// A glyph of the text, placed relative to the text bounds.
+struct Glyph {
+    key: cosmic_text::CacheKey,
+    position: Point,
+    scale: f32,
+}
+
+/// Lays out the glyphs of the text in vectorial mode.
+///
+/// Glyphs are keyed by their outline at [`REFERENCE_SIZE`], so repeated
+/// characters and different sizes share the same cached geometry.
+///
+/// Each line is aligned independently within the given `width`, the same
+/// way the paragraph is aligned when rasterized.
+fn glyphs(text: text::Text<&str, Font>, width: f32) -> Vec<Glyph> {
+    let align_x = text.align_x;
+
+    // Lines are laid out from their start, wrapping like the raster
+    // paragraph, and then aligned one by one. Justified lines are stretched
+    // by the layout itself, like the raster paragraph does once measured.
+    let paragraph = graphics_text::Paragraph::with_text(match align_x {
+        text::Alignment::Justified => text::Text {
+            bounds: Size::new(width, text.bounds.height),
+            ..text
+        },
+        _ => text::Text {
+            align_x: text::Alignment::Left,
+            ..text
+        },
+    });
+
+    let mut glyphs = Vec::new();
+
+    for run in paragraph.buffer().layout_runs() {
+        let offset = match align_x {
+            text::Alignment::Center => (width - run.line_w) / 2.0,
+            text::Alignment::Right => width - run.line_w,
+            text::Alignment::Default if run.rtl => width - run.line_w,
+            text::Alignment::Default | text::Alignment::Left | text::Alignment::Justified => 0.0,
+        };
+
+        for glyph in run.glyphs {
+            let reference = cosmic_text::LayoutGlyph {
+                x: 0.0,
+                y: 0.0,
+                x_offset: 0.0,
+                y_offset: 0.0,
+                ..glyph.clone()
+            };
+
+            glyphs.push(Glyph {
+                key: reference
+                    .physical((0.0, 0.0), REFERENCE_SIZE / glyph.font_size)
+                    .cache_key,
+                position: Point::new(
+                    offset + glyph.x + glyph.x_offset,
+                    glyph.y_offset + run.line_y,
+                ),
+                scale: glyph.font_size / REFERENCE_SIZE,
+            });
+        }
+    }
+
+    glyphs
+}
+
+/// Draws the outline of a glyph at [`REFERENCE_SIZE`], with its origin
+/// at the baseline.
+fn outline<Renderer>(
+    frame: &mut canvas::Frame<Renderer>,
+    commands: &[cosmic_text::Command],
+    color: Color,
+) where
+    Renderer: geometry::Renderer,
+{
+    use cosmic_text::Command;
+
+    let path = canvas::Path::new(|path| {
+        for command in commands {
+            match command {
+                Command::MoveTo(p) => {
+                    path.move_to(Point::new(p.x, -p.y));
+                }
+                Command::LineTo(p) => {
+                    path.line_to(Point::new(p.x, -p.y));
+                }
+                Command::CurveTo(control_a, control_b, to) => {
+                    path.bezier_curve_to(
+                        Point::new(control_a.x, -control_a.y),
+                        Point::new(control_b.x, -control_b.y),
+                        Point::new(to.x, -to.y),
+                    );
+                }
+                Command::QuadTo(control, to) => {
+                    path.quadratic_curve_to(
+                        Point::new(control.x, -control.y),
+                        Point::new(to.x, -to.y),
+                    );
+                }
+                Command::Close => {
+                    path.close();
+                }
+            }
+        }
+    });
+
+    frame.fill(&path, color);
 }
 
 impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for DynamicText<'_, Theme, Renderer>
@@ -148,9 +280,12 @@ where
     }
 
     fn state(&self) -> tree::State {
-        tree::State::new(State {
+        tree::State::new(State::<Renderer> {
             text: paragraph::Plain::<Renderer::Paragraph>::default(),
-            geometry: canvas::Cache::<Renderer>::new(),
+            glyphs: Vec::new(),
+            outlines: HashMap::new(),
+            swash: cosmic_text::SwashCache::new(),
+            color: Cell::new(None),
         })
     }
 
@@ -188,8 +323,47 @@ where
                 hint_factor: None,
             });
 
-            if changed {
-                state.geometry.clear();
+            if !self.vectorial {
+                state.glyphs.clear();
+            } else if changed || state.glyphs.is_empty() {
+                state.glyphs = glyphs(
+                    text::Text {
+                        content: &self.fragment,
+                        bounds: Size::new(bounds.width, f32::INFINITY),
+                        size,
+                        line_height: self.line_height,
+                        font,
+                        align_x: self.align_x,
+                        align_y: alignment::Vertical::Top,
+                        shaping: self.shaping,
+                        wrapping: text::Wrapping::default(),
+                        hint_factor: None,
+                    },
+                    state.text.min_bounds().width,
+                );
+
+                // Only the outlines of the current glyphs are kept around
+                let keys: HashSet<_> = state.glyphs.iter().map(|glyph| glyph.key).collect();
+                state.outlines.retain(|key, _| keys.contains(key));
+
+                if keys.iter().any(|key| !state.outlines.contains_key(key)) {
+                    let mut font_system = graphics_text::font_system()
+                        .write()
+                        .expect("Write font system");
+
+                    for key in keys {
+                        let _ = state.outlines.entry(key).or_insert_with(|| {
+                            let commands = state
+                                .swash
+                                .get_outline_commands_uncached(font_system.raw(), key)?;
+
+                            Some(Outline {
+                                commands,
+                                cache: canvas::Cache::new(),
+                            })
+                        });
+                    }
+                }
             }
 
             state.text.min_bounds()
@@ -209,60 +383,48 @@ where
         let state = tree.state.downcast_ref::<State<Renderer>>();
         let style = theme.style(&self.class);
 
-        let text_bounds = state.text.min_bounds();
-        let text_position = {
-            let x = match self.align_x {
-                text::Alignment::Default | text::Alignment::Left | text::Alignment::Justified => {
-                    0.0
+        let position = layout
+            .bounds()
+            .anchor(state.text.min_bounds(), self.align_x, self.align_y);
+
+        let color = style.color.unwrap_or(defaults.text_color);
+
+        if self.vectorial {
+            if state.color.replace(Some(color)) != Some(color) {
+                for outline in state.outlines.values().flatten() {
+                    outline.cache.clear();
                 }
-                text::Alignment::Center => text_bounds.width / 2.0,
-                text::Alignment::Right => text_bounds.width,
-            };
+            }
 
-            let y = match self.align_y {
-                alignment::Vertical::Top => 0.0,
-                alignment::Vertical::Center => text_bounds.height / 2.0,
-                alignment::Vertical::Bottom => text_bounds.height,
+            // Generous bounds, since outlines extend above the baseline
+            let outline_bounds = Rectangle {
+                x: -REFERENCE_SIZE,
+                y: -REFERENCE_SIZE * 2.0,
+                width: REFERENCE_SIZE * 4.0,
+                height: REFERENCE_SIZE * 4.0,
             };
 
-            Point::new(x, y)
-        };
+            for glyph in &state.glyphs {
+                let Some(Some(Outline { commands, cache })) = state.outlines.get(&glyph.key) else {
+                    continue;
+                };
 
-        let geometry = state.geometry.draw(renderer, text_bounds, |frame| {
-            canvas::Text {
-                content: self.fragment.clone().into_owned(),
-                position: text_position,
-                max_width: text_bounds.width,
-                color: style.color.unwrap_or(defaults.text_color),
-                size: self.size.unwrap_or(renderer.default_size()),
-                line_height: self.line_height,
-                font: self.font.unwrap_or(renderer.default_font()),
-                align_x: self.align_x,
-                align_y: self.align_y,
-                shaping: self.shaping,
-            }
-            .draw_with(|glyph, color| {
-                frame.fill(&glyph, color);
-            });
-        });
+                let translation = position + Vector::new(glyph.position.x, glyph.position.y);
 
-        let position = layout
-            .bounds()
-            .anchor(state.text.min_bounds(), self.align_x, self.align_y);
+                renderer.with_transformation(
+                    Transformation::translate(translation.x, translation.y)
+                        * Transformation::scale(glyph.scale),
+                    |renderer| {
+                        let geometry = cache.draw_with_bounds(renderer, outline_bounds, |frame| {
+                            outline(frame, commands, color);
+                        });
 
-        if self.vectorial {
-            renderer.with_translation(position - Point::ORIGIN, |renderer| {
-                renderer.draw_geometry(geometry);
-            });
+                        renderer.draw_geometry(geometry);
+                    },
+                );
+            }
         } else {
-            let style = theme.style(&self.class);
-
-            renderer.fill_paragraph(
-                state.text.raw(),
-                position,
-                style.color.unwrap_or(defaults.text_color),
-                *viewport,
-            );
+            renderer.fill_paragraph(state.text.raw(), position, color, *viewport);
         }
     }
 }
@@ -1,13 +1,22 @@
 use crate::core;
 use crate::core::alignment;
+use crate::core::clipboard;
+use crate::core::keyboard;
 use crate::core::layout::{self, Layout};
 use crate::core::mouse;
 use crate::core::renderer;
 use crate::core::text::{self, Fragment, Hit, Paragraph};
 use crate::core::widget;
+use crate::core::widget::Id;
+use crate::core::widget::operation::{self, Operation};
 use crate::core::widget::text::Format;
 use crate::core::widget::tree::{self, Tree};
-use crate::core::{Alignment, Color, Element, Length, Pixels, Point, Rectangle, Size, Widget};
+use crate::core::{
+    Alignment, Clipboard, Color, Element, Event, Length, Pixels, Point, Rectangle, Shell, Size,
+    Widget,
+};
+
+use std::any::Any;
 
 #[derive(Debug)]
 pub struct EllipsizedText<'a, Theme, Renderer>
@@ -18,6 +27,8 @@ where
     fragment: Fragment<'a>,
     format: Format<Renderer::Font>,
     class: Theme::Class<'a>,
+    id: Option<Id>,
+    selectable: bool,
 }
 
 impl<'a, Theme, Renderer> EllipsizedText<'a, Theme, Renderer>
@@ -30,9 +41,25 @@ where
             fragment: fragment.into_fragment(),
             format: Format::default(),
             class: Theme::default(),
+            id: None,
+            selectable: false,
         }
     }
 
+    /// Sets the [`Id`] of the [`EllipsizedText`], used to find its
+    /// [`Contents`].
+    pub fn id(mut self, id: impl Into<Id>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Makes the text selectable with a click. Copying a selected text
+    /// places the full fragment on the clipboard, not the ellipsized one.
+    pub fn selectable(mut self, selectable: bool) -> Self {
+        self.selectable = selectable;
+        self
+    }
+
     pub fn size(mut self, size: impl Into<Pixels>) -> Self {
         self.format.size = Some(size.into());
         self
@@ -108,10 +135,60 @@ where
     }
 }
 
+/// The text of an [`EllipsizedText`], as obtained by [`contents`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Contents {
+    /// The complete fragment.
+    pub full: String,
+    /// The text being displayed, which may be ellipsized.
+    pub displayed: String,
+}
+
+impl Contents {
+    /// Returns true if the displayed text is shorter than the fragment.
+    pub fn is_truncated(&self) -> bool {
+        self.full != self.displayed
+    }
+}
+
+/// Produces the [`Contents`] of the [`EllipsizedText`] with the given [`Id`].
+pub fn contents(id: impl Into<Id>) -> impl Operation<Contents> {
+    struct Find {
+        id: Id,
+        contents: Option<Contents>,
+    }
+
+    impl Operation<Contents> for Find {
+        fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation<Contents>)) {
+            operate(self);
+        }
+
+        fn custom(&mut self, id: Option<&Id>, _bounds: Rectangle, state: &mut dyn Any) {
+            if id == Some(&self.id) {
+                self.contents = state.downcast_ref::<Contents>().cloned();
+            }
+        }
+
+        fn finish(&self) -> operation::Outcome<Contents> {
+            match &self.contents {
+                Some(contents) => operation::Outcome::Some(contents.clone()),
+                None => operation::Outcome::None,
+            }
+        }
+    }
+
+    Find {
+        id: id.into(),
+        contents: None,
+    }
+}
+
 struct State<P: text::Paragraph> {
     original: text::paragraph::Plain<P>,
     ellipsis: text::paragraph::Plain<P>,
     ellipsized: text::paragraph::Plain<P>,
+    displayed: Option<String>,
+    is_selected: bool,
 }
 
 impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
@@ -130,6 +207,8 @@ where
             original: text::paragraph::Plain::<Renderer::Paragraph>::default(),
             ellipsis: text::paragraph::Plain::<Renderer::Paragraph>::default(),
             ellipsized: text::paragraph::Plain::<Renderer::Paragraph>::default(),
+            displayed: None,
+            is_selected: false,
         })
     }
 
@@ -201,11 +280,13 @@ where
                             })
                             .unwrap_or(Hit::CharOffset(offset));
 
+                        let displayed = format!(
+                            "{}...",
+                            &self.fragment[..offset].trim().trim_end_matches([',', '.'])
+                        );
+
                         state.ellipsized.update(text::Text {
-                            content: &format!(
-                                "{}...",
-                                &self.fragment[..offset].trim().trim_end_matches([',', '.'])
-                            ),
+                            content: &displayed,
                             bounds,
                             size,
                             line_height: format.line_height,
@@ -216,9 +297,12 @@ where
                             wrapping: format.wrapping,
                             hint_factor: renderer.scale_factor(),
                         });
+
+                        state.displayed = Some(displayed);
                     }
                     _ => {
                         state.ellipsized = state.original.clone();
+                        state.displayed = None;
                     }
                 }
             }
@@ -227,6 +311,81 @@ where
         })
     }
 
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+        let bounds = layout.bounds();
+
+        let mut contents = Contents {
+            full: self.fragment.to_string(),
+            displayed: state
+                .displayed
+                .clone()
+                .unwrap_or_else(|| self.fragment.to_string()),
+        };
+
+        operation.text(self.id.as_ref(), bounds, &contents.full);
+        operation.custom(self.id.as_ref(), bounds, &mut contents);
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        if !self.selectable {
+            return;
+        }
+
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                let is_selected = cursor.is_over(layout.bounds());
+
+                if is_selected != state.is_selected {
+                    state.is_selected = is_selected;
+                    shell.request_redraw();
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Character(c),
+                modifiers,
+                ..
+            }) if state.is_selected && modifiers.command() && c.as_str() == "c" => {
+                clipboard.write(clipboard::Kind::Standard, self.fragment.to_string());
+                shell.capture_event();
+            }
+            _ => {}
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if self.selectable && cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Text
+        } else {
+            mouse::Interaction::None
+        }
+    }
+
     fn draw(
         &self,
         tree: &Tree,
@@ -239,6 +398,7 @@ where
     ) {
         let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
         let style = theme.style(&self.class);
+        let color = style.color.unwrap_or(defaults.text_color);
 
         let position = layout.bounds().anchor(
             state.ellipsized.min_bounds(),
@@ -246,12 +406,17 @@ where
             self.format.align_y,
         );
 
-        renderer.fill_paragraph(
-            state.ellipsized.raw(),
-            position,
-            style.color.unwrap_or(defaults.text_color),
-            *viewport,
-        );
+        if self.selectable && state.is_selected {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle::new(position, state.ellipsized.min_bounds()),
+                    ..renderer::Quad::default()
+                },
+                color.scale_alpha(0.2),
+            );
+        }
+
+        renderer.fill_paragraph(state.ellipsized.raw(), position, color, *viewport);
     }
 }
 
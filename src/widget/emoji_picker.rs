@@ -0,0 +1,1391 @@
+use crate::core::alignment;
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::text;
+use crate::core::widget::Widget;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::{
+    Background, Border, Clipboard, Color, Element, Event, Length, Pixels, Point, Rectangle, Shell,
+    Size, Theme,
+};
+use crate::widget::popover::{self, Placement, Popover};
+
+const PADDING: f32 = 8.0;
+const SEARCH_HEIGHT: f32 = 28.0;
+const TABS_HEIGHT: f32 = 28.0;
+const HEADER_HEIGHT: f32 = 24.0;
+const FOOTER_HEIGHT: f32 = 24.0;
+const SPACING: f32 = 4.0;
+
+/// A button that opens a searchable grid of emoji, grouped by category.
+///
+/// The picker does not remember anything by itself; recently picked emoji
+/// are provided by your application with [`EmojiPicker::recent`].
+pub struct EmojiPicker<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog + popover::Catalog,
+    Renderer: text::Renderer,
+{
+    target: Element<'a, Message, Theme, Renderer>,
+    panel: Panel<'a, Message, Theme, Renderer>,
+    placement: Placement,
+    is_open: Option<bool>,
+    on_toggle: Option<Box<dyn Fn(bool) -> Message + 'a>>,
+}
+
+/// The skin tone applied to the emoji of people and hands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SkinTone {
+    #[default]
+    Default,
+    Light,
+    MediumLight,
+    Medium,
+    MediumDark,
+    Dark,
+}
+
+impl SkinTone {
+    /// All of the skin tones, in order.
+    pub const ALL: [Self; 6] = [
+        Self::Default,
+        Self::Light,
+        Self::MediumLight,
+        Self::Medium,
+        Self::MediumDark,
+        Self::Dark,
+    ];
+
+    /// Returns the Fitzpatrick modifier of the [`SkinTone`], if any.
+    pub fn modifier(self) -> Option<char> {
+        match self {
+            Self::Default => None,
+            Self::Light => Some('\u{1F3FB}'),
+            Self::MediumLight => Some('\u{1F3FC}'),
+            Self::Medium => Some('\u{1F3FD}'),
+            Self::MediumDark => Some('\u{1F3FE}'),
+            Self::Dark => Some('\u{1F3FF}'),
+        }
+    }
+
+    fn color(self) -> Color {
+        match self {
+            Self::Default => Color::from_rgb8(0xFF, 0xCC, 0x4D),
+            Self::Light => Color::from_rgb8(0xF7, 0xDE, 0xCE),
+            Self::MediumLight => Color::from_rgb8(0xF3, 0xD2, 0xA2),
+            Self::Medium => Color::from_rgb8(0xD5, 0xAB, 0x88),
+            Self::MediumDark => Color::from_rgb8(0xAF, 0x7E, 0x57),
+            Self::Dark => Color::from_rgb8(0x7C, 0x53, 0x3E),
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> EmojiPicker<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog + popover::Catalog,
+    Renderer: text::Renderer,
+{
+    pub fn new(target: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        Self {
+            target: target.into(),
+            panel: Panel {
+                on_pick: None,
+                recent: &[],
+                columns: 8,
+                rows: 6,
+                cell_size: 32.0,
+                skin_tone: SkinTone::Default,
+                font: None,
+                class: <Theme as Catalog>::default(),
+            },
+            placement: Placement::Bottom,
+            is_open: None,
+            on_toggle: None,
+        }
+    }
+
+    /// Sets the message produced when an emoji is picked.
+    ///
+    /// The emoji is a [`String`], since skin tones are applied with a
+    /// modifier following the base character.
+    pub fn on_pick(mut self, on_pick: impl Fn(String) -> Message + 'a) -> Self {
+        self.panel.on_pick = Some(Box::new(on_pick));
+        self
+    }
+
+    /// Sets the recently picked emoji, most recent first.
+    pub fn recent(mut self, recent: &'a [String]) -> Self {
+        self.panel.recent = recent;
+        self
+    }
+
+    /// Sets the initial [`SkinTone`] of the picker.
+    pub fn skin_tone(mut self, skin_tone: SkinTone) -> Self {
+        self.panel.skin_tone = skin_tone;
+        self
+    }
+
+    pub fn placement(mut self, placement: Placement) -> Self {
+        self.placement = placement;
+        self
+    }
+
+    /// Controls whether the picker is open; see [`Popover::open`].
+    pub fn open(mut self, is_open: bool) -> Self {
+        self.is_open = Some(is_open);
+        self
+    }
+
+    pub fn on_toggle(mut self, on_toggle: impl Fn(bool) -> Message + 'a) -> Self {
+        self.on_toggle = Some(Box::new(on_toggle));
+        self
+    }
+
+    pub fn columns(mut self, columns: usize) -> Self {
+        self.panel.columns = columns.max(1);
+        self
+    }
+
+    /// Sets the amount of visible rows of emoji.
+    pub fn rows(mut self, rows: usize) -> Self {
+        self.panel.rows = rows.max(1);
+        self
+    }
+
+    pub fn cell_size(mut self, cell_size: impl Into<Pixels>) -> Self {
+        self.panel.cell_size = cell_size.into().0;
+        self
+    }
+
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.panel.font = Some(font.into());
+        self
+    }
+
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        <Theme as Catalog>::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.panel.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<EmojiPicker<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + popover::Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(picker: EmojiPicker<'a, Message, Theme, Renderer>) -> Self {
+        let mut popover = Popover::new(picker.target, picker.panel)
+            .placement(picker.placement)
+            .padding(0);
+
+        if let Some(is_open) = picker.is_open {
+            popover = popover.open(is_open);
+        }
+
+        if let Some(on_toggle) = picker.on_toggle {
+            popover = popover.on_toggle(on_toggle);
+        }
+
+        Element::new(popover)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Category {
+    Smileys,
+    People,
+    Nature,
+    Food,
+    Activities,
+    Travel,
+    Objects,
+    Symbols,
+}
+
+impl Category {
+    const ALL: [Self; 8] = [
+        Self::Smileys,
+        Self::People,
+        Self::Nature,
+        Self::Food,
+        Self::Activities,
+        Self::Travel,
+        Self::Objects,
+        Self::Symbols,
+    ];
+
+    fn title(self) -> &'static str {
+        match self {
+            Self::Smileys => "Smileys",
+            Self::People => "People",
+            Self::Nature => "Animals & Nature",
+            Self::Food => "Food & Drink",
+            Self::Activities => "Activities",
+            Self::Travel => "Travel & Places",
+            Self::Objects => "Objects",
+            Self::Symbols => "Symbols",
+        }
+    }
+
+    fn icon(self) -> &'static str {
+        match self {
+            Self::Smileys => "😀",
+            Self::People => "👋",
+            Self::Nature => "🐻",
+            Self::Food => "🍔",
+            Self::Activities => "⚽",
+            Self::Travel => "🚗",
+            Self::Objects => "💡",
+            Self::Symbols => "❤\u{fe0f}",
+        }
+    }
+
+    fn emoji(self) -> &'static [(&'static str, &'static str)] {
+        match self {
+            Self::Smileys => SMILEYS,
+            Self::People => PEOPLE,
+            Self::Nature => NATURE,
+            Self::Food => FOOD,
+            Self::Activities => ACTIVITIES,
+            Self::Travel => TRAVEL,
+            Self::Objects => OBJECTS,
+            Self::Symbols => SYMBOLS,
+        }
+    }
+
+    fn has_skin_tones(self) -> bool {
+        self == Self::People
+    }
+}
+
+struct Panel<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    on_pick: Option<Box<dyn Fn(String) -> Message + 'a>>,
+    recent: &'a [String],
+    columns: usize,
+    rows: usize,
+    cell_size: f32,
+    skin_tone: SkinTone,
+    font: Option<Renderer::Font>,
+    class: <Theme as Catalog>::Class<'a>,
+}
+
+struct State {
+    query: String,
+    offset: f32,
+    skin_tone: SkinTone,
+}
+
+struct Section<'a> {
+    title: &'static str,
+    icon: &'static str,
+    emoji: Vec<Emoji<'a>>,
+}
+
+#[derive(Clone, Copy)]
+struct Emoji<'a> {
+    glyph: &'a str,
+    name: &'static str,
+    has_skin_tones: bool,
+}
+
+impl Emoji<'_> {
+    fn with(self, skin_tone: SkinTone) -> String {
+        match skin_tone.modifier().filter(|_| self.has_skin_tones) {
+            Some(modifier) => format!("{}{modifier}", self.glyph),
+            None => self.glyph.to_owned(),
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Row {
+    Header(usize),
+    Emoji { section: usize, start: usize },
+}
+
+struct Areas {
+    search: Rectangle,
+    tabs: Rectangle,
+    grid: Rectangle,
+    footer: Rectangle,
+}
+
+impl<'a, Message, Theme, Renderer> Panel<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn width(&self) -> f32 {
+        self.columns as f32 * self.cell_size + PADDING * 2.0
+    }
+
+    fn areas(&self, bounds: Rectangle) -> Areas {
+        let width = bounds.width - PADDING * 2.0;
+        let x = bounds.x + PADDING;
+
+        let search = Rectangle::new(
+            Point::new(x, bounds.y + PADDING),
+            Size::new(width, SEARCH_HEIGHT),
+        );
+
+        let tabs = Rectangle::new(
+            Point::new(x, search.y + search.height + SPACING),
+            Size::new(width, TABS_HEIGHT),
+        );
+
+        let grid = Rectangle::new(
+            Point::new(x, tabs.y + tabs.height + SPACING),
+            Size::new(width, self.rows as f32 * self.cell_size),
+        );
+
+        let footer = Rectangle::new(
+            Point::new(x, grid.y + grid.height + SPACING),
+            Size::new(width, FOOTER_HEIGHT),
+        );
+
+        Areas {
+            search,
+            tabs,
+            grid,
+            footer,
+        }
+    }
+
+    fn sections(&self, query: &str) -> Vec<Section<'a>> {
+        let query = query.trim().to_lowercase();
+
+        if !query.is_empty() {
+            let emoji = Category::ALL
+                .into_iter()
+                .flat_map(|category| {
+                    category
+                        .emoji()
+                        .iter()
+                        .filter(|(_, name)| name.contains(&query))
+                        .map(move |&(glyph, name)| Emoji {
+                            glyph,
+                            name,
+                            has_skin_tones: category.has_skin_tones(),
+                        })
+                })
+                .collect();
+
+            return vec![Section {
+                title: "Results",
+                icon: "🔍",
+                emoji,
+            }];
+        }
+
+        let recent = (!self.recent.is_empty()).then(|| Section {
+            title: "Recent",
+            icon: "🕘",
+            emoji: self
+                .recent
+                .iter()
+                .map(|glyph| Emoji {
+                    glyph,
+                    name: name(glyph),
+                    has_skin_tones: false,
+                })
+                .collect(),
+        });
+
+        recent
+            .into_iter()
+            .chain(Category::ALL.into_iter().map(|category| {
+                Section {
+                    title: category.title(),
+                    icon: category.icon(),
+                    emoji: category
+                        .emoji()
+                        .iter()
+                        .map(|&(glyph, name)| Emoji {
+                            glyph,
+                            name,
+                            has_skin_tones: category.has_skin_tones(),
+                        })
+                        .collect(),
+                }
+            }))
+            .collect()
+    }
+
+    /// Returns the rows of the grid with their vertical offset.
+    fn rows(&self, sections: &[Section<'_>]) -> (Vec<(f32, Row)>, f32) {
+        let mut rows = Vec::new();
+        let mut y = 0.0;
+
+        for (index, section) in sections.iter().enumerate() {
+            rows.push((y, Row::Header(index)));
+            y += HEADER_HEIGHT;
+
+            for start in (0..section.emoji.len()).step_by(self.columns) {
+                rows.push((
+                    y,
+                    Row::Emoji {
+                        section: index,
+                        start,
+                    },
+                ));
+                y += self.cell_size;
+            }
+        }
+
+        (rows, y)
+    }
+
+    fn row_height(&self, row: Row) -> f32 {
+        match row {
+            Row::Header(_) => HEADER_HEIGHT,
+            Row::Emoji { .. } => self.cell_size,
+        }
+    }
+
+    /// Returns the section and index of the emoji at the given position of
+    /// the grid, relative to the top of its content.
+    fn emoji_at(
+        &self,
+        rows: &[(f32, Row)],
+        sections: &[Section<'_>],
+        position: Point,
+    ) -> Option<(usize, usize)> {
+        let (_, row) = rows
+            .iter()
+            .find(|(y, row)| position.y >= *y && position.y < y + self.row_height(*row))?;
+
+        let Row::Emoji { section, start } = *row else {
+            return None;
+        };
+
+        let column = (position.x / self.cell_size) as usize;
+        let index = start + column;
+
+        (column < self.columns && index < sections[section].emoji.len()).then_some((section, index))
+    }
+
+    fn tab_at(&self, tabs: Rectangle, count: usize, position: Point) -> Option<usize> {
+        if !tabs.contains(position) || count == 0 {
+            return None;
+        }
+
+        let width = tabs.width / count as f32;
+
+        Some((((position.x - tabs.x) / width) as usize).min(count - 1))
+    }
+
+    fn swatch_bounds(&self, footer: Rectangle) -> impl Iterator<Item = (SkinTone, Rectangle)> {
+        let size = 14.0;
+        let spacing = 4.0;
+        let count = SkinTone::ALL.len() as f32;
+        let x = footer.x + footer.width - count * (size + spacing) + spacing;
+
+        SkinTone::ALL.into_iter().enumerate().map(move |(i, tone)| {
+            (
+                tone,
+                Rectangle {
+                    x: x + i as f32 * (size + spacing),
+                    y: footer.center_y() - size / 2.0,
+                    width: size,
+                    height: size,
+                },
+            )
+        })
+    }
+
+    fn max_offset(&self, height: f32) -> f32 {
+        (height - self.rows as f32 * self.cell_size).max(0.0)
+    }
+
+    fn text(
+        &self,
+        content: impl Into<String>,
+        bounds: Size,
+        size: f32,
+        align_x: text::Alignment,
+        renderer: &Renderer,
+    ) -> text::Text<String, Renderer::Font> {
+        text::Text {
+            content: content.into(),
+            bounds,
+            size: Pixels(size),
+            line_height: text::LineHeight::default(),
+            font: self.font.unwrap_or_else(|| renderer.default_font()),
+            align_x,
+            align_y: alignment::Vertical::Center,
+            shaping: text::Shaping::Advanced,
+            wrapping: text::Wrapping::None,
+            hint_factor: renderer.scale_factor(),
+        }
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Panel<'_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            query: String::new(),
+            offset: 0.0,
+            skin_tone: self.skin_tone,
+        })
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(Length::Shrink, Length::Shrink)
+    }
+
+    fn layout(
+        &mut self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let height = PADDING * 2.0
+            + SEARCH_HEIGHT
+            + TABS_HEIGHT
+            + FOOTER_HEIGHT
+            + SPACING * 3.0
+            + self.rows as f32 * self.cell_size;
+
+        layout::Node::new(limits.resolve(
+            Length::Shrink,
+            Length::Shrink,
+            Size::new(self.width(), height),
+        ))
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+        let areas = self.areas(layout.bounds());
+
+        match event {
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
+                if !cursor.is_over(areas.grid) {
+                    return;
+                }
+
+                let delta = match delta {
+                    mouse::ScrollDelta::Lines { y, .. } => y * self.cell_size,
+                    mouse::ScrollDelta::Pixels { y, .. } => *y,
+                };
+
+                let sections = self.sections(&state.query);
+                let (_, height) = self.rows(&sections);
+
+                state.offset = (state.offset - delta).clamp(0.0, self.max_offset(height));
+
+                shell.capture_event();
+                shell.request_redraw();
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                let Some(position) = cursor.position() else {
+                    return;
+                };
+
+                let tabs = self.sections("");
+
+                if let Some(tab) = self.tab_at(areas.tabs, tabs.len(), position) {
+                    let (rows, height) = self.rows(&tabs);
+
+                    let y = rows
+                        .iter()
+                        .find(|(_, row)| matches!(row, Row::Header(index) if *index == tab))
+                        .map_or(0.0, |(y, _)| *y);
+
+                    state.query.clear();
+                    state.offset = y.min(self.max_offset(height));
+
+                    shell.capture_event();
+                    shell.request_redraw();
+                    return;
+                }
+
+                if let Some((tone, _)) = self
+                    .swatch_bounds(areas.footer)
+                    .find(|(_, bounds)| bounds.contains(position))
+                {
+                    state.skin_tone = tone;
+
+                    shell.capture_event();
+                    shell.request_redraw();
+                    return;
+                }
+
+                if !areas.grid.contains(position) {
+                    return;
+                }
+
+                let sections = self.sections(&state.query);
+                let (rows, _) = self.rows(&sections);
+
+                let Some((section, index)) = self.emoji_at(
+                    &rows,
+                    &sections,
+                    Point::new(
+                        position.x - areas.grid.x,
+                        position.y - areas.grid.y + state.offset,
+                    ),
+                ) else {
+                    return;
+                };
+
+                if let Some(on_pick) = &self.on_pick {
+                    shell.publish(on_pick(
+                        sections[section].emoji[index].with(state.skin_tone),
+                    ));
+                }
+
+                shell.capture_event();
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key,
+                text,
+                modifiers,
+                ..
+            }) => {
+                match key.as_ref() {
+                    keyboard::Key::Named(key::Named::Escape) if !state.query.is_empty() => {
+                        state.query.clear();
+                    }
+                    keyboard::Key::Named(key::Named::Backspace) => {
+                        let _ = state.query.pop();
+                    }
+                    _ => {
+                        let Some(text) = text
+                            .as_deref()
+                            .filter(|_| !modifiers.command() && !modifiers.alt())
+                            .filter(|text| text.chars().all(|c| !c.is_control()))
+                        else {
+                            return;
+                        };
+
+                        state.query.push_str(text);
+                    }
+                }
+
+                state.offset = 0.0;
+
+                shell.capture_event();
+                shell.request_redraw();
+            }
+            _ => {}
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+        let areas = self.areas(layout.bounds());
+
+        let Some(position) = cursor.position() else {
+            return mouse::Interaction::None;
+        };
+
+        let is_over_emoji = areas.grid.contains(position) && {
+            let sections = self.sections(&state.query);
+            let (rows, _) = self.rows(&sections);
+
+            self.emoji_at(
+                &rows,
+                &sections,
+                Point::new(
+                    position.x - areas.grid.x,
+                    position.y - areas.grid.y + state.offset,
+                ),
+            )
+            .is_some()
+        };
+
+        if is_over_emoji
+            || areas.tabs.contains(position)
+            || self
+                .swatch_bounds(areas.footer)
+                .any(|(_, bounds)| bounds.contains(position))
+        {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::None
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let style = <Theme as Catalog>::style(theme, &self.class);
+        let bounds = layout.bounds();
+        let areas = self.areas(bounds);
+        let position = cursor.position();
+        let label_size = renderer.default_size().0 * 0.8;
+
+        // Search
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: areas.search,
+                border: style.search_border,
+                ..renderer::Quad::default()
+            },
+            style.search_background,
+        );
+
+        let (query, color) = if state.query.is_empty() {
+            ("Type to search", style.placeholder)
+        } else {
+            (state.query.as_str(), style.text_color)
+        };
+
+        renderer.fill_text(
+            self.text(
+                query,
+                Size::new(areas.search.width - 16.0, areas.search.height),
+                label_size,
+                text::Alignment::Left,
+                renderer,
+            ),
+            Point::new(areas.search.x + 8.0, areas.search.center_y()),
+            color,
+            areas.search,
+        );
+
+        // Tabs
+        let tabs = self.sections("");
+        let tab_width = areas.tabs.width / tabs.len() as f32;
+        let (tab_rows, _) = self.rows(&tabs);
+
+        let active = state.query.is_empty().then(|| {
+            tab_rows
+                .iter()
+                .rev()
+                .find_map(|(y, row)| match row {
+                    Row::Header(index) if *y <= state.offset + 1.0 => Some(*index),
+                    _ => None,
+                })
+                .unwrap_or(0)
+        });
+
+        let hovered_tab =
+            position.and_then(|position| self.tab_at(areas.tabs, tabs.len(), position));
+
+        for (index, section) in tabs.iter().enumerate() {
+            let tab = Rectangle {
+                x: areas.tabs.x + index as f32 * tab_width,
+                width: tab_width,
+                ..areas.tabs
+            };
+
+            if hovered_tab == Some(index) {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: tab,
+                        border: Border::default().rounded(4),
+                        ..renderer::Quad::default()
+                    },
+                    style.hovered,
+                );
+            }
+
+            if active == Some(index) {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: tab.x + 4.0,
+                            y: tab.y + tab.height - 2.0,
+                            width: tab.width - 8.0,
+                            height: 2.0,
+                        },
+                        border: Border::default().rounded(1),
+                        ..renderer::Quad::default()
+                    },
+                    style.active_category,
+                );
+            }
+
+            renderer.fill_text(
+                self.text(
+                    section.icon,
+                    tab.size(),
+                    TABS_HEIGHT * 0.55,
+                    text::Alignment::Center,
+                    renderer,
+                ),
+                tab.center(),
+                style.text_color,
+                tab,
+            );
+        }
+
+        // Grid
+        let sections = self.sections(&state.query);
+        let (rows, height) = self.rows(&sections);
+        let offset = state.offset.min(self.max_offset(height));
+
+        let hovered = position
+            .filter(|position| areas.grid.contains(*position))
+            .and_then(|position| {
+                self.emoji_at(
+                    &rows,
+                    &sections,
+                    Point::new(
+                        position.x - areas.grid.x,
+                        position.y - areas.grid.y + offset,
+                    ),
+                )
+            });
+
+        renderer.with_layer(areas.grid, |renderer| {
+            let visible = rows.iter().filter(|(y, row)| {
+                *y + self.row_height(*row) > offset && *y < offset + areas.grid.height
+            });
+
+            for (y, row) in visible {
+                let y = areas.grid.y + y - offset;
+
+                match *row {
+                    Row::Header(index) => {
+                        renderer.fill_text(
+                            self.text(
+                                sections[index].title,
+                                Size::new(areas.grid.width, HEADER_HEIGHT),
+                                label_size,
+                                text::Alignment::Left,
+                                renderer,
+                            ),
+                            Point::new(areas.grid.x + 2.0, y + HEADER_HEIGHT / 2.0),
+                            style.header,
+                            areas.grid,
+                        );
+                    }
+                    Row::Emoji { section, start } => {
+                        let emoji = &sections[section].emoji;
+                        let end = (start + self.columns).min(emoji.len());
+
+                        for (column, index) in (start..end).enumerate() {
+                            let cell = Rectangle {
+                                x: areas.grid.x + column as f32 * self.cell_size,
+                                y,
+                                width: self.cell_size,
+                                height: self.cell_size,
+                            };
+
+                            if hovered == Some((section, index)) {
+                                renderer.fill_quad(
+                                    renderer::Quad {
+                                        bounds: cell.shrink(1.0),
+                                        border: Border::default().rounded(6),
+                                        ..renderer::Quad::default()
+                                    },
+                                    style.hovered,
+                                );
+                            }
+
+                            let glyph = emoji[index].with(state.skin_tone);
+
+                            renderer.fill_text(
+                                self.text(
+                                    glyph,
+                                    cell.size(),
+                                    self.cell_size * 0.65,
+                                    text::Alignment::Center,
+                                    renderer,
+                                ),
+                                cell.center(),
+                                style.text_color,
+                                areas.grid,
+                            );
+                        }
+                    }
+                }
+            }
+        });
+
+        if sections.iter().all(|section| section.emoji.is_empty()) {
+            renderer.fill_text(
+                self.text(
+                    "No emoji found",
+                    areas.grid.size(),
+                    label_size,
+                    text::Alignment::Center,
+                    renderer,
+                ),
+                areas.grid.center(),
+                style.placeholder,
+                areas.grid,
+            );
+        }
+
+        // Footer
+        let name = hovered.map_or("", |(section, index)| sections[section].emoji[index].name);
+
+        renderer.fill_text(
+            self.text(
+                name,
+                areas.footer.size(),
+                label_size,
+                text::Alignment::Left,
+                renderer,
+            ),
+            Point::new(areas.footer.x + 2.0, areas.footer.center_y()),
+            style.text_color,
+            areas.footer,
+        );
+
+        for (tone, swatch) in self.swatch_bounds(areas.footer) {
+            let is_selected = tone == state.skin_tone;
+            let is_hovered = position.is_some_and(|position| swatch.contains(position));
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: swatch,
+                    border: Border::default()
+                        .rounded(swatch.width / 2.0)
+                        .width(if is_selected || is_hovered { 2 } else { 0 })
+                        .color(if is_selected {
+                            style.active_category
+                        } else {
+                            style.placeholder
+                        }),
+                    ..renderer::Quad::default()
+                },
+                tone.color(),
+            );
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Panel<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(panel: Panel<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(panel)
+    }
+}
+
+/// Returns the name of the given emoji, ignoring its skin tone.
+fn name(glyph: &str) -> &'static str {
+    let base = glyph.trim_end_matches(|c| ('\u{1F3FB}'..='\u{1F3FF}').contains(&c));
+
+    Category::ALL
+        .into_iter()
+        .flat_map(Category::emoji)
+        .find(|(emoji, _)| *emoji == base)
+        .map_or("", |(_, name)| name)
+}
+
+/// The appearance of an [`EmojiPicker`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Color`] of the emoji and the hovered name.
+    pub text_color: Color,
+    /// The [`Color`] of the section titles.
+    pub header: Color,
+    /// The [`Color`] of the search placeholder.
+    pub placeholder: Color,
+    /// The [`Background`] of the search field.
+    pub search_background: Background,
+    /// The [`Border`] of the search field.
+    pub search_border: Border,
+    /// The [`Background`] of a hovered emoji or category.
+    pub hovered: Background,
+    /// The [`Color`] marking the current category and skin tone.
+    pub active_category: Color,
+}
+
+/// The theme catalog of an [`EmojiPicker`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for an [`EmojiPicker`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of an [`EmojiPicker`].
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        text_color: palette.background.base.text,
+        header: palette.background.strongest.color,
+        placeholder: palette.background.strong.color,
+        search_background: palette.background.weak.color.into(),
+        search_border: Border::default().rounded(6),
+        hovered: palette.background.weak.color.into(),
+        active_category: palette.primary.base.color,
+    }
+}
+
+const SMILEYS: &[(&str, &str)] = &[
+    ("😀", "grinning face"),
+    ("😃", "smiley"),
+    ("😄", "smile"),
+    ("😁", "grin"),
+    ("😆", "laughing"),
+    ("😅", "sweat smile"),
+    ("🤣", "rolling on the floor laughing"),
+    ("😂", "tears of joy"),
+    ("🙂", "slightly smiling face"),
+    ("🙃", "upside down face"),
+    ("😉", "wink"),
+    ("😊", "blush"),
+    ("😇", "innocent halo"),
+    ("🥰", "smiling face with hearts"),
+    ("😍", "heart eyes"),
+    ("🤩", "star struck"),
+    ("😘", "kissing heart"),
+    ("😋", "yum"),
+    ("😛", "tongue"),
+    ("🤔", "thinking"),
+    ("🤨", "raised eyebrow"),
+    ("😐", "neutral face"),
+    ("😑", "expressionless"),
+    ("😶", "no mouth"),
+    ("🙄", "rolling eyes"),
+    ("😏", "smirk"),
+    ("😴", "sleeping"),
+    ("😎", "cool sunglasses"),
+    ("🤓", "nerd"),
+    ("😕", "confused"),
+    ("😢", "cry"),
+    ("😭", "sob"),
+    ("😱", "scream"),
+    ("😡", "angry rage"),
+    ("🤯", "exploding head"),
+    ("🥳", "partying face"),
+    ("🤗", "hugging"),
+    ("🤫", "shushing"),
+    ("🤐", "zipper mouth"),
+    ("🥺", "pleading"),
+];
+
+const PEOPLE: &[(&str, &str)] = &[
+    ("👋", "waving hand"),
+    ("🤚", "raised back of hand"),
+    ("✋", "raised hand"),
+    ("🖖", "vulcan salute"),
+    ("👌", "ok hand"),
+    ("🤌", "pinched fingers"),
+    ("🤏", "pinching hand"),
+    ("🤞", "crossed fingers"),
+    ("🤟", "love you gesture"),
+    ("🤘", "rock on horns"),
+    ("🤙", "call me hand"),
+    ("👈", "pointing left"),
+    ("👉", "pointing right"),
+    ("👆", "pointing up"),
+    ("👇", "pointing down"),
+    ("👍", "thumbs up"),
+    ("👎", "thumbs down"),
+    ("✊", "raised fist"),
+    ("👊", "punch"),
+    ("👏", "clapping hands"),
+    ("🙌", "raising hands"),
+    ("👐", "open hands"),
+    ("🤲", "palms up"),
+    ("🙏", "folded hands pray"),
+    ("💪", "flexed biceps muscle"),
+    ("👶", "baby"),
+    ("🧒", "child"),
+    ("👦", "boy"),
+    ("👧", "girl"),
+    ("🧑", "person"),
+    ("👨", "man"),
+    ("👩", "woman"),
+    ("🧓", "older person"),
+];
+
+const NATURE: &[(&str, &str)] = &[
+    ("🐶", "dog"),
+    ("🐱", "cat"),
+    ("🐭", "mouse"),
+    ("🐹", "hamster"),
+    ("🐰", "rabbit"),
+    ("🦊", "fox"),
+    ("🐻", "bear"),
+    ("🐼", "panda"),
+    ("🐨", "koala"),
+    ("🐯", "tiger"),
+    ("🦁", "lion"),
+    ("🐮", "cow"),
+    ("🐷", "pig"),
+    ("🐸", "frog"),
+    ("🐵", "monkey"),
+    ("🐔", "chicken"),
+    ("🐧", "penguin"),
+    ("🐦", "bird"),
+    ("🦀", "crab"),
+    ("🐙", "octopus"),
+    ("🦋", "butterfly"),
+    ("🐝", "honeybee"),
+    ("🐢", "turtle"),
+    ("🐍", "snake"),
+    ("🌵", "cactus"),
+    ("🌲", "evergreen tree"),
+    ("🌴", "palm tree"),
+    ("🌱", "seedling"),
+    ("🍀", "four leaf clover"),
+    ("🌸", "cherry blossom"),
+    ("🌻", "sunflower"),
+    ("🌹", "rose"),
+    ("🍁", "maple leaf"),
+    ("🌙", "crescent moon"),
+    ("⭐", "star"),
+    ("🔥", "fire"),
+    ("🌈", "rainbow"),
+    ("❄\u{fe0f}", "snowflake"),
+    ("🌊", "ocean wave"),
+];
+
+const FOOD: &[(&str, &str)] = &[
+    ("🍏", "green apple"),
+    ("🍎", "red apple"),
+    ("🍐", "pear"),
+    ("🍊", "tangerine orange"),
+    ("🍋", "lemon"),
+    ("🍌", "banana"),
+    ("🍉", "watermelon"),
+    ("🍇", "grapes"),
+    ("🍓", "strawberry"),
+    ("🍒", "cherries"),
+    ("🍑", "peach"),
+    ("🥭", "mango"),
+    ("🍍", "pineapple"),
+    ("🥥", "coconut"),
+    ("🥑", "avocado"),
+    ("🍆", "eggplant"),
+    ("🥕", "carrot"),
+    ("🌽", "corn"),
+    ("🌶\u{fe0f}", "hot pepper"),
+    ("🥐", "croissant"),
+    ("🍞", "bread"),
+    ("🧀", "cheese"),
+    ("🍳", "cooking egg"),
+    ("🥓", "bacon"),
+    ("🍔", "hamburger"),
+    ("🍟", "french fries"),
+    ("🍕", "pizza"),
+    ("🌮", "taco"),
+    ("🍣", "sushi"),
+    ("🍜", "ramen noodles"),
+    ("🍩", "doughnut"),
+    ("🍪", "cookie"),
+    ("🎂", "birthday cake"),
+    ("🍫", "chocolate"),
+    ("☕", "hot coffee"),
+    ("🍵", "tea"),
+    ("🍺", "beer"),
+    ("🍷", "wine"),
+];
+
+const ACTIVITIES: &[(&str, &str)] = &[
+    ("⚽", "soccer football"),
+    ("🏀", "basketball"),
+    ("🏈", "american football"),
+    ("⚾", "baseball"),
+    ("🎾", "tennis"),
+    ("🏐", "volleyball"),
+    ("🏉", "rugby"),
+    ("🎱", "billiards"),
+    ("🏓", "ping pong"),
+    ("🏸", "badminton"),
+    ("🥊", "boxing glove"),
+    ("🥋", "martial arts"),
+    ("⛳", "golf"),
+    ("⛸\u{fe0f}", "ice skate"),
+    ("🎣", "fishing"),
+    ("🎿", "ski"),
+    ("🏆", "trophy"),
+    ("🥇", "gold medal"),
+    ("🎯", "direct hit target"),
+    ("🎮", "video game"),
+    ("🎲", "dice"),
+    ("🧩", "puzzle piece"),
+    ("♟\u{fe0f}", "chess pawn"),
+    ("🎨", "artist palette"),
+    ("🎬", "clapper board"),
+    ("🎤", "microphone"),
+    ("🎧", "headphones"),
+    ("🎸", "guitar"),
+    ("🎹", "musical keyboard"),
+    ("🥁", "drum"),
+    ("🎉", "party popper tada"),
+    ("🎁", "gift"),
+];
+
+const TRAVEL: &[(&str, &str)] = &[
+    ("🚗", "car"),
+    ("🚕", "taxi"),
+    ("🚌", "bus"),
+    ("🚓", "police car"),
+    ("🚑", "ambulance"),
+    ("🚒", "fire engine"),
+    ("🚚", "truck"),
+    ("🚲", "bicycle"),
+    ("🛵", "scooter"),
+    ("🏍\u{fe0f}", "motorcycle"),
+    ("🚂", "locomotive"),
+    ("🚆", "train"),
+    ("✈\u{fe0f}", "airplane"),
+    ("🚀", "rocket"),
+    ("🛸", "flying saucer"),
+    ("🚁", "helicopter"),
+    ("⛵", "sailboat"),
+    ("🚢", "ship"),
+    ("⚓", "anchor"),
+    ("🗺\u{fe0f}", "world map"),
+    ("🗽", "statue of liberty"),
+    ("🏰", "castle"),
+    ("🏠", "house home"),
+    ("🏢", "office building"),
+    ("🏥", "hospital"),
+    ("⛺", "tent camping"),
+    ("🌋", "volcano"),
+    ("🗻", "mount fuji"),
+    ("🏖\u{fe0f}", "beach"),
+    ("🌅", "sunrise"),
+    ("🌃", "night with stars"),
+];
+
+const OBJECTS: &[(&str, &str)] = &[
+    ("⌚", "watch"),
+    ("📱", "mobile phone"),
+    ("💻", "laptop"),
+    ("⌨\u{fe0f}", "keyboard"),
+    ("🖥\u{fe0f}", "desktop computer"),
+    ("🖨\u{fe0f}", "printer"),
+    ("🖱\u{fe0f}", "computer mouse"),
+    ("💾", "floppy disk save"),
+    ("💿", "optical disk"),
+    ("📷", "camera"),
+    ("🎥", "movie camera"),
+    ("📺", "television"),
+    ("🔦", "flashlight"),
+    ("💡", "light bulb idea"),
+    ("🔋", "battery"),
+    ("🔌", "electric plug"),
+    ("💸", "money with wings"),
+    ("💰", "money bag"),
+    ("💎", "gem stone"),
+    ("🔧", "wrench"),
+    ("🔨", "hammer"),
+    ("⚙\u{fe0f}", "gear settings"),
+    ("🔩", "nut and bolt"),
+    ("🧲", "magnet"),
+    ("🔬", "microscope"),
+    ("🔭", "telescope"),
+    ("📦", "package box"),
+    ("✏\u{fe0f}", "pencil"),
+    ("📌", "pushpin"),
+    ("📎", "paperclip"),
+    ("📚", "books"),
+    ("📝", "memo note"),
+    ("📅", "calendar"),
+    ("🔒", "locked"),
+    ("🔑", "key"),
+    ("🗑\u{fe0f}", "wastebasket trash"),
+];
+
+const SYMBOLS: &[(&str, &str)] = &[
+    ("❤\u{fe0f}", "red heart"),
+    ("🧡", "orange heart"),
+    ("💛", "yellow heart"),
+    ("💚", "green heart"),
+    ("💙", "blue heart"),
+    ("💜", "purple heart"),
+    ("🖤", "black heart"),
+    ("💔", "broken heart"),
+    ("💯", "hundred points"),
+    ("✅", "check mark"),
+    ("❌", "cross mark"),
+    ("❓", "question mark"),
+    ("❗", "exclamation mark"),
+    ("⚠\u{fe0f}", "warning"),
+    ("🚫", "prohibited"),
+    ("♻\u{fe0f}", "recycling"),
+    ("➕", "plus"),
+    ("➖", "minus"),
+    ("✖\u{fe0f}", "multiply"),
+    ("➗", "divide"),
+    ("♾\u{fe0f}", "infinity"),
+    ("🔴", "red circle"),
+    ("🟢", "green circle"),
+    ("🔵", "blue circle"),
+    ("⬆\u{fe0f}", "up arrow"),
+    ("⬇\u{fe0f}", "down arrow"),
+    ("⬅\u{fe0f}", "left arrow"),
+    ("➡\u{fe0f}", "right arrow"),
+    ("🔁", "repeat"),
+    ("▶\u{fe0f}", "play"),
+    ("⏸\u{fe0f}", "pause"),
+    ("⏹\u{fe0f}", "stop"),
+    ("🔔", "bell"),
+    ("🔇", "muted speaker"),
+    ("💤", "zzz sleep"),
+    ("🆗", "ok button"),
+    ("🆕", "new button"),
+];
@@ -0,0 +1,385 @@
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::{Operation, Widget};
+use crate::core::window;
+use crate::core::{
+    Background, Border, Clipboard, Color, Element, Event, Length, Rectangle, Shell, Size, Theme,
+    Vector,
+};
+
+use std::path::{Path, PathBuf};
+
+/// A widget that accepts files dropped on top of its content.
+///
+/// The drop zone is highlighted while files are being dragged over the
+/// window. If the cursor position is known, only the zone under it is
+/// highlighted and receives the files.
+pub struct FileDrop<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    content: Element<'a, Message, Theme, Renderer>,
+    on_drop: Option<Box<dyn Fn(Vec<PathBuf>) -> Message + 'a>>,
+    on_reject: Option<Box<dyn Fn(Vec<PathBuf>) -> Message + 'a>>,
+    extensions: Vec<String>,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> FileDrop<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    pub fn new(content: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        Self {
+            content: content.into(),
+            on_drop: None,
+            on_reject: None,
+            extensions: Vec::new(),
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the message produced when accepted files are dropped.
+    pub fn on_drop(mut self, on_drop: impl Fn(Vec<PathBuf>) -> Message + 'a) -> Self {
+        self.on_drop = Some(Box::new(on_drop));
+        self
+    }
+
+    /// Sets the message produced when rejected files are dropped.
+    pub fn on_reject(mut self, on_reject: impl Fn(Vec<PathBuf>) -> Message + 'a) -> Self {
+        self.on_reject = Some(Box::new(on_reject));
+        self
+    }
+
+    /// Only accepts files with any of the given extensions, ignoring case.
+    ///
+    /// By default, all files are accepted.
+    pub fn extensions(mut self, extensions: impl IntoIterator<Item = impl AsRef<str>>) -> Self {
+        self.extensions = extensions
+            .into_iter()
+            .map(|extension| extension.as_ref().trim_start_matches('.').to_lowercase())
+            .collect();
+        self
+    }
+
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    fn accepts(&self, path: &Path) -> bool {
+        self.extensions.is_empty()
+            || path
+                .extension()
+                .and_then(|extension| extension.to_str())
+                .is_some_and(|extension| {
+                    self.extensions
+                        .iter()
+                        .any(|allowed| allowed.eq_ignore_ascii_case(extension))
+                })
+    }
+
+    fn status(&self, state: &State, cursor: mouse::Cursor, bounds: Rectangle) -> Status {
+        if state.hovered.is_empty() || !is_target(cursor, bounds) {
+            Status::Idle
+        } else if state.hovered.iter().all(|path| self.accepts(path)) {
+            Status::Accepting
+        } else {
+            Status::Rejecting
+        }
+    }
+}
+
+/// Returns true if dropped files should go to the given bounds.
+fn is_target(cursor: mouse::Cursor, bounds: Rectangle) -> bool {
+    cursor
+        .position()
+        .is_none_or(|position| bounds.contains(position))
+}
+
+#[derive(Default)]
+struct State {
+    hovered: Vec<PathBuf>,
+    dropped: Vec<PathBuf>,
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for FileDrop<'_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: renderer::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.content.as_widget().size_hint()
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget_mut()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content
+            .as_widget_mut()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Window(window::Event::FileHovered(path)) => {
+                state.hovered.push(path.clone());
+                shell.request_redraw();
+            }
+            Event::Window(window::Event::FilesHoveredLeft) => {
+                state.hovered.clear();
+                shell.request_redraw();
+            }
+            Event::Window(window::Event::FileDropped(path)) => {
+                state.hovered.clear();
+
+                if is_target(cursor, bounds) {
+                    state.dropped.push(path.clone());
+                }
+
+                shell.request_redraw();
+            }
+            Event::Window(window::Event::RedrawRequested(_)) if !state.dropped.is_empty() => {
+                // Files are dropped one event at a time, so they are
+                // reported together in the next frame.
+                let (accepted, rejected): (Vec<_>, Vec<_>) =
+                    state.dropped.drain(..).partition(|path| self.accepts(path));
+
+                if let Some(on_drop) = self.on_drop.as_ref().filter(|_| !accepted.is_empty()) {
+                    shell.publish(on_drop(accepted));
+                }
+
+                if let Some(on_reject) = self.on_reject.as_ref().filter(|_| !rejected.is_empty()) {
+                    shell.publish(on_reject(rejected));
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. }) if !state.hovered.is_empty() => {
+                shell.request_redraw();
+            }
+            _ => {}
+        }
+
+        self.content.as_widget_mut().update(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        defaults: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            defaults,
+            layout,
+            cursor,
+            viewport,
+        );
+
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let style = theme.style(&self.class, self.status(state, cursor, bounds));
+
+        if style.background.is_none() && style.border.width == 0.0 {
+            return;
+        }
+
+        renderer.with_layer(*viewport, |renderer| {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border: style.border,
+                    ..renderer::Quad::default()
+                },
+                style
+                    .background
+                    .unwrap_or(Background::Color(Color::TRANSPARENT)),
+            );
+        });
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            viewport,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<FileDrop<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(file_drop: FileDrop<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(file_drop)
+    }
+}
+
+/// The possible status of a [`FileDrop`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// No files are being dragged over the [`FileDrop`].
+    Idle,
+    /// Files that would be accepted are being dragged over.
+    Accepting,
+    /// Some of the files being dragged over would be rejected.
+    Rejecting,
+}
+
+/// The appearance of a [`FileDrop`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Background`] drawn on top of the content, if any.
+    pub background: Option<Background>,
+    /// The [`Border`] of the drop zone.
+    pub border: Border,
+}
+
+/// The theme catalog of a [`FileDrop`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class with the given status.
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style;
+}
+
+/// A styling function for a [`FileDrop`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
+        class(self, status)
+    }
+}
+
+/// The default style of a [`FileDrop`].
+pub fn default(theme: &Theme, status: Status) -> Style {
+    let palette = theme.extended_palette();
+
+    let color = match status {
+        Status::Idle => {
+            return Style {
+                background: None,
+                border: Border::default(),
+            };
+        }
+        Status::Accepting => palette.primary.base.color,
+        Status::Rejecting => palette.danger.base.color,
+    };
+
+    Style {
+        background: Some(color.scale_alpha(0.15).into()),
+        border: Border::default().rounded(4).width(2).color(color),
+    }
+}
@@ -0,0 +1,614 @@
+use crate::core::animation::{Animation, Easing};
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::time::Duration;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::{Operation, Widget};
+use crate::core::window;
+use crate::core::{
+    Background, Border, Clipboard, Color, Element, Event, Length, Padding, Pixels, Point,
+    Rectangle, Shell, Size, Theme, Vector,
+};
+
+use std::collections::HashMap;
+use std::ops::Range;
+
+/// A horizontally scrolling strip of thumbnails.
+///
+/// Thumbnails are only built while they are in view.
+pub struct Filmstrip<'a, T, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    items: &'a [T],
+    view: Box<dyn Fn(&'a T) -> Element<'a, Message, Theme, Renderer> + 'a>,
+    thumbnails: Vec<(usize, Element<'a, Message, Theme, Renderer>)>,
+    selected: Option<usize>,
+    on_select: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    thumbnail_size: Size,
+    width: Length,
+    padding: Padding,
+    spacing: f32,
+    duration: Duration,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, T, Message, Theme, Renderer> Filmstrip<'a, T, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    pub fn new(
+        items: &'a [T],
+        view: impl Fn(&'a T) -> Element<'a, Message, Theme, Renderer> + 'a,
+    ) -> Self {
+        Self {
+            items,
+            view: Box::new(view),
+            thumbnails: Vec::new(),
+            selected: None,
+            on_select: None,
+            thumbnail_size: Size::new(96.0, 64.0),
+            width: Length::Fill,
+            padding: Padding::new(8.0),
+            spacing: 8.0,
+            duration: Duration::from_millis(250),
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the selected item, which is scrolled to the center of the strip.
+    pub fn selected(mut self, selected: Option<usize>) -> Self {
+        self.selected = selected;
+        self
+    }
+
+    /// Sets the message produced when an item is clicked or reached with
+    /// the arrow keys.
+    pub fn on_select(mut self, on_select: impl Fn(usize) -> Message + 'a) -> Self {
+        self.on_select = Some(Box::new(on_select));
+        self
+    }
+
+    pub fn thumbnail_size(mut self, size: impl Into<Size>) -> Self {
+        self.thumbnail_size = size.into();
+        self
+    }
+
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    pub fn padding(mut self, padding: impl Into<Padding>) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.spacing = spacing.into().0;
+        self
+    }
+
+    /// Sets the duration of the scroll to a newly selected item.
+    pub fn duration(mut self, duration: impl Into<Duration>) -> Self {
+        self.duration = duration.into();
+        self
+    }
+
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    fn stride(&self) -> f32 {
+        self.thumbnail_size.width + self.spacing
+    }
+
+    fn max_offset(&self, viewport: f32) -> f32 {
+        let content = self.items.len() as f32 * self.stride() - self.spacing;
+
+        (content - viewport).max(0.0)
+    }
+
+    /// The offset that puts the item at the center of the strip.
+    fn center(&self, index: usize, viewport: f32) -> f32 {
+        let center = index as f32 * self.stride() + self.thumbnail_size.width / 2.0;
+
+        (center - viewport / 2.0).clamp(0.0, self.max_offset(viewport))
+    }
+
+    fn visible(&self, offset: f32, viewport: f32) -> Range<usize> {
+        let start = (offset / self.stride()).floor().max(0.0) as usize;
+        let end = ((offset + viewport) / self.stride()).ceil().max(0.0) as usize;
+
+        start.min(self.items.len())..end.min(self.items.len())
+    }
+
+    /// The bounds of the slot of an item, relative to the strip.
+    fn slot(&self, index: usize, offset: f32) -> Rectangle {
+        Rectangle {
+            x: self.padding.left + index as f32 * self.stride() - offset,
+            y: self.padding.top,
+            width: self.thumbnail_size.width,
+            height: self.thumbnail_size.height,
+        }
+    }
+
+    fn slot_at(&self, state: &State, bounds: Rectangle, cursor: mouse::Cursor) -> Option<usize> {
+        let position = cursor.position_over(bounds)? - Vector::new(bounds.x, bounds.y);
+
+        self.thumbnails
+            .iter()
+            .map(|(index, _)| *index)
+            .find(|index| self.slot(*index, state.offset).contains(position))
+    }
+}
+
+#[derive(Default)]
+struct State {
+    offset: f32,
+    viewport: f32,
+    scroll: Option<(f32, f32, Animation<bool>)>,
+    selected: Option<usize>,
+    is_initialized: bool,
+    is_focused: bool,
+    thumbnails: HashMap<usize, Tree>,
+}
+
+impl<T, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Filmstrip<'_, T, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: renderer::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, Length::Shrink)
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let state = tree.state.downcast_mut::<State>();
+        let size = limits.resolve(
+            self.width,
+            Length::Shrink,
+            Size::new(0.0, self.thumbnail_size.height + self.padding.y()),
+        );
+
+        state.viewport = (size.width - self.padding.x()).max(0.0);
+
+        if !state.is_initialized {
+            state.is_initialized = true;
+            state.selected = self.selected;
+            state.offset = self
+                .selected
+                .map_or(0.0, |index| self.center(index, state.viewport));
+        }
+
+        state.offset = state.offset.clamp(0.0, self.max_offset(state.viewport));
+
+        let visible = self.visible(state.offset, state.viewport);
+
+        state.thumbnails.retain(|index, _| visible.contains(index));
+        self.thumbnails.retain(|(index, _)| visible.contains(index));
+
+        let items = self.items;
+
+        for index in visible {
+            if self.thumbnails.iter().all(|(built, _)| *built != index) {
+                self.thumbnails.push((index, (self.view)(&items[index])));
+            }
+        }
+
+        self.thumbnails.sort_by_key(|(index, _)| *index);
+
+        let limits = layout::Limits::new(Size::ZERO, self.thumbnail_size);
+        let slots: Vec<Rectangle> = self
+            .thumbnails
+            .iter()
+            .map(|(index, _)| self.slot(*index, state.offset))
+            .collect();
+
+        let nodes = self
+            .thumbnails
+            .iter_mut()
+            .zip(slots)
+            .map(|((index, thumbnail), slot)| {
+                let tree = state
+                    .thumbnails
+                    .entry(*index)
+                    .or_insert_with(|| Tree::new(&*thumbnail));
+
+                tree.diff(&*thumbnail);
+
+                let node = thumbnail.as_widget_mut().layout(tree, renderer, &limits);
+                let size = node.size();
+
+                node.move_to(Point::new(
+                    slot.x + (slot.width - size.width) / 2.0,
+                    slot.y + (slot.height - size.height) / 2.0,
+                ))
+            })
+            .collect();
+
+        layout::Node::with_children(size, nodes)
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+
+        operation.container(None, layout.bounds());
+        operation.traverse(&mut |operation| {
+            for ((index, thumbnail), layout) in self.thumbnails.iter_mut().zip(layout.children()) {
+                if let Some(tree) = state.thumbnails.get_mut(index) {
+                    thumbnail
+                        .as_widget_mut()
+                        .operate(tree, layout, renderer, operation);
+                }
+            }
+        });
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+
+        for ((index, thumbnail), layout) in self.thumbnails.iter_mut().zip(layout.children()) {
+            if let Some(tree) = state.thumbnails.get_mut(index) {
+                thumbnail.as_widget_mut().update(
+                    tree, event, layout, cursor, renderer, clipboard, shell, &bounds,
+                );
+            }
+        }
+
+        if shell.is_event_captured() {
+            return;
+        }
+
+        match event {
+            Event::Window(window::Event::RedrawRequested(now)) => {
+                if state.selected != self.selected {
+                    state.selected = self.selected;
+
+                    if let Some(index) = self.selected {
+                        state.scroll = Some((
+                            state.offset,
+                            self.center(index, state.viewport),
+                            Animation::new(false)
+                                .duration(self.duration)
+                                .easing(Easing::EaseOut)
+                                .go(true, *now),
+                        ));
+                    }
+                }
+
+                if let Some((from, to, animation)) = &state.scroll {
+                    state.offset = from + (to - from) * animation.interpolate(0.0, 1.0, *now);
+
+                    if animation.is_animating(*now) {
+                        shell.request_redraw();
+                    } else {
+                        state.scroll = None;
+                    }
+
+                    shell.invalidate_layout();
+                }
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) if cursor.is_over(bounds) => {
+                let delta = match *delta {
+                    mouse::ScrollDelta::Lines { x, y } => (if x == 0.0 { y } else { x }) * 60.0,
+                    mouse::ScrollDelta::Pixels { x, y } => {
+                        if x == 0.0 {
+                            y
+                        } else {
+                            x
+                        }
+                    }
+                };
+
+                let offset = (state.offset - delta).clamp(0.0, self.max_offset(state.viewport));
+
+                if offset != state.offset {
+                    state.offset = offset;
+                    state.scroll = None;
+
+                    shell.invalidate_layout();
+                    shell.request_redraw();
+                    shell.capture_event();
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                if !cursor.is_over(bounds) {
+                    if state.is_focused {
+                        state.is_focused = false;
+                        shell.request_redraw();
+                    }
+
+                    return;
+                }
+
+                state.is_focused = true;
+
+                if let Some((index, on_select)) = self
+                    .slot_at(state, bounds, cursor)
+                    .zip(self.on_select.as_ref())
+                {
+                    shell.publish(on_select(index));
+                }
+
+                shell.request_redraw();
+                shell.capture_event();
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed { key, .. }) if state.is_focused => {
+                let Some(on_select) = &self.on_select else {
+                    return;
+                };
+
+                let last = self.items.len().saturating_sub(1);
+                let selected = self.selected;
+
+                let target = match key.as_ref() {
+                    keyboard::Key::Named(key::Named::ArrowRight) => {
+                        Some(selected.map_or(0, |selected| (selected + 1).min(last)))
+                    }
+                    keyboard::Key::Named(key::Named::ArrowLeft) => {
+                        Some(selected.map_or(0, |selected| selected.saturating_sub(1)))
+                    }
+                    keyboard::Key::Named(key::Named::Home) => Some(0),
+                    keyboard::Key::Named(key::Named::End) => Some(last),
+                    keyboard::Key::Named(key::Named::Escape) => {
+                        state.is_focused = false;
+
+                        shell.request_redraw();
+                        shell.capture_event();
+                        return;
+                    }
+                    _ => None,
+                };
+
+                if let Some(target) = target.filter(|_| !self.items.is_empty()) {
+                    if Some(target) != selected {
+                        shell.publish(on_select(target));
+                    }
+
+                    shell.capture_event();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+
+        let interaction = self
+            .thumbnails
+            .iter()
+            .zip(layout.children())
+            .filter_map(|((index, thumbnail), layout)| {
+                let tree = state.thumbnails.get(index)?;
+
+                Some(
+                    thumbnail
+                        .as_widget()
+                        .mouse_interaction(tree, layout, cursor, &bounds, renderer),
+                )
+            })
+            .max()
+            .unwrap_or_default();
+
+        if interaction == mouse::Interaction::None
+            && self.on_select.is_some()
+            && self.slot_at(state, bounds, cursor).is_some()
+        {
+            return mouse::Interaction::Pointer;
+        }
+
+        interaction
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        defaults: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let style = theme.style(&self.class);
+        let bounds = layout.bounds();
+        let hovered = self.slot_at(state, bounds, cursor);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: style.border,
+                ..renderer::Quad::default()
+            },
+            style.background,
+        );
+
+        let clip = Rectangle {
+            x: bounds.x + self.padding.left,
+            width: bounds.width - self.padding.x(),
+            ..bounds
+        };
+
+        renderer.with_layer(clip, |renderer| {
+            for ((index, thumbnail), layout) in self.thumbnails.iter().zip(layout.children()) {
+                let slot = self.slot(*index, state.offset) + Vector::new(bounds.x, bounds.y);
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: slot,
+                        border: Border {
+                            width: 0.0,
+                            ..style.thumbnail_border
+                        },
+                        ..renderer::Quad::default()
+                    },
+                    style.thumbnail,
+                );
+
+                if let Some(tree) = state.thumbnails.get(index) {
+                    renderer.with_layer(slot, |renderer| {
+                        thumbnail
+                            .as_widget()
+                            .draw(tree, renderer, theme, defaults, layout, cursor, &slot);
+                    });
+                }
+
+                let border = if self.selected == Some(*index) {
+                    style.selected
+                } else if hovered == Some(*index) {
+                    style.hovered
+                } else {
+                    style.thumbnail_border
+                };
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: slot,
+                        border,
+                        ..renderer::Quad::default()
+                    },
+                    Color::TRANSPARENT,
+                );
+
+                if state.is_focused && self.selected == Some(*index) {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: slot.expand(3.0),
+                            border: Border {
+                                radius: border.radius,
+                                width: 1.0,
+                                color: style.selected.color,
+                            },
+                            ..renderer::Quad::default()
+                        },
+                        Color::TRANSPARENT,
+                    );
+                }
+            }
+        });
+    }
+}
+
+impl<'a, T, Message, Theme, Renderer> From<Filmstrip<'a, T, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(filmstrip: Filmstrip<'a, T, Message, Theme, Renderer>) -> Self {
+        Element::new(filmstrip)
+    }
+}
+
+/// The appearance of a [`Filmstrip`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Background`] of the strip.
+    pub background: Background,
+    /// The [`Border`] of the strip.
+    pub border: Border,
+    /// The [`Background`] behind each thumbnail.
+    pub thumbnail: Background,
+    /// The [`Border`] of each thumbnail.
+    pub thumbnail_border: Border,
+    /// The [`Border`] of a hovered thumbnail.
+    pub hovered: Border,
+    /// The [`Border`] of the selected thumbnail.
+    pub selected: Border,
+}
+
+/// The theme catalog of a [`Filmstrip`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`Filmstrip`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`Filmstrip`].
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    let thumbnail_border = Border::default().rounded(4);
+
+    Style {
+        background: palette.background.weak.color.into(),
+        border: Border::default(),
+        thumbnail: palette.background.base.color.into(),
+        thumbnail_border,
+        hovered: thumbnail_border
+            .width(2)
+            .color(palette.background.strong.color),
+        selected: thumbnail_border.width(2).color(palette.primary.base.color),
+    }
+}
@@ -0,0 +1,397 @@
+use crate::core::alignment;
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::widget::Tree;
+use crate::core::widget::{Operation, Widget};
+use crate::core::{
+    Clipboard, Element, Event, Length, Padding, Pixels, Point, Rectangle, Shell, Size, Vector,
+};
+
+/// A container that lays out its children from left to right, wrapping
+/// them into new rows when running out of width.
+pub struct Flow<'a, Message, Theme, Renderer> {
+    children: Vec<Element<'a, Message, Theme, Renderer>>,
+    spacing: f32,
+    row_spacing: Option<f32>,
+    padding: Padding,
+    width: Length,
+    height: Length,
+    align_y: alignment::Vertical,
+    justify: Justify,
+}
+
+/// The distribution of the free space of every row of a [`Flow`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Justify {
+    /// Packs the children at the start of the row.
+    #[default]
+    Start,
+    /// Packs the children at the center of the row.
+    Center,
+    /// Packs the children at the end of the row.
+    End,
+    /// Distributes the free space between the children.
+    SpaceBetween,
+    /// Distributes the free space around the children, with half-size
+    /// spaces at the edges.
+    SpaceAround,
+    /// Distributes the free space evenly between and around the children.
+    SpaceEvenly,
+}
+
+impl Justify {
+    /// Returns the offset of the first child and the extra space added
+    /// between children, given the free space and the amount of children.
+    fn distribute(self, free: f32, children: usize) -> (f32, f32) {
+        let free = free.max(0.0);
+        let count = children as f32;
+
+        match self {
+            Justify::Start => (0.0, 0.0),
+            Justify::Center => (free / 2.0, 0.0),
+            Justify::End => (free, 0.0),
+            Justify::SpaceBetween if children > 1 => (0.0, free / (count - 1.0)),
+            Justify::SpaceBetween => (0.0, 0.0),
+            Justify::SpaceAround => (free / count / 2.0, free / count),
+            Justify::SpaceEvenly => (free / (count + 1.0), free / (count + 1.0)),
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Flow<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    pub fn new() -> Self {
+        Self::from_vec(Vec::new())
+    }
+
+    pub fn with_children(
+        children: impl IntoIterator<Item = Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self::from_vec(children.into_iter().collect())
+    }
+
+    pub fn from_vec(children: Vec<Element<'a, Message, Theme, Renderer>>) -> Self {
+        Self {
+            children,
+            spacing: 0.0,
+            row_spacing: None,
+            padding: Padding::ZERO,
+            width: Length::Fill,
+            height: Length::Shrink,
+            align_y: alignment::Vertical::Top,
+            justify: Justify::default(),
+        }
+    }
+
+    /// Sets the spacing between the children of a row.
+    ///
+    /// It is also used between rows, unless a [`row_spacing`] is set.
+    ///
+    /// [`row_spacing`]: Self::row_spacing
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.spacing = spacing.into().0;
+        self
+    }
+
+    /// Sets the spacing between rows.
+    pub fn row_spacing(mut self, row_spacing: impl Into<Pixels>) -> Self {
+        self.row_spacing = Some(row_spacing.into().0);
+        self
+    }
+
+    pub fn padding(mut self, padding: impl Into<Padding>) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the vertical alignment of the children within their row.
+    pub fn align_y(mut self, align: impl Into<alignment::Vertical>) -> Self {
+        self.align_y = align.into();
+        self
+    }
+
+    pub fn justify(mut self, justify: Justify) -> Self {
+        self.justify = justify;
+        self
+    }
+
+    pub fn push(mut self, child: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        self.children.push(child.into());
+        self
+    }
+
+    pub fn extend(
+        self,
+        children: impl IntoIterator<Item = Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        children.into_iter().fold(self, Self::push)
+    }
+}
+
+impl<Message, Theme, Renderer> Default for Flow<'_, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, Message, Theme, Renderer> FromIterator<Element<'a, Message, Theme, Renderer>>
+    for Flow<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    fn from_iter<T: IntoIterator<Item = Element<'a, Message, Theme, Renderer>>>(iter: T) -> Self {
+        Self::with_children(iter)
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Flow<'_, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    fn children(&self) -> Vec<Tree> {
+        self.children.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&self.children);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits
+            .width(self.width)
+            .height(self.height)
+            .shrink(self.padding);
+
+        let max_width = limits.max().width;
+        let row_spacing = self.row_spacing.unwrap_or(self.spacing);
+        let child_limits = layout::Limits::new(Size::ZERO, Size::new(max_width, f32::INFINITY));
+
+        let mut nodes: Vec<layout::Node> = self
+            .children
+            .iter_mut()
+            .zip(&mut tree.children)
+            .map(|(child, tree)| child.as_widget_mut().layout(tree, renderer, &child_limits))
+            .collect();
+
+        // Break the children into rows, as ranges of indices
+        let mut rows = Vec::new();
+        let mut start = 0;
+        let mut x = 0.0;
+
+        for (i, node) in nodes.iter().enumerate() {
+            let width = node.size().width;
+
+            if i > start && x + self.spacing + width > max_width {
+                rows.push(start..i);
+                start = i;
+                x = width;
+            } else {
+                x += if i > start { self.spacing } else { 0.0 } + width;
+            }
+        }
+
+        if start < nodes.len() {
+            rows.push(start..nodes.len());
+        }
+
+        let rows: Vec<_> = rows
+            .into_iter()
+            .map(|row| {
+                let children = &nodes[row.clone()];
+
+                let width = children.iter().map(|node| node.size().width).sum::<f32>()
+                    + self.spacing * row.len().saturating_sub(1) as f32;
+
+                let height = children
+                    .iter()
+                    .map(|node| node.size().height)
+                    .fold(0.0, f32::max);
+
+                (row, Size::new(width, height))
+            })
+            .collect();
+
+        let intrinsic = Size::new(
+            rows.iter().map(|(_, size)| size.width).fold(0.0, f32::max),
+            rows.iter().map(|(_, size)| size.height).sum::<f32>()
+                + row_spacing * rows.len().saturating_sub(1) as f32,
+        );
+
+        let size = limits.resolve(self.width, self.height, intrinsic);
+        let mut y = self.padding.top;
+
+        for (row, row_size) in rows {
+            let height = row_size.height;
+            let (offset, extra) = self
+                .justify
+                .distribute(size.width - row_size.width, row.len());
+
+            let mut x = self.padding.left + offset;
+
+            for node in &mut nodes[row] {
+                let child = node.size();
+
+                let child_y = match self.align_y {
+                    alignment::Vertical::Top => y,
+                    alignment::Vertical::Center => y + (height - child.height) / 2.0,
+                    alignment::Vertical::Bottom => y + height - child.height,
+                };
+
+                node.move_to_mut(Point::new(x, child_y));
+                x += child.width + self.spacing + extra;
+            }
+
+            y += height + row_spacing;
+        }
+
+        layout::Node::with_children(size.expand(self.padding), nodes)
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        operation.container(None, layout.bounds());
+        operation.traverse(&mut |operation| {
+            self.children
+                .iter_mut()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+                .for_each(|((child, state), layout)| {
+                    child
+                        .as_widget_mut()
+                        .operate(state, layout, renderer, operation);
+                });
+        });
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        for ((child, tree), layout) in self
+            .children
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+        {
+            child.as_widget_mut().update(
+                tree, event, layout, cursor, renderer, clipboard, shell, viewport,
+            );
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.children
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .map(|((child, tree), layout)| {
+                child
+                    .as_widget()
+                    .mouse_interaction(tree, layout, cursor, viewport, renderer)
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        for ((child, tree), layout) in self
+            .children
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .filter(|(_, layout)| layout.bounds().intersects(viewport))
+        {
+            child
+                .as_widget()
+                .draw(tree, renderer, theme, style, layout, cursor, viewport);
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        overlay::from_children(
+            &mut self.children,
+            tree,
+            layout,
+            renderer,
+            viewport,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Flow<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(flow: Flow<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(flow)
+    }
+}
@@ -0,0 +1,460 @@
+use crate::core::gradient::{self, Gradient};
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::widget::Widget;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::{
+    self, Background, Border, Clipboard, Color, Element, Event, Length, Pixels, Point, Radians,
+    Rectangle, Shell, Size, Theme,
+};
+
+/// The size of the handle of a color stop.
+const HANDLE_SIZE: f32 = 14.0;
+
+/// The space between the bar and the handles.
+const HANDLE_GAP: f32 = 4.0;
+
+/// How far a handle needs to be dragged away to be removed.
+const REMOVE_DISTANCE: f32 = 32.0;
+
+/// A color stop of a gradient: an offset from `0.0` to `1.0` and a [`Color`].
+pub type Stop = (f32, Color);
+
+/// A widget that edits the color stops of a gradient.
+///
+/// Stops can be dragged along the bar, added by double-clicking it and
+/// removed by dragging them away; at least two stops are always kept.
+/// Stops are never reordered, so their indices stay stable while dragging.
+///
+/// The bar can preview up to 8 stops, as many as a [`gradient::Linear`]
+/// holds.
+pub struct GradientEditor<'a, Message, Theme = core::Theme>
+where
+    Theme: Catalog,
+{
+    stops: Vec<Stop>,
+    on_change: Box<dyn Fn(Vec<Stop>) -> Message + 'a>,
+    on_select: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    selected: Option<usize>,
+    width: Length,
+    height: f32,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme> GradientEditor<'a, Message, Theme>
+where
+    Theme: Catalog,
+{
+    pub fn new(stops: &[Stop], on_change: impl Fn(Vec<Stop>) -> Message + 'a) -> Self {
+        Self {
+            stops: stops.to_vec(),
+            on_change: Box::new(on_change),
+            on_select: None,
+            selected: None,
+            width: Length::Fill,
+            height: 24.0,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the message produced when a stop is grabbed or added, given
+    /// its index; useful to edit its color elsewhere.
+    pub fn on_select(mut self, on_select: impl Fn(usize) -> Message + 'a) -> Self {
+        self.on_select = Some(Box::new(on_select));
+        self
+    }
+
+    /// Highlights the stop with the given index.
+    pub fn selected(mut self, selected: Option<usize>) -> Self {
+        self.selected = selected;
+        self
+    }
+
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the gradient bar, without the handles.
+    pub fn height(mut self, height: impl Into<Pixels>) -> Self {
+        self.height = height.into().0;
+        self
+    }
+
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    fn bar(&self, bounds: Rectangle) -> Rectangle {
+        Rectangle {
+            height: self.height,
+            ..bounds
+        }
+    }
+
+    fn handle(&self, bounds: Rectangle, offset: f32) -> Rectangle {
+        let x = bounds.x + offset * bounds.width - HANDLE_SIZE / 2.0;
+
+        Rectangle {
+            // Narrow bars pin the handles to their start
+            x: x.clamp(
+                bounds.x,
+                (bounds.x + bounds.width - HANDLE_SIZE).max(bounds.x),
+            ),
+            y: bounds.y + self.height + HANDLE_GAP,
+            width: HANDLE_SIZE,
+            height: HANDLE_SIZE,
+        }
+    }
+
+    /// Returns the stop whose handle is under the given position, preferring
+    /// the selected one.
+    fn stop_at(&self, bounds: Rectangle, position: Point) -> Option<usize> {
+        let hits = |index: &usize| {
+            self.handle(bounds, self.stops[*index].0)
+                .expand(2.0)
+                .contains(position)
+        };
+
+        self.selected
+            .filter(|selected| *selected < self.stops.len() && hits(selected))
+            .or_else(|| (0..self.stops.len()).rev().find(hits))
+    }
+
+    fn offset_at(&self, bounds: Rectangle, x: f32) -> f32 {
+        if bounds.width > 0.0 {
+            ((x - bounds.x) / bounds.width).clamp(0.0, 1.0)
+        } else {
+            0.0
+        }
+    }
+}
+
+/// Returns the color of the gradient at the given offset.
+fn sample(stops: &[Stop], offset: f32) -> Color {
+    let mut sorted = stops.to_vec();
+    sorted.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+    let Some(after) = sorted.iter().position(|(stop, _)| *stop >= offset) else {
+        return sorted.last().map_or(Color::BLACK, |(_, color)| *color);
+    };
+
+    let (end, to) = sorted[after];
+
+    let Some((start, from)) = after.checked_sub(1).map(|before| sorted[before]) else {
+        return to;
+    };
+
+    let t = if end > start {
+        (offset - start) / (end - start)
+    } else {
+        0.0
+    };
+
+    Color {
+        r: from.r + (to.r - from.r) * t,
+        g: from.g + (to.g - from.g) * t,
+        b: from.b + (to.b - from.b) * t,
+        a: from.a + (to.a - from.a) * t,
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct State {
+    dragging: Option<usize>,
+    is_removing: bool,
+    last_click: Option<mouse::Click>,
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for GradientEditor<'_, Message, Theme>
+where
+    Theme: Catalog,
+    Renderer: core::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        layout::atomic(limits, self.width, self.height + HANDLE_GAP + HANDLE_SIZE)
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                let Some(position) = cursor.position_over(bounds) else {
+                    return;
+                };
+
+                if let Some(index) = self.stop_at(bounds, position) {
+                    state.dragging = Some(index);
+
+                    if let Some(on_select) = &self.on_select {
+                        shell.publish(on_select(index));
+                    }
+                } else if self.bar(bounds).contains(position) {
+                    let click = mouse::Click::new(position, mouse::Button::Left, state.last_click);
+
+                    if click.kind() == mouse::click::Kind::Double {
+                        let offset = self.offset_at(bounds, position.x);
+
+                        self.stops.push((offset, sample(&self.stops, offset)));
+                        shell.publish((self.on_change)(self.stops.clone()));
+
+                        if let Some(on_select) = &self.on_select {
+                            shell.publish(on_select(self.stops.len() - 1));
+                        }
+                    }
+
+                    state.last_click = Some(click);
+                }
+
+                shell.capture_event();
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position }) => {
+                let Some(index) = state.dragging.filter(|index| *index < self.stops.len()) else {
+                    return;
+                };
+
+                let distance = if position.y < bounds.y {
+                    bounds.y - position.y
+                } else {
+                    position.y - (bounds.y + bounds.height)
+                };
+
+                let is_removing = self.stops.len() > 2 && distance > REMOVE_DISTANCE;
+
+                if is_removing != state.is_removing {
+                    state.is_removing = is_removing;
+                    shell.request_redraw();
+                }
+
+                if !is_removing {
+                    let offset = self.offset_at(bounds, position.x);
+
+                    if offset != self.stops[index].0 {
+                        self.stops[index].0 = offset;
+                        shell.publish((self.on_change)(self.stops.clone()));
+                    }
+                }
+
+                shell.capture_event();
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                let Some(index) = state.dragging.take() else {
+                    return;
+                };
+
+                if std::mem::take(&mut state.is_removing) && index < self.stops.len() {
+                    let _ = self.stops.remove(index);
+                    shell.publish((self.on_change)(self.stops.clone()));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+
+        if state.dragging.is_some() {
+            mouse::Interaction::Grabbing
+        } else if let Some(position) = cursor.position_over(bounds) {
+            if self.stop_at(bounds, position).is_some() {
+                mouse::Interaction::Grab
+            } else if self.bar(bounds).contains(position) {
+                mouse::Interaction::Crosshair
+            } else {
+                mouse::Interaction::None
+            }
+        } else {
+            mouse::Interaction::None
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _defaults: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let style = theme.style(&self.class);
+
+        let removed = state.dragging.filter(|_| state.is_removing);
+
+        let stops = self
+            .stops
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| Some(*index) != removed)
+            .map(|(_, stop)| *stop);
+
+        let gradient = gradient::Linear::new(Radians::PI / 2.0)
+            .add_stops(stops.map(|(offset, color)| gradient::ColorStop { offset, color }));
+
+        let bar = self.bar(bounds);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: bar,
+                border: style.border,
+                ..renderer::Quad::default()
+            },
+            style.background,
+        );
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: bar,
+                border: style.border,
+                ..renderer::Quad::default()
+            },
+            Gradient::Linear(gradient),
+        );
+
+        for (index, (offset, color)) in self.stops.iter().enumerate() {
+            if Some(index) == removed {
+                continue;
+            }
+
+            let border = if Some(index) == self.selected || Some(index) == state.dragging {
+                style.selected_border
+            } else {
+                style.handle_border
+            };
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: self.handle(bounds, *offset),
+                    border,
+                    ..renderer::Quad::default()
+                },
+                *color,
+            );
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<GradientEditor<'a, Message, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: core::Renderer + 'a,
+{
+    fn from(gradient_editor: GradientEditor<'a, Message, Theme>) -> Self {
+        Element::new(gradient_editor)
+    }
+}
+
+/// The appearance of a [`GradientEditor`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Background`] behind the gradient, visible through translucent
+    /// stops.
+    pub background: Background,
+    /// The [`Border`] of the gradient bar.
+    pub border: Border,
+    /// The [`Border`] of the handles.
+    pub handle_border: Border,
+    /// The [`Border`] of the selected handle.
+    pub selected_border: Border,
+}
+
+/// The theme catalog of a [`GradientEditor`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`GradientEditor`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`GradientEditor`].
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        background: palette.background.weak.color.into(),
+        border: Border::default()
+            .rounded(4)
+            .width(1)
+            .color(palette.background.strong.color),
+        handle_border: Border::default()
+            .rounded(3)
+            .width(2)
+            .color(palette.background.strongest.color),
+        selected_border: Border::default()
+            .rounded(3)
+            .width(2)
+            .color(palette.primary.strong.color),
+    }
+}
@@ -0,0 +1,739 @@
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::widget::{Operation, Tree, Widget};
+use crate::core::{
+    Clipboard, Element, Event, Length, Padding, Pixels, Point, Rectangle, Shell, Size, Vector,
+};
+
+/// A container that lays out its children in the cells of a grid made of
+/// column and row [`Track`]s.
+///
+/// Children can be placed automatically, at a specific [`Placement::cell`]
+/// spanning multiple rows and columns, or inside a named [`Placement::area`]
+/// of the [`Template`]. Different templates can be used depending on the
+/// available width with [`Grid::breakpoint`].
+pub struct Grid<'a, Message, Theme, Renderer> {
+    children: Vec<Element<'a, Message, Theme, Renderer>>,
+    placements: Vec<Placement<'a>>,
+    template: Template<'a>,
+    breakpoints: Vec<(f32, Template<'a>)>,
+    column_spacing: f32,
+    row_spacing: f32,
+    padding: Padding,
+    width: Length,
+    height: Length,
+}
+
+/// The size of a column or a row of a [`Grid`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Track {
+    /// A fixed amount of logical pixels.
+    Fixed(f32),
+    /// A fraction of the remaining space, weighted against the other
+    /// fractional tracks.
+    ///
+    /// It behaves like [`Track::Auto`] when the space is unbounded.
+    Fraction(f32),
+    /// The size of the largest child inside the track.
+    Auto,
+}
+
+impl From<Length> for Track {
+    fn from(length: Length) -> Self {
+        match length {
+            Length::Fill => Track::Fraction(1.0),
+            Length::FillPortion(portion) => Track::Fraction(f32::from(portion)),
+            Length::Shrink => Track::Auto,
+            Length::Fixed(size) => Track::Fixed(size),
+        }
+    }
+}
+
+impl From<f32> for Track {
+    fn from(size: f32) -> Self {
+        Track::Fixed(size)
+    }
+}
+
+impl From<u32> for Track {
+    fn from(size: u32) -> Self {
+        Track::Fixed(size as f32)
+    }
+}
+
+/// The tracks and named areas of a [`Grid`].
+#[derive(Debug, Clone, Default)]
+pub struct Template<'a> {
+    columns: Vec<Track>,
+    rows: Vec<Track>,
+    areas: Vec<Vec<&'a str>>,
+}
+
+impl<'a> Template<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the column tracks of the [`Template`].
+    ///
+    /// Columns without a track take a fraction of the remaining space.
+    pub fn columns(mut self, columns: impl IntoIterator<Item = impl Into<Track>>) -> Self {
+        self.columns = columns.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets the row tracks of the [`Template`].
+    ///
+    /// Rows without a track fit their content.
+    pub fn rows(mut self, rows: impl IntoIterator<Item = impl Into<Track>>) -> Self {
+        self.rows = rows.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets the named areas of the [`Template`].
+    ///
+    /// Every string is a row of whitespace-separated area names, where `.`
+    /// marks an empty cell. An area spans the rectangle containing all
+    /// the cells with its name.
+    pub fn areas(mut self, rows: impl IntoIterator<Item = &'a str>) -> Self {
+        self.areas = rows
+            .into_iter()
+            .map(|row| row.split_whitespace().collect())
+            .collect();
+        self
+    }
+
+    fn column_count(&self) -> usize {
+        self.areas
+            .iter()
+            .map(Vec::len)
+            .fold(self.columns.len(), usize::max)
+            .max(1)
+    }
+
+    fn area(&self, name: &str) -> Option<Cell> {
+        let mut cells = self.areas.iter().enumerate().flat_map(|(row, names)| {
+            names
+                .iter()
+                .enumerate()
+                .filter(|(_, area)| **area == name)
+                .map(move |(column, _)| (row, column))
+        });
+
+        let (row, column) = cells.next()?;
+
+        let (start, end) = cells.fold(
+            ((row, column), (row, column)),
+            |((top, left), (bottom, right)), (row, column)| {
+                (
+                    (top.min(row), left.min(column)),
+                    (bottom.max(row), right.max(column)),
+                )
+            },
+        );
+
+        Some(Cell {
+            row: start.0,
+            column: start.1,
+            rows: end.0 - start.0 + 1,
+            columns: end.1 - start.1 + 1,
+        })
+    }
+}
+
+/// The position of a child inside of a [`Grid`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Placement<'a> {
+    position: Position<'a>,
+    rows: usize,
+    columns: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Position<'a> {
+    Auto,
+    Cell { row: usize, column: usize },
+    Area(&'a str),
+}
+
+impl<'a> Placement<'a> {
+    /// Places the child in the first free cell, in row-major order.
+    pub fn auto() -> Self {
+        Self {
+            position: Position::Auto,
+            rows: 1,
+            columns: 1,
+        }
+    }
+
+    /// Places the child at the given zero-based row and column.
+    pub fn cell(row: usize, column: usize) -> Self {
+        Self {
+            position: Position::Cell { row, column },
+            ..Self::auto()
+        }
+    }
+
+    /// Places the child in the named area of the active [`Template`].
+    ///
+    /// The child is placed automatically if the area does not exist.
+    pub fn area(name: &'a str) -> Self {
+        Self {
+            position: Position::Area(name),
+            ..Self::auto()
+        }
+    }
+
+    /// Sets the amount of rows and columns spanned by the child.
+    pub fn span(self, rows: usize, columns: usize) -> Self {
+        Self {
+            rows: rows.max(1),
+            columns: columns.max(1),
+            ..self
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Cell {
+    row: usize,
+    column: usize,
+    rows: usize,
+    columns: usize,
+}
+
+impl<'a, Message, Theme, Renderer> Grid<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    pub fn new() -> Self {
+        Self {
+            children: Vec::new(),
+            placements: Vec::new(),
+            template: Template::default(),
+            breakpoints: Vec::new(),
+            column_spacing: 0.0,
+            row_spacing: 0.0,
+            padding: Padding::ZERO,
+            width: Length::Fill,
+            height: Length::Shrink,
+        }
+    }
+
+    pub fn with_children(
+        children: impl IntoIterator<Item = Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self::new().extend(children)
+    }
+
+    /// Sets the column tracks of the base [`Template`].
+    pub fn columns(mut self, columns: impl IntoIterator<Item = impl Into<Track>>) -> Self {
+        self.template = self.template.columns(columns);
+        self
+    }
+
+    /// Sets the row tracks of the base [`Template`].
+    pub fn rows(mut self, rows: impl IntoIterator<Item = impl Into<Track>>) -> Self {
+        self.template = self.template.rows(rows);
+        self
+    }
+
+    /// Sets the named areas of the base [`Template`].
+    pub fn areas(mut self, rows: impl IntoIterator<Item = &'a str>) -> Self {
+        self.template = self.template.areas(rows);
+        self
+    }
+
+    /// Uses the given [`Template`] when the available width is at least
+    /// `min_width`.
+    ///
+    /// The base [`Template`] is used when no breakpoint applies.
+    pub fn breakpoint(mut self, min_width: impl Into<Pixels>, template: Template<'a>) -> Self {
+        self.breakpoints.push((min_width.into().0, template));
+        self.breakpoints.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+        self
+    }
+
+    /// Sets the spacing between both columns and rows.
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        let spacing = spacing.into().0;
+
+        self.column_spacing = spacing;
+        self.row_spacing = spacing;
+        self
+    }
+
+    pub fn column_spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.column_spacing = spacing.into().0;
+        self
+    }
+
+    pub fn row_spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.row_spacing = spacing.into().0;
+        self
+    }
+
+    pub fn padding(mut self, padding: impl Into<Padding>) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Adds a child to the [`Grid`] in the first free cell.
+    pub fn push(self, child: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        self.place(Placement::auto(), child)
+    }
+
+    /// Adds a child to the [`Grid`] with the given [`Placement`].
+    pub fn place(
+        mut self,
+        placement: Placement<'a>,
+        child: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        self.children.push(child.into());
+        self.placements.push(placement);
+        self
+    }
+
+    pub fn extend(
+        self,
+        children: impl IntoIterator<Item = Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        children.into_iter().fold(self, Self::push)
+    }
+
+    fn template(&self, width: f32) -> &Template<'a> {
+        self.breakpoints
+            .iter()
+            .rev()
+            .find(|(min_width, _)| width >= *min_width)
+            .map_or(&self.template, |(_, template)| template)
+    }
+}
+
+impl<Message, Theme, Renderer> Default for Grid<'_, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, Message, Theme, Renderer> FromIterator<Element<'a, Message, Theme, Renderer>>
+    for Grid<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    fn from_iter<T: IntoIterator<Item = Element<'a, Message, Theme, Renderer>>>(iter: T) -> Self {
+        Self::with_children(iter)
+    }
+}
+
+/// Resolves the cell of every placement, filling the free cells with the
+/// automatically placed ones.
+fn arrange(template: &Template<'_>, placements: &[Placement<'_>]) -> (Vec<Cell>, usize) {
+    let columns = template.column_count();
+    let mut occupied: Vec<Vec<bool>> = Vec::new();
+
+    let is_free = |occupied: &[Vec<bool>], cell: Cell| {
+        (cell.row..cell.row + cell.rows).all(|row| {
+            occupied.get(row).is_none_or(|row| {
+                row[cell.column..cell.column + cell.columns]
+                    .iter()
+                    .all(|taken| !taken)
+            })
+        })
+    };
+
+    let occupy = |occupied: &mut Vec<Vec<bool>>, cell: Cell| {
+        if occupied.len() < cell.row + cell.rows {
+            occupied.resize(cell.row + cell.rows, vec![false; columns]);
+        }
+
+        for row in &mut occupied[cell.row..cell.row + cell.rows] {
+            row[cell.column..cell.column + cell.columns].fill(true);
+        }
+    };
+
+    let mut cells: Vec<Option<Cell>> = placements
+        .iter()
+        .map(|placement| match placement.position {
+            Position::Auto => None,
+            Position::Cell { row, column } => {
+                let column = column.min(columns - 1);
+
+                Some(Cell {
+                    row,
+                    column,
+                    rows: placement.rows,
+                    columns: placement.columns.min(columns - column),
+                })
+            }
+            Position::Area(name) => template.area(name),
+        })
+        .collect();
+
+    for cell in cells.iter().flatten() {
+        occupy(&mut occupied, *cell);
+    }
+
+    for (cell, placement) in cells.iter_mut().zip(placements) {
+        if cell.is_some() {
+            continue;
+        }
+
+        let span = placement.columns.min(columns);
+
+        let free = (0..)
+            .flat_map(|row| (0..=columns - span).map(move |column| (row, column)))
+            .map(|(row, column)| Cell {
+                row,
+                column,
+                rows: placement.rows,
+                columns: span,
+            })
+            .find(|cell| is_free(&occupied, *cell))
+            .expect("Find free cell");
+
+        occupy(&mut occupied, free);
+        *cell = Some(free);
+    }
+
+    let rows = template
+        .rows
+        .len()
+        .max(template.areas.len())
+        .max(occupied.len());
+
+    (cells.into_iter().flatten().collect(), rows)
+}
+
+/// Resolves the sizes of some tracks, given the available space and the
+/// size of the content of every track.
+fn resolve(
+    tracks: &[Track],
+    default: Track,
+    count: usize,
+    available: f32,
+    spacing: f32,
+    content: &[f32],
+) -> Vec<f32> {
+    let track = |i: usize| tracks.get(i).copied().unwrap_or(default);
+    let is_bounded = available.is_finite();
+
+    let mut sizes: Vec<f32> = (0..count)
+        .map(|i| match track(i) {
+            Track::Fixed(size) => size,
+            Track::Fraction(_) if is_bounded => 0.0,
+            Track::Fraction(_) | Track::Auto => content[i],
+        })
+        .collect();
+
+    if is_bounded {
+        let fractions: f32 = (0..count)
+            .filter_map(|i| match track(i) {
+                Track::Fraction(fraction) => Some(fraction),
+                _ => None,
+            })
+            .sum();
+
+        if fractions > 0.0 {
+            let free =
+                (available - sizes.iter().sum::<f32>() - spacing * count.saturating_sub(1) as f32)
+                    .max(0.0);
+
+            for (i, size) in sizes.iter_mut().enumerate() {
+                if let Track::Fraction(fraction) = track(i) {
+                    *size = free * fraction / fractions;
+                }
+            }
+        }
+    }
+
+    sizes
+}
+
+/// Returns the total size of a span of tracks, including the spacing
+/// between them.
+fn span(sizes: &[f32], start: usize, count: usize, spacing: f32) -> f32 {
+    sizes[start..start + count].iter().sum::<f32>() + spacing * (count - 1) as f32
+}
+
+/// Returns the offset of every track.
+fn offsets(sizes: &[f32], start: f32, spacing: f32) -> Vec<f32> {
+    sizes
+        .iter()
+        .scan(start, |offset, size| {
+            let current = *offset;
+            *offset += size + spacing;
+
+            Some(current)
+        })
+        .collect()
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Grid<'_, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    fn children(&self) -> Vec<Tree> {
+        self.children.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&self.children);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits
+            .width(self.width)
+            .height(self.height)
+            .shrink(self.padding);
+
+        let max = limits.max();
+
+        let available = Size::new(
+            if self.width == Length::Shrink {
+                f32::INFINITY
+            } else {
+                max.width
+            },
+            if self.height == Length::Shrink {
+                f32::INFINITY
+            } else {
+                max.height
+            },
+        );
+
+        let template = self.template(max.width).clone();
+        let (cells, row_count) = arrange(&template, &self.placements);
+        let column_count = template.column_count();
+
+        // Measure the content of the columns with single-column children
+        let mut content = vec![0.0f32; column_count];
+
+        for ((child, tree), cell) in self.children.iter_mut().zip(&mut tree.children).zip(&cells) {
+            if cell.columns > 1 || child.as_widget().size().width.is_fill() {
+                continue;
+            }
+
+            let node = child.as_widget_mut().layout(
+                tree,
+                renderer,
+                &layout::Limits::new(Size::ZERO, Size::new(max.width, max.height)),
+            );
+
+            content[cell.column] = content[cell.column].max(node.size().width);
+        }
+
+        let columns = resolve(
+            &template.columns,
+            Track::Fraction(1.0),
+            column_count,
+            available.width,
+            self.column_spacing,
+            &content,
+        );
+
+        // Measure the content of the rows with single-row children
+        let mut content = vec![0.0f32; row_count];
+
+        for ((child, tree), cell) in self.children.iter_mut().zip(&mut tree.children).zip(&cells) {
+            if cell.rows > 1 || child.as_widget().size().height.is_fill() {
+                continue;
+            }
+
+            let width = span(&columns, cell.column, cell.columns, self.column_spacing);
+
+            let node = child.as_widget_mut().layout(
+                tree,
+                renderer,
+                &layout::Limits::new(Size::ZERO, Size::new(width, max.height)),
+            );
+
+            content[cell.row] = content[cell.row].max(node.size().height);
+        }
+
+        let rows = resolve(
+            &template.rows,
+            Track::Auto,
+            row_count,
+            available.height,
+            self.row_spacing,
+            &content,
+        );
+
+        let xs = offsets(&columns, self.padding.left, self.column_spacing);
+        let ys = offsets(&rows, self.padding.top, self.row_spacing);
+
+        let nodes = self
+            .children
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(&cells)
+            .map(|((child, tree), cell)| {
+                let size = Size::new(
+                    span(&columns, cell.column, cell.columns, self.column_spacing),
+                    span(&rows, cell.row, cell.rows, self.row_spacing),
+                );
+
+                child
+                    .as_widget_mut()
+                    .layout(tree, renderer, &layout::Limits::new(Size::ZERO, size))
+                    .move_to(Point::new(xs[cell.column], ys[cell.row]))
+            })
+            .collect();
+
+        let intrinsic = Size::new(
+            columns.iter().sum::<f32>() + self.column_spacing * (column_count - 1) as f32,
+            rows.iter().sum::<f32>() + self.row_spacing * row_count.saturating_sub(1) as f32,
+        );
+
+        let size = limits.resolve(self.width, self.height, intrinsic);
+
+        layout::Node::with_children(size.expand(self.padding), nodes)
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        operation.container(None, layout.bounds());
+        operation.traverse(&mut |operation| {
+            self.children
+                .iter_mut()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+                .for_each(|((child, state), layout)| {
+                    child
+                        .as_widget_mut()
+                        .operate(state, layout, renderer, operation);
+                });
+        });
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        for ((child, tree), layout) in self
+            .children
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+        {
+            child.as_widget_mut().update(
+                tree, event, layout, cursor, renderer, clipboard, shell, viewport,
+            );
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.children
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .map(|((child, tree), layout)| {
+                child
+                    .as_widget()
+                    .mouse_interaction(tree, layout, cursor, viewport, renderer)
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        for ((child, tree), layout) in self
+            .children
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .filter(|(_, layout)| layout.bounds().intersects(viewport))
+        {
+            child
+                .as_widget()
+                .draw(tree, renderer, theme, style, layout, cursor, viewport);
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        overlay::from_children(
+            &mut self.children,
+            tree,
+            layout,
+            renderer,
+            viewport,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Grid<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(grid: Grid<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(grid)
+    }
+}
@@ -0,0 +1,610 @@
+use crate::core::alignment;
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::text::{self, paragraph};
+use crate::core::widget::Widget;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::{
+    Background, Border, Clipboard, Color, Element, Event, Length, Padding, Pixels, Point,
+    Rectangle, Shell, Size, Theme, Vector,
+};
+
+use std::ops::RangeInclusive;
+
+/// A widget that shows a matrix of values as a grid of colored cells.
+///
+/// Values are mapped to the color scale of the [`Style`]. Hovering a
+/// cell shows its value in a tooltip. Non-finite values are left empty.
+pub struct Heatmap<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    rows: usize,
+    columns: usize,
+    values: &'a [f32],
+    range: RangeInclusive<f32>,
+    row_labels: Vec<String>,
+    column_labels: Vec<String>,
+    tooltip: Box<dyn Fn(usize, usize, f32) -> String + 'a>,
+    on_cell_click: Option<Box<dyn Fn(usize, usize) -> Message + 'a>>,
+    width: Length,
+    height: Length,
+    spacing: f32,
+    text_size: Option<Pixels>,
+    font: Option<Renderer::Font>,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> Heatmap<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// Creates a new [`Heatmap`] with the given values in row-major order.
+    ///
+    /// # Panics
+    /// Panics if the amount of values is not `rows * columns`.
+    pub fn new(rows: usize, columns: usize, values: &'a [f32]) -> Self {
+        assert_eq!(
+            values.len(),
+            rows * columns,
+            "a heatmap of {rows}x{columns} needs {} values",
+            rows * columns
+        );
+
+        let (min, max) = values
+            .iter()
+            .filter(|value| value.is_finite())
+            .fold((f32::INFINITY, f32::NEG_INFINITY), |(min, max), value| {
+                (min.min(*value), max.max(*value))
+            });
+
+        Self {
+            rows,
+            columns,
+            values,
+            range: if min <= max { min..=max } else { 0.0..=1.0 },
+            row_labels: Vec::new(),
+            column_labels: Vec::new(),
+            tooltip: Box::new(|_, _, value| format!("{value:.2}")),
+            on_cell_click: None,
+            width: Length::Fill,
+            height: Length::Fill,
+            spacing: 1.0,
+            text_size: None,
+            font: None,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the range of values mapped to the color scale.
+    ///
+    /// By default, it spans from the smallest to the largest value.
+    pub fn range(mut self, range: RangeInclusive<f32>) -> Self {
+        self.range = range;
+        self
+    }
+
+    pub fn row_labels(mut self, labels: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.row_labels = labels.into_iter().map(Into::into).collect();
+        self
+    }
+
+    pub fn column_labels(mut self, labels: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.column_labels = labels.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets the contents of the tooltip of a cell, given its row, column
+    /// and value.
+    pub fn tooltip(mut self, tooltip: impl Fn(usize, usize, f32) -> String + 'a) -> Self {
+        self.tooltip = Box::new(tooltip);
+        self
+    }
+
+    /// Sets the message produced when a cell is clicked, given its row
+    /// and column.
+    pub fn on_cell_click(mut self, on_cell_click: impl Fn(usize, usize) -> Message + 'a) -> Self {
+        self.on_cell_click = Some(Box::new(on_cell_click));
+        self
+    }
+
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the spacing between cells.
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.spacing = spacing.into().0;
+        self
+    }
+
+    pub fn text_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(size.into());
+        self
+    }
+
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    fn text<'b>(
+        &self,
+        content: &'b str,
+        renderer: &Renderer,
+    ) -> text::Text<&'b str, Renderer::Font> {
+        text::Text {
+            content,
+            bounds: Size::INFINITE,
+            size: self.text_size.unwrap_or_else(|| renderer.default_size()),
+            line_height: text::LineHeight::default(),
+            font: self.font.unwrap_or_else(|| renderer.default_font()),
+            align_x: text::Alignment::Default,
+            align_y: alignment::Vertical::Top,
+            shaping: text::Shaping::Advanced,
+            wrapping: text::Wrapping::None,
+            hint_factor: renderer.scale_factor(),
+        }
+    }
+
+    /// Returns the space between the labels and the grid.
+    fn gap(&self, renderer: &Renderer) -> f32 {
+        self.text_size.unwrap_or_else(|| renderer.default_size()).0 / 2.0
+    }
+
+    /// Returns the bounds of the grid of cells, without labels.
+    fn grid(&self, state: &State<Renderer::Paragraph>, bounds: Rectangle) -> Rectangle {
+        Rectangle {
+            x: bounds.x + state.gutter.width,
+            y: bounds.y + state.gutter.height,
+            width: (bounds.width - state.gutter.width).max(0.0),
+            height: (bounds.height - state.gutter.height).max(0.0),
+        }
+    }
+
+    fn cell_at(&self, grid: Rectangle, position: Point) -> Option<(usize, usize)> {
+        if !grid.contains(position) || self.rows == 0 || self.columns == 0 {
+            return None;
+        }
+
+        let row = ((position.y - grid.y) / grid.height * self.rows as f32) as usize;
+        let column = ((position.x - grid.x) / grid.width * self.columns as f32) as usize;
+
+        Some((row.min(self.rows - 1), column.min(self.columns - 1)))
+    }
+}
+
+struct State<P: text::Paragraph> {
+    row_labels: Vec<paragraph::Plain<P>>,
+    column_labels: Vec<paragraph::Plain<P>>,
+    gutter: Size,
+    hovered: Option<(usize, usize)>,
+    tooltip: paragraph::Plain<P>,
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Heatmap<'_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<Renderer::Paragraph>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::<Renderer::Paragraph> {
+            row_labels: Vec::new(),
+            column_labels: Vec::new(),
+            gutter: Size::ZERO,
+            hovered: None,
+            tooltip: paragraph::Plain::default(),
+        })
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        let measure = |labels: &[String], paragraphs: &mut Vec<paragraph::Plain<_>>| {
+            paragraphs.resize_with(labels.len(), paragraph::Plain::default);
+
+            labels
+                .iter()
+                .zip(paragraphs)
+                .fold(Size::ZERO, |size, (label, paragraph)| {
+                    let _ = paragraph.update(self.text(label, renderer));
+
+                    let bounds = paragraph.min_bounds();
+
+                    Size::new(size.width.max(bounds.width), size.height.max(bounds.height))
+                })
+        };
+
+        let rows = measure(&self.row_labels, &mut state.row_labels);
+        let columns = measure(&self.column_labels, &mut state.column_labels);
+        let gap = self.gap(renderer);
+
+        state.gutter = Size::new(
+            if rows.width > 0.0 {
+                rows.width + gap
+            } else {
+                0.0
+            },
+            if columns.height > 0.0 {
+                columns.height + gap
+            } else {
+                0.0
+            },
+        );
+
+        let intrinsic = Size::new(
+            state.gutter.width + self.columns as f32 * 16.0,
+            state.gutter.height + self.rows as f32 * 16.0,
+        );
+
+        layout::Node::new(limits.resolve(self.width, self.height, intrinsic))
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+        let grid = self.grid(state, layout.bounds());
+
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { .. } | mouse::Event::CursorLeft) => {
+                let hovered = cursor
+                    .position()
+                    .and_then(|position| self.cell_at(grid, position))
+                    .filter(|(row, column)| self.values[row * self.columns + column].is_finite());
+
+                if hovered != state.hovered {
+                    state.hovered = hovered;
+
+                    if let Some((row, column)) = hovered {
+                        let tooltip =
+                            (self.tooltip)(row, column, self.values[row * self.columns + column]);
+
+                        let _ = state.tooltip.update(self.text(&tooltip, renderer));
+                    }
+
+                    shell.request_redraw();
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                let Some(on_cell_click) = &self.on_cell_click else {
+                    return;
+                };
+
+                if let Some((row, column)) = cursor
+                    .position()
+                    .and_then(|position| self.cell_at(grid, position))
+                {
+                    shell.publish(on_cell_click(row, column));
+                    shell.capture_event();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+        let grid = self.grid(state, layout.bounds());
+
+        if self.on_cell_click.is_some() && cursor.is_over(grid) {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::None
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _defaults: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+
+        if !bounds.intersects(viewport) {
+            return;
+        }
+
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+        let style = theme.style(&self.class);
+        let grid = self.grid(state, bounds);
+
+        if self.rows == 0 || self.columns == 0 {
+            return;
+        }
+
+        let cell = Size::new(
+            grid.width / self.columns as f32,
+            grid.height / self.rows as f32,
+        );
+
+        let (min, max) = (*self.range.start(), *self.range.end());
+
+        for (i, value) in self.values.iter().enumerate() {
+            if !value.is_finite() {
+                continue;
+            }
+
+            let (row, column) = (i / self.columns, i % self.columns);
+
+            let t = if max > min {
+                ((value - min) / (max - min)).clamp(0.0, 1.0)
+            } else {
+                0.5
+            };
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: grid.x + column as f32 * cell.width,
+                        y: grid.y + row as f32 * cell.height,
+                        width: (cell.width - self.spacing).max(1.0),
+                        height: (cell.height - self.spacing).max(1.0),
+                    },
+                    border: style.cell_border,
+                    ..renderer::Quad::default()
+                },
+                style.color(t),
+            );
+        }
+
+        let gap = self.gap(renderer);
+
+        for (row, label) in state.row_labels.iter().enumerate().take(self.rows) {
+            let size = label.min_bounds();
+
+            renderer.fill_paragraph(
+                label.raw(),
+                Point::new(
+                    grid.x - gap - size.width,
+                    grid.y + (row as f32 + 0.5) * cell.height - size.height / 2.0,
+                ),
+                style.label,
+                bounds,
+            );
+        }
+
+        for (column, label) in state.column_labels.iter().enumerate().take(self.columns) {
+            let size = label.min_bounds();
+
+            renderer.fill_paragraph(
+                label.raw(),
+                Point::new(
+                    grid.x + (column as f32 + 0.5) * cell.width - size.width / 2.0,
+                    bounds.y,
+                ),
+                style.label,
+                bounds,
+            );
+        }
+
+        let Some((row, column)) = state.hovered else {
+            return;
+        };
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    x: grid.x + column as f32 * cell.width,
+                    y: grid.y + row as f32 * cell.height,
+                    width: (cell.width - self.spacing).max(1.0),
+                    height: (cell.height - self.spacing).max(1.0),
+                },
+                border: style.hovered_border,
+                ..renderer::Quad::default()
+            },
+            Color::TRANSPARENT,
+        );
+
+        let Some(position) = cursor.position() else {
+            return;
+        };
+
+        let padding = Padding::from([4, 8]);
+        let size = state.tooltip.min_bounds().expand(padding);
+        let offset = Vector::new(12.0, 12.0);
+
+        // Flip the tooltip when it would overflow the viewport
+        let x = if position.x + offset.x + size.width > viewport.x + viewport.width {
+            position.x - offset.x - size.width
+        } else {
+            position.x + offset.x
+        };
+
+        let y = if position.y + offset.y + size.height > viewport.y + viewport.height {
+            position.y - offset.y - size.height
+        } else {
+            position.y + offset.y
+        };
+
+        let tooltip = Rectangle::new(Point::new(x, y), size);
+
+        renderer.with_layer(*viewport, |renderer| {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: tooltip,
+                    border: Border::default().rounded(4),
+                    ..renderer::Quad::default()
+                },
+                style.tooltip_background,
+            );
+
+            renderer.fill_paragraph(
+                state.tooltip.raw(),
+                Point::new(tooltip.x + padding.left, tooltip.y + padding.top),
+                style.tooltip_text,
+                *viewport,
+            );
+        });
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Heatmap<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(heatmap: Heatmap<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(heatmap)
+    }
+}
+
+/// The appearance of a [`Heatmap`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Color`] of the smallest values.
+    pub low: Color,
+    /// The [`Color`] of the values in the middle of the range, if any.
+    ///
+    /// Setting it produces a diverging color scale.
+    pub middle: Option<Color>,
+    /// The [`Color`] of the largest values.
+    pub high: Color,
+    /// The [`Border`] of the cells.
+    pub cell_border: Border,
+    /// The [`Border`] of the hovered cell.
+    pub hovered_border: Border,
+    /// The [`Color`] of the row and column labels.
+    pub label: Color,
+    /// The [`Background`] of the tooltip.
+    pub tooltip_background: Background,
+    /// The [`Color`] of the tooltip text.
+    pub tooltip_text: Color,
+}
+
+impl Style {
+    /// Returns the [`Color`] of the scale at the given point, from `0.0`
+    /// to `1.0`.
+    pub fn color(&self, t: f32) -> Color {
+        match self.middle {
+            Some(middle) if t < 0.5 => mix(self.low, middle, t * 2.0),
+            Some(middle) => mix(middle, self.high, t * 2.0 - 1.0),
+            None => mix(self.low, self.high, t),
+        }
+    }
+}
+
+fn mix(a: Color, b: Color, t: f32) -> Color {
+    Color {
+        r: a.r + (b.r - a.r) * t,
+        g: a.g + (b.g - a.g) * t,
+        b: a.b + (b.b - a.b) * t,
+        a: a.a + (b.a - a.a) * t,
+    }
+}
+
+/// The theme catalog of a [`Heatmap`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`Heatmap`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`Heatmap`].
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        low: palette.background.weak.color,
+        middle: None,
+        high: palette.primary.strong.color,
+        cell_border: Border::default().rounded(2),
+        hovered_border: Border::default()
+            .rounded(2)
+            .width(2)
+            .color(palette.background.base.text),
+        label: palette.background.strong.text,
+        tooltip_background: palette.background.strongest.color.into(),
+        tooltip_text: palette.background.strongest.text,
+    }
+}
+
+/// A diverging [`Style`], going from the danger to the success colors of
+/// the [`Theme`].
+pub fn diverging(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        low: palette.danger.base.color,
+        middle: Some(palette.background.weak.color),
+        high: palette.success.base.color,
+        ..default(theme)
+    }
+}
@@ -0,0 +1,542 @@
+use crate::core::keyboard::{self, Key, Modifiers, key};
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::time::{Duration, Instant, milliseconds};
+use crate::core::widget::operation::Focusable;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::{Id, Operation, Widget};
+use crate::core::window;
+use crate::core::{Clipboard, Element, Event, Length, Rectangle, Shell, Size, Vector};
+
+use std::fmt;
+use std::str::FromStr;
+
+/// A widget that publishes messages when the keyboard shortcuts of a
+/// binding table are pressed.
+///
+/// Shortcuts without Ctrl, Alt or Logo modifiers are ignored while a
+/// focusable widget—like a text input—is focused, so typing does not
+/// trigger them.
+pub struct Hotkeys<'a, Message, Theme, Renderer> {
+    bindings: Vec<(Shortcut, Message)>,
+    content: Element<'a, Message, Theme, Renderer>,
+    timeout: Duration,
+}
+
+impl<'a, Message, Theme, Renderer> Hotkeys<'a, Message, Theme, Renderer> {
+    /// Creates a new [`Hotkeys`] widget with the given bindings.
+    ///
+    /// Bindings with an invalid shortcut are ignored. Use [`try_new`] to
+    /// handle them instead; like when shortcuts come from user settings.
+    /// See [`Shortcut`] for the supported syntax.
+    ///
+    /// [`try_new`]: Self::try_new
+    pub fn new(
+        bindings: impl IntoIterator<Item = (&'a str, Message)>,
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self {
+            bindings: bindings
+                .into_iter()
+                .filter_map(|(shortcut, message)| Some((shortcut.parse().ok()?, message)))
+                .collect(),
+            content: content.into(),
+            timeout: milliseconds(1000),
+        }
+    }
+
+    /// Creates a new [`Hotkeys`] widget with the given bindings, or
+    /// returns an [`Error`] if any of the shortcuts is invalid.
+    pub fn try_new(
+        bindings: impl IntoIterator<Item = (&'a str, Message)>,
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Result<Self, Error> {
+        Ok(Self {
+            bindings: bindings
+                .into_iter()
+                .map(|(shortcut, message)| Ok((shortcut.parse()?, message)))
+                .collect::<Result<_, Error>>()?,
+            content: content.into(),
+            timeout: milliseconds(1000),
+        })
+    }
+
+    /// Adds a binding with an already parsed [`Shortcut`].
+    pub fn bind(mut self, shortcut: Shortcut, message: Message) -> Self {
+        self.bindings.push((shortcut, message));
+        self
+    }
+
+    /// Sets the maximum time between the chords of a sequence.
+    pub fn timeout(mut self, timeout: impl Into<Duration>) -> Self {
+        self.timeout = timeout.into();
+        self
+    }
+}
+
+/// A key combined with some modifiers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Chord {
+    pub key: Key,
+    pub modifiers: Modifiers,
+}
+
+impl Chord {
+    fn matches(&self, key: &Key, modified_key: &Key, modifiers: Modifiers) -> bool {
+        // Symbols like `?` are matched by what they type, whatever
+        // the Shift state needed to produce them
+        (self.key == *key && self.modifiers == modifiers)
+            || (self.key == *modified_key && self.modifiers == modifiers - Modifiers::SHIFT)
+    }
+
+    /// Returns whether the [`Chord`] could produce text.
+    fn is_typing(&self) -> bool {
+        !self
+            .modifiers
+            .intersects(Modifiers::CTRL | Modifiers::ALT | Modifiers::LOGO)
+    }
+}
+
+impl fmt::Display for Chord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (modifier, name) in [
+            (Modifiers::CTRL, "Ctrl"),
+            (Modifiers::ALT, "Alt"),
+            (Modifiers::SHIFT, "Shift"),
+            (Modifiers::LOGO, "Logo"),
+        ] {
+            if self.modifiers.contains(modifier) {
+                write!(f, "{name}+")?;
+            }
+        }
+
+        write!(f, "{}", key_name(&self.key))
+    }
+}
+
+/// Returns the capitalized name of a [`Key`], as shown to users.
+pub(crate) fn key_name(key: &Key) -> String {
+    match key.as_ref() {
+        Key::Character(c) => c.to_uppercase(),
+        Key::Named(named) => match NAMES.iter().find(|(_, key)| *key == named) {
+            Some((name, _)) => {
+                let mut chars = name.chars();
+
+                format!(
+                    "{}{}",
+                    chars.next().unwrap_or_default().to_ascii_uppercase(),
+                    chars.as_str()
+                )
+            }
+            None => format!("{named:?}"),
+        },
+        Key::Unidentified => String::from("?"),
+    }
+}
+
+/// A sequence of one or more [`Chord`]s.
+///
+/// Shortcuts are parsed from strings like `"ctrl+s"`, `"mod+shift+p"`,
+/// `"?"` or `"g g"`. Chords are separated by spaces and keys by `+`.
+/// `mod` stands for Cmd on macOS and Ctrl elsewhere.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Shortcut {
+    chords: Vec<Chord>,
+}
+
+impl Shortcut {
+    pub fn chords(&self) -> &[Chord] {
+        &self.chords
+    }
+}
+
+impl fmt::Display for Shortcut {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, chord) in self.chords.iter().enumerate() {
+            if i > 0 {
+                write!(f, " ")?;
+            }
+
+            write!(f, "{chord}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// An error produced when parsing an invalid [`Shortcut`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Error {
+    shortcut: String,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid shortcut: {:?}", self.shortcut)
+    }
+}
+
+impl std::error::Error for Error {}
+
+const NAMES: &[(&str, key::Named)] = &[
+    ("enter", key::Named::Enter),
+    ("escape", key::Named::Escape),
+    ("esc", key::Named::Escape),
+    ("tab", key::Named::Tab),
+    ("space", key::Named::Space),
+    ("backspace", key::Named::Backspace),
+    ("delete", key::Named::Delete),
+    ("del", key::Named::Delete),
+    ("insert", key::Named::Insert),
+    ("up", key::Named::ArrowUp),
+    ("down", key::Named::ArrowDown),
+    ("left", key::Named::ArrowLeft),
+    ("right", key::Named::ArrowRight),
+    ("home", key::Named::Home),
+    ("end", key::Named::End),
+    ("pageup", key::Named::PageUp),
+    ("pagedown", key::Named::PageDown),
+    ("f1", key::Named::F1),
+    ("f2", key::Named::F2),
+    ("f3", key::Named::F3),
+    ("f4", key::Named::F4),
+    ("f5", key::Named::F5),
+    ("f6", key::Named::F6),
+    ("f7", key::Named::F7),
+    ("f8", key::Named::F8),
+    ("f9", key::Named::F9),
+    ("f10", key::Named::F10),
+    ("f11", key::Named::F11),
+    ("f12", key::Named::F12),
+];
+
+impl FromStr for Shortcut {
+    type Err = Error;
+
+    fn from_str(shortcut: &str) -> Result<Self, Self::Err> {
+        let error = || Error {
+            shortcut: shortcut.to_owned(),
+        };
+
+        let chords = shortcut
+            .split_whitespace()
+            .map(|chord| {
+                let mut modifiers = Modifiers::empty();
+                let mut parts: Vec<&str> = chord.split('+').collect();
+
+                // A trailing `+` is the plus key itself
+                if chord.ends_with("++") || chord == "+" {
+                    let _ = parts.pop();
+                    *parts.last_mut().ok_or_else(error)? = "+";
+                }
+
+                let (key, parts) = parts.split_last().ok_or_else(error)?;
+
+                for modifier in parts {
+                    modifiers |= match modifier.to_lowercase().as_str() {
+                        "ctrl" | "control" => Modifiers::CTRL,
+                        "alt" | "option" => Modifiers::ALT,
+                        "shift" => Modifiers::SHIFT,
+                        "logo" | "super" | "meta" | "cmd" => Modifiers::LOGO,
+                        "mod" => Modifiers::COMMAND,
+                        _ => return Err(error()),
+                    };
+                }
+
+                let lowercase = key.to_lowercase();
+
+                let key = match NAMES.iter().find(|(name, _)| *name == lowercase) {
+                    Some((_, named)) => Key::Named(*named),
+                    None if key.chars().count() == 1 => Key::Character(lowercase.into()),
+                    None if lowercase == "plus" => Key::Character("+".into()),
+                    None => return Err(error()),
+                };
+
+                Ok(Chord { key, modifiers })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if chords.is_empty() {
+            return Err(error());
+        }
+
+        Ok(Self { chords })
+    }
+}
+
+#[derive(Debug, Default)]
+struct State {
+    pending: Vec<Chord>,
+    last_press: Option<Instant>,
+}
+
+/// Finds out whether any widget in a tree is focused.
+struct IsFocused(bool);
+
+impl Operation for IsFocused {
+    fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation)) {
+        operate(self);
+    }
+
+    fn focusable(&mut self, _id: Option<&Id>, _bounds: Rectangle, state: &mut dyn Focusable) {
+        self.0 |= state.is_focused();
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Hotkeys<'_, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Renderer: renderer::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.content.as_widget().size_hint()
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget_mut()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content
+            .as_widget_mut()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget_mut().update(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        if shell.is_event_captured() {
+            return;
+        }
+
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            let state = tree.state.downcast_mut::<State>();
+
+            if !state.pending.is_empty() {
+                let expires_at = *state.last_press.get_or_insert(*now) + self.timeout;
+
+                if *now >= expires_at {
+                    state.pending.clear();
+                    state.last_press = None;
+                } else {
+                    shell.request_redraw_at(expires_at);
+                }
+            }
+
+            return;
+        }
+
+        let Event::Keyboard(keyboard::Event::KeyPressed {
+            key,
+            modified_key,
+            modifiers,
+            repeat: false,
+            ..
+        }) = event
+        else {
+            return;
+        };
+
+        if matches!(key, Key::Named(named) if is_modifier(*named)) {
+            return;
+        }
+
+        // The sequence timeout starts on the next redraw
+        tree.state.downcast_mut::<State>().last_press = None;
+
+        let is_focused = {
+            let mut is_focused = IsFocused(false);
+
+            self.content.as_widget_mut().operate(
+                &mut tree.children[0],
+                layout,
+                renderer,
+                &mut is_focused,
+            );
+
+            is_focused.0
+        };
+
+        let state = tree.state.downcast_mut::<State>();
+
+        // A chord that breaks a sequence may still start a new one
+        for start_over in [false, true] {
+            if start_over {
+                if state.pending.is_empty() {
+                    break;
+                }
+
+                state.pending.clear();
+            }
+
+            let position = state.pending.len();
+
+            let candidates: Vec<_> = self
+                .bindings
+                .iter()
+                .filter(|(shortcut, _)| {
+                    shortcut.chords.len() > position
+                        && shortcut.chords[..position] == state.pending[..]
+                        && shortcut.chords[position].matches(key, modified_key, *modifiers)
+                        && !(is_focused && shortcut.chords.iter().any(Chord::is_typing))
+                })
+                .collect();
+
+            if let Some((_, message)) = candidates
+                .iter()
+                .find(|(shortcut, _)| shortcut.chords.len() == position + 1)
+            {
+                state.pending.clear();
+
+                shell.publish(message.clone());
+                shell.capture_event();
+
+                return;
+            }
+
+            if let Some((shortcut, _)) = candidates.first() {
+                state.pending.push(shortcut.chords[position].clone());
+                shell.capture_event();
+                shell.request_redraw();
+
+                return;
+            }
+        }
+
+        state.pending.clear();
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            viewport,
+            translation,
+        )
+    }
+}
+
+fn is_modifier(key: key::Named) -> bool {
+    matches!(
+        key,
+        key::Named::Shift
+            | key::Named::Control
+            | key::Named::Alt
+            | key::Named::Super
+            | key::Named::Meta
+            | key::Named::Hyper
+    )
+}
+
+impl<'a, Message, Theme, Renderer> From<Hotkeys<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(hotkeys: Hotkeys<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(hotkeys)
+    }
+}
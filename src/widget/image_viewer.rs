@@ -0,0 +1,368 @@
+use crate::core::border;
+use crate::core::image::{self, FilterMethod};
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::touch;
+use crate::core::widget::Widget;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::{
+    Clipboard, ContentFit, Element, Event, Image, Length, Point, Radians, Rectangle, Shell, Size,
+    Vector,
+};
+
+pub struct ImageViewer<Handle> {
+    handle: Handle,
+    width: Length,
+    height: Length,
+    content_fit: ContentFit,
+    rotation: Radians,
+    filter_method: FilterMethod,
+    min_scale: f32,
+    max_scale: f32,
+    scale_step: f32,
+}
+
+impl<Handle> ImageViewer<Handle> {
+    pub fn new(handle: impl Into<Handle>) -> Self {
+        Self {
+            handle: handle.into(),
+            width: Length::Fill,
+            height: Length::Fill,
+            content_fit: ContentFit::Contain,
+            rotation: Radians(0.0),
+            filter_method: FilterMethod::default(),
+            min_scale: 0.25,
+            max_scale: 10.0,
+            scale_step: 0.10,
+        }
+    }
+
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets how the image is fit into the viewer at its default zoom level.
+    ///
+    /// [`ContentFit::Contain`] shows the whole image, while [`ContentFit::Cover`]
+    /// fills the viewer.
+    pub fn content_fit(mut self, content_fit: ContentFit) -> Self {
+        self.content_fit = content_fit;
+        self
+    }
+
+    pub fn rotation(mut self, rotation: impl Into<Radians>) -> Self {
+        self.rotation = rotation.into();
+        self
+    }
+
+    pub fn filter_method(mut self, filter_method: FilterMethod) -> Self {
+        self.filter_method = filter_method;
+        self
+    }
+
+    pub fn min_scale(mut self, min_scale: f32) -> Self {
+        self.min_scale = min_scale;
+        self
+    }
+
+    pub fn max_scale(mut self, max_scale: f32) -> Self {
+        self.max_scale = max_scale;
+        self
+    }
+
+    /// Sets the fraction the image is scaled by for every scroll step.
+    pub fn scale_step(mut self, scale_step: f32) -> Self {
+        self.scale_step = scale_step;
+        self
+    }
+
+    /// Returns the size of the image at the default zoom level, without rotation.
+    fn fitted_size<Renderer>(&self, renderer: &Renderer, bounds: Size) -> Size
+    where
+        Renderer: image::Renderer<Handle = Handle>,
+    {
+        let Size { width, height } = renderer.measure_image(&self.handle).unwrap_or_default();
+
+        if width == 0 || height == 0 {
+            return Size::ZERO;
+        }
+
+        let size = Size::new(width as f32, height as f32);
+        let rotated = size.rotate(self.rotation);
+        let fitted = self.content_fit.fit(rotated, bounds);
+
+        size * (fitted.width / rotated.width)
+    }
+}
+
+#[derive(Debug, Default)]
+struct State {
+    scale: f32,
+    offset: Vector,
+    grabbed_at: Option<Point>,
+    fingers: Vec<(touch::Finger, Point)>,
+    last_click: Option<mouse::Click>,
+}
+
+impl State {
+    fn new() -> Self {
+        Self {
+            scale: 1.0,
+            ..Self::default()
+        }
+    }
+
+    /// Scales the image around the given point, keeping it under the same position.
+    fn zoom(&mut self, factor: f32, anchor: Point, bounds: Rectangle, min: f32, max: f32) {
+        let scale = (self.scale * factor).clamp(min, max);
+        let anchor = anchor - bounds.center();
+
+        self.offset = anchor - (anchor - self.offset) * (scale / self.scale);
+        self.scale = scale;
+    }
+
+    /// Keeps the image from being panned beyond its edges.
+    fn clamp(&mut self, bounds: Rectangle, size: Size) {
+        let hidden_width = ((size.width - bounds.width) / 2.0).max(0.0);
+        let hidden_height = ((size.height - bounds.height) / 2.0).max(0.0);
+
+        self.offset = Vector::new(
+            self.offset.x.clamp(-hidden_width, hidden_width),
+            self.offset.y.clamp(-hidden_height, hidden_height),
+        );
+    }
+
+    fn reset(&mut self) {
+        self.scale = 1.0;
+        self.offset = Vector::ZERO;
+    }
+}
+
+impl<Message, Theme, Renderer, Handle> Widget<Message, Theme, Renderer> for ImageViewer<Handle>
+where
+    Renderer: image::Renderer<Handle = Handle>,
+    Handle: Clone,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::new())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        _tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let Size { width, height } = renderer.measure_image(&self.handle).unwrap_or_default();
+        let image_size = Size::new(width as f32, height as f32).rotate(self.rotation);
+
+        layout::Node::new(limits.resolve(self.width, self.height, image_size))
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+        let (offset, scale) = (state.offset, state.scale);
+
+        match event {
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
+                let Some(position) = cursor.position_over(bounds) else {
+                    return;
+                };
+
+                let steps = match *delta {
+                    mouse::ScrollDelta::Lines { y, .. } => y,
+                    mouse::ScrollDelta::Pixels { y, .. } => y / 60.0,
+                };
+
+                state.zoom(
+                    (1.0 + self.scale_step).powf(steps),
+                    position,
+                    bounds,
+                    self.min_scale,
+                    self.max_scale,
+                );
+
+                shell.capture_event();
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                let Some(position) = cursor.position_over(bounds) else {
+                    return;
+                };
+
+                let click = mouse::Click::new(position, mouse::Button::Left, state.last_click);
+
+                if click.kind() == mouse::click::Kind::Double {
+                    state.reset();
+                } else {
+                    state.grabbed_at = Some(position);
+                }
+
+                state.last_click = Some(click);
+                shell.capture_event();
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                state.grabbed_at = None;
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position }) => {
+                let Some(grabbed_at) = state.grabbed_at else {
+                    return;
+                };
+
+                state.offset += *position - grabbed_at;
+                state.grabbed_at = Some(*position);
+
+                shell.capture_event();
+            }
+            Event::Touch(touch::Event::FingerPressed { id, position }) => {
+                if !bounds.contains(*position) || state.fingers.len() >= 2 {
+                    return;
+                }
+
+                state.fingers.push((*id, *position));
+                shell.capture_event();
+            }
+            Event::Touch(touch::Event::FingerMoved { id, position }) => {
+                let Some(index) = state.fingers.iter().position(|(finger, _)| finger == id) else {
+                    return;
+                };
+
+                let before = state.fingers.clone();
+                state.fingers[index].1 = *position;
+
+                if let ([(_, a), (_, b)], [(_, c), (_, d)]) =
+                    (before.as_slice(), state.fingers.as_slice())
+                {
+                    let previous = a.distance(*b);
+                    let current = c.distance(*d);
+                    let midpoint = Point::new((c.x + d.x) / 2.0, (c.y + d.y) / 2.0);
+                    let previous_midpoint = Point::new((a.x + b.x) / 2.0, (a.y + b.y) / 2.0);
+
+                    state.offset += midpoint - previous_midpoint;
+
+                    if previous > 0.0 {
+                        state.zoom(
+                            current / previous,
+                            midpoint,
+                            bounds,
+                            self.min_scale,
+                            self.max_scale,
+                        );
+                    }
+                } else {
+                    state.offset += *position - before[index].1;
+                }
+
+                shell.capture_event();
+            }
+            Event::Touch(
+                touch::Event::FingerLifted { id, .. } | touch::Event::FingerLost { id, .. },
+            ) => {
+                state.fingers.retain(|(finger, _)| finger != id);
+            }
+            _ => {}
+        }
+
+        if state.offset != offset || state.scale != scale {
+            let size = self.fitted_size(renderer, bounds.size()) * state.scale;
+
+            state.clamp(bounds, size.rotate(self.rotation));
+            shell.request_redraw();
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+
+        if state.grabbed_at.is_some() {
+            mouse::Interaction::Grabbing
+        } else if cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Grab
+        } else {
+            mouse::Interaction::None
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        _theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+
+        let size = self.fitted_size(renderer, bounds.size()) * state.scale;
+        let center = bounds.center() + state.offset;
+
+        let drawing_bounds = Rectangle::new(
+            Point::new(center.x - size.width / 2.0, center.y - size.height / 2.0),
+            size,
+        );
+
+        renderer.with_layer(bounds, |renderer| {
+            renderer.draw_image(
+                Image {
+                    handle: self.handle.clone(),
+                    filter_method: self.filter_method,
+                    rotation: self.rotation,
+                    border_radius: border::Radius::default(),
+                    opacity: 1.0,
+                    snap: true,
+                },
+                drawing_bounds,
+                bounds,
+            );
+        });
+    }
+}
+
+impl<'a, Message, Theme, Renderer, Handle> From<ImageViewer<Handle>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Renderer: image::Renderer<Handle = Handle> + 'a,
+    Handle: Clone + 'a,
+{
+    fn from(viewer: ImageViewer<Handle>) -> Self {
+        Element::new(viewer)
+    }
+}
@@ -0,0 +1,869 @@
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::time::Instant;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::{Operation, Widget};
+use crate::core::window;
+use crate::core::{
+    Background, Border, Clipboard, Color, Element, Event, Length, Padding, Pixels, Point,
+    Rectangle, Shadow, Shell, Size, Theme, Vector,
+};
+
+/// The distance the pointer needs to travel before a card is dragged.
+const DRAG_THRESHOLD: f32 = 5.0;
+
+/// A board of columns with cards that can be dragged around, within and
+/// between columns.
+///
+/// Columns may have a work-in-progress limit; a full column refuses cards
+/// coming from other columns.
+pub struct Kanban<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    columns: Vec<Column<'a, Message, Theme, Renderer>>,
+    on_move: Option<Box<dyn Fn(Move) -> Message + 'a>>,
+    column_width: f32,
+    height: Length,
+    spacing: f32,
+    padding: Padding,
+    card_padding: Padding,
+    class: Theme::Class<'a>,
+}
+
+/// A column of a [`Kanban`] board.
+pub struct Column<'a, Message, Theme, Renderer> {
+    title: Element<'a, Message, Theme, Renderer>,
+    cards: Vec<Element<'a, Message, Theme, Renderer>>,
+    limit: Option<usize>,
+}
+
+impl<'a, Message, Theme, Renderer> Column<'a, Message, Theme, Renderer> {
+    pub fn new(
+        title: impl Into<Element<'a, Message, Theme, Renderer>>,
+        cards: impl IntoIterator<Item = Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self {
+            title: title.into(),
+            cards: cards.into_iter().collect(),
+            limit: None,
+        }
+    }
+
+    /// Sets the maximum amount of cards in the [`Column`].
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    fn accepts(&self, is_same: bool) -> bool {
+        is_same || self.limit.is_none_or(|limit| self.cards.len() < limit)
+    }
+}
+
+/// Creates a new [`Column`] with the given title and cards.
+pub fn column<'a, Message, Theme, Renderer>(
+    title: impl Into<Element<'a, Message, Theme, Renderer>>,
+    cards: impl IntoIterator<Item = Element<'a, Message, Theme, Renderer>>,
+) -> Column<'a, Message, Theme, Renderer> {
+    Column::new(title, cards)
+}
+
+/// A card dropped in a new place of a [`Kanban`] board.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Move {
+    /// The index of the card in its original column.
+    pub card: usize,
+    /// The index of the original column.
+    pub from: usize,
+    /// The index of the new column.
+    pub to: usize,
+    /// The index of the card in the new column, once moved.
+    pub index: usize,
+}
+
+impl<'a, Message, Theme, Renderer> Kanban<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    pub fn new(columns: impl IntoIterator<Item = Column<'a, Message, Theme, Renderer>>) -> Self {
+        Self {
+            columns: columns.into_iter().collect(),
+            on_move: None,
+            column_width: 250.0,
+            height: Length::Shrink,
+            spacing: 10.0,
+            padding: Padding::new(10.0),
+            card_padding: Padding::new(10.0),
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the message produced when a card is dropped in a new place.
+    ///
+    /// Without it, cards can't be dragged.
+    pub fn on_move(mut self, on_move: impl Fn(Move) -> Message + 'a) -> Self {
+        self.on_move = Some(Box::new(on_move));
+        self
+    }
+
+    pub fn column_width(mut self, width: impl Into<Pixels>) -> Self {
+        self.column_width = width.into().0;
+        self
+    }
+
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the spacing between columns and between cards.
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.spacing = spacing.into().0;
+        self
+    }
+
+    /// Sets the padding of the columns.
+    pub fn padding(mut self, padding: impl Into<Padding>) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    pub fn card_padding(mut self, padding: impl Into<Padding>) -> Self {
+        self.card_padding = padding.into();
+        self
+    }
+
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Returns the index of the tree of the title of a column; its cards
+    /// follow right after.
+    fn tree_index(&self, column: usize) -> usize {
+        self.columns[..column]
+            .iter()
+            .map(|column| 1 + column.cards.len())
+            .sum()
+    }
+
+    /// Returns where the dragged card would land, if anywhere.
+    fn target(&self, layout: Layout<'_>, drag: &Drag, cursor: Point) -> Option<(usize, usize)> {
+        let (to, column) = layout.children().enumerate().find(|(_, column)| {
+            let bounds = column.bounds();
+
+            cursor.x >= bounds.x && cursor.x < bounds.x + bounds.width
+        })?;
+
+        let (from, card) = drag.card;
+        let shift = drag.size.height + self.spacing;
+
+        let index = column
+            .children()
+            .skip(1)
+            .enumerate()
+            .filter(|(index, _)| to != from || *index != card)
+            .filter(|(index, layout)| {
+                let removed = if to == from && *index > card {
+                    shift
+                } else {
+                    0.0
+                };
+
+                layout.bounds().center_y() - removed < cursor.y
+            })
+            .count();
+
+        Some((to, index))
+    }
+
+    /// Returns the shift of every card and the extra height of every
+    /// column while dragging.
+    fn shifts(&self, drag: Option<&Drag>) -> (Vec<Vec<f32>>, Vec<f32>) {
+        let mut cards: Vec<Vec<f32>> = self
+            .columns
+            .iter()
+            .map(|column| vec![0.0; column.cards.len()])
+            .collect();
+
+        let mut extra = vec![0.0; self.columns.len()];
+
+        let Some(drag) = drag.filter(|drag| drag.is_active) else {
+            return (cards, extra);
+        };
+
+        let (from, card) = drag.card;
+        let shift = drag.size.height + self.spacing;
+
+        // The card stays in place until it has somewhere to go
+        let Some((to, index)) = drag
+            .target
+            .filter(|(to, _)| self.columns[*to].accepts(*to == from))
+        else {
+            return (cards, extra);
+        };
+
+        for offset in cards[from].iter_mut().skip(card + 1) {
+            *offset -= shift;
+        }
+
+        let mut position = 0;
+
+        for (i, offset) in cards[to].iter_mut().enumerate() {
+            if to == from && i == card {
+                continue;
+            }
+
+            if position >= index {
+                *offset += shift;
+            }
+
+            position += 1;
+        }
+
+        if to != from {
+            extra[to] = shift;
+        }
+
+        (cards, extra)
+    }
+}
+
+struct State {
+    drag: Option<Drag>,
+    /// The animated shift of every card, by column.
+    cards: Vec<Vec<f32>>,
+    /// The animated extra height of every column.
+    extra: Vec<f32>,
+    /// The last redraw, if any.
+    now: Option<Instant>,
+}
+
+struct Drag {
+    card: (usize, usize),
+    origin: Point,
+    grab: Vector,
+    size: Size,
+    cursor: Point,
+    target: Option<(usize, usize)>,
+    is_active: bool,
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Kanban<'_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: renderer::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        let (cards, extra) = self.shifts(None);
+
+        tree::State::new(State {
+            drag: None,
+            cards,
+            extra,
+            now: None,
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.columns
+            .iter()
+            .flat_map(|column| std::iter::once(&column.title).chain(&column.cards))
+            .map(Tree::new)
+            .collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let elements: Vec<_> = self
+            .columns
+            .iter()
+            .flat_map(|column| std::iter::once(&column.title).chain(&column.cards))
+            .collect();
+
+        tree.diff_children(&elements);
+
+        let state = tree.state.downcast_mut::<State>();
+        let is_same_shape = state.cards.len() == self.columns.len()
+            && state
+                .cards
+                .iter()
+                .zip(&self.columns)
+                .all(|(cards, column)| cards.len() == column.cards.len());
+
+        if !is_same_shape {
+            (state.cards, state.extra) = self.shifts(None);
+            state.drag = None;
+        }
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(Length::Shrink, self.height)
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let padding = self.padding;
+        let card_padding = self.card_padding;
+        let content_width = (self.column_width - padding.x()).max(0.0);
+        let card_width = (content_width - card_padding.x()).max(0.0);
+
+        let mut trees = tree.children.iter_mut();
+        let mut columns = Vec::with_capacity(self.columns.len());
+        let mut height: f32 = 0.0;
+
+        for column in &mut self.columns {
+            let title_limits =
+                layout::Limits::new(Size::ZERO, Size::new(content_width, f32::INFINITY));
+
+            let title = column
+                .title
+                .as_widget_mut()
+                .layout(trees.next().unwrap(), renderer, &title_limits)
+                .move_to(Point::new(padding.left, padding.top));
+
+            let mut y = padding.top + title.size().height + self.spacing;
+            let mut nodes = vec![title];
+
+            for card in &mut column.cards {
+                let card_limits =
+                    layout::Limits::new(Size::ZERO, Size::new(card_width, f32::INFINITY))
+                        .width(card_width);
+
+                let content = card
+                    .as_widget_mut()
+                    .layout(trees.next().unwrap(), renderer, &card_limits)
+                    .move_to(Point::new(card_padding.left, card_padding.top));
+
+                let size = Size::new(content_width, content.size().height + card_padding.y());
+
+                nodes.push(
+                    layout::Node::with_children(size, vec![content])
+                        .move_to(Point::new(padding.left, y)),
+                );
+
+                y += size.height + self.spacing;
+            }
+
+            height = height.max(y - self.spacing + padding.bottom);
+            columns.push(nodes);
+        }
+
+        let count = self.columns.len() as f32;
+        let width = count * self.column_width + (count - 1.0).max(0.0) * self.spacing;
+        let size = limits.resolve(Length::Shrink, self.height, Size::new(width, height));
+
+        layout::Node::with_children(
+            size,
+            columns
+                .into_iter()
+                .enumerate()
+                .map(|(i, nodes)| {
+                    layout::Node::with_children(Size::new(self.column_width, size.height), nodes)
+                        .move_to(Point::new(
+                            i as f32 * (self.column_width + self.spacing),
+                            0.0,
+                        ))
+                })
+                .collect(),
+        )
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        operation.container(None, layout.bounds());
+        operation.traverse(&mut |operation| {
+            let mut trees = tree.children.iter_mut();
+
+            for (column, layout) in self.columns.iter_mut().zip(layout.children()) {
+                let mut children = layout.children();
+
+                column.title.as_widget_mut().operate(
+                    trees.next().unwrap(),
+                    children.next().unwrap(),
+                    renderer,
+                    operation,
+                );
+
+                for (card, layout) in column.cards.iter_mut().zip(children) {
+                    card.as_widget_mut().operate(
+                        trees.next().unwrap(),
+                        layout.children().next().unwrap(),
+                        renderer,
+                        operation,
+                    );
+                }
+            }
+        });
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        let is_dragging = tree
+            .state
+            .downcast_ref::<State>()
+            .drag
+            .as_ref()
+            .is_some_and(|drag| drag.is_active);
+
+        if !is_dragging || matches!(event, Event::Window(_)) {
+            let mut trees = tree.children.iter_mut();
+
+            for (column, layout) in self.columns.iter_mut().zip(layout.children()) {
+                let mut children = layout.children();
+
+                column.title.as_widget_mut().update(
+                    trees.next().unwrap(),
+                    event,
+                    children.next().unwrap(),
+                    cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    viewport,
+                );
+
+                for (card, layout) in column.cards.iter_mut().zip(children) {
+                    card.as_widget_mut().update(
+                        trees.next().unwrap(),
+                        event,
+                        layout.children().next().unwrap(),
+                        cursor,
+                        renderer,
+                        clipboard,
+                        shell,
+                        viewport,
+                    );
+                }
+            }
+        }
+
+        let state = tree.state.downcast_mut::<State>();
+
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            let elapsed = now
+                .saturating_duration_since(state.now.unwrap_or(*now))
+                .as_secs_f32();
+            let step = 1.0 - (-elapsed * 20.0).exp();
+            let (cards, extra) = self.shifts(state.drag.as_ref());
+
+            let mut is_animating = false;
+
+            for (current, target) in state
+                .cards
+                .iter_mut()
+                .flatten()
+                .zip(cards.iter().flatten())
+                .chain(state.extra.iter_mut().zip(&extra))
+            {
+                if (*target - *current).abs() < 0.5 {
+                    *current = *target;
+                } else {
+                    *current += (*target - *current) * step;
+                    is_animating = true;
+                }
+            }
+
+            state.now = Some(*now);
+
+            if is_animating {
+                shell.request_redraw();
+            }
+
+            return;
+        }
+
+        if self.on_move.is_none() {
+            return;
+        }
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                if !shell.is_event_captured() =>
+            {
+                let Some(position) = cursor.position_over(layout.bounds()) else {
+                    return;
+                };
+
+                let card = layout.children().enumerate().find_map(|(i, column)| {
+                    column
+                        .children()
+                        .skip(1)
+                        .enumerate()
+                        .find(|(_, card)| card.bounds().contains(position))
+                        .map(|(j, card)| ((i, j), card.bounds()))
+                });
+
+                if let Some((card, bounds)) = card {
+                    state.drag = Some(Drag {
+                        card,
+                        origin: position,
+                        grab: position - bounds.position(),
+                        size: bounds.size(),
+                        cursor: position,
+                        target: None,
+                        is_active: false,
+                    });
+
+                    shell.capture_event();
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position }) => {
+                let Some(mut drag) = state.drag.take() else {
+                    return;
+                };
+
+                drag.cursor = *position;
+                drag.is_active |= drag.origin.distance(*position) > DRAG_THRESHOLD;
+
+                if drag.is_active {
+                    drag.target = self.target(layout, &drag, *position);
+
+                    shell.request_redraw();
+                    shell.capture_event();
+                }
+
+                state.drag = Some(drag);
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                let Some(drag) = state.drag.take() else {
+                    return;
+                };
+
+                let (from, card) = drag.card;
+
+                if let Some(((to, index), on_move)) = drag
+                    .target
+                    .filter(|_| drag.is_active)
+                    .filter(|(to, index)| {
+                        self.columns[*to].accepts(*to == from) && (*to, *index) != drag.card
+                    })
+                    .zip(self.on_move.as_ref())
+                {
+                    shell.publish(on_move(Move {
+                        card,
+                        from,
+                        to,
+                        index,
+                    }));
+
+                    // The cards settle in their new place right away
+                    (state.cards, state.extra) = self.shifts(None);
+                }
+
+                shell.request_redraw();
+                shell.capture_event();
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key::Named::Escape),
+                ..
+            }) if is_dragging => {
+                state.drag = None;
+
+                shell.request_redraw();
+                shell.capture_event();
+            }
+            _ => {}
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+
+        if state.drag.as_ref().is_some_and(|drag| drag.is_active) {
+            return mouse::Interaction::Grabbing;
+        }
+
+        let mut trees = tree.children.iter();
+
+        for (column, layout) in self.columns.iter().zip(layout.children()) {
+            let mut children = layout.children();
+
+            let interaction = column.title.as_widget().mouse_interaction(
+                trees.next().unwrap(),
+                children.next().unwrap(),
+                cursor,
+                viewport,
+                renderer,
+            );
+
+            if interaction != mouse::Interaction::None {
+                return interaction;
+            }
+
+            for (card, layout) in column.cards.iter().zip(children) {
+                let interaction = card.as_widget().mouse_interaction(
+                    trees.next().unwrap(),
+                    layout.children().next().unwrap(),
+                    cursor,
+                    viewport,
+                    renderer,
+                );
+
+                if interaction != mouse::Interaction::None {
+                    return interaction;
+                }
+
+                if self.on_move.is_some() && cursor.is_over(layout.bounds()) {
+                    return mouse::Interaction::Grab;
+                }
+            }
+        }
+
+        mouse::Interaction::None
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        defaults: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let style = theme.style(&self.class);
+        let drag = state.drag.as_ref().filter(|drag| drag.is_active);
+
+        let cursor = if drag.is_some() {
+            mouse::Cursor::Unavailable
+        } else {
+            cursor
+        };
+
+        let draw_card = |renderer: &mut Renderer,
+                         card: &Element<'_, Message, Theme, Renderer>,
+                         tree: &Tree,
+                         layout: Layout<'_>,
+                         cursor: mouse::Cursor,
+                         shadow: Shadow| {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: layout.bounds(),
+                    border: style.card_border,
+                    shadow,
+                    ..renderer::Quad::default()
+                },
+                style.card,
+            );
+
+            card.as_widget().draw(
+                tree,
+                renderer,
+                theme,
+                defaults,
+                layout.children().next().unwrap(),
+                cursor,
+                viewport,
+            );
+        };
+
+        let mut trees = tree.children.iter();
+
+        for (i, (column, layout)) in self.columns.iter().zip(layout.children()).enumerate() {
+            let bounds = layout.bounds();
+            let extra = state.extra.get(i).copied().unwrap_or_default();
+
+            let is_refusing = drag.is_some_and(|drag| {
+                drag.target.is_some_and(|(to, _)| to == i) && !column.accepts(drag.card.0 == i)
+            });
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        height: bounds.height + extra,
+                        ..bounds
+                    },
+                    border: if is_refusing {
+                        style.column_border.color(style.refused)
+                    } else {
+                        style.column_border
+                    },
+                    ..renderer::Quad::default()
+                },
+                style.column,
+            );
+
+            let mut children = layout.children();
+
+            column.title.as_widget().draw(
+                trees.next().unwrap(),
+                renderer,
+                theme,
+                defaults,
+                children.next().unwrap(),
+                cursor,
+                viewport,
+            );
+
+            for (j, (card, layout)) in column.cards.iter().zip(children).enumerate() {
+                let tree = trees.next().unwrap();
+
+                if drag.is_some_and(|drag| drag.card == (i, j)) {
+                    continue;
+                }
+
+                let shift = state
+                    .cards
+                    .get(i)
+                    .and_then(|cards| cards.get(j))
+                    .copied()
+                    .unwrap_or_default();
+
+                renderer.with_translation(Vector::new(0.0, shift), |renderer| {
+                    draw_card(renderer, card, tree, layout, cursor, Shadow::default());
+                });
+            }
+        }
+
+        let Some(drag) = drag else {
+            return;
+        };
+
+        let (from, card) = drag.card;
+
+        let Some((layout, element)) = layout
+            .children()
+            .nth(from)
+            .and_then(|column| column.children().nth(card + 1))
+            .zip(self.columns[from].cards.get(card))
+        else {
+            return;
+        };
+
+        let tree = &tree.children[self.tree_index(from) + 1 + card];
+        let offset = (drag.cursor - drag.grab) - layout.position();
+
+        renderer.with_layer(*viewport, |renderer| {
+            renderer.with_translation(offset, |renderer| {
+                draw_card(
+                    renderer,
+                    element,
+                    tree,
+                    layout,
+                    cursor,
+                    style.dragged_shadow,
+                );
+            });
+        });
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Kanban<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(kanban: Kanban<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(kanban)
+    }
+}
+
+/// The appearance of a [`Kanban`] board.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Background`] of the columns.
+    pub column: Background,
+    /// The [`Border`] of the columns.
+    pub column_border: Border,
+    /// The [`Background`] of the cards.
+    pub card: Background,
+    /// The [`Border`] of the cards.
+    pub card_border: Border,
+    /// The [`Shadow`] of the card being dragged.
+    pub dragged_shadow: Shadow,
+    /// The border [`Color`] of a full column refusing the dragged card.
+    pub refused: Color,
+}
+
+/// The theme catalog of a [`Kanban`] board.
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`Kanban`] board.
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`Kanban`] board.
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        column: palette.background.weak.color.into(),
+        column_border: Border::default()
+            .rounded(6)
+            .width(1)
+            .color(Color::TRANSPARENT),
+        card: palette.background.base.color.into(),
+        card_border: Border::default()
+            .rounded(4)
+            .width(1)
+            .color(palette.background.strong.color),
+        dragged_shadow: Shadow {
+            color: Color::BLACK.scale_alpha(0.3),
+            offset: Vector::new(0.0, 4.0),
+            blur_radius: 12.0,
+        },
+        refused: palette.danger.base.color,
+    }
+}
@@ -0,0 +1,312 @@
+use crate::core::alignment;
+use crate::core::keyboard::{Key, Modifiers, key};
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::text::{self, paragraph};
+use crate::core::widget::Widget;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::{
+    Background, Border, Color, Element, Length, Padding, Pixels, Point, Rectangle, Shadow, Size,
+    Theme, Vector,
+};
+
+use crate::widget::hotkeys::{self, Chord, Shortcut};
+
+/// A widget that shows a [`Shortcut`] as a row of keyboard keys.
+///
+/// Modifiers follow the conventions of the platform, like `⌘` and `⌥`
+/// on macOS.
+pub struct Keycap<'a, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    caps: Vec<Vec<String>>,
+    size: Option<Pixels>,
+    padding: Padding,
+    spacing: f32,
+    font: Option<Renderer::Font>,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Theme, Renderer> Keycap<'a, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// Creates a new [`Keycap`] from a shortcut string, like `"mod+k"`.
+    ///
+    /// # Panics
+    /// Panics if the shortcut is invalid. See [`Shortcut`] for the
+    /// supported syntax.
+    pub fn new(shortcut: &str) -> Self {
+        match shortcut.parse() {
+            Ok(shortcut) => Self::with_shortcut(&shortcut),
+            Err(error) => panic!("{error}"),
+        }
+    }
+
+    pub fn with_shortcut(shortcut: &Shortcut) -> Self {
+        Self {
+            caps: shortcut.chords().iter().map(caps).collect(),
+            size: None,
+            padding: Padding::from([2, 6]),
+            spacing: 4.0,
+            font: None,
+            class: Theme::default(),
+        }
+    }
+
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.size = Some(size.into());
+        self
+    }
+
+    pub fn padding(mut self, padding: impl Into<Padding>) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the spacing between keys. Chords are separated by twice as much.
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.spacing = spacing.into().0;
+        self
+    }
+
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+}
+
+/// Returns the label of every key of a [`Chord`].
+fn caps(chord: &Chord) -> Vec<String> {
+    let is_mac = cfg!(target_os = "macos");
+
+    let modifiers: &[(Modifiers, &str)] = if is_mac {
+        &[
+            (Modifiers::CTRL, "⌃"),
+            (Modifiers::ALT, "⌥"),
+            (Modifiers::SHIFT, "⇧"),
+            (Modifiers::LOGO, "⌘"),
+        ]
+    } else {
+        &[
+            (Modifiers::CTRL, "Ctrl"),
+            (Modifiers::ALT, "Alt"),
+            (Modifiers::SHIFT, "Shift"),
+            (Modifiers::LOGO, "Super"),
+        ]
+    };
+
+    let symbol = match chord.key.as_ref() {
+        Key::Named(key::Named::ArrowUp) => Some("↑"),
+        Key::Named(key::Named::ArrowDown) => Some("↓"),
+        Key::Named(key::Named::ArrowLeft) => Some("←"),
+        Key::Named(key::Named::ArrowRight) => Some("→"),
+        Key::Named(key::Named::Enter) if is_mac => Some("↩"),
+        Key::Named(key::Named::Backspace) if is_mac => Some("⌫"),
+        Key::Named(key::Named::Delete) if is_mac => Some("⌦"),
+        Key::Named(key::Named::Tab) if is_mac => Some("⇥"),
+        Key::Named(key::Named::Escape) => Some("Esc"),
+        _ => None,
+    };
+
+    modifiers
+        .iter()
+        .filter(|(modifier, _)| chord.modifiers.contains(*modifier))
+        .map(|(_, name)| (*name).to_owned())
+        .chain([symbol.map_or_else(|| hotkeys::key_name(&chord.key), str::to_owned)])
+        .collect()
+}
+
+struct State<P: text::Paragraph> {
+    labels: Vec<Vec<paragraph::Plain<P>>>,
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for Keycap<'_, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<Renderer::Paragraph>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::<Renderer::Paragraph> { labels: Vec::new() })
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: Length::Shrink,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+        let size = self.size.unwrap_or_else(|| renderer.default_size());
+
+        state.labels.resize_with(self.caps.len(), Vec::new);
+
+        let mut x = 0.0;
+        let mut height: f32 = 0.0;
+        let mut children = Vec::new();
+
+        for (chord, (caps, labels)) in self.caps.iter().zip(&mut state.labels).enumerate() {
+            if chord > 0 {
+                x += self.spacing * 2.0;
+            }
+
+            labels.resize_with(caps.len(), paragraph::Plain::default);
+
+            for (cap, label) in caps.iter().zip(labels.iter_mut()) {
+                let _ = label.update(text::Text {
+                    content: cap,
+                    bounds: Size::INFINITE,
+                    size,
+                    line_height: text::LineHeight::default(),
+                    font: self.font.unwrap_or_else(|| renderer.default_font()),
+                    align_x: text::Alignment::Center,
+                    align_y: alignment::Vertical::Center,
+                    shaping: text::Shaping::Advanced,
+                    wrapping: text::Wrapping::None,
+                    hint_factor: renderer.scale_factor(),
+                });
+
+                let bounds = label.min_bounds().expand(self.padding);
+                let cap = Size::new(bounds.width.max(bounds.height), bounds.height);
+
+                children.push(layout::Node::new(cap).move_to(Point::new(x, 0.0)));
+
+                x += cap.width + self.spacing;
+                height = height.max(cap.height);
+            }
+
+            x -= self.spacing;
+        }
+
+        let intrinsic = Size::new(x.max(0.0), height);
+
+        layout::Node::with_children(
+            limits.resolve(Length::Shrink, Length::Shrink, intrinsic),
+            children,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _defaults: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+        let style = theme.style(&self.class);
+
+        for (cap, label) in layout.children().zip(state.labels.iter().flatten()) {
+            let bounds = cap.bounds();
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border: style.border,
+                    shadow: style.shadow,
+                    ..renderer::Quad::default()
+                },
+                style.background,
+            );
+
+            renderer.fill_paragraph(label.raw(), bounds.center(), style.text_color, *viewport);
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Keycap<'a, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(keycap: Keycap<'a, Theme, Renderer>) -> Self {
+        Element::new(keycap)
+    }
+}
+
+/// The appearance of a [`Keycap`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Background`] of the keys.
+    pub background: Background,
+    /// The [`Border`] of the keys.
+    pub border: Border,
+    /// The [`Shadow`] of the keys.
+    pub shadow: Shadow,
+    /// The [`Color`] of the labels.
+    pub text_color: Color,
+}
+
+/// The theme catalog of a [`Keycap`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`Keycap`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`Keycap`].
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        background: palette.background.weakest.color.into(),
+        border: Border::default()
+            .rounded(4)
+            .width(1)
+            .color(palette.background.strong.color),
+        shadow: Shadow {
+            color: palette.background.strong.color,
+            offset: Vector::new(0.0, 1.5),
+            blur_radius: 0.0,
+        },
+        text_color: palette.background.weakest.text,
+    }
+}
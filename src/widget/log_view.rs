@@ -0,0 +1,618 @@
+use crate::core::alignment;
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::text::{self, Paragraph as _};
+use crate::core::widget::Widget;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::{
+    Background, Border, Clipboard, Color, Element, Event, Length, Padding, Pixels, Point,
+    Rectangle, Shell, Size, Theme,
+};
+
+use std::collections::VecDeque;
+
+const SCROLLBAR_WIDTH: f32 = 6.0;
+const SCROLLER_MIN_HEIGHT: f32 = 20.0;
+
+/// A bounded buffer of log lines.
+///
+/// Once full, pushing a new line drops the oldest one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Log {
+    entries: VecDeque<Entry>,
+    capacity: usize,
+}
+
+/// A line of a [`Log`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    pub level: Level,
+    pub message: String,
+}
+
+/// The severity of an [`Entry`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum Level {
+    Trace,
+    Debug,
+    #[default]
+    Info,
+    Warning,
+    Error,
+}
+
+impl Log {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            entries: VecDeque::with_capacity(capacity.min(1024)),
+            capacity: capacity.max(1),
+        }
+    }
+
+    pub fn push(&mut self, level: Level, message: impl Into<String>) {
+        if self.entries.len() == self.capacity {
+            let _ = self.entries.pop_front();
+        }
+
+        self.entries.push_back(Entry {
+            level,
+            message: message.into(),
+        });
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &Entry> {
+        self.entries.iter()
+    }
+}
+
+impl Default for Log {
+    fn default() -> Self {
+        Self::new(10_000)
+    }
+}
+
+/// A console displaying the lines of a [`Log`].
+///
+/// Only the visible lines are laid out and drawn. The view follows the tail
+/// of the [`Log`] until the user scrolls up, and resumes following once
+/// scrolled back to the bottom.
+pub struct LogView<'a, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    log: &'a Log,
+    level: Level,
+    search: &'a str,
+    width: Length,
+    height: Length,
+    padding: Padding,
+    text_size: Option<Pixels>,
+    line_height: text::LineHeight,
+    font: Option<Renderer::Font>,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Theme, Renderer> LogView<'a, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    pub fn new(log: &'a Log) -> Self {
+        Self {
+            log,
+            level: Level::Trace,
+            search: "",
+            width: Length::Fill,
+            height: Length::Fill,
+            padding: Padding::new(5.0),
+            text_size: None,
+            line_height: text::LineHeight::default(),
+            font: None,
+            class: Theme::default(),
+        }
+    }
+
+    /// Hides every line with a severity lower than the given [`Level`].
+    pub fn level(mut self, level: Level) -> Self {
+        self.level = level;
+        self
+    }
+
+    /// Highlights every occurrence of the given text, ignoring ASCII case.
+    pub fn search(mut self, search: &'a str) -> Self {
+        self.search = search;
+        self
+    }
+
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    pub fn padding(mut self, padding: impl Into<Padding>) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    pub fn text_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(size.into());
+        self
+    }
+
+    pub fn line_height(mut self, line_height: impl Into<text::LineHeight>) -> Self {
+        self.line_height = line_height.into();
+        self
+    }
+
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    fn entries(&self) -> impl Iterator<Item = &'a Entry> + use<'a, Theme, Renderer> {
+        let level = self.level;
+
+        self.log.iter().filter(move |entry| entry.level >= level)
+    }
+
+    fn viewport(&self, state: &State, bounds: Rectangle, renderer: &Renderer) -> Viewport {
+        let size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        let line_height = self.line_height.to_absolute(size).0;
+        let content = bounds.shrink(self.padding);
+
+        let lines = self.entries().count();
+        let max_offset = (lines as f32 * line_height - content.height).max(0.0);
+
+        let offset = if state.is_following {
+            max_offset
+        } else {
+            state.offset.min(max_offset)
+        };
+
+        Viewport {
+            bounds,
+            content,
+            size,
+            line_height,
+            lines,
+            offset,
+            max_offset,
+        }
+    }
+}
+
+struct State {
+    offset: f32,
+    is_following: bool,
+    drag: Option<f32>,
+}
+
+impl State {
+    fn scroll_to(&mut self, offset: f32, viewport: &Viewport) {
+        self.offset = offset.clamp(0.0, viewport.max_offset);
+        self.is_following = self.offset >= viewport.max_offset;
+    }
+}
+
+struct Viewport {
+    bounds: Rectangle,
+    content: Rectangle,
+    size: Pixels,
+    line_height: f32,
+    lines: usize,
+    offset: f32,
+    max_offset: f32,
+}
+
+impl Viewport {
+    fn scrollbar(&self) -> Option<(Rectangle, Rectangle)> {
+        if self.max_offset <= 0.0 {
+            return None;
+        }
+
+        let track = Rectangle {
+            x: self.bounds.x + self.bounds.width - SCROLLBAR_WIDTH - 2.0,
+            y: self.bounds.y + 2.0,
+            width: SCROLLBAR_WIDTH,
+            height: self.bounds.height - 4.0,
+        };
+
+        let total = self.lines as f32 * self.line_height;
+        let height = (track.height * self.content.height / total)
+            .max(SCROLLER_MIN_HEIGHT)
+            .min(track.height);
+
+        let scroller = Rectangle {
+            y: track.y + (track.height - height) * self.offset / self.max_offset,
+            height,
+            ..track
+        };
+
+        Some((track, scroller))
+    }
+
+    fn offset_at(&self, track: Rectangle, scroller: Rectangle, y: f32) -> f32 {
+        let range = track.height - scroller.height;
+
+        if range <= 0.0 {
+            return 0.0;
+        }
+
+        (y - track.y) / range * self.max_offset
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer> for LogView<'a, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            offset: 0.0,
+            is_following: true,
+            drag: None,
+        })
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        layout::atomic(limits, self.width, self.height)
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+        let viewport = self.viewport(state, layout.bounds(), renderer);
+
+        match event {
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
+                if !cursor.is_over(viewport.bounds) || viewport.max_offset <= 0.0 {
+                    return;
+                }
+
+                let delta = match delta {
+                    mouse::ScrollDelta::Lines { y, .. } => y * viewport.line_height * 3.0,
+                    mouse::ScrollDelta::Pixels { y, .. } => *y,
+                };
+
+                state.scroll_to(viewport.offset - delta, &viewport);
+
+                shell.request_redraw();
+                shell.capture_event();
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                let Some((track, scroller)) = viewport.scrollbar() else {
+                    return;
+                };
+
+                let Some(position) = cursor.position_over(track.expand(2.0)) else {
+                    return;
+                };
+
+                let grab = if scroller.contains(position) {
+                    position.y - scroller.y
+                } else {
+                    let grab = scroller.height / 2.0;
+
+                    state.scroll_to(
+                        viewport.offset_at(track, scroller, position.y - grab),
+                        &viewport,
+                    );
+                    shell.request_redraw();
+
+                    grab
+                };
+
+                state.drag = Some(grab);
+                shell.capture_event();
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position }) => {
+                let Some(grab) = state.drag else {
+                    return;
+                };
+
+                let Some((track, scroller)) = viewport.scrollbar() else {
+                    return;
+                };
+
+                state.scroll_to(
+                    viewport.offset_at(track, scroller, position.y - grab),
+                    &viewport,
+                );
+
+                shell.request_redraw();
+                shell.capture_event();
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+                if state.drag.take().is_some() =>
+            {
+                shell.capture_event();
+            }
+            _ => {}
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+
+        if state.drag.is_some() {
+            return mouse::Interaction::Grabbing;
+        }
+
+        let viewport = self.viewport(state, layout.bounds(), renderer);
+
+        match viewport.scrollbar() {
+            Some((_, scroller)) if cursor.is_over(scroller) => mouse::Interaction::Grab,
+            _ if cursor.is_over(viewport.content) => mouse::Interaction::Text,
+            _ => mouse::Interaction::None,
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _defaults: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let view = self.viewport(state, layout.bounds(), renderer);
+        let style = theme.style(&self.class);
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
+
+        let Some(clip) = view.bounds.intersection(viewport) else {
+            return;
+        };
+
+        if let Some(background) = style.background {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: view.bounds,
+                    border: style.border,
+                    ..renderer::Quad::default()
+                },
+                background,
+            );
+        }
+
+        let first = (view.offset / view.line_height).floor() as usize;
+        let visible = (view.content.height / view.line_height).ceil() as usize + 1;
+        let search = self.search.to_ascii_lowercase();
+        let hint_factor = renderer.scale_factor();
+
+        let text = |content: &'a str| text::Text {
+            content,
+            bounds: Size::new(f32::INFINITY, view.line_height),
+            size: view.size,
+            line_height: self.line_height,
+            font,
+            align_x: text::Alignment::Left,
+            align_y: alignment::Vertical::Top,
+            shaping: text::Shaping::Advanced,
+            wrapping: text::Wrapping::None,
+            hint_factor,
+        };
+
+        renderer.with_layer(view.content, |renderer| {
+            for (i, entry) in self.entries().skip(first).take(visible).enumerate() {
+                let position = Point::new(
+                    view.content.x,
+                    view.content.y + (first + i) as f32 * view.line_height - view.offset,
+                );
+
+                if !search.is_empty() {
+                    let haystack = entry.message.to_ascii_lowercase();
+
+                    for (start, matched) in haystack.match_indices(&search) {
+                        let prefix = Renderer::Paragraph::with_text(text(&entry.message[..start]))
+                            .min_width();
+
+                        let width = Renderer::Paragraph::with_text(text(
+                            &entry.message[start..start + matched.len()],
+                        ))
+                        .min_width();
+
+                        renderer.fill_quad(
+                            renderer::Quad {
+                                bounds: Rectangle {
+                                    x: position.x + prefix,
+                                    y: position.y,
+                                    width,
+                                    height: view.line_height,
+                                },
+                                border: Border::default().rounded(2),
+                                ..renderer::Quad::default()
+                            },
+                            style.highlight,
+                        );
+                    }
+                }
+
+                renderer.fill_text(
+                    text(&entry.message).with_content(entry.message.clone()),
+                    position,
+                    style.color(entry.level),
+                    view.content,
+                );
+            }
+        });
+
+        if let Some((_, scroller)) = view.scrollbar() {
+            renderer.with_layer(clip, |renderer| {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: scroller,
+                        border: Border::default().rounded(SCROLLBAR_WIDTH / 2.0),
+                        ..renderer::Quad::default()
+                    },
+                    if state.is_following {
+                        style.scroller.scale_alpha(0.5)
+                    } else {
+                        style.scroller
+                    },
+                );
+            });
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<LogView<'a, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(log_view: LogView<'a, Theme, Renderer>) -> Self {
+        Element::new(log_view)
+    }
+}
+
+/// The appearance of a [`LogView`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Background`] of the console.
+    pub background: Option<Background>,
+    /// The [`Border`] of the console.
+    pub border: Border,
+    /// The text [`Color`] of [`Level::Trace`] lines.
+    pub trace: Color,
+    /// The text [`Color`] of [`Level::Debug`] lines.
+    pub debug: Color,
+    /// The text [`Color`] of [`Level::Info`] lines.
+    pub info: Color,
+    /// The text [`Color`] of [`Level::Warning`] lines.
+    pub warning: Color,
+    /// The text [`Color`] of [`Level::Error`] lines.
+    pub error: Color,
+    /// The [`Background`] of search matches.
+    pub highlight: Background,
+    /// The [`Color`] of the scroller.
+    pub scroller: Color,
+}
+
+impl Style {
+    /// Returns the text [`Color`] of the given [`Level`].
+    pub fn color(&self, level: Level) -> Color {
+        match level {
+            Level::Trace => self.trace,
+            Level::Debug => self.debug,
+            Level::Info => self.info,
+            Level::Warning => self.warning,
+            Level::Error => self.error,
+        }
+    }
+}
+
+/// The theme catalog of a [`LogView`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`LogView`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`LogView`].
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        background: Some(palette.background.weakest.color.into()),
+        border: Border::default()
+            .rounded(4)
+            .width(1)
+            .color(palette.background.weak.color),
+        trace: palette.background.strong.color,
+        debug: palette.secondary.base.color,
+        info: palette.background.base.text,
+        warning: palette.warning.base.color,
+        error: palette.danger.base.color,
+        highlight: palette.warning.weak.color.scale_alpha(0.6).into(),
+        scroller: palette.background.strong.color,
+    }
+}
@@ -0,0 +1,435 @@
+use crate::core::keyboard;
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::{Operation, Widget};
+use crate::core::{
+    Background, Border, Clipboard, Color, Element, Event, Length, Pixels, Point, Rectangle, Shadow,
+    Shell, Size, Theme, Transformation, Vector,
+};
+
+/// The height of the slices a circular lens is drawn with.
+const SLICE_HEIGHT: f32 = 3.0;
+
+/// A wrapper that shows a zoomed-in view of its content under the cursor
+/// while some modifiers are held.
+pub struct Magnifier<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    content: Element<'a, Message, Theme, Renderer>,
+    zoom: f32,
+    size: f32,
+    shape: Shape,
+    modifiers: keyboard::Modifiers,
+    class: Theme::Class<'a>,
+}
+
+/// The shape of the lens of a [`Magnifier`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Shape {
+    #[default]
+    Circle,
+    Rectangle,
+}
+
+impl<'a, Message, Theme, Renderer> Magnifier<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    pub fn new(content: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        Self {
+            content: content.into(),
+            zoom: 2.0,
+            size: 160.0,
+            shape: Shape::default(),
+            modifiers: keyboard::Modifiers::ALT,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the magnification of the lens; `2.0` by default.
+    pub fn zoom(mut self, zoom: f32) -> Self {
+        self.zoom = zoom.max(1.0);
+        self
+    }
+
+    /// Sets the diameter of a circular lens, or the side of a rectangular one.
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.size = size.into().0;
+        self
+    }
+
+    pub fn shape(mut self, shape: Shape) -> Self {
+        self.shape = shape;
+        self
+    }
+
+    /// Sets the modifiers that must be held to show the lens; Alt by default.
+    ///
+    /// With no modifiers, the lens is shown whenever the content is hovered.
+    pub fn modifiers(mut self, modifiers: keyboard::Modifiers) -> Self {
+        self.modifiers = modifiers;
+        self
+    }
+
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    fn lens(&self, state: &State, bounds: Rectangle, cursor: mouse::Cursor) -> Option<Point> {
+        if !state.modifiers.contains(self.modifiers) {
+            return None;
+        }
+
+        cursor.position_over(bounds)
+    }
+}
+
+#[derive(Debug, Default)]
+struct State {
+    modifiers: keyboard::Modifiers,
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Magnifier<'_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: renderer::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&[&self.content]);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.content.as_widget().size_hint()
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget_mut()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content
+            .as_widget_mut()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+        let was_shown = self.lens(state, layout.bounds(), cursor).is_some();
+
+        if let Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) = event {
+            state.modifiers = *modifiers;
+        }
+
+        let is_shown = self.lens(state, layout.bounds(), cursor).is_some();
+
+        if is_shown != was_shown
+            || (is_shown && matches!(event, Event::Mouse(mouse::Event::CursorMoved { .. })))
+        {
+            shell.request_redraw();
+        }
+
+        self.content.as_widget_mut().update(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+
+        if self.lens(state, layout.bounds(), cursor).is_some() {
+            return mouse::Interaction::ZoomIn;
+        }
+
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        defaults: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            defaults,
+            layout,
+            cursor,
+            viewport,
+        );
+
+        let Some(center) = self.lens(state, layout.bounds(), cursor) else {
+            return;
+        };
+
+        let style = theme.style(&self.class);
+        let radius = self.size / 2.0;
+
+        let lens = Rectangle {
+            x: center.x - radius,
+            y: center.y - radius,
+            width: self.size,
+            height: self.size,
+        };
+
+        // The part of the content seen through the lens
+        let seen = Rectangle {
+            x: center.x - radius / self.zoom,
+            y: center.y - radius / self.zoom,
+            width: self.size / self.zoom,
+            height: self.size / self.zoom,
+        };
+
+        let transformation = Transformation::translate(center.x, center.y)
+            * Transformation::scale(self.zoom)
+            * Transformation::translate(-center.x, -center.y);
+
+        let border = Border {
+            radius: match self.shape {
+                Shape::Circle => radius.into(),
+                Shape::Rectangle => style.border.radius,
+            },
+            ..style.border
+        };
+
+        renderer.with_layer(lens.expand(style.shadow.blur_radius * 2.0), |renderer| {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: lens,
+                    border: Border {
+                        width: 0.0,
+                        ..border
+                    },
+                    shadow: style.shadow,
+                    ..renderer::Quad::default()
+                },
+                style.background,
+            );
+        });
+
+        let draw_slice = |renderer: &mut Renderer, slice: Rectangle| {
+            renderer.with_layer(slice, |renderer| {
+                renderer.with_transformation(transformation, |renderer| {
+                    self.content.as_widget().draw(
+                        &tree.children[0],
+                        renderer,
+                        theme,
+                        defaults,
+                        layout,
+                        mouse::Cursor::Unavailable,
+                        &seen,
+                    );
+                });
+            });
+        };
+
+        match self.shape {
+            Shape::Rectangle => {
+                draw_slice(renderer, lens);
+            }
+            Shape::Circle => {
+                // Layers can only clip rectangles, so the circle is drawn
+                // as a stack of thin slices under a round border
+                let slices = (self.size / SLICE_HEIGHT).ceil() as usize;
+
+                for slice in 0..slices {
+                    let top = lens.y + slice as f32 * SLICE_HEIGHT;
+                    let bottom = (top + SLICE_HEIGHT).min(lens.y + lens.height);
+                    let distance = if bottom <= center.y {
+                        center.y - bottom
+                    } else if top >= center.y {
+                        top - center.y
+                    } else {
+                        0.0
+                    };
+
+                    let half = (radius * radius - distance * distance).max(0.0).sqrt();
+
+                    draw_slice(
+                        renderer,
+                        Rectangle {
+                            x: center.x - half,
+                            y: top,
+                            width: half * 2.0,
+                            height: bottom - top,
+                        },
+                    );
+                }
+            }
+        }
+
+        renderer.with_layer(lens, |renderer| {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: lens,
+                    border,
+                    ..renderer::Quad::default()
+                },
+                Color::TRANSPARENT,
+            );
+        });
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            viewport,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Magnifier<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(magnifier: Magnifier<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(magnifier)
+    }
+}
+
+/// The appearance of a [`Magnifier`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Background`] behind the zoomed content.
+    pub background: Background,
+    /// The [`Border`] of the lens.
+    ///
+    /// Its radius is ignored by circular lenses.
+    pub border: Border,
+    /// The [`Shadow`] of the lens.
+    pub shadow: Shadow,
+}
+
+/// The theme catalog of a [`Magnifier`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`Magnifier`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`Magnifier`].
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        background: palette.background.base.color.into(),
+        border: Border::default()
+            .rounded(8)
+            .width(2)
+            .color(palette.background.strong.color),
+        shadow: Shadow {
+            color: Color::BLACK.scale_alpha(0.3),
+            offset: Vector::new(0.0, 4.0),
+            blur_radius: 12.0,
+        },
+    }
+}
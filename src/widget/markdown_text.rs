@@ -0,0 +1,812 @@
+use crate::core::alignment;
+use crate::core::font;
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::text::{self, Paragraph as _, Span};
+use crate::core::time::{Duration, Instant};
+use crate::core::widget::Widget;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::window;
+use crate::core::{
+    Background, Border, Clipboard, Color, Element, Event, Font, Length, Pixels, Point, Rectangle,
+    Shell, Size, Theme, Vector,
+};
+
+/// A widget that renders a small, safe subset of Markdown.
+///
+/// It supports paragraphs, bullet and numbered lists, `**bold**`, `*italics*`,
+/// `` `code` `` spans, and `[links](https://iced.rs)`. Anything else is shown
+/// as plain text.
+///
+/// The text can be revealed progressively, like a [`Typewriter`]. Markup is
+/// parsed first, so it is never shown half-written.
+///
+/// [`Typewriter`]: crate::widget::Typewriter
+pub struct MarkdownText<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer<Font = Font>,
+{
+    source: &'a str,
+    reveal: Option<Duration>,
+    on_link: Option<Box<dyn Fn(String) -> Message + 'a>>,
+    width: Length,
+    size: Option<Pixels>,
+    line_height: text::LineHeight,
+    font: Option<Font>,
+    spacing: f32,
+    class: Theme::Class<'a>,
+    renderer: std::marker::PhantomData<Renderer>,
+}
+
+impl<'a, Message, Theme, Renderer> MarkdownText<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer<Font = Font>,
+{
+    pub fn new(source: &'a str) -> Self {
+        Self {
+            source,
+            reveal: None,
+            on_link: None,
+            width: Length::Fill,
+            size: None,
+            line_height: text::LineHeight::default(),
+            font: None,
+            spacing: 10.0,
+            class: Theme::default(),
+            renderer: std::marker::PhantomData,
+        }
+    }
+
+    /// Reveals the text progressively, taking the given time per character.
+    pub fn reveal(mut self, char_rate: impl Into<Duration>) -> Self {
+        self.reveal = Some(char_rate.into());
+        self
+    }
+
+    /// Sets the message produced when a link is clicked, given its URL.
+    pub fn on_link(mut self, on_link: impl Fn(String) -> Message + 'a) -> Self {
+        self.on_link = Some(Box::new(on_link));
+        self
+    }
+
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.size = Some(size.into());
+        self
+    }
+
+    pub fn line_height(mut self, line_height: impl Into<text::LineHeight>) -> Self {
+        self.line_height = line_height.into();
+        self
+    }
+
+    pub fn font(mut self, font: impl Into<Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the spacing between paragraphs and list items.
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.spacing = spacing.into().0;
+        self
+    }
+
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    fn text_size(&self, renderer: &Renderer) -> Pixels {
+        self.size.unwrap_or_else(|| renderer.default_size())
+    }
+
+    fn paragraph<Link>(
+        &self,
+        spans: &[Span<'_, Link, Font>],
+        width: f32,
+        renderer: &Renderer,
+    ) -> Renderer::Paragraph {
+        Renderer::Paragraph::with_spans(text::Text {
+            content: spans,
+            bounds: Size::new(width, f32::INFINITY),
+            size: self.text_size(renderer),
+            line_height: self.line_height,
+            font: self.font.unwrap_or_else(|| renderer.default_font()),
+            align_x: text::Alignment::Left,
+            align_y: alignment::Vertical::Top,
+            shaping: text::Shaping::Advanced,
+            wrapping: text::Wrapping::WordOrGlyph,
+            hint_factor: renderer.scale_factor(),
+        })
+    }
+
+    fn spans<'b>(&self, inlines: &'b [Inline], chars: usize) -> Vec<Span<'b, (), Font>> {
+        let base = self.font.unwrap_or_default();
+        let mut remaining = chars;
+
+        inlines
+            .iter()
+            .map_while(|inline| {
+                if remaining == 0 {
+                    return None;
+                }
+
+                let count = inline.text.chars().count();
+
+                let content = if count <= remaining {
+                    inline.text.as_str()
+                } else {
+                    let end = inline
+                        .text
+                        .char_indices()
+                        .nth(remaining)
+                        .map_or(inline.text.len(), |(i, _)| i);
+
+                    &inline.text[..end]
+                };
+
+                remaining = remaining.saturating_sub(count);
+
+                let span = Span::new(content);
+
+                let font = if inline.is_code {
+                    Font::MONOSPACE
+                } else if !inline.is_bold && !inline.is_italic {
+                    return Some(span);
+                } else {
+                    Font {
+                        weight: if inline.is_bold {
+                            font::Weight::Bold
+                        } else {
+                            base.weight
+                        },
+                        style: if inline.is_italic {
+                            font::Style::Italic
+                        } else {
+                            base.style
+                        },
+                        ..base
+                    }
+                };
+
+                Some(span.font(font))
+            })
+            .collect()
+    }
+
+    fn is_revealing(&self, state: &State<Renderer::Paragraph>) -> bool {
+        self.reveal.is_some() && state.revealed < state.total
+    }
+}
+
+/// A paragraph or a list item.
+#[derive(Debug, Clone, PartialEq)]
+struct Block {
+    marker: Option<String>,
+    inlines: Vec<Inline>,
+}
+
+/// A run of text with the same formatting.
+#[derive(Debug, Clone, PartialEq, Default)]
+struct Inline {
+    text: String,
+    is_bold: bool,
+    is_italic: bool,
+    is_code: bool,
+    link: Option<String>,
+}
+
+impl Block {
+    fn chars(&self) -> usize {
+        self.inlines
+            .iter()
+            .map(|inline| inline.text.chars().count())
+            .sum()
+    }
+}
+
+fn parse(source: &str) -> Vec<Block> {
+    let mut blocks = Vec::new();
+    let mut current: Option<(Option<String>, String)> = None;
+
+    let flush = |blocks: &mut Vec<Block>, current: &mut Option<(Option<String>, String)>| {
+        if let Some((marker, text)) = current.take() {
+            blocks.push(Block {
+                marker,
+                inlines: inlines(text.trim()),
+            });
+        }
+    };
+
+    for line in source.lines() {
+        let trimmed = line.trim();
+
+        if trimmed.is_empty() {
+            flush(&mut blocks, &mut current);
+            continue;
+        }
+
+        if let Some((marker, rest)) = list_item(trimmed) {
+            flush(&mut blocks, &mut current);
+            current = Some((Some(marker), rest.to_owned()));
+            continue;
+        }
+
+        match &mut current {
+            Some((_, text)) => {
+                text.push(' ');
+                text.push_str(trimmed);
+            }
+            None => {
+                current = Some((None, trimmed.to_owned()));
+            }
+        }
+    }
+
+    flush(&mut blocks, &mut current);
+
+    blocks
+}
+
+/// Returns the marker and the contents of a list item line.
+fn list_item(line: &str) -> Option<(String, &str)> {
+    for bullet in ["- ", "* ", "+ "] {
+        if let Some(rest) = line.strip_prefix(bullet) {
+            return Some((String::from("•"), rest.trim_start()));
+        }
+    }
+
+    let digits = line.chars().take_while(char::is_ascii_digit).count();
+
+    if digits == 0 {
+        return None;
+    }
+
+    let rest = line[digits..].strip_prefix(". ")?;
+
+    Some((format!("{}.", &line[..digits]), rest.trim_start()))
+}
+
+fn inlines(text: &str) -> Vec<Inline> {
+    let mut inlines = Vec::new();
+    let mut current = Inline::default();
+
+    let push = |inlines: &mut Vec<Inline>, current: &mut Inline| {
+        if !current.text.is_empty() {
+            let next = Inline {
+                text: String::new(),
+                ..current.clone()
+            };
+
+            inlines.push(std::mem::replace(current, next));
+        }
+    };
+
+    let mut rest = text;
+
+    while let Some(c) = rest.chars().next() {
+        let after = &rest[c.len_utf8()..];
+
+        match c {
+            '\\' if after.starts_with(|c: char| c.is_ascii_punctuation()) => {
+                let escaped = after.chars().next().unwrap_or_default();
+
+                current.text.push(escaped);
+                rest = &after[escaped.len_utf8()..];
+            }
+            '`' if after.contains('`') => {
+                let (code, remaining) = after.split_once('`').unwrap_or((after, ""));
+
+                push(&mut inlines, &mut current);
+                inlines.push(Inline {
+                    text: code.to_owned(),
+                    is_code: true,
+                    ..Inline::default()
+                });
+
+                rest = remaining;
+            }
+            '[' => match link(after) {
+                Some((label, url, remaining)) => {
+                    push(&mut inlines, &mut current);
+                    inlines.push(Inline {
+                        text: label.to_owned(),
+                        link: Some(url.to_owned()),
+                        ..current.clone()
+                    });
+
+                    rest = remaining;
+                }
+                None => {
+                    current.text.push(c);
+                    rest = after;
+                }
+            },
+            '*' if after.starts_with('*') => {
+                let after = &after[1..];
+
+                if current.is_bold || after.contains("**") {
+                    push(&mut inlines, &mut current);
+                    current.is_bold = !current.is_bold;
+                } else {
+                    current.text.push_str("**");
+                }
+
+                rest = after;
+            }
+            '*' | '_' => {
+                if current.is_italic || after.contains(c) {
+                    push(&mut inlines, &mut current);
+                    current.is_italic = !current.is_italic;
+                } else {
+                    current.text.push(c);
+                }
+
+                rest = after;
+            }
+            _ => {
+                current.text.push(c);
+                rest = after;
+            }
+        }
+    }
+
+    push(&mut inlines, &mut current);
+
+    inlines
+}
+
+/// Parses the label and URL of a link, after its opening bracket.
+fn link(text: &str) -> Option<(&str, &str, &str)> {
+    let (label, rest) = text.split_once("](")?;
+    let (url, rest) = rest.split_once(')')?;
+
+    if label.contains('[') || url.contains(char::is_whitespace) {
+        return None;
+    }
+
+    Some((label, url, rest))
+}
+
+struct State<P> {
+    source: String,
+    width: f32,
+    blocks: Vec<Block>,
+    rendered: Vec<Rendered<P>>,
+    total: usize,
+    revealed: usize,
+    partial: Option<(usize, P)>,
+    start: Option<Instant>,
+    hovered_link: Option<(usize, usize)>,
+}
+
+struct Rendered<P> {
+    paragraph: P,
+    marker: Option<P>,
+    position: Vector,
+    chars: usize,
+}
+
+impl<P: text::Paragraph> State<P> {
+    /// Returns the visible paragraph of every block, with its index.
+    fn visible(&self, reveal: bool) -> impl Iterator<Item = (usize, &Rendered<P>, &P)> {
+        let mut revealed = self.revealed;
+
+        self.rendered
+            .iter()
+            .enumerate()
+            .map_while(move |(i, rendered)| {
+                if !reveal {
+                    return Some((i, rendered, &rendered.paragraph));
+                }
+
+                if revealed == 0 {
+                    return None;
+                }
+
+                let paragraph = match &self.partial {
+                    Some((index, partial)) if *index == i && revealed < rendered.chars => partial,
+                    _ => &rendered.paragraph,
+                };
+
+                revealed = revealed.saturating_sub(rendered.chars);
+
+                Some((i, rendered, paragraph))
+            })
+    }
+
+    fn link_at(&self, reveal: bool, position: Point) -> Option<(usize, usize)> {
+        self.visible(reveal).find_map(|(i, rendered, paragraph)| {
+            let span = paragraph.hit_span(position - rendered.position)?;
+
+            self.blocks[i]
+                .inlines
+                .get(span)
+                .filter(|inline| inline.link.is_some())
+                .map(|_| (i, span))
+        })
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for MarkdownText<'_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer<Font = Font>,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<Renderer::Paragraph>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::<Renderer::Paragraph> {
+            source: String::new(),
+            width: 0.0,
+            blocks: Vec::new(),
+            rendered: Vec::new(),
+            total: 0,
+            revealed: 0,
+            partial: None,
+            start: None,
+            hovered_link: None,
+        })
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+        let limits = limits.width(self.width).height(Length::Shrink);
+        let max_width = limits.max().width;
+
+        if state.source != self.source {
+            state.source = self.source.to_owned();
+            state.blocks = parse(self.source);
+            state.total = state.blocks.iter().map(Block::chars).sum();
+            state.revealed = 0;
+            state.partial = None;
+            state.start = None;
+            state.hovered_link = None;
+            state.rendered.clear();
+        }
+
+        if state.rendered.is_empty() || state.width != max_width {
+            state.width = max_width;
+
+            let markers: Vec<_> = state
+                .blocks
+                .iter()
+                .map(|block| {
+                    block.marker.as_deref().map(|marker| {
+                        self.paragraph(&[Span::<(), _>::new(marker)], max_width, renderer)
+                    })
+                })
+                .collect();
+
+            let indent = markers
+                .iter()
+                .flatten()
+                .map(|marker| marker.min_width())
+                .fold(0.0, f32::max)
+                + self.text_size(renderer).0 * 0.5;
+
+            let mut y = 0.0;
+
+            state.rendered = state
+                .blocks
+                .iter()
+                .zip(markers)
+                .map(|(block, marker)| {
+                    let x = if marker.is_some() { indent } else { 0.0 };
+                    let paragraph = self.paragraph(
+                        &self.spans(&block.inlines, usize::MAX),
+                        (max_width - x).max(0.0),
+                        renderer,
+                    );
+
+                    let rendered = Rendered {
+                        position: Vector::new(x, y),
+                        chars: block.chars(),
+                        marker,
+                        paragraph,
+                    };
+
+                    y += rendered.paragraph.min_height() + self.spacing;
+
+                    rendered
+                })
+                .collect();
+
+            if let Some((index, _)) = state.partial.take() {
+                let revealed = state.revealed
+                    - state.rendered[..index]
+                        .iter()
+                        .map(|rendered| rendered.chars)
+                        .sum::<usize>();
+
+                state.partial = Some((
+                    index,
+                    self.paragraph(
+                        &self.spans(&state.blocks[index].inlines, revealed),
+                        (max_width - state.rendered[index].position.x).max(0.0),
+                        renderer,
+                    ),
+                ));
+            }
+        }
+
+        let intrinsic = state.rendered.iter().fold(Size::ZERO, |size, rendered| {
+            let bounds = rendered.paragraph.min_bounds();
+
+            Size::new(
+                size.width.max(rendered.position.x + bounds.width),
+                rendered.position.y + bounds.height,
+            )
+        });
+
+        layout::Node::new(limits.resolve(self.width, Length::Shrink, intrinsic))
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Window(window::Event::RedrawRequested(now)) => {
+                let Some(speed) = self.reveal.filter(|_| state.revealed < state.total) else {
+                    return;
+                };
+
+                let speed = speed.max(Duration::from_millis(1));
+                let start = *state.start.get_or_insert(*now);
+                let elapsed = now.saturating_duration_since(start);
+
+                let revealed = ((elapsed.as_nanos() / speed.as_nanos()) as usize).min(state.total);
+
+                if revealed != state.revealed {
+                    state.revealed = revealed;
+
+                    let mut remaining = revealed;
+                    let index = state.rendered.iter().position(|rendered| {
+                        if remaining < rendered.chars {
+                            true
+                        } else {
+                            remaining -= rendered.chars;
+                            false
+                        }
+                    });
+
+                    state.partial = index.map(|index| {
+                        (
+                            index,
+                            self.paragraph(
+                                &self.spans(&state.blocks[index].inlines, remaining),
+                                (state.width - state.rendered[index].position.x).max(0.0),
+                                renderer,
+                            ),
+                        )
+                    });
+                }
+
+                if state.revealed < state.total {
+                    shell.request_redraw_at(start + speed * (state.revealed as u32 + 1));
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. }) if self.on_link.is_some() => {
+                let hovered_link = cursor
+                    .position_in(bounds)
+                    .and_then(|position| state.link_at(self.reveal.is_some(), position));
+
+                if hovered_link != state.hovered_link {
+                    state.hovered_link = hovered_link;
+                    shell.request_redraw();
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                let Some(on_link) = &self.on_link else {
+                    return;
+                };
+
+                let Some(position) = cursor.position_in(bounds) else {
+                    return;
+                };
+
+                let url = state
+                    .link_at(self.is_revealing(state), position)
+                    .and_then(|(block, span)| state.blocks[block].inlines[span].link.as_ref());
+
+                if let Some(url) = url {
+                    shell.publish(on_link(url.clone()));
+                    shell.capture_event();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        _layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+
+        if state.hovered_link.is_some() {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::None
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        defaults: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+
+        if !bounds.intersects(viewport) {
+            return;
+        }
+
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+        let style = theme.style(&self.class);
+        let color = style.text.unwrap_or(defaults.text_color);
+        let size = self.text_size(renderer).0;
+        let line_height = self.line_height.to_absolute(Pixels(size)).0;
+
+        for (i, rendered, paragraph) in state.visible(self.is_revealing(state)) {
+            let position = bounds.position() + rendered.position;
+
+            if let Some(marker) = &rendered.marker {
+                renderer.fill_paragraph(
+                    marker,
+                    Point::new(bounds.x, position.y),
+                    style.marker.unwrap_or(color),
+                    *viewport,
+                );
+            }
+
+            for (span, inline) in state.blocks[i].inlines.iter().enumerate() {
+                if !inline.is_code && inline.link.is_none() {
+                    continue;
+                }
+
+                for region in paragraph.span_bounds(span) {
+                    let region = region + (position - Point::ORIGIN);
+
+                    if inline.is_code {
+                        renderer.fill_quad(
+                            renderer::Quad {
+                                bounds: region.expand([0.0, 3.0]),
+                                border: Border::default().rounded(3),
+                                ..renderer::Quad::default()
+                            },
+                            style.code_background,
+                        );
+                    } else {
+                        let is_hovered = state.hovered_link == Some((i, span));
+
+                        renderer.fill_quad(
+                            renderer::Quad {
+                                bounds: Rectangle {
+                                    x: region.x,
+                                    y: region.y + size + (line_height - size) / 2.0 - size * 0.08,
+                                    width: region.width,
+                                    height: if is_hovered { 2.0 } else { 1.0 },
+                                },
+                                ..renderer::Quad::default()
+                            },
+                            style.link,
+                        );
+                    }
+                }
+            }
+
+            renderer.fill_paragraph(paragraph, position, color, *viewport);
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<MarkdownText<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer<Font = Font> + 'a,
+{
+    fn from(markdown_text: MarkdownText<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(markdown_text)
+    }
+}
+
+/// The appearance of a [`MarkdownText`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Color`] of the text, if any.
+    pub text: Option<Color>,
+    /// The [`Color`] of the list markers, if different from the text.
+    pub marker: Option<Color>,
+    /// The [`Color`] of the underline of links.
+    pub link: Color,
+    /// The [`Background`] of code spans.
+    pub code_background: Background,
+}
+
+/// The theme catalog of a [`MarkdownText`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`MarkdownText`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`MarkdownText`].
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        text: None,
+        marker: Some(palette.background.strong.color),
+        link: palette.primary.base.color,
+        code_background: palette.background.weak.color.into(),
+    }
+}
@@ -0,0 +1,969 @@
+use crate::core::alignment;
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::text::{self, Fragment, IntoFragment, Paragraph};
+use crate::core::widget::Widget;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::{
+    Background, Border, Clipboard, Color, Element, Event, Length, Padding, Pixels, Point,
+    Rectangle, Shadow, Shell, Size, Theme, Vector,
+};
+
+pub struct MenuBar<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    menus: Vec<Item<'a, Message>>,
+    width: Length,
+    padding: Padding,
+    text_size: Option<Pixels>,
+    font: Option<Renderer::Font>,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> MenuBar<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    pub fn new(menus: impl IntoIterator<Item = Item<'a, Message>>) -> Self {
+        Self {
+            menus: menus.into_iter().collect(),
+            width: Length::Fill,
+            padding: Padding::from([4, 10]),
+            text_size: None,
+            font: None,
+            class: Theme::default(),
+        }
+    }
+
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    pub fn padding(mut self, padding: impl Into<Padding>) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    pub fn text_size(mut self, text_size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(text_size.into());
+        self
+    }
+
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    fn metrics(&self, renderer: &Renderer) -> Metrics<Renderer::Font> {
+        let size = self.text_size.unwrap_or_else(|| renderer.default_size());
+
+        Metrics {
+            size,
+            line_height: text::LineHeight::default().to_absolute(size).0,
+            font: self.font.unwrap_or_else(|| renderer.default_font()),
+            padding: self.padding,
+            hint_factor: renderer.scale_factor(),
+        }
+    }
+}
+
+/// An entry of a [`MenuBar`].
+///
+/// Top-level entries are usually submenus.
+pub struct Item<'a, Message> {
+    label: Fragment<'a>,
+    kind: Kind<'a, Message>,
+    shortcut: Option<Fragment<'a>>,
+    on_press: Option<Message>,
+}
+
+enum Kind<'a, Message> {
+    Action,
+    Check(bool),
+    Submenu(Vec<Item<'a, Message>>),
+    Separator,
+}
+
+impl<'a, Message> Item<'a, Message> {
+    pub fn new(label: impl IntoFragment<'a>) -> Self {
+        Self {
+            label: label.into_fragment(),
+            kind: Kind::Action,
+            shortcut: None,
+            on_press: None,
+        }
+    }
+
+    pub fn checkbox(label: impl IntoFragment<'a>, is_checked: bool) -> Self {
+        Self {
+            kind: Kind::Check(is_checked),
+            ..Self::new(label)
+        }
+    }
+
+    pub fn submenu(
+        label: impl IntoFragment<'a>,
+        items: impl IntoIterator<Item = Item<'a, Message>>,
+    ) -> Self {
+        Self {
+            kind: Kind::Submenu(items.into_iter().collect()),
+            ..Self::new(label)
+        }
+    }
+
+    pub fn separator() -> Self {
+        Self {
+            kind: Kind::Separator,
+            ..Self::new("")
+        }
+    }
+
+    pub fn shortcut(mut self, shortcut: impl IntoFragment<'a>) -> Self {
+        self.shortcut = Some(shortcut.into_fragment());
+        self
+    }
+
+    pub fn on_press(mut self, on_press: Message) -> Self {
+        self.on_press = Some(on_press);
+        self
+    }
+
+    pub fn on_press_maybe(mut self, on_press: Option<Message>) -> Self {
+        self.on_press = on_press;
+        self
+    }
+
+    fn children(&self) -> &[Item<'a, Message>] {
+        match &self.kind {
+            Kind::Submenu(items) => items,
+            _ => &[],
+        }
+    }
+
+    fn is_enabled(&self) -> bool {
+        match &self.kind {
+            Kind::Action | Kind::Check(_) => self.on_press.is_some(),
+            Kind::Submenu(items) => !items.is_empty(),
+            Kind::Separator => false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Metrics<Font> {
+    size: Pixels,
+    line_height: f32,
+    font: Font,
+    padding: Padding,
+    hint_factor: Option<f32>,
+}
+
+impl<Font: Copy> Metrics<Font> {
+    fn item_height<Message>(&self, item: &Item<'_, Message>) -> f32 {
+        match item.kind {
+            Kind::Separator => self.padding.top + 1.0,
+            _ => self.line_height + self.padding.y(),
+        }
+    }
+
+    fn measure<P: Paragraph<Font = Font>>(&self, content: &str) -> f32 {
+        P::with_text(text::Text {
+            content,
+            bounds: Size::INFINITE,
+            size: self.size,
+            line_height: text::LineHeight::default(),
+            font: self.font,
+            align_x: text::Alignment::Left,
+            align_y: alignment::Vertical::Top,
+            shaping: text::Shaping::Advanced,
+            wrapping: text::Wrapping::None,
+            hint_factor: self.hint_factor,
+        })
+        .min_width()
+    }
+
+    fn text(
+        &self,
+        content: &str,
+        bounds: Size,
+        align_x: text::Alignment,
+    ) -> text::Text<String, Font> {
+        text::Text {
+            content: content.to_owned(),
+            bounds,
+            size: self.size,
+            line_height: text::LineHeight::default(),
+            font: self.font,
+            align_x,
+            align_y: alignment::Vertical::Center,
+            shaping: text::Shaping::Advanced,
+            wrapping: text::Wrapping::None,
+            hint_factor: self.hint_factor,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct State {
+    /// The index of the open menu, followed by the selected item
+    /// of every open panel.
+    path: Vec<usize>,
+}
+
+/// Returns the items of every open panel, from the top-level menu to the
+/// deepest open submenu.
+fn panels<'a, 'b, Message>(
+    menus: &'b [Item<'a, Message>],
+    path: &[usize],
+) -> Vec<&'b [Item<'a, Message>]> {
+    let mut panels = Vec::new();
+
+    let Some(menu) = path.first().and_then(|index| menus.get(*index)) else {
+        return panels;
+    };
+
+    let mut items = menu.children();
+
+    if items.is_empty() {
+        return panels;
+    }
+
+    panels.push(items);
+
+    for index in &path[1..] {
+        match items.get(*index).map(Item::children) {
+            Some(children) if !children.is_empty() => {
+                panels.push(children);
+                items = children;
+            }
+            _ => break,
+        }
+    }
+
+    panels
+}
+
+fn next_enabled<Message>(
+    items: &[Item<'_, Message>],
+    start: Option<usize>,
+    forward: bool,
+) -> Option<usize> {
+    let len = items.len();
+
+    (1..=len)
+        .map(|offset| match (start, forward) {
+            (None, true) => offset - 1,
+            (None, false) => len - offset,
+            (Some(start), true) => (start + offset) % len,
+            (Some(start), false) => (start + len - offset % len) % len,
+        })
+        .find(|index| items[*index].is_enabled())
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for MenuBar<'_, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        _tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let metrics = self.metrics(renderer);
+        let height = metrics.line_height + self.padding.y();
+
+        let mut x = 0.0;
+
+        let entries = self
+            .menus
+            .iter()
+            .map(|menu| {
+                let width = metrics.measure::<Renderer::Paragraph>(&menu.label) + self.padding.x();
+                let node = layout::Node::new(Size::new(width, height)).move_to(Point::new(x, 0.0));
+
+                x += width;
+
+                node
+            })
+            .collect();
+
+        let size = limits.resolve(self.width, Length::Shrink, Size::new(x, height));
+
+        layout::Node::with_children(size, entries)
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+
+        let hovered = layout
+            .children()
+            .position(|entry| cursor.is_over(entry.bounds()));
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                let Some(index) = hovered else {
+                    return;
+                };
+
+                let menu = &self.menus[index];
+
+                if state.path.first() == Some(&index) {
+                    state.path.clear();
+                } else if !menu.children().is_empty() {
+                    state.path = vec![index];
+                } else if let Some(on_press) = menu.on_press.clone() {
+                    state.path.clear();
+                    shell.publish(on_press);
+                }
+
+                shell.invalidate_layout();
+                shell.request_redraw();
+                shell.capture_event();
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                let (Some(index), Some(open)) = (hovered, state.path.first()) else {
+                    return;
+                };
+
+                if *open != index && !self.menus[index].children().is_empty() {
+                    state.path = vec![index];
+
+                    shell.invalidate_layout();
+                    shell.request_redraw();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let is_over_entry = layout
+            .children()
+            .zip(&self.menus)
+            .any(|(entry, menu)| menu.is_enabled() && cursor.is_over(entry.bounds()));
+
+        if is_over_entry {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::None
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _defaults: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let style = theme.style(&self.class);
+        let metrics = self.metrics(renderer);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: layout.bounds(),
+                ..renderer::Quad::default()
+            },
+            style.bar_background,
+        );
+
+        for (i, (entry, menu)) in layout.children().zip(&self.menus).enumerate() {
+            let bounds = entry.bounds();
+            let is_selected =
+                state.path.first() == Some(&i) || state.path.is_empty() && cursor.is_over(bounds);
+
+            let text_color = if !menu.is_enabled() {
+                style.secondary_text_color
+            } else if is_selected {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        border: Border::default().rounded(style.border.radius),
+                        ..renderer::Quad::default()
+                    },
+                    style.selected_background,
+                );
+
+                style.selected_text_color
+            } else {
+                style.text_color
+            };
+
+            renderer.fill_text(
+                metrics.text(&menu.label, bounds.size(), text::Alignment::Center),
+                bounds.center(),
+                text_color,
+                *viewport,
+            );
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        _viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let state = tree.state.downcast_mut::<State>();
+        let index = *state.path.first()?;
+        let anchor = layout.children().nth(index)?.bounds() + translation;
+
+        if self.menus.get(index)?.children().is_empty() {
+            return None;
+        }
+
+        Some(overlay::Element::new(Box::new(Menus {
+            menus: &self.menus,
+            state,
+            bar: layout.bounds() + translation,
+            anchor,
+            metrics: self.metrics(renderer),
+            class: &self.class,
+        })))
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<MenuBar<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(menu_bar: MenuBar<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(menu_bar)
+    }
+}
+
+struct Menus<'a, 'b, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    menus: &'b [Item<'a, Message>],
+    state: &'b mut State,
+    bar: Rectangle,
+    anchor: Rectangle,
+    metrics: Metrics<Renderer::Font>,
+    class: &'b Theme::Class<'a>,
+}
+
+impl<Message, Theme, Renderer> Menus<'_, '_, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn hovered(&self, layout: Layout<'_>, cursor: mouse::Cursor) -> Option<(usize, usize)> {
+        let position = cursor.position()?;
+        let panels = panels(self.menus, &self.state.path);
+
+        layout
+            .children()
+            .zip(panels)
+            .enumerate()
+            .find_map(|(level, (panel, items))| {
+                let bounds = panel.bounds();
+
+                if !bounds.contains(position) {
+                    return None;
+                }
+
+                let mut y = bounds.y + self.metrics.padding.top / 2.0;
+
+                for (index, item) in items.iter().enumerate() {
+                    y += self.metrics.item_height(item);
+
+                    if position.y < y {
+                        return Some((level, index));
+                    }
+                }
+
+                None
+            })
+    }
+
+    fn activate(&mut self, shell: &mut Shell<'_, Message>) {
+        let panels = panels(self.menus, &self.state.path);
+        let level = self.state.path.len() - 1;
+
+        let Some(item) = level
+            .checked_sub(1)
+            .and_then(|panel| panels.get(panel))
+            .and_then(|items| items.get(self.state.path[level]))
+        else {
+            return;
+        };
+
+        match &item.kind {
+            Kind::Submenu(items) => {
+                if let Some(first) = next_enabled(items, None, true) {
+                    self.state.path.push(first);
+                }
+            }
+            Kind::Action | Kind::Check(_) => {
+                if let Some(on_press) = item.on_press.clone() {
+                    shell.publish(on_press);
+                    self.state.path.clear();
+                }
+            }
+            Kind::Separator => {}
+        }
+    }
+}
+
+impl<Message, Theme, Renderer> overlay::Overlay<Message, Theme, Renderer>
+    for Menus<'_, '_, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn layout(&mut self, _renderer: &Renderer, bounds: Size) -> layout::Node {
+        let metrics = self.metrics;
+        let panels = panels(self.menus, &self.state.path);
+
+        let mut nodes: Vec<layout::Node> = Vec::with_capacity(panels.len());
+
+        for (level, items) in panels.iter().enumerate() {
+            let has_checks = items.iter().any(|item| matches!(item.kind, Kind::Check(_)));
+            let indicator = if has_checks { metrics.line_height } else { 0.0 };
+
+            let content = items
+                .iter()
+                .map(|item| {
+                    let label = metrics.measure::<Renderer::Paragraph>(&item.label);
+                    let shortcut = item.shortcut.as_deref().map_or(0.0, |shortcut| {
+                        metrics.line_height * 2.0 + metrics.measure::<Renderer::Paragraph>(shortcut)
+                    });
+                    let arrow = if item.children().is_empty() {
+                        0.0
+                    } else {
+                        metrics.line_height * 2.0
+                    };
+
+                    label + shortcut + arrow
+                })
+                .fold(0.0, f32::max);
+
+            let size = Size::new(
+                (indicator + content + metrics.padding.x()).max(self.anchor.width),
+                items
+                    .iter()
+                    .map(|item| metrics.item_height(item))
+                    .sum::<f32>()
+                    + metrics.padding.top,
+            );
+
+            let position = match nodes.last() {
+                None => Point::new(self.anchor.x, self.bar.y + self.bar.height),
+                Some(parent) => {
+                    let parent = parent.bounds();
+                    let offset: f32 = panels[level - 1][..self.state.path[level]]
+                        .iter()
+                        .map(|item| metrics.item_height(item))
+                        .sum();
+
+                    let x = if parent.x + parent.width + size.width > bounds.width {
+                        parent.x - size.width
+                    } else {
+                        parent.x + parent.width
+                    };
+
+                    Point::new(x, parent.y + offset)
+                }
+            };
+
+            let position = Point::new(
+                position.x.min(bounds.width - size.width).max(0.0),
+                position.y.min(bounds.height - size.height).max(0.0),
+            );
+
+            nodes.push(layout::Node::new(size).move_to(position));
+        }
+
+        layout::Node::with_children(bounds, nodes)
+    }
+
+    fn update(
+        &mut self,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let path = self.state.path.clone();
+
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                if let Some((level, index)) = self.hovered(layout, cursor) {
+                    let panels = panels(self.menus, &self.state.path);
+
+                    if panels[level][index].is_enabled() {
+                        self.state.path.truncate(level + 1);
+                        self.state.path.push(index);
+                    } else {
+                        self.state.path.truncate(level + 1);
+                    }
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(_)) => {
+                if let Some((level, index)) = self.hovered(layout, cursor) {
+                    self.state.path.truncate(level + 1);
+                    self.state.path.push(index);
+                    self.activate(shell);
+
+                    shell.capture_event();
+                } else if !cursor.is_over(self.bar) {
+                    self.state.path.clear();
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed { key, .. }) => {
+                let panels = panels(self.menus, &self.state.path);
+                let level = self.state.path.len() - 1;
+                let selected = self.state.path.get(level).copied().filter(|_| level > 0);
+
+                let items = if level == 0 {
+                    panels[0]
+                } else {
+                    panels[level - 1]
+                };
+
+                match key.as_ref() {
+                    keyboard::Key::Named(key::Named::ArrowDown | key::Named::ArrowUp) => {
+                        let forward = key == &keyboard::Key::Named(key::Named::ArrowDown);
+
+                        if let Some(index) = next_enabled(items, selected, forward) {
+                            if level > 0 {
+                                self.state.path[level] = index;
+                            } else {
+                                self.state.path.push(index);
+                            }
+                        }
+                    }
+                    keyboard::Key::Named(key::Named::ArrowRight) => {
+                        let is_submenu = selected
+                            .and_then(|index| items.get(index))
+                            .is_some_and(|item| !item.children().is_empty());
+
+                        if is_submenu {
+                            self.activate(shell);
+                        } else if let Some(next) = next_enabled(self.menus, Some(path[0]), true) {
+                            self.state.path = vec![next];
+                        }
+                    }
+                    keyboard::Key::Named(key::Named::ArrowLeft) => {
+                        if level > 1 {
+                            let _ = self.state.path.pop();
+                        } else if let Some(previous) =
+                            next_enabled(self.menus, Some(path[0]), false)
+                        {
+                            self.state.path = vec![previous];
+                        }
+                    }
+                    keyboard::Key::Named(key::Named::Enter | key::Named::Space) => {
+                        self.activate(shell);
+                    }
+                    keyboard::Key::Named(key::Named::Escape) => {
+                        if level > 1 {
+                            let _ = self.state.path.pop();
+                        } else {
+                            self.state.path.clear();
+                        }
+                    }
+                    _ => return,
+                }
+
+                shell.capture_event();
+            }
+            _ => {}
+        }
+
+        if self.state.path != path {
+            shell.invalidate_layout();
+            shell.request_redraw();
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let panels = panels(self.menus, &self.state.path);
+
+        match self.hovered(layout, cursor) {
+            Some((level, index)) if panels[level][index].is_enabled() => {
+                mouse::Interaction::Pointer
+            }
+            Some(_) => mouse::Interaction::Idle,
+            None if layout
+                .children()
+                .any(|panel| cursor.is_over(panel.bounds())) =>
+            {
+                mouse::Interaction::Idle
+            }
+            None => mouse::Interaction::None,
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _defaults: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+    ) {
+        let style = theme.style(self.class);
+        let metrics = self.metrics;
+        let panels = panels(self.menus, &self.state.path);
+
+        for (level, (panel, items)) in layout.children().zip(panels).enumerate() {
+            let bounds = panel.bounds();
+            let selected = self.state.path.get(level + 1).copied();
+            let has_checks = items.iter().any(|item| matches!(item.kind, Kind::Check(_)));
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border: style.border,
+                    shadow: style.shadow,
+                    ..renderer::Quad::default()
+                },
+                style.menu_background,
+            );
+
+            let mut y = bounds.y + metrics.padding.top / 2.0;
+
+            for (index, item) in items.iter().enumerate() {
+                let height = metrics.item_height(item);
+                let row = Rectangle {
+                    x: bounds.x + metrics.padding.left / 2.0,
+                    y,
+                    width: bounds.width - metrics.padding.x() / 2.0,
+                    height,
+                };
+
+                y += height;
+
+                if let Kind::Separator = item.kind {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: Rectangle {
+                                y: row.center_y().floor(),
+                                height: 1.0,
+                                ..row
+                            },
+                            ..renderer::Quad::default()
+                        },
+                        style.separator,
+                    );
+
+                    continue;
+                }
+
+                let text_color = if !item.is_enabled() {
+                    style.secondary_text_color
+                } else if selected == Some(index) {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: row,
+                            border: Border::default().rounded(style.border.radius),
+                            ..renderer::Quad::default()
+                        },
+                        style.selected_background,
+                    );
+
+                    style.selected_text_color
+                } else {
+                    style.text_color
+                };
+
+                let mut x = row.x + metrics.padding.left / 2.0;
+
+                if has_checks {
+                    if let Kind::Check(true) = item.kind {
+                        renderer.fill_text(
+                            metrics.text(
+                                "✓",
+                                Size::new(metrics.line_height, height),
+                                text::Alignment::Center,
+                            ),
+                            Point::new(x + metrics.line_height / 2.0, row.center_y()),
+                            text_color,
+                            bounds,
+                        );
+                    }
+
+                    x += metrics.line_height;
+                }
+
+                renderer.fill_text(
+                    metrics.text(&item.label, row.size(), text::Alignment::Left),
+                    Point::new(x, row.center_y()),
+                    text_color,
+                    bounds,
+                );
+
+                let right = row.x + row.width - metrics.padding.right / 2.0;
+
+                if !item.children().is_empty() {
+                    renderer.fill_text(
+                        metrics.text("›", row.size(), text::Alignment::Right),
+                        Point::new(right, row.center_y()),
+                        text_color,
+                        bounds,
+                    );
+                } else if let Some(shortcut) = &item.shortcut {
+                    renderer.fill_text(
+                        metrics.text(shortcut, row.size(), text::Alignment::Right),
+                        Point::new(right, row.center_y()),
+                        if item.is_enabled() && selected != Some(index) {
+                            style.secondary_text_color
+                        } else {
+                            text_color
+                        },
+                        bounds,
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// The appearance of a [`MenuBar`] and its menus.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Background`] of the bar.
+    pub bar_background: Background,
+    /// The [`Background`] of an open menu.
+    pub menu_background: Background,
+    /// The [`Border`] of an open menu.
+    pub border: Border,
+    /// The [`Shadow`] of an open menu.
+    pub shadow: Shadow,
+    /// The [`Color`] of the labels.
+    pub text_color: Color,
+    /// The [`Color`] of shortcuts and disabled labels.
+    pub secondary_text_color: Color,
+    /// The [`Background`] of the selected entry.
+    pub selected_background: Background,
+    /// The [`Color`] of the label of the selected entry.
+    pub selected_text_color: Color,
+    /// The [`Background`] of separators.
+    pub separator: Background,
+}
+
+/// The theme catalog of a [`MenuBar`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`MenuBar`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`MenuBar`].
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        bar_background: palette.background.weak.color.into(),
+        menu_background: palette.background.base.color.into(),
+        border: Border::default()
+            .rounded(4)
+            .width(1)
+            .color(palette.background.strong.color),
+        shadow: Shadow {
+            color: Color::BLACK.scale_alpha(0.2),
+            offset: Vector::new(0.0, 2.0),
+            blur_radius: 8.0,
+        },
+        text_color: palette.background.base.text,
+        secondary_text_color: palette.background.strongest.color,
+        selected_background: palette.primary.base.color.into(),
+        selected_text_color: palette.primary.base.text,
+        separator: palette.background.strong.color.into(),
+    }
+}
@@ -0,0 +1,557 @@
+use crate::core::animation::{Animation, Easing};
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::time::{Duration, Instant, milliseconds};
+use crate::core::touch;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::{Operation, Widget};
+use crate::core::window;
+use crate::core::{
+    Background, Clipboard, Color, Element, Event, Length, Rectangle, Shell, Size, Theme, Vector,
+};
+
+/// How far a dialog slides while it fades in.
+const SLIDE_DISTANCE: f32 = 16.0;
+
+/// A widget that displays a stack of dialogs on top of some base content.
+///
+/// Only the top dialog receives input; the base and any dialogs below it
+/// are dimmed by a backdrop.
+pub struct Modal<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    /// The base content, followed by every dialog from bottom to top.
+    children: Vec<Element<'a, Message, Theme, Renderer>>,
+    on_dismiss: Option<Message>,
+    dismiss_on_backdrop: bool,
+    dismiss_on_escape: bool,
+    duration: Duration,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> Modal<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    pub fn new(
+        base: impl Into<Element<'a, Message, Theme, Renderer>>,
+        dialogs: impl IntoIterator<Item = Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self {
+            children: std::iter::once(base.into()).chain(dialogs).collect(),
+            on_dismiss: None,
+            dismiss_on_backdrop: true,
+            dismiss_on_escape: true,
+            duration: milliseconds(200),
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the message produced when the top dialog is dismissed by
+    /// clicking the backdrop or pressing Escape.
+    pub fn on_dismiss(mut self, on_dismiss: Message) -> Self {
+        self.on_dismiss = Some(on_dismiss);
+        self
+    }
+
+    pub fn dismiss_on_backdrop(mut self, dismiss_on_backdrop: bool) -> Self {
+        self.dismiss_on_backdrop = dismiss_on_backdrop;
+        self
+    }
+
+    pub fn dismiss_on_escape(mut self, dismiss_on_escape: bool) -> Self {
+        self.dismiss_on_escape = dismiss_on_escape;
+        self
+    }
+
+    pub fn duration(mut self, duration: impl Into<Duration>) -> Self {
+        self.duration = duration.into();
+        self
+    }
+
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    fn top(&self) -> Option<usize> {
+        (self.children.len() > 1).then(|| self.children.len() - 1)
+    }
+}
+
+/// A stack of open dialogs, to be kept in the state of an application.
+///
+/// The top of the stack is the dialog that currently receives input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Dialogs<T> {
+    stack: Vec<T>,
+}
+
+impl<T> Dialogs<T> {
+    pub fn new() -> Self {
+        Self { stack: Vec::new() }
+    }
+
+    /// Opens a dialog on top of the current ones.
+    pub fn open(&mut self, dialog: T) {
+        self.stack.push(dialog);
+    }
+
+    /// Closes the top dialog, returning it.
+    pub fn close(&mut self) -> Option<T> {
+        self.stack.pop()
+    }
+
+    pub fn close_all(&mut self) {
+        self.stack.clear();
+    }
+
+    pub fn top(&self) -> Option<&T> {
+        self.stack.last()
+    }
+
+    pub fn top_mut(&mut self) -> Option<&mut T> {
+        self.stack.last_mut()
+    }
+
+    pub fn is_open(&self) -> bool {
+        !self.stack.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.stack.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.stack.is_empty()
+    }
+
+    /// Returns the open dialogs, from bottom to top.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+        self.stack.iter()
+    }
+}
+
+impl<T> Default for Dialogs<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+struct State {
+    dialogs: usize,
+    transition: Animation<bool>,
+    /// The transition to start on the next redraw, if any.
+    pending: Option<bool>,
+    now: Instant,
+}
+
+impl State {
+    fn tick(&mut self, now: Instant) {
+        self.now = now;
+
+        if let Some(is_opening) = self.pending.take() {
+            self.transition.go_mut(is_opening, now);
+        }
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Modal<'_, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: renderer::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            dialogs: self.children.len() - 1,
+            transition: Animation::new(true),
+            pending: None,
+            now: Instant::now(),
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.children.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&self.children);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.children[0].as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.children[0].as_widget().size_hint()
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let state = tree.state.downcast_mut::<State>();
+        let dialogs = self.children.len() - 1;
+
+        if state.dialogs != dialogs {
+            let is_opening = dialogs > state.dialogs;
+
+            state.dialogs = dialogs;
+            state.transition = Animation::new(!is_opening)
+                .duration(self.duration)
+                .easing(Easing::EaseOut);
+            state.pending = Some(is_opening);
+        }
+
+        let base = self.children[0]
+            .as_widget_mut()
+            .layout(&mut tree.children[0], renderer, limits);
+
+        let size = base.size();
+        let dialog_limits = layout::Limits::new(Size::ZERO, size);
+
+        let nodes = std::iter::once(base)
+            .chain(
+                self.children[1..]
+                    .iter_mut()
+                    .zip(&mut tree.children[1..])
+                    .map(|(dialog, tree)| {
+                        let node = dialog
+                            .as_widget_mut()
+                            .layout(tree, renderer, &dialog_limits);
+                        let dialog_size = node.size();
+
+                        node.move_to((
+                            ((size.width - dialog_size.width) / 2.0).max(0.0),
+                            ((size.height - dialog_size.height) / 2.0).max(0.0),
+                        ))
+                    }),
+            )
+            .collect();
+
+        layout::Node::with_children(size, nodes)
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        // Only the top dialog is reachable while open, which keeps
+        // focus from escaping to the base.
+        let index = self.top().unwrap_or(0);
+
+        operation.container(None, layout.bounds());
+        operation.traverse(&mut |operation| {
+            self.children[index].as_widget_mut().operate(
+                &mut tree.children[index],
+                layout.children().nth(index).unwrap(),
+                renderer,
+                operation,
+            );
+        });
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        let Some(top) = self.top() else {
+            self.children[0].as_widget_mut().update(
+                &mut tree.children[0],
+                event,
+                layout.children().next().unwrap(),
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+                viewport,
+            );
+
+            if let Event::Window(window::Event::RedrawRequested(now)) = event {
+                let state = tree.state.downcast_mut::<State>();
+                state.tick(*now);
+
+                if state.transition.is_animating(*now) {
+                    shell.request_redraw();
+                }
+            }
+
+            return;
+        };
+
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            let state = tree.state.downcast_mut::<State>();
+            state.tick(*now);
+
+            if state.transition.is_animating(*now) {
+                shell.request_redraw();
+            }
+
+            // Keep animations in the base and covered dialogs running
+            for (index, (child, layout)) in self.children[..top]
+                .iter_mut()
+                .zip(layout.children())
+                .enumerate()
+            {
+                child.as_widget_mut().update(
+                    &mut tree.children[index],
+                    event,
+                    layout,
+                    mouse::Cursor::Unavailable,
+                    renderer,
+                    clipboard,
+                    shell,
+                    viewport,
+                );
+            }
+        }
+
+        let dialog = layout.children().nth(top).unwrap();
+
+        self.children[top].as_widget_mut().update(
+            &mut tree.children[top],
+            event,
+            dialog,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        if shell.is_event_captured() {
+            return;
+        }
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(_))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if self.dismiss_on_backdrop && !cursor.is_over(dialog.bounds()) {
+                    if let Some(on_dismiss) = self.on_dismiss.clone() {
+                        shell.publish(on_dismiss);
+                    }
+                }
+
+                shell.capture_event();
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key::Named::Escape),
+                ..
+            }) => {
+                if self.dismiss_on_escape {
+                    if let Some(on_dismiss) = self.on_dismiss.clone() {
+                        shell.publish(on_dismiss);
+                    }
+                }
+
+                shell.capture_event();
+            }
+            Event::Mouse(_) | Event::Touch(_) | Event::Keyboard(_) => {
+                shell.capture_event();
+            }
+            _ => {}
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let index = self.top().unwrap_or(0);
+        let layout = layout.children().nth(index).unwrap();
+
+        let interaction = self.children[index].as_widget().mouse_interaction(
+            &tree.children[index],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        );
+
+        if index > 0 && interaction == mouse::Interaction::None && cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Idle
+        } else {
+            interaction
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        defaults: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let style = theme.style(&self.class);
+        let top = self.top();
+        let bounds = layout.bounds();
+
+        let progress = state.transition.interpolate(0.0, 1.0, state.now);
+        let is_closing = !state.transition.value() && progress > 0.0;
+
+        for (index, ((child, tree), layout)) in self
+            .children
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .enumerate()
+        {
+            let cursor = if Some(index) == top || top.is_none() {
+                cursor
+            } else {
+                mouse::Cursor::Unavailable
+            };
+
+            if index == 0 {
+                child
+                    .as_widget()
+                    .draw(tree, renderer, theme, defaults, layout, cursor, viewport);
+
+                continue;
+            }
+
+            let is_entering = Some(index) == top && state.transition.value();
+            let (alpha, offset) = if is_entering {
+                (progress, (1.0 - progress) * SLIDE_DISTANCE)
+            } else {
+                (1.0, 0.0)
+            };
+
+            renderer.with_layer(bounds, |renderer| {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        ..renderer::Quad::default()
+                    },
+                    style.backdrop.scale_alpha(alpha),
+                );
+
+                renderer.with_translation(Vector::new(0.0, offset), |renderer| {
+                    child
+                        .as_widget()
+                        .draw(tree, renderer, theme, defaults, layout, cursor, viewport);
+                });
+            });
+        }
+
+        // The dialog that just closed is gone, but its backdrop fades out
+        if is_closing {
+            renderer.with_layer(bounds, |renderer| {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        ..renderer::Quad::default()
+                    },
+                    style.backdrop.scale_alpha(progress),
+                );
+            });
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let index = self.top().unwrap_or(0);
+
+        self.children[index].as_widget_mut().overlay(
+            &mut tree.children[index],
+            layout.children().nth(index)?,
+            renderer,
+            viewport,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Modal<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(modal: Modal<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(modal)
+    }
+}
+
+/// The appearance of a [`Modal`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Background`] drawn between the base and a dialog.
+    pub backdrop: Background,
+}
+
+/// The theme catalog of a [`Modal`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`Modal`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`Modal`].
+pub fn default(_theme: &Theme) -> Style {
+    Style {
+        backdrop: Color::BLACK.scale_alpha(0.5).into(),
+    }
+}
@@ -0,0 +1,561 @@
+use crate::core::alignment;
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::text;
+use crate::core::time::{Duration, Instant};
+use crate::core::widget::Widget;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::window;
+use crate::core::{
+    Background, Border, Clipboard, Color, Element, Event, Font, Length, Pixels, Point, Rectangle,
+    Shell, Size, Theme,
+};
+
+use std::collections::{BTreeMap, VecDeque};
+use std::sync::Mutex;
+use std::sync::atomic::{self, AtomicBool};
+
+/// The amount of frames kept for the graph and the frame rate.
+const FRAMES: usize = 120;
+
+/// The height of the frame time graph.
+const GRAPH_HEIGHT: f32 = 40.0;
+
+/// The frame time of 60 FPS, marked in the graph.
+const TARGET: Duration = Duration::from_micros(16_667);
+
+/// A layer showing the frame rate, a frame time graph, and the time spans
+/// and counters recorded with [`time_with`] and [`counter`].
+///
+/// It is meant to be stacked on top of an application and toggled with a
+/// hotkey; F12 by default. While shown, it redraws every frame.
+///
+/// Functions annotated with `#[time]` are recorded as well, alongside
+/// their `iced::debug` span. The spans iced produces internally cannot be
+/// read back by an application, so they are not shown.
+///
+/// Time spans and counters are only recorded with the `perf_hud` feature
+/// enabled and while a [`PerfHud`] is shown.
+pub struct PerfHud<'a, Theme>
+where
+    Theme: Catalog,
+{
+    hotkey: keyboard::Key,
+    is_visible: bool,
+    width: f32,
+    text_size: Option<Pixels>,
+    align_x: alignment::Horizontal,
+    align_y: alignment::Vertical,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Theme> PerfHud<'a, Theme>
+where
+    Theme: Catalog,
+{
+    pub fn new() -> Self {
+        Self {
+            hotkey: keyboard::Key::Named(key::Named::F12),
+            is_visible: false,
+            width: 240.0,
+            text_size: None,
+            align_x: alignment::Horizontal::Right,
+            align_y: alignment::Vertical::Top,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the key that shows and hides the [`PerfHud`].
+    pub fn hotkey(mut self, hotkey: keyboard::Key) -> Self {
+        self.hotkey = hotkey;
+        self
+    }
+
+    /// Sets whether the [`PerfHud`] is shown before the hotkey is pressed.
+    pub fn visible(mut self, is_visible: bool) -> Self {
+        self.is_visible = is_visible;
+        self
+    }
+
+    pub fn width(mut self, width: impl Into<Pixels>) -> Self {
+        self.width = width.into().0;
+        self
+    }
+
+    pub fn text_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(size.into());
+        self
+    }
+
+    /// Sets the corner of the panel.
+    pub fn align_x(mut self, align_x: impl Into<alignment::Horizontal>) -> Self {
+        self.align_x = align_x.into();
+        self
+    }
+
+    /// Sets the corner of the panel.
+    pub fn align_y(mut self, align_y: impl Into<alignment::Vertical>) -> Self {
+        self.align_y = align_y.into();
+        self
+    }
+
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+}
+
+impl<Theme> Default for PerfHud<'_, Theme>
+where
+    Theme: Catalog,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+struct State {
+    is_visible: bool,
+    frames: VecDeque<Duration>,
+    last_frame: Option<Instant>,
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for PerfHud<'_, Theme>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer<Font = Font>,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            is_visible: self.is_visible,
+            frames: VecDeque::with_capacity(FRAMES),
+            last_frame: None,
+        })
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(Length::Fill, Length::Fill)
+    }
+
+    fn layout(
+        &mut self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        layout::atomic(limits, Length::Fill, Length::Fill)
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        _layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+
+        match event {
+            Event::Keyboard(keyboard::Event::KeyPressed { key, .. }) if *key == self.hotkey => {
+                state.is_visible = !state.is_visible;
+                state.frames.clear();
+                state.last_frame = None;
+
+                RECORDING.store(state.is_visible, atomic::Ordering::Relaxed);
+
+                shell.capture_event();
+                shell.request_redraw();
+            }
+            Event::Window(window::Event::RedrawRequested(now)) if state.is_visible => {
+                if let Some(last_frame) = state.last_frame {
+                    if state.frames.len() == FRAMES {
+                        let _ = state.frames.pop_front();
+                    }
+
+                    state
+                        .frames
+                        .push_back(now.saturating_duration_since(last_frame));
+                }
+
+                state.last_frame = Some(*now);
+
+                RECORDING.store(true, atomic::Ordering::Relaxed);
+                shell.request_redraw();
+            }
+            _ => {}
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _defaults: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+
+        if !state.is_visible {
+            return;
+        }
+
+        let style = theme.style(&self.class);
+        let bounds = layout.bounds();
+        let size = self
+            .text_size
+            .unwrap_or_else(|| renderer.default_size() * 0.8);
+        let line_height = (size.0 * 1.4).round();
+        let padding = 8.0;
+
+        let frame_time = if state.frames.is_empty() {
+            Duration::ZERO
+        } else {
+            state.frames.iter().sum::<Duration>() / state.frames.len() as u32
+        };
+
+        let fps = if frame_time.is_zero() {
+            0.0
+        } else {
+            1.0 / frame_time.as_secs_f64()
+        };
+
+        let mut lines = vec![(
+            format!("{fps:>5.1} FPS {:>8.2} ms", ms(frame_time)),
+            style.text_color,
+        )];
+
+        let timings = timings();
+        let counters = counters();
+
+        if !timings.is_empty() {
+            lines.push((String::new(), style.text_color));
+        }
+
+        for (name, timing) in &timings {
+            lines.push((
+                format!("{:<14} {:>7.2} ms", ellipsize(name, 14), ms(timing.average)),
+                style.text_color,
+            ));
+        }
+
+        if !counters.is_empty() {
+            lines.push((String::new(), style.text_color));
+        }
+
+        for (name, value) in &counters {
+            lines.push((
+                format!("{:<14} {value:>10}", ellipsize(name, 14)),
+                style.counter,
+            ));
+        }
+
+        let height = padding * 3.0 + GRAPH_HEIGHT + lines.len() as f32 * line_height;
+        let margin = 8.0;
+
+        let panel = Rectangle {
+            x: match self.align_x {
+                alignment::Horizontal::Left => bounds.x + margin,
+                alignment::Horizontal::Center => bounds.center_x() - self.width / 2.0,
+                alignment::Horizontal::Right => bounds.x + bounds.width - self.width - margin,
+            },
+            y: match self.align_y {
+                alignment::Vertical::Top => bounds.y + margin,
+                alignment::Vertical::Center => bounds.center_y() - height / 2.0,
+                alignment::Vertical::Bottom => bounds.y + bounds.height - height - margin,
+            },
+            width: self.width,
+            height,
+        };
+
+        renderer.with_layer(bounds, |renderer| {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: panel,
+                    border: style.border,
+                    ..renderer::Quad::default()
+                },
+                style.background,
+            );
+
+            let graph = Rectangle {
+                x: panel.x + padding,
+                y: panel.y + padding,
+                width: panel.width - padding * 2.0,
+                height: GRAPH_HEIGHT,
+            };
+
+            // The graph fits two target frames, unless there are slower ones
+            let scale = state
+                .frames
+                .iter()
+                .max()
+                .copied()
+                .unwrap_or_default()
+                .max(TARGET * 2);
+
+            let bar = graph.width / FRAMES as f32;
+
+            for (i, frame) in state.frames.iter().enumerate() {
+                let height = (frame.as_secs_f32() / scale.as_secs_f32() * graph.height).max(1.0);
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: graph.x + (FRAMES - state.frames.len() + i) as f32 * bar,
+                            y: graph.y + graph.height - height,
+                            width: bar.max(1.0),
+                            height,
+                        },
+                        ..renderer::Quad::default()
+                    },
+                    if *frame > TARGET {
+                        style.slow_frame
+                    } else {
+                        style.frame
+                    },
+                );
+            }
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: graph.x,
+                        y: (graph.y + graph.height
+                            - TARGET.as_secs_f32() / scale.as_secs_f32() * graph.height)
+                            .round(),
+                        width: graph.width,
+                        height: 1.0,
+                    },
+                    ..renderer::Quad::default()
+                },
+                style.target,
+            );
+
+            for (i, (line, color)) in lines.into_iter().enumerate() {
+                renderer.fill_text(
+                    text::Text {
+                        content: line,
+                        bounds: Size::new(graph.width, line_height),
+                        size,
+                        line_height: text::LineHeight::Absolute(Pixels(line_height)),
+                        font: Font::MONOSPACE,
+                        align_x: text::Alignment::Left,
+                        align_y: alignment::Vertical::Top,
+                        shaping: text::Shaping::Basic,
+                        wrapping: text::Wrapping::None,
+                        hint_factor: renderer.scale_factor(),
+                    },
+                    Point::new(
+                        graph.x,
+                        graph.y + graph.height + padding + i as f32 * line_height,
+                    ),
+                    color,
+                    panel,
+                );
+            }
+        });
+    }
+}
+
+static RECORDING: AtomicBool = AtomicBool::new(false);
+static TIMINGS: Mutex<BTreeMap<String, Timing>> = Mutex::new(BTreeMap::new());
+static COUNTERS: Mutex<BTreeMap<String, f64>> = Mutex::new(BTreeMap::new());
+
+/// The recorded durations of a time span.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Timing {
+    /// The duration of the last run.
+    pub last: Duration,
+    /// The moving average of the durations.
+    pub average: Duration,
+    /// The amount of runs.
+    pub count: usize,
+}
+
+/// Returns true if time spans and counters are being recorded; that is,
+/// if the `perf_hud` feature is enabled and a [`PerfHud`] is shown.
+pub fn is_recording() -> bool {
+    cfg!(feature = "perf_hud") && RECORDING.load(atomic::Ordering::Relaxed)
+}
+
+/// Runs the given closure and records its duration under the given name.
+pub fn time_with<T>(name: impl Into<String>, f: impl FnOnce() -> T) -> T {
+    if !is_recording() {
+        return f();
+    }
+
+    let start = Instant::now();
+    let result = f();
+
+    record(name, start.elapsed());
+
+    result
+}
+
+/// Records a run of a time span.
+pub fn record(name: impl Into<String>, duration: Duration) {
+    if !is_recording() {
+        return;
+    }
+
+    let Ok(mut timings) = TIMINGS.lock() else {
+        return;
+    };
+
+    let _ = timings
+        .entry(name.into())
+        .and_modify(|timing| {
+            timing.last = duration;
+            timing.average = timing.average.mul_f64(0.9) + duration.mul_f64(0.1);
+            timing.count += 1;
+        })
+        .or_insert(Timing {
+            last: duration,
+            average: duration,
+            count: 1,
+        });
+}
+
+/// Sets the value of a custom counter.
+pub fn counter(name: impl Into<String>, value: impl Into<f64>) {
+    if !is_recording() {
+        return;
+    }
+
+    if let Ok(mut counters) = COUNTERS.lock() {
+        let _ = counters.insert(name.into(), value.into());
+    }
+}
+
+/// Returns the recorded time spans, sorted by name.
+pub fn timings() -> Vec<(String, Timing)> {
+    TIMINGS
+        .lock()
+        .map(|timings| {
+            timings
+                .iter()
+                .map(|(name, timing)| (name.clone(), *timing))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Returns the custom counters, sorted by name.
+pub fn counters() -> Vec<(String, f64)> {
+    COUNTERS
+        .lock()
+        .map(|counters| {
+            counters
+                .iter()
+                .map(|(name, value)| (name.clone(), *value))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn ms(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1_000.0
+}
+
+fn ellipsize(name: &str, length: usize) -> String {
+    if name.chars().count() <= length {
+        return name.to_owned();
+    }
+
+    name.chars()
+        .take(length - 1)
+        .chain(std::iter::once('…'))
+        .collect()
+}
+
+impl<'a, Message, Theme, Renderer> From<PerfHud<'a, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer<Font = Font> + 'a,
+{
+    fn from(perf_hud: PerfHud<'a, Theme>) -> Self {
+        Element::new(perf_hud)
+    }
+}
+
+/// The appearance of a [`PerfHud`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Background`] of the panel.
+    pub background: Background,
+    /// The [`Border`] of the panel.
+    pub border: Border,
+    /// The [`Color`] of the frame rate and the time spans.
+    pub text_color: Color,
+    /// The [`Color`] of the custom counters.
+    pub counter: Color,
+    /// The [`Color`] of the frames faster than 60 FPS in the graph.
+    pub frame: Color,
+    /// The [`Color`] of the frames slower than 60 FPS in the graph.
+    pub slow_frame: Color,
+    /// The [`Color`] of the line marking 60 FPS in the graph.
+    pub target: Color,
+}
+
+/// The theme catalog of a [`PerfHud`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`PerfHud`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`PerfHud`].
+///
+/// It is dark regardless of the [`Theme`], so it stands out.
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        background: Color::BLACK.scale_alpha(0.8).into(),
+        border: Border::default().rounded(6),
+        text_color: Color::WHITE,
+        counter: Color::from_rgb8(0x9C, 0xDC, 0xFE),
+        frame: palette.success.base.color,
+        slow_frame: palette.danger.base.color,
+        target: Color::WHITE.scale_alpha(0.4),
+    }
+}
@@ -0,0 +1,813 @@
+use crate::core::animation::{Animation, Easing};
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::time::{Duration, Instant};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::{Operation, Widget};
+use crate::core::window;
+use crate::core::{
+    Border, Clipboard, Color, Element, Event, Length, Padding, Pixels, Point, Rectangle, Shadow,
+    Shell, Size, Theme, Transformation, Vector,
+};
+
+/// The scale a [`Popover`] grows from while opening.
+const INITIAL_SCALE: f32 = 0.9;
+
+/// A wrapper that shows a bubble with an arrow pointing at its target.
+///
+/// Clicking the target toggles the bubble. A bubble that does not fit on
+/// its [`Placement`] flips to the opposite side, and it is always kept
+/// within the window.
+pub struct Popover<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    target: Element<'a, Message, Theme, Renderer>,
+    content: Element<'a, Message, Theme, Renderer>,
+    is_open: Option<bool>,
+    on_toggle: Option<Box<dyn Fn(bool) -> Message + 'a>>,
+    placement: Placement,
+    close_on_click_outside: bool,
+    close_on_escape: bool,
+    gap: f32,
+    arrow: f32,
+    padding: Padding,
+    max_width: f32,
+    duration: Duration,
+    class: Theme::Class<'a>,
+}
+
+/// The side of the target a [`Popover`] is placed on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Placement {
+    Top,
+    #[default]
+    Bottom,
+    Left,
+    Right,
+}
+
+impl Placement {
+    fn opposite(self) -> Self {
+        match self {
+            Placement::Top => Placement::Bottom,
+            Placement::Bottom => Placement::Top,
+            Placement::Left => Placement::Right,
+            Placement::Right => Placement::Left,
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Popover<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    pub fn new(
+        target: impl Into<Element<'a, Message, Theme, Renderer>>,
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self {
+            target: target.into(),
+            content: content.into(),
+            is_open: None,
+            on_toggle: None,
+            placement: Placement::default(),
+            close_on_click_outside: true,
+            close_on_escape: true,
+            gap: 4.0,
+            arrow: 8.0,
+            padding: Padding::new(10.0),
+            max_width: 320.0,
+            duration: Duration::from_millis(150),
+            class: Theme::default(),
+        }
+    }
+
+    /// Controls whether the [`Popover`] is open.
+    ///
+    /// Once set, the [`Popover`] does not open or close by itself; it only
+    /// produces the [`on_toggle`](Self::on_toggle) message.
+    pub fn open(mut self, is_open: bool) -> Self {
+        self.is_open = Some(is_open);
+        self
+    }
+
+    /// Sets the message produced when the target is clicked or the
+    /// [`Popover`] is dismissed.
+    pub fn on_toggle(mut self, on_toggle: impl Fn(bool) -> Message + 'a) -> Self {
+        self.on_toggle = Some(Box::new(on_toggle));
+        self
+    }
+
+    pub fn placement(mut self, placement: Placement) -> Self {
+        self.placement = placement;
+        self
+    }
+
+    /// Sets whether clicking outside of the [`Popover`] and its target
+    /// dismisses it. Enabled by default.
+    pub fn close_on_click_outside(mut self, close_on_click_outside: bool) -> Self {
+        self.close_on_click_outside = close_on_click_outside;
+        self
+    }
+
+    /// Sets whether pressing Escape dismisses the [`Popover`]. Enabled by
+    /// default.
+    pub fn close_on_escape(mut self, close_on_escape: bool) -> Self {
+        self.close_on_escape = close_on_escape;
+        self
+    }
+
+    /// Sets the distance between the target and the tip of the arrow.
+    pub fn gap(mut self, gap: impl Into<Pixels>) -> Self {
+        self.gap = gap.into().0;
+        self
+    }
+
+    /// Sets the length of the arrow; `0` hides it.
+    pub fn arrow(mut self, arrow: impl Into<Pixels>) -> Self {
+        self.arrow = arrow.into().0;
+        self
+    }
+
+    pub fn padding(mut self, padding: impl Into<Padding>) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    pub fn max_width(mut self, max_width: impl Into<Pixels>) -> Self {
+        self.max_width = max_width.into().0;
+        self
+    }
+
+    /// Sets the duration of the opening and closing animation.
+    pub fn duration(mut self, duration: impl Into<Duration>) -> Self {
+        self.duration = duration.into();
+        self
+    }
+
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+}
+
+struct State {
+    animation: Animation<bool>,
+    /// The transition to start on the next redraw, if any.
+    pending: Option<bool>,
+    now: Instant,
+    side: Placement,
+}
+
+impl State {
+    fn is_open(&self) -> bool {
+        self.pending.unwrap_or(self.animation.value())
+    }
+
+    fn is_shown(&self) -> bool {
+        self.is_open() || self.animation.value() || self.animation.is_animating(self.now)
+    }
+
+    fn toggle(&mut self, is_open: bool, duration: Duration) {
+        self.animation = Animation::new(!is_open)
+            .duration(duration)
+            .easing(Easing::EaseOut);
+        self.pending = Some(is_open);
+    }
+
+    fn tick(&mut self, now: Instant) {
+        self.now = now;
+
+        if let Some(is_open) = self.pending.take() {
+            self.animation.go_mut(is_open, now);
+        }
+    }
+}
+
+/// Requests the [`Popover`] to be opened or closed, on behalf of the user.
+fn request<Message>(
+    state: &mut State,
+    is_open: bool,
+    is_controlled: bool,
+    on_toggle: Option<&dyn Fn(bool) -> Message>,
+    duration: Duration,
+    shell: &mut Shell<'_, Message>,
+) {
+    if let Some(on_toggle) = on_toggle {
+        shell.publish(on_toggle(is_open));
+    }
+
+    if !is_controlled {
+        state.toggle(is_open, duration);
+    }
+
+    shell.invalidate_layout();
+    shell.request_redraw();
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Popover<'_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: renderer::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            animation: Animation::new(self.is_open.unwrap_or(false)),
+            pending: None,
+            now: Instant::now(),
+            side: self.placement,
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.target), Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&[&self.target, &self.content]);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.target.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.target.as_widget().size_hint()
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.target
+            .as_widget_mut()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.target
+            .as_widget_mut()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        self.target.as_widget_mut().update(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        let state = tree.state.downcast_mut::<State>();
+
+        if let Some(is_open) = self.is_open.filter(|is_open| *is_open != state.is_open()) {
+            state.toggle(is_open, self.duration);
+
+            shell.invalidate_layout();
+            shell.request_redraw();
+        }
+
+        match event {
+            Event::Window(window::Event::RedrawRequested(now)) => {
+                state.tick(*now);
+
+                if state.animation.is_animating(*now) {
+                    shell.request_redraw();
+                } else if !state.is_open() {
+                    // The overlay is gone once closed
+                    shell.invalidate_layout();
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                if cursor.is_over(layout.bounds()) =>
+            {
+                request(
+                    state,
+                    !state.is_open(),
+                    self.is_open.is_some(),
+                    self.on_toggle.as_deref(),
+                    self.duration,
+                    shell,
+                );
+            }
+            _ => {}
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.target.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.target.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let state = tree.state.downcast_mut::<State>();
+        let mut children = tree.children.iter_mut();
+
+        let target = self.target.as_widget_mut().overlay(
+            children.next().unwrap(),
+            layout,
+            renderer,
+            viewport,
+            translation,
+        );
+
+        let bubble = state.is_shown().then(|| {
+            overlay::Element::new(Box::new(Overlay {
+                content: &mut self.content,
+                tree: children.next().unwrap(),
+                state,
+                target: layout.bounds() + translation,
+                is_controlled: self.is_open.is_some(),
+                on_toggle: self.on_toggle.as_deref(),
+                placement: self.placement,
+                close_on_click_outside: self.close_on_click_outside,
+                close_on_escape: self.close_on_escape,
+                gap: self.gap,
+                arrow: self.arrow,
+                padding: self.padding,
+                max_width: self.max_width,
+                duration: self.duration,
+                class: &self.class,
+            }))
+        });
+
+        if target.is_some() || bubble.is_some() {
+            Some(
+                overlay::Group::with_children(target.into_iter().chain(bubble).collect()).overlay(),
+            )
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Popover<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(popover: Popover<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(popover)
+    }
+}
+
+struct Overlay<'a, 'b, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    content: &'b mut Element<'a, Message, Theme, Renderer>,
+    tree: &'b mut Tree,
+    state: &'b mut State,
+    target: Rectangle,
+    is_controlled: bool,
+    on_toggle: Option<&'b dyn Fn(bool) -> Message>,
+    placement: Placement,
+    close_on_click_outside: bool,
+    close_on_escape: bool,
+    gap: f32,
+    arrow: f32,
+    padding: Padding,
+    max_width: f32,
+    duration: Duration,
+    class: &'b Theme::Class<'a>,
+}
+
+impl<Message, Theme, Renderer> Overlay<'_, '_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    /// Returns the tip of the arrow, given the bounds of the bubble.
+    fn tip(&self, bubble: Rectangle, radius: f32) -> Point {
+        let target = self.target;
+        let inset = radius + self.arrow;
+
+        let along_x = || {
+            target.center_x().clamp(
+                bubble.x + inset,
+                (bubble.x + bubble.width - inset).max(bubble.x + inset),
+            )
+        };
+
+        let along_y = || {
+            target.center_y().clamp(
+                bubble.y + inset,
+                (bubble.y + bubble.height - inset).max(bubble.y + inset),
+            )
+        };
+
+        match self.state.side {
+            Placement::Top => Point::new(along_x(), bubble.y + bubble.height + self.arrow),
+            Placement::Bottom => Point::new(along_x(), bubble.y - self.arrow),
+            Placement::Left => Point::new(bubble.x + bubble.width + self.arrow, along_y()),
+            Placement::Right => Point::new(bubble.x - self.arrow, along_y()),
+        }
+    }
+}
+
+impl<Message, Theme, Renderer> overlay::Overlay<Message, Theme, Renderer>
+    for Overlay<'_, '_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: renderer::Renderer,
+{
+    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> layout::Node {
+        let content = self.content.as_widget_mut().layout(
+            self.tree,
+            renderer,
+            &layout::Limits::new(
+                Size::ZERO,
+                Size::new(self.max_width.min(bounds.width), bounds.height),
+            )
+            .shrink(self.padding),
+        );
+
+        let size = content.size().expand(self.padding);
+        let target = self.target;
+        let gap = self.gap + self.arrow;
+
+        let place = |placement| match placement {
+            Placement::Top => Point::new(
+                target.center_x() - size.width / 2.0,
+                target.y - gap - size.height,
+            ),
+            Placement::Bottom => Point::new(
+                target.center_x() - size.width / 2.0,
+                target.y + target.height + gap,
+            ),
+            Placement::Left => Point::new(
+                target.x - gap - size.width,
+                target.center_y() - size.height / 2.0,
+            ),
+            Placement::Right => Point::new(
+                target.x + target.width + gap,
+                target.center_y() - size.height / 2.0,
+            ),
+        };
+
+        // Only the axis facing the target decides whether a side fits
+        let fits = |placement, position: Point| match placement {
+            Placement::Top | Placement::Bottom => {
+                position.y >= 0.0 && position.y + size.height <= bounds.height
+            }
+            Placement::Left | Placement::Right => {
+                position.x >= 0.0 && position.x + size.width <= bounds.width
+            }
+        };
+
+        let mut side = self.placement;
+        let mut position = place(side);
+
+        if !fits(side, position) && fits(side.opposite(), place(side.opposite())) {
+            side = side.opposite();
+            position = place(side);
+        }
+
+        self.state.side = side;
+
+        // Keep the bubble within the window and aligned to whole pixels
+        let position = Point::new(
+            position.x.min(bounds.width - size.width).max(0.0).round(),
+            position.y.min(bounds.height - size.height).max(0.0).round(),
+        );
+
+        layout::Node::with_children(
+            size,
+            vec![content.move_to(Point::new(self.padding.left, self.padding.top))],
+        )
+        .move_to(position)
+    }
+
+    fn update(
+        &mut self,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let bounds = layout.bounds();
+
+        if self.state.is_open() {
+            self.content.as_widget_mut().update(
+                self.tree,
+                event,
+                layout.children().next().unwrap(),
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+                &bounds,
+            );
+        }
+
+        if !self.state.is_open() || shell.is_event_captured() {
+            return;
+        }
+
+        let is_dismissed = match event {
+            // The target toggles the popover by itself
+            Event::Mouse(mouse::Event::ButtonPressed(_)) => {
+                self.close_on_click_outside
+                    && !cursor.is_over(bounds)
+                    && !cursor.is_over(self.target)
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key::Named::Escape),
+                ..
+            }) => {
+                if self.close_on_escape {
+                    shell.capture_event();
+                }
+
+                self.close_on_escape
+            }
+            _ => false,
+        };
+
+        if is_dismissed {
+            request(
+                self.state,
+                false,
+                self.is_controlled,
+                self.on_toggle,
+                self.duration,
+                shell,
+            );
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            self.tree,
+            layout.children().next().unwrap(),
+            cursor,
+            &layout.bounds(),
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) {
+        let style = theme.style(self.class);
+        let bounds = layout.bounds();
+        let progress = self.state.animation.interpolate(0.0, 1.0, self.state.now);
+        let tip = self.tip(bounds, style.border.radius.top_left);
+
+        // The bubble grows out of the tip of its arrow
+        let scale = INITIAL_SCALE + (1.0 - INITIAL_SCALE) * progress;
+        let transformation = Transformation::translate(tip.x, tip.y)
+            * Transformation::scale(scale)
+            * Transformation::translate(-tip.x, -tip.y);
+
+        renderer.with_layer(
+            bounds.expand(style.shadow.blur_radius * 2.0 + self.arrow),
+            |renderer| {
+                renderer.with_transformation(transformation, |renderer| {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds,
+                            border: style.border,
+                            shadow: style.shadow,
+                            ..renderer::Quad::default()
+                        },
+                        style.background.scale_alpha(progress),
+                    );
+
+                    if self.arrow > 0.0 {
+                        let width = style.border.width;
+                        let direction = match self.state.side {
+                            Placement::Top => Vector::new(0.0, 1.0),
+                            Placement::Bottom => Vector::new(0.0, -1.0),
+                            Placement::Left => Vector::new(1.0, 0.0),
+                            Placement::Right => Vector::new(-1.0, 0.0),
+                        };
+
+                        if width > 0.0 {
+                            arrow(
+                                renderer,
+                                tip + direction * (width * std::f32::consts::SQRT_2),
+                                direction,
+                                self.arrow + width * (1.0 + std::f32::consts::SQRT_2),
+                                style.border.color.scale_alpha(progress),
+                            );
+                        }
+
+                        // Covers the border of the bubble under the arrow
+                        arrow(
+                            renderer,
+                            tip,
+                            direction,
+                            self.arrow + width,
+                            style.background.scale_alpha(progress),
+                        );
+                    }
+
+                    self.content.as_widget().draw(
+                        self.tree,
+                        renderer,
+                        theme,
+                        &renderer::Style {
+                            text_color: style.text_color,
+                        },
+                        layout.children().next().unwrap(),
+                        cursor,
+                        &bounds,
+                    );
+                });
+            },
+        );
+    }
+}
+
+/// Draws a right triangle pointing in the given direction, as a stack of
+/// one pixel slices.
+fn arrow<Renderer>(
+    renderer: &mut Renderer,
+    tip: Point,
+    direction: Vector,
+    length: f32,
+    color: Color,
+) where
+    Renderer: renderer::Renderer,
+{
+    let slices = length.ceil() as usize;
+
+    for slice in 0..slices {
+        let distance = slice as f32;
+        let half = length - distance;
+        let center = tip - direction * (distance + 1.0);
+
+        let bounds = if direction.x == 0.0 {
+            Rectangle {
+                x: center.x - half,
+                y: center.y.min(center.y + direction.y),
+                width: half * 2.0,
+                height: 1.0,
+            }
+        } else {
+            Rectangle {
+                x: center.x.min(center.x + direction.x),
+                y: center.y - half,
+                width: 1.0,
+                height: half * 2.0,
+            }
+        };
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                ..renderer::Quad::default()
+            },
+            color,
+        );
+    }
+}
+
+/// The appearance of a [`Popover`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Color`] of the bubble and its arrow.
+    pub background: Color,
+    /// The [`Border`] of the bubble and its arrow.
+    pub border: Border,
+    /// The [`Shadow`] of the bubble.
+    pub shadow: Shadow,
+    /// The default text [`Color`] of the content.
+    pub text_color: Color,
+}
+
+/// The theme catalog of a [`Popover`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`Popover`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`Popover`].
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        background: palette.background.base.color,
+        border: Border::default()
+            .rounded(8)
+            .width(1)
+            .color(palette.background.strong.color),
+        shadow: Shadow {
+            color: Color::BLACK.scale_alpha(0.2),
+            offset: Vector::new(0.0, 4.0),
+            blur_radius: 12.0,
+        },
+        text_color: palette.background.base.text,
+    }
+}
@@ -0,0 +1,743 @@
+use crate::core::alignment;
+use crate::core::clipboard;
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::text::{self, Fragment, IntoFragment, Paragraph as _};
+use crate::core::time::{Duration, Instant};
+use crate::core::touch;
+use crate::core::widget::operation::{self, Operation};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::{Id, Widget};
+use crate::core::window;
+use crate::core::{
+    Background, Border, Clipboard, Color, Element, Event, Length, Padding, Pixels, Point,
+    Rectangle, Shell, Size, Theme,
+};
+
+use std::collections::VecDeque;
+
+const CURSOR_BLINK_INTERVAL: Duration = Duration::from_millis(500);
+
+/// A single-line, terminal-style input with command history.
+///
+/// Up and down browse previous submissions. Tab, or moving right at the end
+/// of the input, accepts the ghost suggestion; taken from the history first
+/// and then from the provided [`suggestions`](Self::suggestions).
+pub struct Prompt<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    id: Option<Id>,
+    symbol: Fragment<'a>,
+    placeholder: Fragment<'a>,
+    suggestions: Vec<&'a str>,
+    on_submit: Option<Box<dyn Fn(String) -> Message + 'a>>,
+    history_limit: usize,
+    width: Length,
+    padding: Padding,
+    size: Option<Pixels>,
+    line_height: text::LineHeight,
+    font: Option<Renderer::Font>,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> Prompt<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    pub fn new() -> Self {
+        Self {
+            id: None,
+            symbol: Fragment::Borrowed("> "),
+            placeholder: Fragment::Borrowed(""),
+            suggestions: Vec::new(),
+            on_submit: None,
+            history_limit: 100,
+            width: Length::Fill,
+            padding: Padding::new(5.0),
+            size: None,
+            line_height: text::LineHeight::default(),
+            font: None,
+            class: Theme::default(),
+        }
+    }
+
+    pub fn id(mut self, id: impl Into<Id>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Sets the text shown before the input; `"> "` by default.
+    pub fn symbol(mut self, symbol: impl IntoFragment<'a>) -> Self {
+        self.symbol = symbol.into_fragment();
+        self
+    }
+
+    pub fn placeholder(mut self, placeholder: impl IntoFragment<'a>) -> Self {
+        self.placeholder = placeholder.into_fragment();
+        self
+    }
+
+    /// Sets the commands suggested as ghost text while typing.
+    pub fn suggestions(mut self, suggestions: impl IntoIterator<Item = &'a str>) -> Self {
+        self.suggestions = suggestions.into_iter().collect();
+        self
+    }
+
+    /// Sets the message produced when Enter is pressed.
+    ///
+    /// The input is cleared and its contents are added to the history.
+    pub fn on_submit(mut self, on_submit: impl Fn(String) -> Message + 'a) -> Self {
+        self.on_submit = Some(Box::new(on_submit));
+        self
+    }
+
+    /// Sets the maximum amount of submissions kept in the history.
+    pub fn history_limit(mut self, history_limit: usize) -> Self {
+        self.history_limit = history_limit;
+        self
+    }
+
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    pub fn padding(mut self, padding: impl Into<Padding>) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.size = Some(size.into());
+        self
+    }
+
+    pub fn line_height(mut self, line_height: impl Into<text::LineHeight>) -> Self {
+        self.line_height = line_height.into();
+        self
+    }
+
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Returns the remainder of the current suggestion, if any.
+    fn suggestion<'b>(&'b self, state: &'b State) -> Option<&'b str> {
+        if state.value.is_empty() || state.cursor != state.value.len() {
+            return None;
+        }
+
+        state
+            .history
+            .iter()
+            .rev()
+            .map(String::as_str)
+            .chain(self.suggestions.iter().copied())
+            .find(|candidate| {
+                candidate.len() > state.value.len() && candidate.starts_with(&state.value)
+            })
+            .map(|candidate| &candidate[state.value.len()..])
+    }
+
+    fn text<'b>(
+        &self,
+        content: &'b str,
+        renderer: &Renderer,
+    ) -> text::Text<&'b str, Renderer::Font> {
+        text::Text {
+            content,
+            bounds: Size::INFINITE,
+            size: self.size.unwrap_or_else(|| renderer.default_size()),
+            line_height: self.line_height,
+            font: self.font.unwrap_or_else(|| renderer.default_font()),
+            align_x: text::Alignment::Left,
+            align_y: alignment::Vertical::Center,
+            shaping: text::Shaping::Advanced,
+            wrapping: text::Wrapping::None,
+            hint_factor: renderer.scale_factor(),
+        }
+    }
+
+    fn measure(&self, content: &str, renderer: &Renderer) -> f32 {
+        if content.is_empty() {
+            return 0.0;
+        }
+
+        Renderer::Paragraph::with_text(self.text(content, renderer)).min_width()
+    }
+}
+
+impl<Message, Theme, Renderer> Default for Prompt<'_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Default)]
+struct State {
+    value: String,
+    cursor: usize,
+    history: VecDeque<String>,
+    browsing: Option<usize>,
+    draft: String,
+    focus: Option<Focus>,
+}
+
+struct Focus {
+    /// The time of the last change; or `None` until the next redraw.
+    updated_at: Option<Instant>,
+    /// The time since the last change, as of the last redraw.
+    elapsed: Duration,
+}
+
+impl State {
+    fn set_value(&mut self, value: String) {
+        self.cursor = value.len();
+        self.value = value;
+    }
+
+    fn insert(&mut self, text: &str) {
+        self.value.insert_str(self.cursor, text);
+        self.cursor += text.len();
+        self.browsing = None;
+    }
+
+    fn previous(&self, jump: bool) -> usize {
+        let before = &self.value[..self.cursor];
+
+        if jump {
+            before
+                .trim_end()
+                .trim_end_matches(|c: char| !c.is_whitespace())
+                .len()
+        } else {
+            before
+                .char_indices()
+                .next_back()
+                .map_or(0, |(index, _)| index)
+        }
+    }
+
+    fn next(&self, jump: bool) -> usize {
+        let after = &self.value[self.cursor..];
+
+        let offset = if jump {
+            let start = after.len() - after.trim_start().len();
+
+            after[start..]
+                .find(char::is_whitespace)
+                .map_or(after.len(), |index| start + index)
+        } else {
+            after.chars().next().map_or(0, char::len_utf8)
+        };
+
+        self.cursor + offset
+    }
+
+    fn touch(&mut self) {
+        if let Some(focus) = &mut self.focus {
+            focus.updated_at = None;
+            focus.elapsed = Duration::ZERO;
+        }
+    }
+}
+
+impl operation::Focusable for State {
+    fn is_focused(&self) -> bool {
+        self.focus.is_some()
+    }
+
+    fn focus(&mut self) {
+        self.focus = Some(Focus {
+            updated_at: None,
+            elapsed: Duration::ZERO,
+        });
+    }
+
+    fn unfocus(&mut self) {
+        self.focus = None;
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Prompt<'_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        _tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let size = self.size.unwrap_or_else(|| renderer.default_size());
+        let height = self.line_height.to_absolute(size).0 + self.padding.y();
+
+        layout::Node::new(limits.resolve(self.width, Length::Shrink, Size::new(0.0, height)))
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+
+        operation.focusable(self.id.as_ref(), layout.bounds(), state);
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some(position) = cursor.position_over(bounds) else {
+                    state.focus = None;
+                    return;
+                };
+
+                let x = position.x
+                    - bounds.x
+                    - self.padding.left
+                    - self.measure(&self.symbol, renderer);
+
+                let paragraph = Renderer::Paragraph::with_text(self.text(&state.value, renderer));
+
+                let index = if x <= 0.0 {
+                    0
+                } else {
+                    paragraph
+                        .hit_test(Point::new(x, paragraph.min_height() / 2.0))
+                        .map_or(state.value.len(), text::Hit::cursor)
+                        .min(state.value.len())
+                };
+
+                state.cursor = (0..=index)
+                    .rev()
+                    .find(|index| state.value.is_char_boundary(*index))
+                    .unwrap_or(0);
+
+                operation::Focusable::focus(state);
+                shell.request_redraw();
+                shell.capture_event();
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key,
+                modifiers,
+                text,
+                ..
+            }) => {
+                if state.focus.is_none() {
+                    return;
+                }
+
+                match key.as_ref() {
+                    keyboard::Key::Named(key::Named::Enter) => {
+                        let value = std::mem::take(&mut state.value);
+
+                        state.cursor = 0;
+                        state.browsing = None;
+                        state.draft.clear();
+
+                        if !value.trim().is_empty()
+                            && state.history.back() != Some(&value)
+                            && self.history_limit > 0
+                        {
+                            if state.history.len() >= self.history_limit {
+                                let _ = state.history.pop_front();
+                            }
+
+                            state.history.push_back(value.clone());
+                        }
+
+                        if let Some(on_submit) = &self.on_submit {
+                            shell.publish(on_submit(value));
+                        }
+                    }
+                    keyboard::Key::Named(key::Named::ArrowUp) => {
+                        if state.history.is_empty() {
+                            return;
+                        }
+
+                        let index = match state.browsing {
+                            Some(index) => index.saturating_sub(1),
+                            None => {
+                                state.draft = state.value.clone();
+                                state.history.len() - 1
+                            }
+                        };
+
+                        state.browsing = Some(index);
+                        state.set_value(state.history[index].clone());
+                    }
+                    keyboard::Key::Named(key::Named::ArrowDown) => {
+                        let Some(index) = state.browsing else {
+                            return;
+                        };
+
+                        if index + 1 < state.history.len() {
+                            state.browsing = Some(index + 1);
+                            state.set_value(state.history[index + 1].clone());
+                        } else {
+                            state.browsing = None;
+                            let draft = std::mem::take(&mut state.draft);
+                            state.set_value(draft);
+                        }
+                    }
+                    keyboard::Key::Named(key::Named::Tab) => {
+                        let Some(suggestion) = self.suggestion(state).map(str::to_owned) else {
+                            return;
+                        };
+
+                        state.insert(&suggestion);
+                    }
+                    keyboard::Key::Named(key::Named::ArrowRight) => {
+                        if state.cursor == state.value.len() {
+                            let Some(suggestion) = self.suggestion(state).map(str::to_owned) else {
+                                return;
+                            };
+
+                            state.insert(&suggestion);
+                        } else {
+                            state.cursor = state.next(modifiers.jump());
+                        }
+                    }
+                    keyboard::Key::Named(key::Named::ArrowLeft) => {
+                        state.cursor = state.previous(modifiers.jump());
+                    }
+                    keyboard::Key::Named(key::Named::Home) => {
+                        state.cursor = 0;
+                    }
+                    keyboard::Key::Named(key::Named::End) => {
+                        state.cursor = state.value.len();
+                    }
+                    keyboard::Key::Named(key::Named::Backspace) => {
+                        let start = state.previous(modifiers.jump());
+
+                        let _ = state.value.drain(start..state.cursor);
+                        state.cursor = start;
+                        state.browsing = None;
+                    }
+                    keyboard::Key::Named(key::Named::Delete) => {
+                        let end = state.next(modifiers.jump());
+
+                        let _ = state.value.drain(state.cursor..end);
+                        state.browsing = None;
+                    }
+                    keyboard::Key::Named(key::Named::Escape) => {
+                        if state.value.is_empty() {
+                            state.focus = None;
+                        } else {
+                            state.set_value(String::new());
+                            state.browsing = None;
+                        }
+                    }
+                    keyboard::Key::Character("v") if modifiers.command() => {
+                        let Some(contents) = clipboard.read(clipboard::Kind::Standard) else {
+                            return;
+                        };
+
+                        let contents: String =
+                            contents.chars().filter(|c| !c.is_control()).collect();
+
+                        state.insert(&contents);
+                    }
+                    _ => {
+                        let Some(text) = text.as_deref() else {
+                            return;
+                        };
+
+                        if modifiers.command() || text.chars().any(char::is_control) {
+                            return;
+                        }
+
+                        state.insert(text);
+                    }
+                }
+
+                state.touch();
+                shell.request_redraw();
+                shell.capture_event();
+            }
+            Event::Window(window::Event::RedrawRequested(now)) => {
+                let Some(focus) = &mut state.focus else {
+                    return;
+                };
+
+                let updated_at = *focus.updated_at.get_or_insert(*now);
+                focus.elapsed = now.saturating_duration_since(updated_at);
+
+                let elapsed = focus.elapsed.as_millis();
+                let interval = CURSOR_BLINK_INTERVAL.as_millis();
+
+                shell.request_redraw_at(
+                    *now + Duration::from_millis((interval - elapsed % interval) as u64),
+                );
+            }
+            Event::Window(window::Event::Unfocused) => {
+                state.focus = None;
+            }
+            _ => {}
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Text
+        } else {
+            mouse::Interaction::None
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _defaults: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let content = bounds.shrink(self.padding);
+
+        let status = if state.focus.is_some() {
+            Status::Focused
+        } else if cursor.is_over(bounds) {
+            Status::Hovered
+        } else {
+            Status::Active
+        };
+
+        let style = theme.style(&self.class, status);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: style.border,
+                ..renderer::Quad::default()
+            },
+            style.background,
+        );
+
+        let Some(clip) = content.intersection(viewport) else {
+            return;
+        };
+
+        let symbol_width = self.measure(&self.symbol, renderer);
+        let cursor_x = symbol_width + self.measure(&state.value[..state.cursor], renderer);
+
+        // Scroll horizontally to keep the cursor in view
+        let scroll = (cursor_x + 1.0 - content.width).max(0.0);
+        let y = content.center_y();
+
+        let fill = |renderer: &mut Renderer, content: &str, x: f32, color: Color| {
+            renderer.fill_text(
+                self.text(content, renderer)
+                    .with_content(content.to_owned()),
+                Point::new(x, y),
+                color,
+                clip,
+            );
+        };
+
+        renderer.with_layer(clip, |renderer| {
+            let x = content.x - scroll;
+
+            fill(renderer, &self.symbol, x, style.symbol);
+
+            if state.value.is_empty() {
+                fill(
+                    renderer,
+                    &self.placeholder,
+                    x + symbol_width,
+                    style.placeholder,
+                );
+            } else {
+                fill(renderer, &state.value, x + symbol_width, style.value);
+            }
+
+            if let Some(suggestion) = self.suggestion(state) {
+                fill(
+                    renderer,
+                    suggestion,
+                    x + symbol_width + self.measure(&state.value, renderer),
+                    style.suggestion,
+                );
+            }
+
+            let Some(focus) = &state.focus else {
+                return;
+            };
+
+            let is_visible =
+                (focus.elapsed.as_millis() / CURSOR_BLINK_INTERVAL.as_millis()) % 2 == 0;
+
+            if is_visible {
+                let height = self
+                    .line_height
+                    .to_absolute(self.size.unwrap_or_else(|| renderer.default_size()));
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: x + cursor_x,
+                            y: y - height.0 / 2.0,
+                            width: 1.0,
+                            height: height.0,
+                        },
+                        ..renderer::Quad::default()
+                    },
+                    style.cursor,
+                );
+            }
+        });
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Prompt<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(prompt: Prompt<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(prompt)
+    }
+}
+
+/// The possible status of a [`Prompt`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The [`Prompt`] can be interacted with.
+    Active,
+    /// The [`Prompt`] is being hovered.
+    Hovered,
+    /// The [`Prompt`] is focused.
+    Focused,
+}
+
+/// The appearance of a [`Prompt`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Background`] of the prompt.
+    pub background: Background,
+    /// The [`Border`] of the prompt.
+    pub border: Border,
+    /// The [`Color`] of the symbol before the input.
+    pub symbol: Color,
+    /// The [`Color`] of the input.
+    pub value: Color,
+    /// The [`Color`] of the placeholder.
+    pub placeholder: Color,
+    /// The [`Color`] of the ghost suggestion.
+    pub suggestion: Color,
+    /// The [`Color`] of the cursor.
+    pub cursor: Color,
+}
+
+/// The theme catalog of a [`Prompt`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class with the given status.
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style;
+}
+
+/// A styling function for a [`Prompt`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
+        class(self, status)
+    }
+}
+
+/// The default style of a [`Prompt`].
+pub fn default(theme: &Theme, status: Status) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        background: palette.background.weakest.color.into(),
+        border: Border::default().rounded(4).width(1).color(match status {
+            Status::Active => palette.background.weak.color,
+            Status::Hovered => palette.background.strong.color,
+            Status::Focused => palette.primary.strong.color,
+        }),
+        symbol: palette.primary.base.color,
+        value: palette.background.base.text,
+        placeholder: palette.background.strong.color,
+        suggestion: palette.background.strong.color,
+        cursor: palette.background.base.text,
+    }
+}
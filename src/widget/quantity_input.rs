@@ -0,0 +1,1002 @@
+use crate::core::alignment;
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::text::{self, Paragraph as _};
+use crate::core::touch;
+use crate::core::widget::operation::{self, Operation};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::{Id, Widget};
+use crate::core::{
+    Background, Border, Clipboard, Color, Element, Event, Length, Padding, Pixels, Point,
+    Rectangle, Shell, Size, Theme, Vector,
+};
+
+use std::fmt;
+use std::ops::RangeInclusive;
+
+/// The width of the chevron next to the unit.
+const CHEVRON: f32 = 7.0;
+
+/// A numeric input that understands units, like `12 px` or `30%`.
+///
+/// Typing a value with a different unit switches to it. The unit can also
+/// be picked from a dropdown or cycled by scrolling over it.
+pub struct QuantityInput<'a, U, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    id: Option<Id>,
+    value: f32,
+    unit: U,
+    units: &'a [U],
+    ranges: Vec<(U, RangeInclusive<f32>)>,
+    on_change: Option<Box<dyn Fn(f32, U) -> Message + 'a>>,
+    step: f32,
+    precision: usize,
+    width: Length,
+    padding: Padding,
+    text_size: Option<Pixels>,
+    font: Option<Renderer::Font>,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, U, Message, Theme, Renderer> QuantityInput<'a, U, Message, Theme, Renderer>
+where
+    U: Clone + PartialEq + fmt::Display,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    pub fn new(units: &'a [U], value: f32, unit: U) -> Self {
+        Self {
+            id: None,
+            value,
+            unit,
+            units,
+            ranges: Vec::new(),
+            on_change: None,
+            step: 1.0,
+            precision: 2,
+            width: Length::Fixed(140.0),
+            padding: Padding::from([5, 8]),
+            text_size: None,
+            font: None,
+            class: Theme::default(),
+        }
+    }
+
+    pub fn id(mut self, id: impl Into<Id>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Sets the message produced when the value or the unit changes.
+    pub fn on_change(mut self, on_change: impl Fn(f32, U) -> Message + 'a) -> Self {
+        self.on_change = Some(Box::new(on_change));
+        self
+    }
+
+    /// Limits the values of the given unit to a range.
+    pub fn range(mut self, unit: U, range: RangeInclusive<f32>) -> Self {
+        self.ranges.retain(|(other, _)| *other != unit);
+        self.ranges.push((unit, range));
+        self
+    }
+
+    /// Sets the amount the arrow keys add or subtract. Holding Shift
+    /// multiplies it by ten.
+    pub fn step(mut self, step: f32) -> Self {
+        self.step = step;
+        self
+    }
+
+    /// Sets the maximum amount of decimals displayed.
+    pub fn precision(mut self, precision: usize) -> Self {
+        self.precision = precision;
+        self
+    }
+
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    pub fn padding(mut self, padding: impl Into<Padding>) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    pub fn text_size(mut self, text_size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(text_size.into());
+        self
+    }
+
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    fn clamp(&self, value: f32, unit: &U) -> f32 {
+        self.ranges
+            .iter()
+            .find(|(other, _)| other == unit)
+            .map_or(value, |(_, range)| {
+                value.clamp(*range.start(), *range.end())
+            })
+    }
+
+    fn change(&self, value: f32, unit: U, shell: &mut Shell<'_, Message>) {
+        if let Some(on_change) = &self.on_change {
+            shell.publish(on_change(self.clamp(value, &unit), unit));
+        }
+    }
+
+    /// Parses the typed text and produces a change, if valid.
+    fn commit(&self, state: &mut State, shell: &mut Shell<'_, Message>) -> bool {
+        let Some(typed) = state.typed.take() else {
+            return true;
+        };
+
+        match parse(&typed, self.units) {
+            Some((value, unit)) => {
+                let unit =
+                    unit.map_or_else(|| self.unit.clone(), |index| self.units[index].clone());
+
+                self.change(value, unit, shell);
+
+                true
+            }
+            None => {
+                state.typed = Some(typed);
+
+                false
+            }
+        }
+    }
+
+    fn cycle(&self, delta: isize, shell: &mut Shell<'_, Message>) {
+        if self.units.is_empty() {
+            return;
+        }
+
+        let current = self
+            .units
+            .iter()
+            .position(|unit| *unit == self.unit)
+            .unwrap_or(0);
+
+        let index = (current as isize + delta).rem_euclid(self.units.len() as isize) as usize;
+
+        self.change(self.value, self.units[index].clone(), shell);
+    }
+
+    fn unit_area(&self, bounds: Rectangle, state: &State) -> Rectangle {
+        let width = state.unit_width + CHEVRON + self.padding.x() + 4.0;
+
+        Rectangle {
+            x: bounds.x + bounds.width - width,
+            width,
+            ..bounds
+        }
+    }
+
+    fn text(
+        &self,
+        content: impl Into<String>,
+        bounds: Size,
+        renderer: &Renderer,
+    ) -> text::Text<String, Renderer::Font> {
+        text::Text {
+            content: content.into(),
+            bounds,
+            size: self.text_size.unwrap_or_else(|| renderer.default_size()),
+            line_height: text::LineHeight::default(),
+            font: self.font.unwrap_or_else(|| renderer.default_font()),
+            align_x: text::Alignment::Left,
+            align_y: alignment::Vertical::Center,
+            shaping: text::Shaping::Basic,
+            wrapping: text::Wrapping::None,
+            hint_factor: renderer.scale_factor(),
+        }
+    }
+
+    fn line_height(&self, renderer: &Renderer) -> f32 {
+        let size = self.text_size.unwrap_or_else(|| renderer.default_size());
+
+        text::LineHeight::default().to_absolute(size).0
+    }
+}
+
+/// Parses a number followed by an optional unit, returning the index of
+/// the unit.
+fn parse<U: fmt::Display>(input: &str, units: &[U]) -> Option<(f32, Option<usize>)> {
+    let input = input.trim();
+
+    let split = input
+        .find(|c: char| !(c.is_ascii_digit() || matches!(c, '.' | '-' | '+')))
+        .unwrap_or(input.len());
+
+    let (number, unit) = input.split_at(split);
+    let value: f32 = number.trim().parse().ok()?;
+    let unit = unit.trim();
+
+    if !value.is_finite() {
+        return None;
+    }
+
+    if unit.is_empty() {
+        return Some((value, None));
+    }
+
+    let index = units
+        .iter()
+        .position(|candidate| candidate.to_string().eq_ignore_ascii_case(unit))?;
+
+    Some((value, Some(index)))
+}
+
+/// Formats a value with at most the given decimals.
+fn format(value: f32, precision: usize) -> String {
+    let formatted = format!("{value:.precision$}");
+
+    let formatted = if formatted.contains('.') {
+        formatted.trim_end_matches('0').trim_end_matches('.')
+    } else {
+        &formatted
+    };
+
+    if formatted == "-0" {
+        String::from("0")
+    } else {
+        formatted.to_owned()
+    }
+}
+
+#[derive(Default)]
+struct State {
+    is_focused: bool,
+    typed: Option<String>,
+    is_open: bool,
+    scroll: f32,
+    unit_width: f32,
+}
+
+impl operation::Focusable for State {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+        self.typed = None;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+        self.typed = None;
+    }
+}
+
+impl<U, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for QuantityInput<'_, U, Message, Theme, Renderer>
+where
+    U: Clone + PartialEq + fmt::Display,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let state = tree.state.downcast_mut::<State>();
+
+        state.unit_width = self
+            .units
+            .iter()
+            .chain(std::iter::once(&self.unit))
+            .map(|unit| {
+                let label = self.text(unit.to_string(), Size::INFINITE, renderer);
+
+                Renderer::Paragraph::with_text(label.as_ref())
+                    .min_bounds()
+                    .width
+            })
+            .fold(0.0, f32::max);
+
+        let height = self.line_height(renderer) + self.padding.y();
+
+        layout::Node::new(limits.resolve(
+            self.width,
+            Length::Shrink,
+            Size::new(state.unit_width + self.padding.x() * 2.0 + 60.0, height),
+        ))
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+
+        operation.focusable(self.id.as_ref(), layout.bounds(), state);
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        if self.on_change.is_none() {
+            return;
+        }
+
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+        let unit_area = self.unit_area(bounds, state);
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if cursor.is_over(unit_area) {
+                    let _ = self.commit(state, shell);
+
+                    state.typed = None;
+                    state.is_open = !state.is_open;
+
+                    shell.capture_event();
+                    shell.request_redraw();
+                } else if cursor.is_over(bounds) {
+                    if !state.is_focused {
+                        state.is_focused = true;
+                        state.typed = None;
+                    }
+
+                    shell.capture_event();
+                    shell.request_redraw();
+                } else if state.is_focused {
+                    // Invalid input is discarded when leaving
+                    let _ = self.commit(state, shell);
+
+                    state.is_focused = false;
+                    state.typed = None;
+
+                    shell.request_redraw();
+                }
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) if cursor.is_over(unit_area) => {
+                state.scroll += match delta {
+                    mouse::ScrollDelta::Lines { y, .. } => *y,
+                    mouse::ScrollDelta::Pixels { y, .. } => *y / bounds.height,
+                };
+
+                let steps = state.scroll.trunc();
+
+                if steps != 0.0 {
+                    state.scroll -= steps;
+
+                    self.cycle(-steps as isize, shell);
+                }
+
+                shell.capture_event();
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key,
+                text,
+                modifiers,
+                ..
+            }) if state.is_focused => {
+                match key.as_ref() {
+                    keyboard::Key::Named(key::Named::Enter) => {
+                        if self.commit(state, shell) {
+                            state.is_focused = false;
+                        }
+                    }
+                    keyboard::Key::Named(key::Named::Escape) => {
+                        state.is_focused = false;
+                        state.typed = None;
+                    }
+                    keyboard::Key::Named(key::Named::Tab) => {
+                        let _ = self.commit(state, shell);
+
+                        state.is_focused = false;
+                        state.typed = None;
+
+                        shell.request_redraw();
+                        return;
+                    }
+                    keyboard::Key::Named(named @ (key::Named::ArrowUp | key::Named::ArrowDown)) => {
+                        let (value, unit) = state
+                            .typed
+                            .as_deref()
+                            .and_then(|typed| parse(typed, self.units))
+                            .map_or((self.value, self.unit.clone()), |(value, unit)| {
+                                (
+                                    value,
+                                    unit.map_or_else(
+                                        || self.unit.clone(),
+                                        |index| self.units[index].clone(),
+                                    ),
+                                )
+                            });
+
+                        let step = if modifiers.shift() {
+                            self.step * 10.0
+                        } else {
+                            self.step
+                        };
+
+                        let delta = if named == key::Named::ArrowUp {
+                            step
+                        } else {
+                            -step
+                        };
+
+                        state.typed = None;
+
+                        self.change(value + delta, unit, shell);
+                    }
+                    keyboard::Key::Named(key::Named::Backspace) => {
+                        // The whole value is selected until something is typed
+                        let _ = state.typed.get_or_insert_with(String::new).pop();
+                    }
+                    _ => {
+                        let Some(text) = text
+                            .as_deref()
+                            .filter(|_| !modifiers.command())
+                            .filter(|text| text.chars().all(|c| !c.is_control()))
+                        else {
+                            return;
+                        };
+
+                        state.typed.get_or_insert_with(String::new).push_str(text);
+                    }
+                }
+
+                shell.capture_event();
+                shell.request_redraw();
+            }
+            _ => {}
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+
+        if self.on_change.is_none() {
+            mouse::Interaction::None
+        } else if cursor.is_over(self.unit_area(bounds, state)) {
+            mouse::Interaction::Pointer
+        } else if cursor.is_over(bounds) {
+            mouse::Interaction::Text
+        } else {
+            mouse::Interaction::None
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _defaults: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let unit_area = self.unit_area(bounds, state);
+
+        let status = if self.on_change.is_none() {
+            Status::Disabled
+        } else if state
+            .typed
+            .as_deref()
+            .is_some_and(|typed| parse(typed, self.units).is_none())
+        {
+            Status::Invalid
+        } else if state.is_focused || state.is_open {
+            Status::Focused
+        } else if cursor.is_over(bounds) {
+            Status::Hovered
+        } else {
+            Status::Active
+        };
+
+        let style = theme.style(&self.class, status);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: style.border,
+                ..renderer::Quad::default()
+            },
+            style.background,
+        );
+
+        // Value
+        let value = format(self.value, self.precision);
+        let content = state.typed.as_deref().unwrap_or(&value);
+
+        let field = Rectangle {
+            x: bounds.x + self.padding.left,
+            y: bounds.y,
+            width: unit_area.x - bounds.x - self.padding.left,
+            height: bounds.height,
+        };
+
+        let text = self.text(content, field.size(), renderer);
+        let text_width = Renderer::Paragraph::with_text(text.as_ref())
+            .min_bounds()
+            .width;
+        let line_height = self.line_height(renderer);
+
+        if state.is_focused {
+            let (x, width) = if state.typed.is_some() {
+                (field.x + text_width.min(field.width - 1.0), 1.0)
+            } else {
+                (field.x, text_width.min(field.width))
+            };
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x,
+                        y: field.center_y() - line_height / 2.0,
+                        width,
+                        height: line_height,
+                    },
+                    ..renderer::Quad::default()
+                },
+                if state.typed.is_some() {
+                    style.text_color
+                } else {
+                    style.selection
+                },
+            );
+        }
+
+        renderer.fill_text(
+            text,
+            Point::new(field.x, field.center_y()),
+            style.text_color,
+            field.intersection(viewport).unwrap_or(field),
+        );
+
+        // Unit
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    x: unit_area.x,
+                    y: unit_area.y + 4.0,
+                    width: 1.0,
+                    height: unit_area.height - 8.0,
+                },
+                ..renderer::Quad::default()
+            },
+            style.divider,
+        );
+
+        let unit_color = if cursor.is_over(unit_area) && status != Status::Disabled {
+            style.text_color
+        } else {
+            style.unit_color
+        };
+
+        renderer.fill_text(
+            self.text(self.unit.to_string(), unit_area.size(), renderer),
+            Point::new(unit_area.x + self.padding.left, unit_area.center_y()),
+            unit_color,
+            *viewport,
+        );
+
+        chevron(
+            renderer,
+            Point::new(
+                unit_area.x + unit_area.width - self.padding.right - CHEVRON / 2.0,
+                unit_area.center_y(),
+            ),
+            unit_color,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        _renderer: &Renderer,
+        _viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let state = tree.state.downcast_mut::<State>();
+
+        if !state.is_open || self.units.is_empty() {
+            return None;
+        }
+
+        let bounds = layout.bounds() + translation;
+        let target = self.unit_area(bounds, state);
+
+        Some(overlay::Element::new(Box::new(Menu {
+            input: self,
+            state,
+            target,
+        })))
+    }
+}
+
+impl<'a, U, Message, Theme, Renderer> From<QuantityInput<'a, U, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    U: Clone + PartialEq + fmt::Display + 'a,
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(quantity_input: QuantityInput<'a, U, Message, Theme, Renderer>) -> Self {
+        Element::new(quantity_input)
+    }
+}
+
+/// Draws a small triangle pointing down, centered at the given point.
+fn chevron<Renderer>(renderer: &mut Renderer, center: Point, color: Color)
+where
+    Renderer: renderer::Renderer,
+{
+    let rows = (CHEVRON / 2.0).ceil() as usize;
+
+    for row in 0..rows {
+        let width = CHEVRON - row as f32 * 2.0;
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    x: center.x - width / 2.0,
+                    y: center.y - rows as f32 / 2.0 + row as f32,
+                    width,
+                    height: 1.0,
+                },
+                ..renderer::Quad::default()
+            },
+            color,
+        );
+    }
+}
+
+/// The dropdown of units of a [`QuantityInput`].
+struct Menu<'a, 'b, U, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    input: &'b QuantityInput<'a, U, Message, Theme, Renderer>,
+    state: &'b mut State,
+    target: Rectangle,
+}
+
+impl<U, Message, Theme, Renderer> Menu<'_, '_, U, Message, Theme, Renderer>
+where
+    U: Clone + PartialEq + fmt::Display,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn item_height(&self, renderer: &Renderer) -> f32 {
+        self.input.line_height(renderer) + self.input.padding.y()
+    }
+
+    fn item_at(
+        &self,
+        bounds: Rectangle,
+        renderer: &Renderer,
+        cursor: mouse::Cursor,
+    ) -> Option<usize> {
+        let position = cursor.position_in(bounds)?;
+        let index = ((position.y - 4.0) / self.item_height(renderer)) as usize;
+
+        (position.y >= 4.0 && index < self.input.units.len()).then_some(index)
+    }
+}
+
+impl<U, Message, Theme, Renderer> overlay::Overlay<Message, Theme, Renderer>
+    for Menu<'_, '_, U, Message, Theme, Renderer>
+where
+    U: Clone + PartialEq + fmt::Display,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> layout::Node {
+        let size = Size::new(
+            (self.state.unit_width + self.input.padding.x() + 16.0).max(self.target.width),
+            self.item_height(renderer) * self.input.units.len() as f32 + 8.0,
+        );
+
+        let below = self.target.y + self.target.height + 4.0;
+
+        let y = if below + size.height > bounds.height && self.target.y - size.height - 4.0 >= 0.0 {
+            self.target.y - size.height - 4.0
+        } else {
+            below
+        };
+
+        let x = (self.target.x + self.target.width - size.width)
+            .min(bounds.width - size.width)
+            .max(0.0);
+
+        layout::Node::new(size).move_to(Point::new(x, y))
+    }
+
+    fn update(
+        &mut self,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if let Some(index) = self.item_at(bounds, renderer, cursor) {
+                    self.input
+                        .change(self.input.value, self.input.units[index].clone(), shell);
+
+                    self.state.is_open = false;
+
+                    shell.capture_event();
+                    shell.request_redraw();
+                } else if cursor.is_over(bounds) {
+                    shell.capture_event();
+                } else if !cursor.is_over(self.target) {
+                    // The unit button toggles the menu by itself
+                    self.state.is_open = false;
+
+                    shell.request_redraw();
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. }) if cursor.is_over(bounds) => {
+                shell.request_redraw();
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key::Named::Escape),
+                ..
+            }) => {
+                self.state.is_open = false;
+
+                shell.capture_event();
+                shell.request_redraw();
+            }
+            _ => {}
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if self.item_at(layout.bounds(), renderer, cursor).is_some() {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::None
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) {
+        let style = theme.style(&self.input.class, Status::Focused);
+        let bounds = layout.bounds();
+        let item_height = self.item_height(renderer);
+        let hovered = self.item_at(bounds, renderer, cursor);
+
+        renderer.with_layer(bounds, |renderer| {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border: style.menu_border,
+                    ..renderer::Quad::default()
+                },
+                style.menu_background,
+            );
+
+            for (index, unit) in self.input.units.iter().enumerate() {
+                let item = Rectangle {
+                    x: bounds.x + 4.0,
+                    y: bounds.y + 4.0 + index as f32 * item_height,
+                    width: bounds.width - 8.0,
+                    height: item_height,
+                };
+
+                if hovered == Some(index) {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: item,
+                            border: Border::default().rounded(4),
+                            ..renderer::Quad::default()
+                        },
+                        style.menu_hovered,
+                    );
+                }
+
+                if *unit == self.input.unit {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: Rectangle {
+                                x: item.x + 2.0,
+                                y: item.y + item.height * 0.25,
+                                width: 2.0,
+                                height: item.height * 0.5,
+                            },
+                            border: Border::default().rounded(1),
+                            ..renderer::Quad::default()
+                        },
+                        style.selection,
+                    );
+                }
+
+                renderer.fill_text(
+                    self.input.text(unit.to_string(), item.size(), renderer),
+                    Point::new(item.x + self.input.padding.left, item.center_y()),
+                    style.text_color,
+                    bounds,
+                );
+            }
+        });
+    }
+}
+
+/// The possible status of a [`QuantityInput`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The [`QuantityInput`] can be interacted with.
+    Active,
+    /// The [`QuantityInput`] is being hovered.
+    Hovered,
+    /// The [`QuantityInput`] is focused or its units are open.
+    Focused,
+    /// The typed text is not a valid quantity.
+    Invalid,
+    /// The [`QuantityInput`] cannot be interacted with.
+    Disabled,
+}
+
+/// The appearance of a [`QuantityInput`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Background`] of the input.
+    pub background: Background,
+    /// The [`Border`] of the input.
+    pub border: Border,
+    /// The [`Color`] of the value and the units of the menu.
+    pub text_color: Color,
+    /// The [`Color`] of the unit.
+    pub unit_color: Color,
+    /// The [`Color`] of the selected value and the current unit.
+    pub selection: Color,
+    /// The [`Color`] of the line between the value and the unit.
+    pub divider: Color,
+    /// The [`Background`] of the menu of units.
+    pub menu_background: Background,
+    /// The [`Border`] of the menu of units.
+    pub menu_border: Border,
+    /// The [`Background`] of a hovered unit of the menu.
+    pub menu_hovered: Background,
+}
+
+/// The theme catalog of a [`QuantityInput`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class with the given status.
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style;
+}
+
+/// A styling function for a [`QuantityInput`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
+        class(self, status)
+    }
+}
+
+/// The default style of a [`QuantityInput`].
+pub fn default(theme: &Theme, status: Status) -> Style {
+    let palette = theme.extended_palette();
+
+    let border_color = match status {
+        Status::Active | Status::Disabled => palette.background.strong.color,
+        Status::Hovered => palette.background.strongest.color,
+        Status::Focused => palette.primary.strong.color,
+        Status::Invalid => palette.danger.base.color,
+    };
+
+    Style {
+        background: if status == Status::Disabled {
+            palette.background.weak.color.into()
+        } else {
+            palette.background.base.color.into()
+        },
+        border: Border::default().rounded(4).width(1).color(border_color),
+        text_color: if status == Status::Disabled {
+            palette.background.strongest.color
+        } else {
+            palette.background.base.text
+        },
+        unit_color: palette.background.strongest.color,
+        selection: palette.primary.weak.color,
+        divider: palette.background.strong.color,
+        menu_background: palette.background.base.color.into(),
+        menu_border: Border::default()
+            .rounded(6)
+            .width(1)
+            .color(palette.background.strong.color),
+        menu_hovered: palette.background.weak.color.into(),
+    }
+}
@@ -0,0 +1,516 @@
+use crate::core::keyboard;
+use crate::core::keyboard::key::{self, Key};
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::touch;
+use crate::core::widget::Widget;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::window;
+use crate::core::{
+    self, Background, Border, Clipboard, Element, Event, Length, Pixels, Point, Rectangle, Shell,
+    Size, Theme,
+};
+
+use std::ops::RangeInclusive;
+
+/// A slider with two handles that selects a range of values.
+///
+/// The arrow keys move the last grabbed handle while hovered; Shift uses
+/// the shift step, if set.
+pub struct RangeSlider<'a, T, Message, Theme = core::Theme>
+where
+    Theme: Catalog,
+{
+    range: RangeInclusive<T>,
+    values: (T, T),
+    step: T,
+    shift_step: Option<T>,
+    on_change: Box<dyn Fn((T, T)) -> Message + 'a>,
+    on_release: Option<Message>,
+    width: Length,
+    height: f32,
+    class: Theme::Class<'a>,
+    status: Option<Status>,
+}
+
+impl<'a, T, Message, Theme> RangeSlider<'a, T, Message, Theme>
+where
+    T: Copy + From<u8> + PartialOrd,
+    Theme: Catalog,
+{
+    pub fn new(
+        range: RangeInclusive<T>,
+        (low, high): (T, T),
+        on_change: impl Fn((T, T)) -> Message + 'a,
+    ) -> Self {
+        let clamp = |value: T| {
+            if value < *range.start() {
+                *range.start()
+            } else if value > *range.end() {
+                *range.end()
+            } else {
+                value
+            }
+        };
+
+        let (low, high) = if low <= high {
+            (clamp(low), clamp(high))
+        } else {
+            (clamp(high), clamp(low))
+        };
+
+        Self {
+            range,
+            values: (low, high),
+            step: T::from(1),
+            shift_step: None,
+            on_change: Box::new(on_change),
+            on_release: None,
+            width: Length::Fill,
+            height: 16.0,
+            class: Theme::default(),
+            status: None,
+        }
+    }
+
+    /// Sets the step that values snap to.
+    pub fn step(mut self, step: impl Into<T>) -> Self {
+        self.step = step.into();
+        self
+    }
+
+    /// Sets the step used while holding Shift.
+    pub fn shift_step(mut self, shift_step: impl Into<T>) -> Self {
+        self.shift_step = Some(shift_step.into());
+        self
+    }
+
+    /// Sets the message produced when a handle is released.
+    pub fn on_release(mut self, on_release: Message) -> Self {
+        self.on_release = Some(on_release);
+        self
+    }
+
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    pub fn height(mut self, height: impl Into<Pixels>) -> Self {
+        self.height = height.into().0;
+        self
+    }
+
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+}
+
+/// One of the handles of a [`RangeSlider`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+enum Handle {
+    #[default]
+    Low,
+    High,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+struct State {
+    dragging: Option<Handle>,
+    active: Handle,
+    modifiers: keyboard::Modifiers,
+}
+
+impl<'a, T, Message, Theme> RangeSlider<'a, T, Message, Theme>
+where
+    T: Copy + PartialOrd + Into<f64> + num_traits::FromPrimitive,
+    Theme: Catalog,
+{
+    fn step_for(&self, state: &State) -> f64 {
+        if state.modifiers.shift() {
+            self.shift_step.unwrap_or(self.step)
+        } else {
+            self.step
+        }
+        .into()
+    }
+
+    /// Returns the horizontal span the handles travel along, leaving room
+    /// for them at both ends.
+    fn track(&self, bounds: Rectangle) -> (f32, f32) {
+        let inset = bounds.height / 2.0;
+
+        (bounds.x + inset, (bounds.width - inset * 2.0).max(0.0))
+    }
+
+    fn ratio(&self, value: T) -> f32 {
+        let start = (*self.range.start()).into();
+        let end = (*self.range.end()).into();
+
+        if start >= end {
+            0.0
+        } else {
+            ((value.into() - start) / (end - start)) as f32
+        }
+    }
+
+    fn locate(&self, state: &State, bounds: Rectangle, position: Point) -> Option<T> {
+        let (x, width) = self.track(bounds);
+
+        let start = (*self.range.start()).into();
+        let end = (*self.range.end()).into();
+        let step = self.step_for(state);
+
+        let percent = if width > 0.0 {
+            f64::from(((position.x - x) / width).clamp(0.0, 1.0))
+        } else {
+            0.0
+        };
+
+        let steps = (percent * (end - start) / step).round();
+
+        T::from_f64((steps * step + start).clamp(start, end))
+    }
+
+    fn nudge(&self, state: &State, value: T, direction: f64) -> Option<T> {
+        let step = self.step_for(state);
+        let start = (*self.range.start()).into();
+        let end = (*self.range.end()).into();
+
+        let steps = ((value.into() - start) / step).round();
+
+        T::from_f64((start + step * (steps + direction)).clamp(start, end))
+    }
+
+    /// Moves the given handle, keeping it on its side of the other one.
+    fn change(&mut self, handle: Handle, value: T, shell: &mut Shell<'_, Message>) {
+        let (low, high) = self.values;
+
+        let values = match handle {
+            Handle::Low if value > high => (high, high),
+            Handle::Low => (value, high),
+            Handle::High if value < low => (low, low),
+            Handle::High => (low, value),
+        };
+
+        if values.0.into() != low.into() || values.1.into() != high.into() {
+            self.values = values;
+            shell.publish((self.on_change)(values));
+        }
+    }
+}
+
+impl<T, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for RangeSlider<'_, T, Message, Theme>
+where
+    T: Copy + PartialOrd + Into<f64> + num_traits::FromPrimitive,
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: core::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        layout::atomic(limits, self.width, self.height)
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some(position) = cursor.position_over(bounds) else {
+                    return;
+                };
+
+                let (x, width) = self.track(bounds);
+                let low = x + width * self.ratio(self.values.0);
+                let high = x + width * self.ratio(self.values.1);
+
+                // Overlapping handles are told apart by the side of the cursor
+                let handle = if (position.x - low).abs() < (position.x - high).abs()
+                    || (low == high && position.x < low)
+                {
+                    Handle::Low
+                } else {
+                    Handle::High
+                };
+
+                state.dragging = Some(handle);
+                state.active = handle;
+
+                if let Some(value) = self.locate(state, bounds, position) {
+                    self.change(handle, value, shell);
+                }
+
+                shell.capture_event();
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                if let Some(handle) = state.dragging {
+                    if let Some(value) = cursor
+                        .land()
+                        .position()
+                        .and_then(|position| self.locate(state, bounds, position))
+                    {
+                        self.change(handle, value, shell);
+                    }
+
+                    shell.capture_event();
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. }) => {
+                let was_dragging = state.dragging.take().is_some();
+
+                if let Some(on_release) = self.on_release.clone().filter(|_| was_dragging) {
+                    shell.publish(on_release);
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed { key, .. }) if cursor.is_over(bounds) => {
+                let direction = match key {
+                    Key::Named(key::Named::ArrowRight | key::Named::ArrowUp) => 1.0,
+                    Key::Named(key::Named::ArrowLeft | key::Named::ArrowDown) => -1.0,
+                    _ => return,
+                };
+
+                let value = match state.active {
+                    Handle::Low => self.values.0,
+                    Handle::High => self.values.1,
+                };
+
+                if let Some(value) = self.nudge(state, value, direction) {
+                    self.change(state.active, value, shell);
+                }
+
+                shell.capture_event();
+            }
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                state.modifiers = *modifiers;
+            }
+            _ => {}
+        }
+
+        let status = if state.dragging.is_some() {
+            Status::Dragged
+        } else if cursor.is_over(bounds) {
+            Status::Hovered
+        } else {
+            Status::Active
+        };
+
+        if let Event::Window(window::Event::RedrawRequested(_)) = event {
+            self.status = Some(status);
+        } else if self.status.is_some_and(|current| current != status) {
+            shell.request_redraw();
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+
+        if state.dragging.is_some() {
+            mouse::Interaction::Grabbing
+        } else if cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Grab
+        } else {
+            mouse::Interaction::None
+        }
+    }
+
+    fn draw(
+        &self,
+        _tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _defaults: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let style = theme.style(&self.class, self.status.unwrap_or(Status::Active));
+
+        let radius = style.handle_radius;
+        let (x, width) = self.track(bounds);
+        let low = x + width * self.ratio(self.values.0);
+        let high = x + width * self.ratio(self.values.1);
+        let rail_y = bounds.center_y() - style.rail_width / 2.0;
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    x: bounds.x,
+                    y: rail_y,
+                    width: bounds.width,
+                    height: style.rail_width,
+                },
+                border: Border::default().rounded(style.rail_width / 2.0),
+                ..renderer::Quad::default()
+            },
+            style.rail,
+        );
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    x: low,
+                    y: rail_y,
+                    width: high - low,
+                    height: style.rail_width,
+                },
+                ..renderer::Quad::default()
+            },
+            style.range,
+        );
+
+        for center in [low, high] {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: center - radius,
+                        y: bounds.center_y() - radius,
+                        width: radius * 2.0,
+                        height: radius * 2.0,
+                    },
+                    border: style.handle_border.rounded(radius),
+                    ..renderer::Quad::default()
+                },
+                style.handle,
+            );
+        }
+    }
+}
+
+impl<'a, T, Message, Theme, Renderer> From<RangeSlider<'a, T, Message, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    T: Copy + PartialOrd + Into<f64> + num_traits::FromPrimitive + 'a,
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: core::Renderer + 'a,
+{
+    fn from(range_slider: RangeSlider<'a, T, Message, Theme>) -> Self {
+        Element::new(range_slider)
+    }
+}
+
+/// The possible status of a [`RangeSlider`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The [`RangeSlider`] can be interacted with.
+    Active,
+    /// The [`RangeSlider`] is being hovered.
+    Hovered,
+    /// One of the handles is being dragged.
+    Dragged,
+}
+
+/// The appearance of a [`RangeSlider`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Background`] of the whole rail.
+    pub rail: Background,
+    /// The [`Background`] of the rail between the handles.
+    pub range: Background,
+    /// The thickness of the rail.
+    pub rail_width: f32,
+    /// The [`Background`] of the handles.
+    pub handle: Background,
+    /// The radius of the handles.
+    pub handle_radius: f32,
+    /// The [`Border`] of the handles.
+    pub handle_border: Border,
+}
+
+/// The theme catalog of a [`RangeSlider`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class with the given status.
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style;
+}
+
+/// A styling function for a [`RangeSlider`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
+        class(self, status)
+    }
+}
+
+/// The default style of a [`RangeSlider`].
+pub fn default(theme: &Theme, status: Status) -> Style {
+    let palette = theme.extended_palette();
+
+    let color = match status {
+        Status::Active => palette.primary.base.color,
+        Status::Hovered => palette.primary.strong.color,
+        Status::Dragged => palette.primary.weak.color,
+    };
+
+    Style {
+        rail: palette.background.strong.color.into(),
+        range: color.into(),
+        rail_width: 4.0,
+        handle: color.into(),
+        handle_radius: 7.0,
+        handle_border: Border::default()
+            .width(2)
+            .color(palette.background.base.color),
+    }
+}
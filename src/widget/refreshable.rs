@@ -0,0 +1,625 @@
+use crate::core::border;
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::time::{Duration, Instant, milliseconds};
+use crate::core::touch;
+use crate::core::widget::operation::Scrollable;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::{Id, Operation, Widget};
+use crate::core::window;
+use crate::core::{
+    Background, Border, Clipboard, Color, Element, Event, Length, Pixels, Rectangle, Shadow, Shell,
+    Size, Theme, Vector,
+};
+
+use std::any::Any;
+use std::f32::consts::TAU;
+
+/// The diameter of the indicator.
+const INDICATOR_SIZE: f32 = 36.0;
+
+/// The amount of dots in the spinner of the indicator.
+const SPINNER_DOTS: usize = 8;
+
+/// How long the wheel needs to rest before a wheel pull is released.
+const WHEEL_RELEASE: Duration = Duration::from_millis(150);
+
+/// A wrapper that refreshes its content when pulled down from the top.
+///
+/// Pulling only starts while the first scrollable inside the content is
+/// scrolled to the top; by dragging a finger or with the mouse wheel.
+/// Once released past the threshold, the indicator keeps spinning until
+/// the refresh is completed with [`finish`].
+pub struct Refreshable<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    id: Option<Id>,
+    content: Element<'a, Message, Theme, Renderer>,
+    on_refresh: Option<Message>,
+    threshold: f32,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> Refreshable<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    pub fn new(content: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        Self {
+            id: None,
+            content: content.into(),
+            on_refresh: None,
+            threshold: 64.0,
+            class: Theme::default(),
+        }
+    }
+
+    pub fn id(mut self, id: impl Into<Id>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    pub fn on_refresh(mut self, on_refresh: Message) -> Self {
+        self.on_refresh = Some(on_refresh);
+        self
+    }
+
+    /// Sets the distance the content needs to be pulled to refresh.
+    pub fn threshold(mut self, threshold: impl Into<Pixels>) -> Self {
+        self.threshold = threshold.into().0;
+        self
+    }
+
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+}
+
+/// Completes the refresh of the [`Refreshable`] with the given [`Id`],
+/// hiding its indicator.
+pub fn finish(id: impl Into<Id>) -> impl Operation {
+    struct Finish {
+        id: Id,
+    }
+
+    impl Operation for Finish {
+        fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation)) {
+            operate(self);
+        }
+
+        fn custom(&mut self, id: Option<&Id>, _bounds: Rectangle, state: &mut dyn Any) {
+            if id != Some(&self.id) {
+                return;
+            }
+
+            if let Some(state) = state.downcast_mut::<State>() {
+                state.is_finished = state.is_refreshing;
+            }
+        }
+    }
+
+    Finish { id: id.into() }
+}
+
+struct State {
+    pull: f32,
+    gesture: Option<Gesture>,
+    is_refreshing: bool,
+    is_finished: bool,
+    release: Option<Release>,
+    started_at: Option<Instant>,
+    now: Instant,
+}
+
+/// Times are `None` until the next redraw, which stamps them.
+#[derive(Clone, Copy)]
+enum Gesture {
+    Touch { finger: touch::Finger, last: f32 },
+    Wheel { last: Option<Instant> },
+}
+
+/// The indicator settling into place after a pull.
+struct Release {
+    from: f32,
+    at: Option<Instant>,
+}
+
+impl State {
+    /// Returns how far the indicator is pulled down.
+    fn offset(&self, threshold: f32) -> f32 {
+        let Some(release) = &self.release else {
+            return self.pull;
+        };
+
+        let target = if self.is_refreshing { threshold } else { 0.0 };
+        let elapsed = release
+            .at
+            .map_or(Duration::ZERO, |at| self.now.saturating_duration_since(at));
+        let t = (elapsed.as_secs_f32() / 0.2).min(1.0);
+        let eased = 1.0 - (1.0 - t).powi(3);
+
+        release.from + (target - release.from) * eased
+    }
+
+    fn settle(&mut self, threshold: f32) {
+        self.release = Some(Release {
+            from: self.offset(threshold),
+            at: None,
+        });
+        self.gesture = None;
+        self.pull = 0.0;
+    }
+}
+
+/// Finds whether the first scrollable is scrolled to the top.
+struct FindTop {
+    is_at_top: Option<bool>,
+}
+
+impl Operation for FindTop {
+    fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation)) {
+        if self.is_at_top.is_none() {
+            operate(self);
+        }
+    }
+
+    fn scrollable(
+        &mut self,
+        _id: Option<&Id>,
+        _bounds: Rectangle,
+        _content_bounds: Rectangle,
+        translation: Vector,
+        _state: &mut dyn Scrollable,
+    ) {
+        if self.is_at_top.is_none() {
+            self.is_at_top = Some(translation.y <= 0.0);
+        }
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Refreshable<'_, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: renderer::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            pull: 0.0,
+            gesture: None,
+            is_refreshing: false,
+            is_finished: false,
+            release: None,
+            started_at: None,
+            now: Instant::now(),
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.content.as_widget().size_hint()
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget_mut()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        operation.custom(
+            self.id.as_ref(),
+            layout.bounds(),
+            tree.state.downcast_mut::<State>(),
+        );
+
+        self.content
+            .as_widget_mut()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let threshold = self.threshold;
+
+        let is_at_top = {
+            let mut find = FindTop { is_at_top: None };
+
+            self.content.as_widget_mut().operate(
+                &mut tree.children[0],
+                layout,
+                renderer,
+                &mut find,
+            );
+
+            find.is_at_top.unwrap_or(true)
+        };
+
+        let state = tree.state.downcast_mut::<State>();
+
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            state.now = *now;
+
+            if state.is_finished {
+                state.is_finished = false;
+                state.is_refreshing = false;
+                state.settle(threshold);
+            }
+
+            if let Some(Gesture::Wheel { last }) = &mut state.gesture {
+                let last = *last.get_or_insert(*now);
+
+                if *now >= last + WHEEL_RELEASE {
+                    release(state, self.on_refresh.as_ref(), threshold, shell);
+                }
+            }
+
+            let _ = state.started_at.get_or_insert(*now);
+
+            let is_settling = state.release.as_mut().is_some_and(|release| {
+                now.saturating_duration_since(*release.at.get_or_insert(*now)) < milliseconds(200)
+            });
+
+            if state.is_refreshing || is_settling {
+                shell.request_redraw();
+            } else if let Some(Gesture::Wheel {
+                last: Some(last), ..
+            }) = state.gesture
+            {
+                shell.request_redraw_at(last + WHEEL_RELEASE);
+            }
+        }
+
+        // A pull in progress takes over the gesture
+        if state.gesture.is_some() && !state.is_refreshing {
+            match event {
+                Event::Touch(touch::Event::FingerMoved { id, position }) => {
+                    if let Some(Gesture::Touch { finger, last }) = state.gesture.filter(
+                        |gesture| matches!(gesture, Gesture::Touch { finger, .. } if finger == id),
+                    ) {
+                        state.pull = (state.pull + (position.y - last) / 2.0).max(0.0);
+                        state.gesture = (state.pull > 0.0).then_some(Gesture::Touch {
+                            finger,
+                            last: position.y,
+                        });
+
+                        shell.request_redraw();
+                        shell.capture_event();
+                        return;
+                    }
+                }
+                Event::Touch(
+                    touch::Event::FingerLifted { id, .. } | touch::Event::FingerLost { id, .. },
+                ) if matches!(state.gesture, Some(Gesture::Touch { finger, .. }) if finger == *id) =>
+                {
+                    release(state, self.on_refresh.as_ref(), threshold, shell);
+
+                    shell.request_redraw();
+                    shell.capture_event();
+                    return;
+                }
+                Event::Mouse(mouse::Event::WheelScrolled { delta }) if cursor.is_over(bounds) => {
+                    let y = match delta {
+                        mouse::ScrollDelta::Lines { y, .. } => y * 20.0,
+                        mouse::ScrollDelta::Pixels { y, .. } => *y,
+                    };
+
+                    state.pull = (state.pull + y / 2.0).max(0.0);
+                    state.gesture = (state.pull > 0.0).then_some(Gesture::Wheel { last: None });
+
+                    shell.request_redraw();
+                    shell.capture_event();
+                    return;
+                }
+                _ => {}
+            }
+        }
+
+        self.content.as_widget_mut().update(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        let state = tree.state.downcast_mut::<State>();
+
+        if state.is_refreshing || state.gesture.is_some() || !is_at_top || self.on_refresh.is_none()
+        {
+            return;
+        }
+
+        match event {
+            Event::Touch(touch::Event::FingerPressed { id, position })
+                if bounds.contains(*position) =>
+            {
+                state.gesture = Some(Gesture::Touch {
+                    finger: *id,
+                    last: position.y,
+                });
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) if cursor.is_over(bounds) => {
+                let y = match delta {
+                    mouse::ScrollDelta::Lines { y, .. } => y * 20.0,
+                    mouse::ScrollDelta::Pixels { y, .. } => *y,
+                };
+
+                if y > 0.0 {
+                    state.pull = y / 2.0;
+                    state.release = None;
+                    state.gesture = Some(Gesture::Wheel { last: None });
+
+                    shell.request_redraw();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        defaults: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            defaults,
+            layout,
+            cursor,
+            viewport,
+        );
+
+        let state = tree.state.downcast_ref::<State>();
+        let offset = state.offset(self.threshold);
+
+        if offset <= 0.0 {
+            return;
+        }
+
+        let bounds = layout.bounds();
+        let style = theme.style(&self.class);
+        let progress = (offset / self.threshold).min(1.0);
+
+        let indicator = Rectangle {
+            x: bounds.center_x() - INDICATOR_SIZE / 2.0,
+            y: bounds.y - INDICATOR_SIZE + offset.min(self.threshold * 1.5),
+            width: INDICATOR_SIZE,
+            height: INDICATOR_SIZE,
+        };
+
+        // Spins on its own while refreshing; follows the finger otherwise
+        let turn = if state.is_refreshing {
+            state
+                .now
+                .saturating_duration_since(state.started_at.unwrap_or(state.now))
+                .as_secs_f32()
+        } else {
+            offset / self.threshold * 0.5
+        };
+
+        renderer.with_layer(bounds, |renderer| {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: indicator,
+                    border: Border {
+                        radius: border::radius(INDICATOR_SIZE / 2.0),
+                        ..style.border
+                    },
+                    shadow: style.shadow,
+                    ..renderer::Quad::default()
+                },
+                style.background,
+            );
+
+            let center = indicator.center();
+            let radius = INDICATOR_SIZE * 0.25;
+            let dot = INDICATOR_SIZE * 0.12;
+            let lead = (turn.fract() * SPINNER_DOTS as f32).floor();
+
+            // Dots appear one by one while pulling
+            let visible = if state.is_refreshing {
+                SPINNER_DOTS
+            } else {
+                (progress * SPINNER_DOTS as f32).ceil() as usize
+            };
+
+            for i in 0..visible {
+                let angle = TAU * i as f32 / SPINNER_DOTS as f32;
+                let behind = (lead - i as f32).rem_euclid(SPINNER_DOTS as f32);
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: center.x + radius * angle.sin() - dot / 2.0,
+                            y: center.y - radius * angle.cos() - dot / 2.0,
+                            width: dot,
+                            height: dot,
+                        },
+                        border: Border::default().rounded(dot / 2.0),
+                        ..renderer::Quad::default()
+                    },
+                    style
+                        .indicator
+                        .scale_alpha(1.0 - 0.8 * behind / SPINNER_DOTS as f32),
+                );
+            }
+        });
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            viewport,
+            translation,
+        )
+    }
+}
+
+/// Lets go of the pull, refreshing if it went past the threshold.
+fn release<Message: Clone>(
+    state: &mut State,
+    on_refresh: Option<&Message>,
+    threshold: f32,
+    shell: &mut Shell<'_, Message>,
+) {
+    if let Some(on_refresh) = on_refresh.filter(|_| state.pull >= threshold) {
+        state.is_refreshing = true;
+        state.started_at = None;
+
+        shell.publish(on_refresh.clone());
+    }
+
+    state.settle(threshold);
+}
+
+impl<'a, Message, Theme, Renderer> From<Refreshable<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(refreshable: Refreshable<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(refreshable)
+    }
+}
+
+/// The appearance of a [`Refreshable`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Background`] of the indicator.
+    pub background: Background,
+    /// The [`Border`] of the indicator; always fully rounded.
+    pub border: Border,
+    /// The [`Shadow`] of the indicator.
+    pub shadow: Shadow,
+    /// The [`Color`] of the spinner inside the indicator.
+    pub indicator: Color,
+}
+
+/// The theme catalog of a [`Refreshable`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`Refreshable`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`Refreshable`].
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        background: palette.background.base.color.into(),
+        border: Border::default()
+            .width(1)
+            .color(palette.background.strong.color),
+        shadow: Shadow {
+            color: Color::BLACK.scale_alpha(0.2),
+            offset: Vector::new(0.0, 2.0),
+            blur_radius: 6.0,
+        },
+        indicator: palette.primary.base.color,
+    }
+}
@@ -0,0 +1,436 @@
+use crate::core::alignment;
+use crate::core::animation::{Animation, Easing};
+use crate::core::border;
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::text::{self, Paragraph as _};
+use crate::core::time::{Duration, Instant};
+use crate::core::widget::Widget;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::window;
+use crate::core::{
+    Border, Clipboard, Color, Element, Event, Length, Pixels, Point, Rectangle, Shadow, Shell,
+    Size, Theme, Vector,
+};
+
+use std::collections::HashMap;
+
+/// The diameter of the dot marking the position of a cursor.
+const DOT_SIZE: f32 = 10.0;
+
+/// A layer drawing the cursors and selections of other users.
+///
+/// It is meant to be stacked on top of the content the users share and
+/// never captures any events. Cursors glide to their new position when
+/// they are updated.
+pub struct RemoteCursors<'a, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    cursors: &'a [Cursor],
+    width: Length,
+    height: Length,
+    duration: Duration,
+    text_size: Option<Pixels>,
+    font: Option<Renderer::Font>,
+    class: Theme::Class<'a>,
+}
+
+/// The cursor of a remote user.
+///
+/// Cursors are told apart by their name.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cursor {
+    pub name: String,
+    pub color: Color,
+    pub position: Point,
+    pub selection: Option<Rectangle>,
+}
+
+impl Cursor {
+    pub fn new(name: impl Into<String>, color: Color, position: Point) -> Self {
+        Self {
+            name: name.into(),
+            color,
+            position,
+            selection: None,
+        }
+    }
+
+    /// Sets the area selected by the user.
+    pub fn selection(mut self, selection: Rectangle) -> Self {
+        self.selection = Some(selection);
+        self
+    }
+}
+
+impl<'a, Theme, Renderer> RemoteCursors<'a, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    pub fn new(cursors: &'a [Cursor]) -> Self {
+        Self {
+            cursors,
+            width: Length::Fill,
+            height: Length::Fill,
+            duration: Duration::from_millis(150),
+            text_size: None,
+            font: None,
+            class: Theme::default(),
+        }
+    }
+
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the time a cursor takes to glide to its new position.
+    pub fn duration(mut self, duration: impl Into<Duration>) -> Self {
+        self.duration = duration.into();
+        self
+    }
+
+    pub fn text_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(size.into());
+        self
+    }
+
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+}
+
+struct State<P> {
+    tracks: HashMap<String, Track<P>>,
+    now: Option<Instant>,
+}
+
+struct Track<P> {
+    from: Target,
+    to: Target,
+    animation: Animation<bool>,
+    label: P,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Target {
+    position: Point,
+    selection: Option<Rectangle>,
+}
+
+impl<P> Track<P> {
+    fn current(&self, now: Option<Instant>) -> Target {
+        let Some(now) = now else {
+            return self.to;
+        };
+
+        let t = self.animation.interpolate(0.0, 1.0, now);
+        let lerp = |from: f32, to: f32| from + (to - from) * t;
+
+        let position = Point::new(
+            lerp(self.from.position.x, self.to.position.x),
+            lerp(self.from.position.y, self.to.position.y),
+        );
+
+        let selection = match (self.from.selection, self.to.selection) {
+            (Some(from), Some(to)) => Some(Rectangle {
+                x: lerp(from.x, to.x),
+                y: lerp(from.y, to.y),
+                width: lerp(from.width, to.width),
+                height: lerp(from.height, to.height),
+            }),
+            (_, to) => to,
+        };
+
+        Target {
+            position,
+            selection,
+        }
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for RemoteCursors<'_, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<Renderer::Paragraph>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::<Renderer::Paragraph> {
+            tracks: HashMap::new(),
+            now: None,
+        })
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, self.height)
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        state
+            .tracks
+            .retain(|name, _| self.cursors.iter().any(|cursor| &cursor.name == name));
+
+        for cursor in self.cursors {
+            if state.tracks.contains_key(&cursor.name) {
+                continue;
+            }
+
+            let target = Target {
+                position: cursor.position,
+                selection: cursor.selection,
+            };
+
+            let label = Renderer::Paragraph::with_text(text::Text {
+                content: cursor.name.as_str(),
+                bounds: Size::INFINITE,
+                size: self.text_size.unwrap_or_else(|| renderer.default_size()) * 0.85,
+                line_height: text::LineHeight::default(),
+                font: self.font.unwrap_or_else(|| renderer.default_font()),
+                align_x: text::Alignment::Left,
+                align_y: alignment::Vertical::Top,
+                shaping: text::Shaping::Advanced,
+                wrapping: text::Wrapping::None,
+                hint_factor: renderer.scale_factor(),
+            });
+
+            let _ = state.tracks.insert(
+                cursor.name.clone(),
+                Track {
+                    from: target,
+                    to: target,
+                    animation: Animation::new(true),
+                    label,
+                },
+            );
+        }
+
+        layout::atomic(limits, self.width, self.height)
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        _layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let Event::Window(window::Event::RedrawRequested(now)) = event else {
+            return;
+        };
+
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+        let previous = state.now.replace(*now);
+
+        for cursor in self.cursors {
+            let Some(track) = state.tracks.get_mut(&cursor.name) else {
+                continue;
+            };
+
+            let target = Target {
+                position: cursor.position,
+                selection: cursor.selection,
+            };
+
+            if track.to != target {
+                track.from = track.current(previous);
+                track.to = target;
+                track.animation = Animation::new(false)
+                    .duration(self.duration)
+                    .easing(Easing::EaseOut)
+                    .go(true, *now);
+            }
+
+            if track.animation.is_animating(*now) {
+                shell.request_redraw();
+            }
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _defaults: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+        let style = theme.style(&self.class);
+        let bounds = layout.bounds();
+        let offset = Vector::new(bounds.x, bounds.y);
+
+        renderer.with_layer(bounds, |renderer| {
+            for cursor in self.cursors {
+                let Some(track) = state.tracks.get(&cursor.name) else {
+                    continue;
+                };
+
+                let current = track.current(state.now);
+                let position = current.position + offset;
+
+                if let Some(selection) = current.selection {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: selection + offset,
+                            border: Border::default()
+                                .width(1)
+                                .color(cursor.color.scale_alpha(style.selection_alpha * 2.0)),
+                            ..renderer::Quad::default()
+                        },
+                        cursor.color.scale_alpha(style.selection_alpha),
+                    );
+                }
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: position.x - DOT_SIZE / 2.0,
+                            y: position.y - DOT_SIZE / 2.0,
+                            width: DOT_SIZE,
+                            height: DOT_SIZE,
+                        },
+                        border: Border::default()
+                            .rounded(DOT_SIZE / 2.0)
+                            .width(2)
+                            .color(style.text_color),
+                        shadow: style.shadow,
+                        ..renderer::Quad::default()
+                    },
+                    cursor.color,
+                );
+
+                let size = track.label.min_bounds();
+                let tag = Rectangle {
+                    x: position.x + DOT_SIZE / 2.0,
+                    y: position.y + DOT_SIZE / 2.0,
+                    width: size.width + 8.0,
+                    height: size.height + 4.0,
+                };
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: tag,
+                        border: Border {
+                            radius: style.radius,
+                            ..Border::default()
+                        },
+                        shadow: style.shadow,
+                        ..renderer::Quad::default()
+                    },
+                    cursor.color,
+                );
+
+                renderer.fill_paragraph(
+                    &track.label,
+                    tag.position() + Vector::new(4.0, 2.0),
+                    style.text_color,
+                    bounds,
+                );
+            }
+        });
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<RemoteCursors<'a, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(remote_cursors: RemoteCursors<'a, Theme, Renderer>) -> Self {
+        Element::new(remote_cursors)
+    }
+}
+
+/// The appearance of a [`RemoteCursors`] layer.
+///
+/// Each cursor brings its own [`Color`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Color`] of the name tags and of the outline of the dots.
+    pub text_color: Color,
+    /// The [`border::Radius`] of the name tags.
+    pub radius: border::Radius,
+    /// The opacity of the fill of a selection.
+    pub selection_alpha: f32,
+    /// The [`Shadow`] of the dots and name tags.
+    pub shadow: Shadow,
+}
+
+/// The theme catalog of a [`RemoteCursors`] layer.
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`RemoteCursors`] layer.
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`RemoteCursors`] layer.
+pub fn default(_theme: &Theme) -> Style {
+    Style {
+        text_color: Color::WHITE,
+        radius: 4.0.into(),
+        selection_alpha: 0.15,
+        shadow: Shadow {
+            color: Color::BLACK.scale_alpha(0.2),
+            offset: Vector::new(0.0, 1.0),
+            blur_radius: 3.0,
+        },
+    }
+}
@@ -0,0 +1,669 @@
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::time::{Duration, Instant, milliseconds};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::{Operation, Widget};
+use crate::core::window;
+use crate::core::{
+    Background, Border, Clipboard, Color, Element, Event, Length, Padding, Pixels, Point,
+    Rectangle, Shadow, Shell, Size, Theme, Vector,
+};
+
+/// The distance between the cursor and a tooltip following it.
+const CURSOR_OFFSET: f32 = 16.0;
+
+/// A wrapper that shows arbitrary content next to its target after hovering
+/// it for a while.
+///
+/// A tooltip that does not fit on its [`Placement`] flips to the opposite
+/// side, and it is always kept within the window.
+pub struct RichTooltip<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    target: Element<'a, Message, Theme, Renderer>,
+    content: Element<'a, Message, Theme, Renderer>,
+    placement: Placement,
+    show_delay: Duration,
+    hide_delay: Duration,
+    gap: f32,
+    padding: Padding,
+    max_width: f32,
+    is_interactive: bool,
+    class: Theme::Class<'a>,
+}
+
+/// The placement of a [`RichTooltip`] relative to its target.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Placement {
+    #[default]
+    Top,
+    Bottom,
+    Left,
+    Right,
+    FollowCursor,
+}
+
+impl<'a, Message, Theme, Renderer> RichTooltip<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    pub fn new(
+        target: impl Into<Element<'a, Message, Theme, Renderer>>,
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self {
+            target: target.into(),
+            content: content.into(),
+            placement: Placement::default(),
+            show_delay: milliseconds(500),
+            hide_delay: milliseconds(100),
+            gap: 6.0,
+            padding: Padding::new(8.0),
+            max_width: 300.0,
+            is_interactive: false,
+            class: Theme::default(),
+        }
+    }
+
+    pub fn placement(mut self, placement: Placement) -> Self {
+        self.placement = placement;
+        self
+    }
+
+    /// Sets how long the target must be hovered before the tooltip shows.
+    pub fn show_delay(mut self, delay: impl Into<Duration>) -> Self {
+        self.show_delay = delay.into();
+        self
+    }
+
+    /// Sets how long the tooltip lingers after the cursor leaves.
+    pub fn hide_delay(mut self, delay: impl Into<Duration>) -> Self {
+        self.hide_delay = delay.into();
+        self
+    }
+
+    /// Sets the distance between the target and the tooltip.
+    pub fn gap(mut self, gap: impl Into<Pixels>) -> Self {
+        self.gap = gap.into().0;
+        self
+    }
+
+    pub fn padding(mut self, padding: impl Into<Padding>) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    pub fn max_width(mut self, max_width: impl Into<Pixels>) -> Self {
+        self.max_width = max_width.into().0;
+        self
+    }
+
+    /// Sets whether the tooltip stays open while hovered, letting its
+    /// content receive input.
+    pub fn interactive(mut self, is_interactive: bool) -> Self {
+        self.is_interactive = is_interactive;
+        self
+    }
+
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct State {
+    phase: Phase,
+    is_tooltip_hovered: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+enum Phase {
+    #[default]
+    Idle,
+    Pending {
+        at: Option<Instant>,
+    },
+    Open {
+        cursor: Point,
+    },
+    Closing {
+        cursor: Point,
+        at: Option<Instant>,
+    },
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for RichTooltip<'_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: renderer::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.target), Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&[&self.target, &self.content]);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.target.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.target.as_widget().size_hint()
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.target
+            .as_widget_mut()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.target
+            .as_widget_mut()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        if let Event::Mouse(_) | Event::Window(window::Event::RedrawRequested(_)) = event {
+            let state = tree.state.downcast_mut::<State>();
+            let now = match event {
+                Event::Window(window::Event::RedrawRequested(now)) => Some(*now),
+                _ => None,
+            };
+            let position = cursor.position_over(layout.bounds());
+            let is_hovered = position.is_some() || state.is_tooltip_hovered;
+
+            match (state.phase, position) {
+                (Phase::Idle, Some(cursor)) => {
+                    if self.show_delay == Duration::ZERO {
+                        state.phase = Phase::Open { cursor };
+                        shell.invalidate_layout();
+                    } else {
+                        state.phase = Phase::Pending { at: now };
+                    }
+
+                    schedule(now, self.show_delay, shell);
+                }
+                (Phase::Pending { .. }, None) => {
+                    state.phase = Phase::Idle;
+                }
+                (Phase::Pending { at }, _) if is_waiting(at, now, self.show_delay) => {
+                    state.phase = Phase::Pending { at: at.or(now) };
+                    schedule(at.or(now), self.show_delay, shell);
+                }
+                (Phase::Pending { .. }, Some(cursor)) => {
+                    state.phase = Phase::Open { cursor };
+                    shell.invalidate_layout();
+                    shell.request_redraw();
+                }
+                (Phase::Open { cursor: last }, Some(cursor))
+                    if self.placement == Placement::FollowCursor && last != cursor =>
+                {
+                    state.phase = Phase::Open { cursor };
+                    shell.request_redraw();
+                }
+                (Phase::Open { cursor }, _) if !is_hovered => {
+                    state.phase = Phase::Closing { cursor, at: now };
+                    schedule(now, self.hide_delay, shell);
+                }
+                (Phase::Closing { cursor, .. }, _) if is_hovered => {
+                    state.phase = Phase::Open { cursor };
+                }
+                (Phase::Closing { cursor, at }, _) if is_waiting(at, now, self.hide_delay) => {
+                    state.phase = Phase::Closing {
+                        cursor,
+                        at: at.or(now),
+                    };
+                    schedule(at.or(now), self.hide_delay, shell);
+                }
+                (Phase::Closing { .. }, _) => {
+                    state.phase = Phase::Idle;
+                    state.is_tooltip_hovered = false;
+                    shell.invalidate_layout();
+
+                    if !matches!(event, Event::Window(window::Event::RedrawRequested(_))) {
+                        shell.request_redraw();
+                    }
+                }
+                (Phase::Open { .. } | Phase::Idle, _) => {}
+            }
+
+            // Pressing the target dismisses its tooltip
+            if matches!(event, Event::Mouse(mouse::Event::ButtonPressed(_)))
+                && position.is_some()
+                && state.phase != Phase::Idle
+            {
+                let was_open = matches!(state.phase, Phase::Open { .. });
+
+                state.phase = Phase::Idle;
+
+                if was_open {
+                    shell.invalidate_layout();
+                    shell.request_redraw();
+                }
+            }
+        }
+
+        self.target.as_widget_mut().update(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.target.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.target.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let state = tree.state.downcast_mut::<State>();
+        let mut children = tree.children.iter_mut();
+
+        let target = self.target.as_widget_mut().overlay(
+            children.next().unwrap(),
+            layout,
+            renderer,
+            viewport,
+            translation,
+        );
+
+        let cursor = match state.phase {
+            Phase::Open { cursor } | Phase::Closing { cursor, .. } => Some(cursor),
+            Phase::Idle | Phase::Pending { .. } => None,
+        };
+
+        let tooltip = cursor.map(|cursor| {
+            overlay::Element::new(Box::new(Overlay {
+                content: &mut self.content,
+                tree: children.next().unwrap(),
+                state,
+                target: layout.bounds() + translation,
+                cursor: cursor + translation,
+                placement: self.placement,
+                gap: self.gap,
+                padding: self.padding,
+                max_width: self.max_width,
+                is_interactive: self.is_interactive,
+                class: &self.class,
+            }))
+        });
+
+        if target.is_some() || tooltip.is_some() {
+            Some(
+                overlay::Group::with_children(target.into_iter().chain(tooltip).collect())
+                    .overlay(),
+            )
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<RichTooltip<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(tooltip: RichTooltip<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(tooltip)
+    }
+}
+
+/// Returns whether a delay started `at` is still running at `now`.
+///
+/// Both instants come from redraw timestamps; a delay that has not
+/// been stamped yet is always running.
+fn is_waiting(at: Option<Instant>, now: Option<Instant>, delay: Duration) -> bool {
+    match at.or(now).zip(now) {
+        Some((at, now)) => now < at + delay,
+        None => true,
+    }
+}
+
+fn schedule<Message>(at: Option<Instant>, delay: Duration, shell: &mut Shell<'_, Message>) {
+    match at {
+        Some(at) => shell.request_redraw_at(at + delay),
+        None => shell.request_redraw(),
+    }
+}
+
+struct Overlay<'a, 'b, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    content: &'b mut Element<'a, Message, Theme, Renderer>,
+    tree: &'b mut Tree,
+    state: &'b mut State,
+    target: Rectangle,
+    cursor: Point,
+    placement: Placement,
+    gap: f32,
+    padding: Padding,
+    max_width: f32,
+    is_interactive: bool,
+    class: &'b Theme::Class<'a>,
+}
+
+impl<Message, Theme, Renderer> overlay::Overlay<Message, Theme, Renderer>
+    for Overlay<'_, '_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: renderer::Renderer,
+{
+    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> layout::Node {
+        let content = self.content.as_widget_mut().layout(
+            self.tree,
+            renderer,
+            &layout::Limits::new(
+                Size::ZERO,
+                Size::new(self.max_width.min(bounds.width), bounds.height),
+            )
+            .shrink(self.padding),
+        );
+
+        let size = content.size().expand(self.padding);
+        let target = self.target;
+        let gap = self.gap;
+
+        let place = |placement| match placement {
+            Placement::Top => Point::new(
+                target.center_x() - size.width / 2.0,
+                target.y - gap - size.height,
+            ),
+            Placement::Bottom => Point::new(
+                target.center_x() - size.width / 2.0,
+                target.y + target.height + gap,
+            ),
+            Placement::Left => Point::new(
+                target.x - gap - size.width,
+                target.center_y() - size.height / 2.0,
+            ),
+            Placement::Right => Point::new(
+                target.x + target.width + gap,
+                target.center_y() - size.height / 2.0,
+            ),
+            Placement::FollowCursor => {
+                Point::new(self.cursor.x + CURSOR_OFFSET, self.cursor.y + CURSOR_OFFSET)
+            }
+        };
+
+        let fits = |position: Point| {
+            position.x >= 0.0
+                && position.y >= 0.0
+                && position.x + size.width <= bounds.width
+                && position.y + size.height <= bounds.height
+        };
+
+        let mut position = place(self.placement);
+
+        if !fits(position) {
+            let flipped = match self.placement {
+                Placement::Top => place(Placement::Bottom),
+                Placement::Bottom => place(Placement::Top),
+                Placement::Left => place(Placement::Right),
+                Placement::Right => place(Placement::Left),
+                Placement::FollowCursor => Point::new(
+                    if position.x + size.width > bounds.width {
+                        self.cursor.x - CURSOR_OFFSET - size.width
+                    } else {
+                        position.x
+                    },
+                    if position.y + size.height > bounds.height {
+                        self.cursor.y - CURSOR_OFFSET - size.height
+                    } else {
+                        position.y
+                    },
+                ),
+            };
+
+            if fits(flipped) || self.placement == Placement::FollowCursor {
+                position = flipped;
+            }
+        }
+
+        // Keep the tooltip within the window and aligned to whole pixels
+        let position = Point::new(
+            position.x.min(bounds.width - size.width).max(0.0).round(),
+            position.y.min(bounds.height - size.height).max(0.0).round(),
+        );
+
+        layout::Node::with_children(
+            size,
+            vec![content.move_to(Point::new(self.padding.left, self.padding.top))],
+        )
+        .move_to(position)
+    }
+
+    fn update(
+        &mut self,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        if !self.is_interactive {
+            return;
+        }
+
+        if let Event::Mouse(mouse::Event::CursorMoved { .. }) = event {
+            self.state.is_tooltip_hovered = cursor.is_over(layout.bounds());
+        }
+
+        self.content.as_widget_mut().update(
+            self.tree,
+            event,
+            layout.children().next().unwrap(),
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            &layout.bounds(),
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if !self.is_interactive {
+            return mouse::Interaction::None;
+        }
+
+        self.content.as_widget().mouse_interaction(
+            self.tree,
+            layout.children().next().unwrap(),
+            cursor,
+            &layout.bounds(),
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) {
+        let style = theme.style(self.class);
+        let bounds = layout.bounds();
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: style.border,
+                shadow: style.shadow,
+                ..renderer::Quad::default()
+            },
+            style.background,
+        );
+
+        self.content.as_widget().draw(
+            self.tree,
+            renderer,
+            theme,
+            &renderer::Style {
+                text_color: style.text_color,
+            },
+            layout.children().next().unwrap(),
+            if self.is_interactive {
+                cursor
+            } else {
+                mouse::Cursor::Unavailable
+            },
+            &bounds,
+        );
+    }
+}
+
+/// The appearance of a [`RichTooltip`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Background`] of the tooltip.
+    pub background: Background,
+    /// The [`Border`] of the tooltip.
+    pub border: Border,
+    /// The [`Shadow`] of the tooltip.
+    pub shadow: Shadow,
+    /// The default text [`Color`] of the content.
+    pub text_color: Color,
+}
+
+/// The theme catalog of a [`RichTooltip`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`RichTooltip`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`RichTooltip`].
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        background: palette.background.base.color.into(),
+        border: Border::default()
+            .rounded(6)
+            .width(1)
+            .color(palette.background.strong.color),
+        shadow: Shadow {
+            color: Color::BLACK.scale_alpha(0.2),
+            offset: Vector::new(0.0, 4.0),
+            blur_radius: 12.0,
+        },
+        text_color: palette.background.base.text,
+    }
+}
@@ -0,0 +1,336 @@
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::widget::operation::Scrollable;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::{Id, Operation, Widget};
+use crate::core::{
+    Background, Clipboard, Element, Event, Length, Pixels, Rectangle, Shell, Size, Theme, Vector,
+};
+
+/// A widget that draws the scroll progress of a scrollable inside of its
+/// content as a bar along its top edge.
+///
+/// The scrollable is found by its [`Id`].
+pub struct ScrollProgress<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    target: Id,
+    content: Element<'a, Message, Theme, Renderer>,
+    thickness: f32,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> ScrollProgress<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    pub fn new(
+        target: impl Into<Id>,
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self {
+            target: target.into(),
+            content: content.into(),
+            thickness: 3.0,
+            class: Theme::default(),
+        }
+    }
+
+    pub fn thickness(mut self, thickness: impl Into<Pixels>) -> Self {
+        self.thickness = thickness.into().0;
+        self
+    }
+
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+}
+
+#[derive(Default)]
+struct State {
+    progress: f32,
+}
+
+/// Finds the vertical scroll progress of the scrollable with the given [`Id`].
+struct FindProgress<'a> {
+    target: &'a Id,
+    progress: Option<f32>,
+}
+
+impl Operation for FindProgress<'_> {
+    fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation)) {
+        if self.progress.is_none() {
+            operate(self);
+        }
+    }
+
+    fn scrollable(
+        &mut self,
+        id: Option<&Id>,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+        translation: Vector,
+        _state: &mut dyn Scrollable,
+    ) {
+        if id != Some(self.target) {
+            return;
+        }
+
+        let max = content_bounds.height - bounds.height;
+
+        self.progress = Some(if max > 0.0 {
+            (translation.y / max).clamp(0.0, 1.0)
+        } else {
+            1.0
+        });
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for ScrollProgress<'_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: renderer::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.content.as_widget().size_hint()
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget_mut()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content
+            .as_widget_mut()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget_mut().update(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        let mut find = FindProgress {
+            target: &self.target,
+            progress: None,
+        };
+
+        self.content
+            .as_widget_mut()
+            .operate(&mut tree.children[0], layout, renderer, &mut find);
+
+        let state = tree.state.downcast_mut::<State>();
+        let progress = find.progress.unwrap_or_default();
+
+        if state.progress != progress {
+            state.progress = progress;
+            shell.request_redraw();
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let style = theme.style(&self.class);
+
+        let track = Rectangle {
+            height: self.thickness,
+            ..bounds
+        };
+
+        renderer.with_layer(track, |renderer| {
+            if let Some(background) = style.track {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: track,
+                        ..renderer::Quad::default()
+                    },
+                    background,
+                );
+            }
+
+            if state.progress > 0.0 {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            width: track.width * state.progress,
+                            ..track
+                        },
+                        ..renderer::Quad::default()
+                    },
+                    style.bar,
+                );
+            }
+        });
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            viewport,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<ScrollProgress<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(scroll_progress: ScrollProgress<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(scroll_progress)
+    }
+}
+
+/// The appearance of a [`ScrollProgress`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Background`] behind the bar, if any.
+    pub track: Option<Background>,
+    /// The [`Background`] of the bar.
+    pub bar: Background,
+}
+
+/// The theme catalog of a [`ScrollProgress`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`ScrollProgress`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`ScrollProgress`].
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        track: None,
+        bar: palette.primary.base.color.into(),
+    }
+}
@@ -0,0 +1,341 @@
+use crate::core;
+use crate::core::alignment;
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::text;
+use crate::core::time::{Duration, Instant, milliseconds};
+use crate::core::widget;
+use crate::core::widget::text::{Catalog, Format, Style, StyleFn};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::window;
+use crate::core::{
+    Alignment, Clipboard, Color, Element, Event, Length, Pixels, Rectangle, Shell, Size, Widget,
+};
+
+use std::iter;
+
+#[derive(Debug)]
+pub struct SecretText<'a, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fragment: core::text::Fragment<'a>,
+    format: Format<Renderer::Font>,
+    class: Theme::Class<'a>,
+    revealed: bool,
+    mask: char,
+    duration: Duration,
+    tick_rate: u64,
+}
+
+impl<'a, Theme, Renderer> SecretText<'a, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    pub fn new(fragment: impl core::text::IntoFragment<'a>) -> Self {
+        Self {
+            fragment: fragment.into_fragment(),
+            format: Format::default(),
+            class: Theme::default(),
+            revealed: false,
+            mask: '•',
+            duration: Duration::from_millis(200),
+            tick_rate: 50,
+        }
+    }
+
+    pub fn revealed(mut self, revealed: bool) -> Self {
+        self.revealed = revealed;
+        self
+    }
+
+    pub fn mask(mut self, mask: char) -> Self {
+        self.mask = mask;
+        self
+    }
+
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.format.size = Some(size.into());
+        self
+    }
+
+    pub fn line_height(mut self, line_height: impl Into<text::LineHeight>) -> Self {
+        self.format.line_height = line_height.into();
+        self
+    }
+
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.format.font = Some(font.into());
+        self
+    }
+
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.format.width = width.into();
+        self
+    }
+
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.format.height = height.into();
+        self
+    }
+
+    pub fn align_x(mut self, alignment: impl Into<text::Alignment>) -> Self {
+        self.format.align_x = alignment.into();
+        self
+    }
+
+    pub fn align_y(mut self, alignment: impl Into<alignment::Vertical>) -> Self {
+        self.format.align_y = alignment.into();
+        self
+    }
+
+    pub fn center(self) -> Self {
+        self.align_x(Alignment::Center).align_y(Alignment::Center)
+    }
+
+    pub fn shaping(mut self, shaping: text::Shaping) -> Self {
+        self.format.shaping = shaping;
+        self
+    }
+
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    pub fn color(self, color: impl Into<Color>) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.color_maybe(Some(color))
+    }
+
+    pub fn color_maybe(self, color: Option<impl Into<Color>>) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        let color = color.map(Into::into);
+
+        self.style(move |_theme| Style { color })
+    }
+
+    pub fn quick(self) -> Self {
+        self.duration(milliseconds(200))
+    }
+
+    pub fn slow(self) -> Self {
+        self.duration(milliseconds(400))
+    }
+
+    pub fn duration(mut self, duration: impl Into<Duration>) -> Self {
+        self.duration = duration.into();
+        self
+    }
+
+    pub fn tick_rate(mut self, tick_rate: impl Into<Duration>) -> Self {
+        self.tick_rate = tick_rate.into().as_millis() as u64;
+        self
+    }
+
+    fn masked(&self) -> String {
+        iter::repeat_n(self.mask, self.fragment.chars().count()).collect()
+    }
+}
+
+#[derive(Debug)]
+struct State<P: text::Paragraph> {
+    revealed: bool,
+    internal: widget::text::State<P>,
+    animation: Animation,
+}
+
+#[derive(Debug)]
+enum Animation {
+    Ticking {
+        fragment: String,
+        ticks: u64,
+        /// The time of the next tick; or `None` to tick on the next redraw.
+        next_redraw: Option<Instant>,
+    },
+    Done,
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for SecretText<'_, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<Renderer::Paragraph>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            revealed: self.revealed,
+            internal: widget::text::State::<Renderer::Paragraph>::default(),
+            animation: Animation::Done,
+        })
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.format.width,
+            height: self.format.height,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let state = &mut tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        if state.revealed != self.revealed {
+            // The animation starts from whatever is shown
+            let fragment = match &state.animation {
+                Animation::Ticking { fragment, .. } => fragment.clone(),
+                Animation::Done if state.revealed => self.fragment.clone().into_owned(),
+                Animation::Done => self.masked(),
+            };
+
+            state.revealed = self.revealed;
+
+            state.animation = Animation::Ticking {
+                fragment,
+                ticks: 0,
+                next_redraw: None,
+            };
+        }
+
+        let fragment = match &state.animation {
+            Animation::Ticking { fragment, .. } => fragment.clone(),
+            Animation::Done if self.revealed => self.fragment.clone().into_owned(),
+            Animation::Done => self.masked(),
+        };
+
+        widget::text::layout(
+            &mut state.internal,
+            renderer,
+            limits,
+            &fragment,
+            self.format,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        defaults: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor_position: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+        let style = theme.style(&self.class);
+
+        widget::text::draw(
+            renderer,
+            defaults,
+            layout.bounds(),
+            state.internal.raw(),
+            style,
+            viewport,
+        );
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        if layout.bounds().intersection(viewport).is_none() {
+            return;
+        }
+
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+            if let Animation::Ticking {
+                fragment,
+                ticks,
+                next_redraw,
+            } = &mut state.animation
+            {
+                if next_redraw.is_none_or(|next_redraw| next_redraw <= *now) {
+                    *ticks += 1;
+
+                    let total = self.fragment.chars().count();
+                    let progress = (total as f32 / self.duration.as_millis() as f32
+                        * (*ticks * self.tick_rate) as f32)
+                        as usize;
+
+                    if progress >= total {
+                        state.animation = Animation::Done;
+                        shell.invalidate_layout();
+
+                        return;
+                    }
+
+                    let mut rng = rand::rng();
+
+                    *fragment = if self.revealed {
+                        scramble(self.fragment.chars(), total, progress, &mut rng)
+                    } else {
+                        scramble(iter::repeat(self.mask), total, progress, &mut rng)
+                    };
+
+                    *next_redraw = Some(*now + Duration::from_millis(self.tick_rate));
+
+                    shell.invalidate_layout();
+                }
+
+                shell.request_redraw_at(next_redraw.unwrap_or(*now));
+            }
+        }
+    }
+}
+
+/// Takes the first `progress` characters of `target` and fills the rest,
+/// up to `total` characters, with random letters.
+///
+/// The random letters never depend on the secret; so neither revealing
+/// nor hiding it discloses its characters before they are meant to show.
+fn scramble(
+    target: impl Iterator<Item = char>,
+    total: usize,
+    progress: usize,
+    rng: &mut impl rand::Rng,
+) -> String {
+    target
+        .take(progress)
+        .chain((progress..total).map(|_| rng.random_range('a'..='z')))
+        .collect()
+}
+
+impl<'a, Message, Theme, Renderer> From<SecretText<'a, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(text: SecretText<'a, Theme, Renderer>) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(text)
+    }
+}
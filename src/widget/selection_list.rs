@@ -0,0 +1,674 @@
+use crate::core::alignment;
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::text;
+use crate::core::time::{Duration, Instant};
+use crate::core::widget::Widget;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::window;
+use crate::core::{
+    Background, Border, Clipboard, Color, Element, Event, Length, Pixels, Point, Rectangle, Shell,
+    Size, Theme,
+};
+
+use std::fmt::Display;
+use std::ops::Range;
+
+/// How long typed characters are kept to jump to a matching row.
+const TYPE_AHEAD: Duration = Duration::from_millis(1000);
+
+type IsDisabled<'a, T> = Box<dyn Fn(usize, &T) -> bool + 'a>;
+
+/// A list of items that can be selected with the mouse and the keyboard.
+///
+/// Once clicked, the list takes keyboard input: the arrows, Home, End and
+/// the page keys move the active row; Space toggles it; Shift extends the
+/// selection and typing jumps to the first row starting with the typed
+/// text. With [`multiple`](Self::multiple), Ctrl (or Cmd) toggles rows,
+/// Shift selects ranges and Ctrl+A selects everything.
+///
+/// Only the rows inside the viewport are drawn, so it can be placed in a
+/// scrollable with plenty of items.
+pub struct SelectionList<'a, T, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    items: &'a [T],
+    selected: &'a [usize],
+    on_select: Option<Box<dyn Fn(Vec<usize>) -> Message + 'a>>,
+    on_visible: Option<Box<dyn Fn(Range<usize>) -> Message + 'a>>,
+    is_disabled: Option<IsDisabled<'a, T>>,
+    is_multiple: bool,
+    width: Length,
+    row_height: f32,
+    padding: f32,
+    text_size: Option<Pixels>,
+    font: Option<Renderer::Font>,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, T, Message, Theme, Renderer> SelectionList<'a, T, Message, Theme, Renderer>
+where
+    T: Display,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    pub fn new(items: &'a [T], selected: &'a [usize]) -> Self {
+        Self {
+            items,
+            selected,
+            on_select: None,
+            on_visible: None,
+            is_disabled: None,
+            is_multiple: false,
+            width: Length::Fill,
+            row_height: 30.0,
+            padding: 10.0,
+            text_size: None,
+            font: None,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the message produced with the new selected indices, in
+    /// ascending order.
+    pub fn on_select(mut self, on_select: impl Fn(Vec<usize>) -> Message + 'a) -> Self {
+        self.on_select = Some(Box::new(on_select));
+        self
+    }
+
+    /// Sets the message produced when the range of rows inside the
+    /// viewport changes; useful to load items lazily.
+    pub fn on_visible(mut self, on_visible: impl Fn(Range<usize>) -> Message + 'a) -> Self {
+        self.on_visible = Some(Box::new(on_visible));
+        self
+    }
+
+    /// Sets which rows can't be selected nor become active.
+    pub fn disabled(mut self, is_disabled: impl Fn(usize, &T) -> bool + 'a) -> Self {
+        self.is_disabled = Some(Box::new(is_disabled));
+        self
+    }
+
+    /// Sets whether more than one row can be selected.
+    pub fn multiple(mut self, is_multiple: bool) -> Self {
+        self.is_multiple = is_multiple;
+        self
+    }
+
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    pub fn row_height(mut self, row_height: impl Into<Pixels>) -> Self {
+        self.row_height = row_height.into().0;
+        self
+    }
+
+    /// Sets the horizontal padding of the rows.
+    pub fn padding(mut self, padding: impl Into<Pixels>) -> Self {
+        self.padding = padding.into().0;
+        self
+    }
+
+    pub fn text_size(mut self, text_size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(text_size.into());
+        self
+    }
+
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    fn is_enabled(&self, index: usize) -> bool {
+        self.items.get(index).is_some_and(|item| {
+            self.is_disabled
+                .as_ref()
+                .is_none_or(|is_disabled| !is_disabled(index, item))
+        })
+    }
+
+    /// Returns the closest enabled row from `index`, looking forward or
+    /// backward first.
+    fn enabled_from(&self, index: usize, forward: bool) -> Option<usize> {
+        let last = self.items.len().checked_sub(1)?;
+        let index = index.min(last);
+
+        let after = || (index..=last).find(|index| self.is_enabled(*index));
+        let before = || (0..=index).rev().find(|index| self.is_enabled(*index));
+
+        if forward {
+            after().or_else(before)
+        } else {
+            before().or_else(after)
+        }
+    }
+
+    fn select(&self, selection: Vec<usize>, shell: &mut Shell<'_, Message>) {
+        let Some(on_select) = &self.on_select else {
+            return;
+        };
+
+        let mut selection: Vec<usize> = selection
+            .into_iter()
+            .filter(|index| self.is_enabled(*index))
+            .collect();
+
+        selection.sort_unstable();
+        selection.dedup();
+
+        let mut current = self.selected.to_vec();
+        current.sort_unstable();
+        current.dedup();
+
+        if selection != current {
+            shell.publish(on_select(selection));
+        }
+    }
+
+    /// Selects the rows from the anchor to `index`; on top of the current
+    /// selection if `is_additive`.
+    fn select_range(
+        &self,
+        anchor: usize,
+        index: usize,
+        is_additive: bool,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let range = anchor.min(index)..=anchor.max(index);
+
+        let selection = if is_additive {
+            self.selected.iter().copied().chain(range).collect()
+        } else {
+            range.collect()
+        };
+
+        self.select(selection, shell);
+    }
+
+    /// Handles a click or a key moving to `index`.
+    fn activate(
+        &self,
+        state: &mut State,
+        index: usize,
+        modifiers: keyboard::Modifiers,
+        is_toggle: bool,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        state.active = Some(index);
+
+        if self.is_multiple && modifiers.shift() {
+            let anchor = state.anchor.unwrap_or(index);
+
+            self.select_range(anchor, index, modifiers.command(), shell);
+            return;
+        }
+
+        state.anchor = Some(index);
+
+        if self.is_multiple && is_toggle {
+            let selection = if self.selected.contains(&index) {
+                self.selected
+                    .iter()
+                    .copied()
+                    .filter(|selected| *selected != index)
+                    .collect()
+            } else {
+                self.selected.iter().copied().chain([index]).collect()
+            };
+
+            self.select(selection, shell);
+        } else {
+            self.select(vec![index], shell);
+        }
+    }
+
+    fn text(
+        &self,
+        content: String,
+        bounds: Size,
+        renderer: &Renderer,
+    ) -> text::Text<String, Renderer::Font> {
+        text::Text {
+            content,
+            bounds,
+            size: self.text_size.unwrap_or_else(|| renderer.default_size()),
+            line_height: text::LineHeight::default(),
+            font: self.font.unwrap_or_else(|| renderer.default_font()),
+            align_x: text::Alignment::Default,
+            align_y: alignment::Vertical::Center,
+            shaping: text::Shaping::Advanced,
+            wrapping: text::Wrapping::None,
+            hint_factor: renderer.scale_factor(),
+        }
+    }
+}
+
+#[derive(Default)]
+struct State {
+    is_focused: bool,
+    active: Option<usize>,
+    anchor: Option<usize>,
+    hovered: Option<usize>,
+    typed: String,
+    typed_at: Option<Instant>,
+    visible: Option<Range<usize>>,
+    modifiers: keyboard::Modifiers,
+}
+
+impl<T, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for SelectionList<'_, T, Message, Theme, Renderer>
+where
+    T: Display,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, Length::Shrink)
+    }
+
+    fn layout(
+        &mut self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let height = self.items.len() as f32 * self.row_height;
+
+        layout::atomic(limits, self.width, height)
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+        let row_at = |position: Point| {
+            let index = ((position.y - bounds.y) / self.row_height) as usize;
+
+            (index < self.items.len()).then_some(index)
+        };
+
+        if let Some(on_visible) = &self.on_visible {
+            let visible = visible_rows(bounds, viewport, self.row_height, self.items.len());
+
+            if state.visible.as_ref() != Some(&visible) {
+                state.visible = Some(visible.clone());
+                shell.publish(on_visible(visible));
+            }
+        }
+
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                let hovered = cursor.position_over(bounds).and_then(row_at);
+
+                if state.hovered != hovered {
+                    state.hovered = hovered;
+                    shell.request_redraw();
+                }
+            }
+            Event::Mouse(mouse::Event::CursorLeft) if state.hovered.take().is_some() => {
+                shell.request_redraw();
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                let Some(position) = cursor.position_over(bounds) else {
+                    if state.is_focused {
+                        state.is_focused = false;
+                        shell.request_redraw();
+                    }
+
+                    return;
+                };
+
+                state.is_focused = true;
+
+                if let Some(index) = row_at(position).filter(|index| self.is_enabled(*index)) {
+                    let modifiers = state.modifiers;
+
+                    self.activate(state, index, modifiers, modifiers.command(), shell);
+                }
+
+                shell.request_redraw();
+                shell.capture_event();
+            }
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                state.modifiers = *modifiers;
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key,
+                modifiers,
+                text,
+                ..
+            }) if state.is_focused => {
+                let rows_per_page = (viewport.height / self.row_height).floor().max(1.0) as usize;
+                let last = self.items.len().saturating_sub(1);
+                let active = state.active;
+
+                let target = match key.as_ref() {
+                    keyboard::Key::Named(key::Named::ArrowDown) => {
+                        Some(active.map_or(0, |active| (active + 1).min(last)))
+                            .and_then(|index| self.enabled_from(index, true))
+                    }
+                    keyboard::Key::Named(key::Named::ArrowUp) => {
+                        Some(active.map_or(0, |active| active.saturating_sub(1)))
+                            .and_then(|index| self.enabled_from(index, false))
+                    }
+                    keyboard::Key::Named(key::Named::PageDown) => {
+                        Some(active.map_or(0, |active| (active + rows_per_page).min(last)))
+                            .and_then(|index| self.enabled_from(index, true))
+                    }
+                    keyboard::Key::Named(key::Named::PageUp) => {
+                        Some(active.map_or(0, |active| active.saturating_sub(rows_per_page)))
+                            .and_then(|index| self.enabled_from(index, false))
+                    }
+                    keyboard::Key::Named(key::Named::Home) => self.enabled_from(0, true),
+                    keyboard::Key::Named(key::Named::End) => self.enabled_from(last, false),
+                    keyboard::Key::Named(key::Named::Space | key::Named::Enter) => {
+                        if let Some(active) = active.filter(|active| self.is_enabled(*active)) {
+                            self.activate(state, active, *modifiers, true, shell);
+                        }
+
+                        shell.capture_event();
+                        return;
+                    }
+                    keyboard::Key::Named(key::Named::Escape) => {
+                        state.is_focused = false;
+
+                        shell.request_redraw();
+                        shell.capture_event();
+                        return;
+                    }
+                    keyboard::Key::Character("a") if modifiers.command() && self.is_multiple => {
+                        self.select((0..self.items.len()).collect(), shell);
+
+                        shell.capture_event();
+                        return;
+                    }
+                    _ => {
+                        let Some(text) = text
+                            .as_deref()
+                            .filter(|text| !text.chars().any(char::is_control))
+                            .filter(|_| !modifiers.command())
+                        else {
+                            return;
+                        };
+
+                        // Typed characters expire on a later redraw
+                        state.typed.push_str(&text.to_lowercase());
+                        state.typed_at = None;
+
+                        let typed = &state.typed;
+
+                        (0..self.items.len()).find(|index| {
+                            self.is_enabled(*index)
+                                && self.items[*index]
+                                    .to_string()
+                                    .to_lowercase()
+                                    .starts_with(typed.as_str())
+                        })
+                    }
+                };
+
+                if let Some(index) = target {
+                    // Moving with Ctrl keeps the selection
+                    if modifiers.command() && !modifiers.shift() && self.is_multiple {
+                        state.active = Some(index);
+                    } else {
+                        self.activate(state, index, *modifiers, false, shell);
+                    }
+                }
+
+                shell.request_redraw();
+                shell.capture_event();
+            }
+            Event::Window(window::Event::RedrawRequested(now)) if !state.typed.is_empty() => {
+                let expires_at = *state.typed_at.get_or_insert(*now) + TYPE_AHEAD;
+
+                if *now >= expires_at {
+                    state.typed.clear();
+                    state.typed_at = None;
+                } else {
+                    shell.request_redraw_at(expires_at);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+
+        match state.hovered {
+            Some(index) if cursor.is_over(layout.bounds()) => {
+                if self.is_enabled(index) && self.on_select.is_some() {
+                    mouse::Interaction::Pointer
+                } else {
+                    mouse::Interaction::NotAllowed
+                }
+            }
+            _ => mouse::Interaction::None,
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _defaults: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let style = theme.style(&self.class);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: style.border,
+                ..renderer::Quad::default()
+            },
+            style.background,
+        );
+
+        let Some(clip) = bounds.intersection(viewport) else {
+            return;
+        };
+
+        for index in visible_rows(bounds, viewport, self.row_height, self.items.len()) {
+            let row = Rectangle {
+                y: bounds.y + index as f32 * self.row_height,
+                height: self.row_height,
+                ..bounds
+            };
+
+            let is_enabled = self.is_enabled(index);
+            let is_selected = self.selected.contains(&index);
+
+            let background = if is_selected {
+                Some(style.selected)
+            } else if is_enabled && state.hovered == Some(index) {
+                Some(style.hovered)
+            } else {
+                None
+            };
+
+            if let Some(background) = background {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: row,
+                        ..renderer::Quad::default()
+                    },
+                    background,
+                );
+            }
+
+            if state.is_focused && state.active == Some(index) {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: row.shrink(1.0),
+                        border: Border::default().width(1).color(style.active),
+                        ..renderer::Quad::default()
+                    },
+                    Color::TRANSPARENT,
+                );
+            }
+
+            let color = if !is_enabled {
+                style.disabled_text
+            } else if is_selected {
+                style.selected_text
+            } else {
+                style.text
+            };
+
+            let content = self.items[index].to_string();
+            let size = Size::new((row.width - self.padding * 2.0).max(0.0), row.height);
+
+            renderer.fill_text(
+                self.text(content, size, renderer),
+                Point::new(row.x + self.padding, row.center_y()),
+                color,
+                clip,
+            );
+        }
+    }
+}
+
+/// Returns the rows of a list inside the viewport.
+fn visible_rows(
+    bounds: Rectangle,
+    viewport: &Rectangle,
+    row_height: f32,
+    rows: usize,
+) -> Range<usize> {
+    let Some(visible) = bounds.intersection(viewport) else {
+        return 0..0;
+    };
+
+    let start = ((visible.y - bounds.y) / row_height).floor().max(0.0) as usize;
+    let end = ((visible.y + visible.height - bounds.y) / row_height)
+        .ceil()
+        .max(0.0) as usize;
+
+    start.min(rows)..end.min(rows)
+}
+
+impl<'a, T, Message, Theme, Renderer> From<SelectionList<'a, T, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    T: Display + 'a,
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(selection_list: SelectionList<'a, T, Message, Theme, Renderer>) -> Self {
+        Element::new(selection_list)
+    }
+}
+
+/// The appearance of a [`SelectionList`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Background`] of the list.
+    pub background: Background,
+    /// The [`Border`] of the list.
+    pub border: Border,
+    /// The [`Background`] of a hovered row.
+    pub hovered: Background,
+    /// The [`Background`] of a selected row.
+    pub selected: Background,
+    /// The outline [`Color`] of the active row, while focused.
+    pub active: Color,
+    /// The [`Color`] of the text of a row.
+    pub text: Color,
+    /// The [`Color`] of the text of a selected row.
+    pub selected_text: Color,
+    /// The [`Color`] of the text of a disabled row.
+    pub disabled_text: Color,
+}
+
+/// The theme catalog of a [`SelectionList`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`SelectionList`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`SelectionList`].
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        background: palette.background.base.color.into(),
+        border: Border::default()
+            .width(1)
+            .color(palette.background.strong.color),
+        hovered: palette.background.weak.color.into(),
+        selected: palette.primary.base.color.into(),
+        active: palette.primary.strong.color,
+        text: palette.background.base.text,
+        selected_text: palette.primary.base.text,
+        disabled_text: palette.background.strong.color,
+    }
+}
@@ -0,0 +1,765 @@
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::{Operation, Widget};
+use crate::core::{
+    Background, Border, Clipboard, Color, Element, Event, Length, Padding, Pixels, Point,
+    Rectangle, Shadow, Shell, Size, Theme, Vector,
+};
+
+/// The width of the button that opens the overflow popover.
+const OVERFLOW_WIDTH: f32 = 24.0;
+
+/// A bar with a left, a center and a right section of items.
+///
+/// When the bar is too narrow, the items with the lowest priority are hidden
+/// into a popover opened from the end of the bar.
+pub struct StatusBar<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    items: Vec<Item<'a, Message, Theme, Renderer>>,
+    sections: [usize; 2],
+    width: Length,
+    padding: Padding,
+    spacing: f32,
+    class: Theme::Class<'a>,
+}
+
+/// An item of a [`StatusBar`].
+pub struct Item<'a, Message, Theme, Renderer> {
+    content: Element<'a, Message, Theme, Renderer>,
+    priority: u8,
+}
+
+impl<'a, Message, Theme, Renderer> Item<'a, Message, Theme, Renderer> {
+    pub fn new(content: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        Self {
+            content: content.into(),
+            priority: 0,
+        }
+    }
+
+    /// Sets the priority of the [`Item`]; items with a lower priority are
+    /// hidden first.
+    pub fn priority(mut self, priority: u8) -> Self {
+        self.priority = priority;
+        self
+    }
+}
+
+/// Creates a new [`Item`] with the given content.
+pub fn item<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> Item<'a, Message, Theme, Renderer> {
+    Item::new(content)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Section {
+    Left,
+    Center,
+    Right,
+}
+
+impl<'a, Message, Theme, Renderer> StatusBar<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    pub fn new(
+        left: impl IntoIterator<Item = Item<'a, Message, Theme, Renderer>>,
+        center: impl IntoIterator<Item = Item<'a, Message, Theme, Renderer>>,
+        right: impl IntoIterator<Item = Item<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        let mut items: Vec<_> = left.into_iter().collect();
+        let left = items.len();
+
+        items.extend(center);
+
+        let center = items.len();
+
+        items.extend(right);
+
+        Self {
+            items,
+            sections: [left, center],
+            width: Length::Fill,
+            padding: Padding::from([2, 8]),
+            spacing: 16.0,
+            class: Theme::default(),
+        }
+    }
+
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    pub fn padding(mut self, padding: impl Into<Padding>) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the spacing between items, with a separator in the middle.
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.spacing = spacing.into().0;
+        self
+    }
+
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    fn section(&self, index: usize) -> Section {
+        if index < self.sections[0] {
+            Section::Left
+        } else if index < self.sections[1] {
+            Section::Center
+        } else {
+            Section::Right
+        }
+    }
+
+    /// Returns the width taken by the visible items of a section.
+    fn section_width(&self, section: Section, widths: &[f32], hidden: &[bool]) -> f32 {
+        let visible: Vec<f32> = widths
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| self.section(*index) == section && !hidden[*index])
+            .map(|(_, width)| *width)
+            .collect();
+
+        visible.iter().sum::<f32>() + visible.len().saturating_sub(1) as f32 * self.spacing
+    }
+}
+
+#[derive(Debug, Default)]
+struct State {
+    hidden: Vec<bool>,
+    overflow: Option<Rectangle>,
+    is_open: bool,
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for StatusBar<'_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: renderer::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.items
+            .iter()
+            .map(|item| Tree::new(&item.content))
+            .collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let contents: Vec<_> = self.items.iter().map(|item| &item.content).collect();
+
+        tree.diff_children(&contents);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, Length::Shrink)
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width);
+        let width = limits.max().width;
+        let inner = (width - self.padding.x()).max(0.0);
+
+        let nodes: Vec<layout::Node> = self
+            .items
+            .iter_mut()
+            .zip(&mut tree.children)
+            .map(|(item, tree)| {
+                item.content.as_widget_mut().layout(
+                    tree,
+                    renderer,
+                    &layout::Limits::new(Size::ZERO, Size::new(inner, limits.max().height)),
+                )
+            })
+            .collect();
+
+        let widths: Vec<f32> = nodes.iter().map(|node| node.size().width).collect();
+        let height = nodes
+            .iter()
+            .map(|node| node.size().height)
+            .fold(0.0, f32::max)
+            + self.padding.y();
+
+        // Lowest priority first; on ties, the last items go first
+        let mut victims: Vec<usize> = (0..self.items.len()).collect();
+        victims.sort_by_key(|index| (self.items[*index].priority, std::cmp::Reverse(*index)));
+
+        let mut hidden = vec![false; self.items.len()];
+
+        let required = |hidden: &[bool]| {
+            let sections = [Section::Left, Section::Center, Section::Right]
+                .map(|section| self.section_width(section, &widths, hidden));
+            let gaps = sections.iter().filter(|width| **width > 0.0).count();
+            let overflow = if hidden.contains(&true) {
+                OVERFLOW_WIDTH + self.spacing
+            } else {
+                0.0
+            };
+
+            sections.iter().sum::<f32>() + gaps.saturating_sub(1) as f32 * self.spacing + overflow
+        };
+
+        for victim in victims {
+            if required(&hidden) <= inner {
+                break;
+            }
+
+            hidden[victim] = true;
+        }
+
+        let overflow = hidden.contains(&true).then_some(Rectangle {
+            x: width - self.padding.right - OVERFLOW_WIDTH,
+            y: 0.0,
+            width: OVERFLOW_WIDTH,
+            height,
+        });
+
+        let left = self.section_width(Section::Left, &widths, &hidden);
+        let center = self.section_width(Section::Center, &widths, &hidden);
+        let right = self.section_width(Section::Right, &widths, &hidden);
+        let end = overflow.map_or(width - self.padding.right, |overflow| {
+            overflow.x - self.spacing
+        });
+
+        let left_end = self.padding.left + left + self.spacing;
+        let right_start = end - right - self.spacing;
+
+        let mut x = [
+            self.padding.left,
+            ((width - center) / 2.0).clamp(left_end.min(right_start), right_start.max(left_end)),
+            end - right,
+        ];
+
+        let nodes = nodes
+            .into_iter()
+            .enumerate()
+            .map(|(index, node)| {
+                if hidden[index] {
+                    return layout::Node::new(Size::ZERO);
+                }
+
+                let slot = &mut x[self.section(index) as usize];
+                let size = node.size();
+                let node = node.move_to(Point::new(*slot, (height - size.height) / 2.0));
+
+                *slot += size.width + self.spacing;
+
+                node
+            })
+            .collect();
+
+        let state = tree.state.downcast_mut::<State>();
+
+        state.is_open &= overflow.is_some();
+        state.hidden = hidden;
+        state.overflow = overflow;
+
+        layout::Node::with_children(Size::new(width, height), nodes)
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        operation.container(None, layout.bounds());
+        operation.traverse(&mut |operation| {
+            for ((item, tree), layout) in self
+                .items
+                .iter_mut()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+            {
+                item.content
+                    .as_widget_mut()
+                    .operate(tree, layout, renderer, operation);
+            }
+        });
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+
+        for (((item, tree), layout), is_hidden) in self
+            .items
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .zip(&state.hidden)
+        {
+            if *is_hidden {
+                continue;
+            }
+
+            item.content.as_widget_mut().update(
+                tree, event, layout, cursor, renderer, clipboard, shell, viewport,
+            );
+        }
+
+        let Some(overflow) = state.overflow else {
+            return;
+        };
+
+        let overflow = overflow + Vector::new(layout.position().x, layout.position().y);
+
+        if matches!(
+            event,
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+        ) && cursor.is_over(overflow)
+            && !shell.is_event_captured()
+        {
+            state.is_open = !state.is_open;
+
+            shell.capture_event();
+            shell.request_redraw();
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+
+        if state.overflow.is_some_and(|overflow| {
+            cursor.is_over(overflow + Vector::new(layout.position().x, layout.position().y))
+        }) {
+            return mouse::Interaction::Pointer;
+        }
+
+        self.items
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .zip(&state.hidden)
+            .filter(|(_, is_hidden)| !**is_hidden)
+            .map(|(((item, tree), layout), _)| {
+                item.content
+                    .as_widget()
+                    .mouse_interaction(tree, layout, cursor, viewport, renderer)
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _defaults: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let style = theme.style(&self.class);
+        let bounds = layout.bounds();
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: style.border,
+                ..renderer::Quad::default()
+            },
+            style.background,
+        );
+
+        let defaults = renderer::Style {
+            text_color: style.text_color,
+        };
+
+        let mut previous: Option<(usize, Rectangle)> = None;
+
+        for (index, ((item, tree), layout)) in self
+            .items
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .enumerate()
+        {
+            if state.hidden[index] {
+                continue;
+            }
+
+            let item_bounds = layout.bounds();
+
+            if let Some((_, previous)) =
+                previous.filter(|(previous, _)| self.section(*previous) == self.section(index))
+            {
+                let x = (previous.x + previous.width + item_bounds.x) / 2.0;
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: x.round(),
+                            y: bounds.y + bounds.height * 0.25,
+                            width: 1.0,
+                            height: bounds.height * 0.5,
+                        },
+                        ..renderer::Quad::default()
+                    },
+                    style.separator,
+                );
+            }
+
+            item.content
+                .as_widget()
+                .draw(tree, renderer, theme, &defaults, layout, cursor, viewport);
+
+            previous = Some((index, item_bounds));
+        }
+
+        if let Some(overflow) = state.overflow {
+            let overflow = overflow + Vector::new(bounds.x, bounds.y);
+
+            if state.is_open || cursor.is_over(overflow) {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: overflow,
+                        border: Border::default().rounded(4),
+                        ..renderer::Quad::default()
+                    },
+                    style.hovered,
+                );
+            }
+
+            for dot in -1..=1 {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: overflow.center_x() + dot as f32 * 5.0 - 1.5,
+                            y: overflow.center_y() - 1.5,
+                            width: 3.0,
+                            height: 3.0,
+                        },
+                        border: Border::default().rounded(1.5),
+                        ..renderer::Quad::default()
+                    },
+                    style.text_color,
+                );
+            }
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let state = tree.state.downcast_mut::<State>();
+        let position = layout.position();
+        let mut children = Vec::new();
+        let mut hidden = Vec::new();
+
+        for (((item, tree), layout), is_hidden) in self
+            .items
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .zip(&state.hidden)
+        {
+            if *is_hidden {
+                hidden.push((&mut item.content, tree));
+            } else {
+                children.extend(item.content.as_widget_mut().overlay(
+                    tree,
+                    layout,
+                    renderer,
+                    viewport,
+                    translation,
+                ));
+            }
+        }
+
+        if let Some(overflow) = state.overflow.filter(|_| state.is_open) {
+            children.push(overlay::Element::new(Box::new(Popover {
+                items: hidden,
+                is_open: &mut state.is_open,
+                anchor: overflow + Vector::new(position.x, position.y) + translation,
+                padding: self.padding,
+                class: &self.class,
+            })));
+        }
+
+        (!children.is_empty()).then(|| overlay::Group::with_children(children).overlay())
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<StatusBar<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(status_bar: StatusBar<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(status_bar)
+    }
+}
+
+/// The popover listing the hidden items of a [`StatusBar`].
+struct Popover<'a, 'b, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    items: Vec<(&'b mut Element<'a, Message, Theme, Renderer>, &'b mut Tree)>,
+    is_open: &'b mut bool,
+    anchor: Rectangle,
+    padding: Padding,
+    class: &'b Theme::Class<'a>,
+}
+
+impl<Message, Theme, Renderer> overlay::Overlay<Message, Theme, Renderer>
+    for Popover<'_, '_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: renderer::Renderer,
+{
+    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> layout::Node {
+        let padding = Padding::new(self.padding.left.max(8.0));
+        let limits = layout::Limits::new(Size::ZERO, bounds).shrink(padding);
+
+        let mut y = padding.top;
+        let mut width: f32 = 0.0;
+
+        let nodes: Vec<layout::Node> = self
+            .items
+            .iter_mut()
+            .map(|(content, tree)| {
+                let node = content.as_widget_mut().layout(tree, renderer, &limits);
+                let size = node.size();
+                let node = node.move_to(Point::new(padding.left, y));
+
+                y += size.height + padding.top;
+                width = width.max(size.width);
+
+                node
+            })
+            .collect();
+
+        let size = Size::new(width + padding.x(), y);
+
+        // Open upwards, unless there is no room above the bar
+        let y = if self.anchor.y >= size.height {
+            self.anchor.y - size.height
+        } else {
+            self.anchor.y + self.anchor.height
+        };
+
+        let x = (self.anchor.x + self.anchor.width - size.width)
+            .min(bounds.width - size.width)
+            .max(0.0);
+
+        layout::Node::with_children(size, nodes).move_to(Point::new(x, y))
+    }
+
+    fn update(
+        &mut self,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let bounds = layout.bounds();
+
+        for ((content, tree), layout) in self.items.iter_mut().zip(layout.children()) {
+            content.as_widget_mut().update(
+                tree, event, layout, cursor, renderer, clipboard, shell, &bounds,
+            );
+        }
+
+        match event {
+            // The overflow button toggles the popover by itself
+            Event::Mouse(mouse::Event::ButtonPressed(_))
+                if !cursor.is_over(bounds) && !cursor.is_over(self.anchor) =>
+            {
+                *self.is_open = false;
+                shell.request_redraw();
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key::Named::Escape),
+                ..
+            }) => {
+                *self.is_open = false;
+                shell.capture_event();
+                shell.request_redraw();
+            }
+            _ => {}
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let bounds = layout.bounds();
+
+        self.items
+            .iter()
+            .zip(layout.children())
+            .map(|((content, tree), layout)| {
+                content
+                    .as_widget()
+                    .mouse_interaction(tree, layout, cursor, &bounds, renderer)
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) {
+        let style = theme.style(self.class);
+        let bounds = layout.bounds();
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: style.popover_border,
+                shadow: style.shadow,
+                ..renderer::Quad::default()
+            },
+            style.popover_background,
+        );
+
+        let defaults = renderer::Style {
+            text_color: style.text_color,
+        };
+
+        for ((content, tree), layout) in self.items.iter().zip(layout.children()) {
+            content
+                .as_widget()
+                .draw(tree, renderer, theme, &defaults, layout, cursor, &bounds);
+        }
+    }
+}
+
+/// The appearance of a [`StatusBar`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Background`] of the bar.
+    pub background: Background,
+    /// The [`Border`] of the bar.
+    pub border: Border,
+    /// The default text [`Color`] of the items.
+    pub text_color: Color,
+    /// The [`Color`] of the separators between items.
+    pub separator: Color,
+    /// The [`Background`] of the hovered overflow button.
+    pub hovered: Background,
+    /// The [`Background`] of the overflow popover.
+    pub popover_background: Background,
+    /// The [`Border`] of the overflow popover.
+    pub popover_border: Border,
+    /// The [`Shadow`] of the overflow popover.
+    pub shadow: Shadow,
+}
+
+/// The theme catalog of a [`StatusBar`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`StatusBar`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`StatusBar`].
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        background: palette.background.weak.color.into(),
+        border: Border::default(),
+        text_color: palette.background.weak.text,
+        separator: palette.background.strong.color,
+        hovered: palette.background.strong.color.into(),
+        popover_background: palette.background.base.color.into(),
+        popover_border: Border::default()
+            .rounded(4)
+            .width(1)
+            .color(palette.background.strong.color),
+        shadow: Shadow {
+            color: Color::BLACK.scale_alpha(0.2),
+            offset: Vector::new(0.0, 2.0),
+            blur_radius: 8.0,
+        },
+    }
+}
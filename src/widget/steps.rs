@@ -0,0 +1,655 @@
+use crate::core::alignment;
+use crate::core::animation::{Animation, Easing};
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::text::{self, Fragment, IntoFragment};
+use crate::core::time::{Duration, Instant, milliseconds};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::{Operation, Widget};
+use crate::core::window;
+use crate::core::{
+    Background, Border, Clipboard, Color, Element, Event, Length, Pixels, Point, Rectangle, Shell,
+    Size, Theme, Vector,
+};
+
+const MARKER_SIZE: f32 = 28.0;
+const MARKER_SPACING: f32 = 6.0;
+
+pub struct Steps<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    current: usize,
+    labels: Vec<Fragment<'a>>,
+    contents: Vec<Element<'a, Message, Theme, Renderer>>,
+    disabled: Vec<bool>,
+    on_step_change: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    width: Length,
+    height: Length,
+    spacing: f32,
+    text_size: Option<Pixels>,
+    font: Option<Renderer::Font>,
+    duration: Duration,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> Steps<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    pub fn new(current: usize) -> Self {
+        Self {
+            current,
+            labels: Vec::new(),
+            contents: Vec::new(),
+            disabled: Vec::new(),
+            on_step_change: None,
+            width: Length::Fill,
+            height: Length::Shrink,
+            spacing: 20.0,
+            text_size: None,
+            font: None,
+            duration: milliseconds(250),
+            class: Theme::default(),
+        }
+    }
+
+    pub fn push(
+        self,
+        label: impl IntoFragment<'a>,
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        self.push_maybe(label, content, true)
+    }
+
+    pub fn push_disabled(
+        self,
+        label: impl IntoFragment<'a>,
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        self.push_maybe(label, content, false)
+    }
+
+    pub fn push_maybe(
+        mut self,
+        label: impl IntoFragment<'a>,
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        is_enabled: bool,
+    ) -> Self {
+        self.labels.push(label.into_fragment());
+        self.contents.push(content.into());
+        self.disabled.push(!is_enabled);
+        self
+    }
+
+    pub fn on_step_change(mut self, on_step_change: impl Fn(usize) -> Message + 'a) -> Self {
+        self.on_step_change = Some(Box::new(on_step_change));
+        self
+    }
+
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.spacing = spacing.into().0;
+        self
+    }
+
+    pub fn text_size(mut self, text_size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(text_size.into());
+        self
+    }
+
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    pub fn duration(mut self, duration: impl Into<Duration>) -> Self {
+        self.duration = duration.into();
+        self
+    }
+
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    fn status(&self, index: usize) -> Status {
+        if self.disabled[index] {
+            Status::Disabled
+        } else if index < self.current {
+            Status::Completed
+        } else if index == self.current {
+            Status::Current
+        } else {
+            Status::Upcoming
+        }
+    }
+
+    fn header_height(&self, renderer: &Renderer) -> f32 {
+        let size = self.text_size.unwrap_or_else(|| renderer.default_size());
+
+        MARKER_SIZE + MARKER_SPACING + text::LineHeight::default().to_absolute(size).0
+    }
+
+    fn slots(&self, bounds: Rectangle, renderer: &Renderer) -> impl Iterator<Item = Rectangle> {
+        let width = bounds.width / self.labels.len().max(1) as f32;
+        let height = self.header_height(renderer);
+
+        (0..self.labels.len()).map(move |i| Rectangle {
+            x: bounds.x + width * i as f32,
+            y: bounds.y,
+            width,
+            height,
+        })
+    }
+}
+
+struct State {
+    current: usize,
+    previous: Option<usize>,
+    transition: Animation<bool>,
+    /// Whether the transition starts on the next redraw.
+    is_pending: bool,
+    now: Instant,
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Steps<'_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            current: self.current,
+            previous: None,
+            transition: Animation::new(true),
+            is_pending: false,
+            now: Instant::now(),
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.contents.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&self.contents);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let state = tree.state.downcast_mut::<State>();
+
+        if state.current != self.current {
+            state.previous = Some(state.current);
+            state.current = self.current;
+            state.transition = Animation::new(false)
+                .duration(self.duration)
+                .easing(Easing::EaseInOut);
+            state.is_pending = true;
+        }
+
+        let limits = limits.width(self.width).height(self.height);
+        let offset = self.header_height(renderer) + self.spacing;
+        let content_limits = limits.shrink(Size::new(0.0, offset));
+
+        let mut content_size = Size::ZERO;
+
+        let children = self
+            .contents
+            .iter_mut()
+            .zip(&mut tree.children)
+            .enumerate()
+            .map(|(i, (content, tree))| {
+                if i != state.current && Some(i) != state.previous {
+                    return layout::Node::new(Size::ZERO);
+                }
+
+                let node = content
+                    .as_widget_mut()
+                    .layout(tree, renderer, &content_limits)
+                    .move_to(Point::new(0.0, offset));
+
+                content_size = content_size.max(node.size());
+
+                node
+            })
+            .collect();
+
+        let size = limits.resolve(
+            self.width,
+            self.height,
+            Size::new(content_size.width, content_size.height + offset),
+        );
+
+        layout::Node::with_children(size, children)
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let current = self.current;
+
+        operation.container(None, layout.bounds());
+        operation.traverse(&mut |operation| {
+            if let (Some(content), Some(layout)) = (
+                self.contents.get_mut(current),
+                layout.children().nth(current),
+            ) {
+                content.as_widget_mut().operate(
+                    &mut tree.children[current],
+                    layout,
+                    renderer,
+                    operation,
+                );
+            }
+        });
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        if let Some(content) = self.contents.get_mut(self.current) {
+            content.as_widget_mut().update(
+                &mut tree.children[self.current],
+                event,
+                layout.children().nth(self.current).unwrap(),
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+                viewport,
+            );
+        }
+
+        if shell.is_event_captured() {
+            return;
+        }
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                let Some(on_step_change) = &self.on_step_change else {
+                    return;
+                };
+
+                let hovered = self
+                    .slots(layout.bounds(), renderer)
+                    .position(|slot| cursor.is_over(slot));
+
+                if let Some(index) = hovered {
+                    if index != self.current && !self.disabled[index] {
+                        shell.publish(on_step_change(index));
+                        shell.capture_event();
+                    }
+                }
+            }
+            Event::Window(window::Event::RedrawRequested(now)) => {
+                let state = tree.state.downcast_mut::<State>();
+                state.now = *now;
+
+                if std::mem::take(&mut state.is_pending) {
+                    state.transition.go_mut(true, *now);
+                }
+
+                if state.transition.is_animating(*now) {
+                    shell.request_redraw();
+                } else if state.previous.take().is_some() {
+                    shell.invalidate_layout();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if self.on_step_change.is_some() {
+            let hovered = self
+                .slots(layout.bounds(), renderer)
+                .position(|slot| cursor.is_over(slot));
+
+            if let Some(index) = hovered {
+                return if index != self.current && !self.disabled[index] {
+                    mouse::Interaction::Pointer
+                } else {
+                    mouse::Interaction::None
+                };
+            }
+        }
+
+        self.contents
+            .get(self.current)
+            .map(|content| {
+                content.as_widget().mouse_interaction(
+                    &tree.children[self.current],
+                    layout.children().nth(self.current).unwrap(),
+                    cursor,
+                    viewport,
+                    renderer,
+                )
+            })
+            .unwrap_or_default()
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        defaults: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+
+        let size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
+        let line_height = text::LineHeight::default();
+
+        let slots: Vec<Rectangle> = self.slots(bounds, renderer).collect();
+
+        for (i, slot) in slots.iter().enumerate() {
+            let style = theme.style(&self.class, self.status(i));
+
+            let marker = Rectangle {
+                x: slot.center_x() - MARKER_SIZE / 2.0,
+                y: slot.y,
+                width: MARKER_SIZE,
+                height: MARKER_SIZE,
+            };
+
+            if let Some(next) = slots.get(i + 1) {
+                let next_style = theme.style(&self.class, self.status(i + 1));
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: marker.x + MARKER_SIZE + MARKER_SPACING,
+                            y: marker.center_y() - 1.0,
+                            width: next.center_x()
+                                - slot.center_x()
+                                - MARKER_SIZE
+                                - MARKER_SPACING * 2.0,
+                            height: 2.0,
+                        },
+                        ..renderer::Quad::default()
+                    },
+                    next_style.connector,
+                );
+            }
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: marker,
+                    border: Border {
+                        radius: (MARKER_SIZE / 2.0).into(),
+                        ..style.marker_border
+                    },
+                    ..renderer::Quad::default()
+                },
+                style.marker,
+            );
+
+            renderer.fill_text(
+                text::Text {
+                    content: (i + 1).to_string(),
+                    bounds: marker.size(),
+                    size,
+                    line_height,
+                    font,
+                    align_x: text::Alignment::Center,
+                    align_y: alignment::Vertical::Center,
+                    shaping: text::Shaping::Basic,
+                    wrapping: text::Wrapping::None,
+                    hint_factor: renderer.scale_factor(),
+                },
+                marker.center(),
+                style.marker_text,
+                *viewport,
+            );
+
+            renderer.fill_text(
+                text::Text {
+                    content: self.labels[i].to_string(),
+                    bounds: Size::new(slot.width, line_height.to_absolute(size).0),
+                    size,
+                    line_height,
+                    font,
+                    align_x: text::Alignment::Center,
+                    align_y: alignment::Vertical::Top,
+                    shaping: text::Shaping::Advanced,
+                    wrapping: text::Wrapping::None,
+                    hint_factor: renderer.scale_factor(),
+                },
+                Point::new(slot.center_x(), marker.y + MARKER_SIZE + MARKER_SPACING),
+                style.label,
+                *viewport,
+            );
+        }
+
+        let offset = self.header_height(renderer) + self.spacing;
+        let content_bounds = Rectangle {
+            y: bounds.y + offset,
+            height: bounds.height - offset,
+            ..bounds
+        };
+
+        let Some(clip) = content_bounds.intersection(viewport) else {
+            return;
+        };
+
+        let progress = state.transition.interpolate(0.0, 1.0, state.now);
+        let direction = match state.previous {
+            Some(previous) if previous > state.current => -1.0,
+            _ => 1.0,
+        };
+
+        renderer.with_layer(clip, |renderer| {
+            let mut visible = vec![(state.current, (1.0 - progress) * direction)];
+
+            if let Some(previous) = state.previous {
+                visible.push((previous, -progress * direction));
+            }
+
+            for (index, shift) in visible {
+                let (Some(content), Some(layout)) =
+                    (self.contents.get(index), layout.children().nth(index))
+                else {
+                    continue;
+                };
+
+                renderer.with_translation(
+                    Vector::new(shift * content_bounds.width, 0.0),
+                    |renderer| {
+                        content.as_widget().draw(
+                            &tree.children[index],
+                            renderer,
+                            theme,
+                            defaults,
+                            layout,
+                            cursor,
+                            &clip,
+                        );
+                    },
+                );
+            }
+        });
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let content = self.contents.get_mut(self.current)?;
+
+        content.as_widget_mut().overlay(
+            &mut tree.children[self.current],
+            layout.children().nth(self.current)?,
+            renderer,
+            viewport,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Steps<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(steps: Steps<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(steps)
+    }
+}
+
+/// The possible status of a step in a [`Steps`] header.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The step has been completed.
+    Completed,
+    /// The step is currently being shown.
+    Current,
+    /// The step has not been reached yet.
+    Upcoming,
+    /// The step cannot be selected.
+    Disabled,
+}
+
+/// The appearance of a step in a [`Steps`] header.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Background`] of the numbered marker.
+    pub marker: Background,
+    /// The [`Border`] of the numbered marker.
+    pub marker_border: Border,
+    /// The [`Color`] of the number inside the marker.
+    pub marker_text: Color,
+    /// The [`Color`] of the label below the marker.
+    pub label: Color,
+    /// The [`Background`] of the line connecting the marker to the previous one.
+    pub connector: Background,
+}
+
+/// The theme catalog of a [`Steps`] widget.
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class with the given status.
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style;
+}
+
+/// A styling function for a [`Steps`] widget.
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
+        class(self, status)
+    }
+}
+
+/// The default style of a [`Steps`] widget.
+pub fn default(theme: &Theme, status: Status) -> Style {
+    let palette = theme.extended_palette();
+
+    match status {
+        Status::Completed => Style {
+            marker: palette.primary.weak.color.into(),
+            marker_border: Border::default(),
+            marker_text: palette.primary.weak.text,
+            label: palette.background.base.text,
+            connector: palette.primary.weak.color.into(),
+        },
+        Status::Current => Style {
+            marker: palette.primary.base.color.into(),
+            marker_border: Border::default()
+                .width(2)
+                .color(palette.primary.strong.color),
+            marker_text: palette.primary.base.text,
+            label: palette.background.base.text,
+            connector: palette.primary.weak.color.into(),
+        },
+        Status::Upcoming => Style {
+            marker: palette.background.weak.color.into(),
+            marker_border: Border::default(),
+            marker_text: palette.background.weak.text,
+            label: palette.background.strongest.color,
+            connector: palette.background.strong.color.into(),
+        },
+        Status::Disabled => Style {
+            marker: palette.background.weakest.color.into(),
+            marker_border: Border::default(),
+            marker_text: palette.background.strong.color,
+            label: palette.background.strong.color,
+            connector: palette.background.strong.color.into(),
+        },
+    }
+}
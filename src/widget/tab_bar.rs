@@ -0,0 +1,1062 @@
+use crate::core::alignment;
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::text::{self, Fragment, IntoFragment};
+use crate::core::widget::Widget;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::{
+    Background, Border, Clipboard, Color, Element, Event, Length, Padding, Pixels, Point,
+    Rectangle, Shadow, Shell, Size, Theme, Vector,
+};
+
+/// The distance the cursor must travel before a pressed tab is dragged.
+const DRAG_THRESHOLD: f32 = 4.0;
+
+/// A bar of tabs that shrink to fit, with close buttons and an overflow
+/// menu for the tabs that do not.
+///
+/// Tabs can be reordered by dragging them. The wheel scrolls through the
+/// tabs when they overflow.
+pub struct TabBar<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    tabs: Vec<Tab<'a>>,
+    selected: Option<usize>,
+    on_select: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    on_close: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    on_reorder: Option<Box<dyn Fn(usize, usize) -> Message + 'a>>,
+    width: Length,
+    min_tab_width: f32,
+    max_tab_width: f32,
+    padding: Padding,
+    text_size: Option<Pixels>,
+    font: Option<Renderer::Font>,
+    class: Theme::Class<'a>,
+}
+
+/// A tab of a [`TabBar`].
+#[derive(Debug, Clone)]
+pub struct Tab<'a> {
+    label: Fragment<'a>,
+    is_dirty: bool,
+    is_closable: bool,
+}
+
+impl<'a> Tab<'a> {
+    pub fn new(label: impl IntoFragment<'a>) -> Self {
+        Self {
+            label: label.into_fragment(),
+            is_dirty: false,
+            is_closable: true,
+        }
+    }
+
+    /// Sets whether the [`Tab`] has unsaved changes, shown as a dot in
+    /// place of its close button.
+    pub fn dirty(mut self, is_dirty: bool) -> Self {
+        self.is_dirty = is_dirty;
+        self
+    }
+
+    pub fn closable(mut self, is_closable: bool) -> Self {
+        self.is_closable = is_closable;
+        self
+    }
+}
+
+/// Creates a new [`Tab`] with the given label.
+pub fn tab<'a>(label: impl IntoFragment<'a>) -> Tab<'a> {
+    Tab::new(label)
+}
+
+impl<'a, Message, Theme, Renderer> TabBar<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    pub fn new(tabs: impl IntoIterator<Item = Tab<'a>>) -> Self {
+        Self {
+            tabs: tabs.into_iter().collect(),
+            selected: None,
+            on_select: None,
+            on_close: None,
+            on_reorder: None,
+            width: Length::Fill,
+            min_tab_width: 80.0,
+            max_tab_width: 200.0,
+            padding: Padding::from([6, 12]),
+            text_size: None,
+            font: None,
+            class: Theme::default(),
+        }
+    }
+
+    pub fn selected(mut self, selected: impl Into<Option<usize>>) -> Self {
+        self.selected = selected.into();
+        self
+    }
+
+    pub fn on_select(mut self, on_select: impl Fn(usize) -> Message + 'a) -> Self {
+        self.on_select = Some(Box::new(on_select));
+        self
+    }
+
+    /// Sets the message produced when the close button of a tab is pressed
+    /// or the tab is middle-clicked.
+    pub fn on_close(mut self, on_close: impl Fn(usize) -> Message + 'a) -> Self {
+        self.on_close = Some(Box::new(on_close));
+        self
+    }
+
+    /// Sets the message produced when a tab is dragged from one index to
+    /// another. Tabs can only be dragged if set.
+    pub fn on_reorder(mut self, on_reorder: impl Fn(usize, usize) -> Message + 'a) -> Self {
+        self.on_reorder = Some(Box::new(on_reorder));
+        self
+    }
+
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the width under which tabs overflow into the menu.
+    pub fn min_tab_width(mut self, width: impl Into<Pixels>) -> Self {
+        self.min_tab_width = width.into().0;
+        self
+    }
+
+    pub fn max_tab_width(mut self, width: impl Into<Pixels>) -> Self {
+        self.max_tab_width = width.into().0;
+        self
+    }
+
+    pub fn padding(mut self, padding: impl Into<Padding>) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    pub fn text_size(mut self, text_size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(text_size.into());
+        self
+    }
+
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    fn metrics(&self, renderer: &Renderer) -> Metrics<Renderer::Font> {
+        let size = self.text_size.unwrap_or_else(|| renderer.default_size());
+
+        Metrics {
+            size,
+            line_height: text::LineHeight::default().to_absolute(size).0,
+            font: self.font.unwrap_or_else(|| renderer.default_font()),
+            hint_factor: renderer.scale_factor(),
+        }
+    }
+
+    fn geometry(&self, bounds: Rectangle, offset: usize) -> Geometry {
+        let tabs = self.tabs.len();
+
+        if tabs as f32 * self.min_tab_width <= bounds.width {
+            return Geometry {
+                bar: bounds,
+                tab_width: (bounds.width / tabs.max(1) as f32).min(self.max_tab_width),
+                visible: 0..tabs,
+                overflow: None,
+            };
+        }
+
+        let available = (bounds.width - bounds.height).max(0.0);
+        let count = ((available / self.min_tab_width).floor() as usize).clamp(1, tabs);
+        let first = offset.min(tabs - count);
+
+        Geometry {
+            bar: bounds,
+            tab_width: (available / count as f32).min(self.max_tab_width),
+            visible: first..first + count,
+            overflow: Some(Rectangle {
+                x: bounds.x + bounds.width - bounds.height,
+                width: bounds.height,
+                ..bounds
+            }),
+        }
+    }
+
+    fn close_button(&self, tab: Rectangle, metrics: &Metrics<Renderer::Font>) -> Rectangle {
+        let size = metrics.line_height;
+
+        Rectangle {
+            x: tab.x + tab.width - self.padding.right / 2.0 - size,
+            y: tab.center_y() - size / 2.0,
+            width: size,
+            height: size,
+        }
+    }
+
+    fn target(
+        &self,
+        geometry: &Geometry,
+        metrics: &Metrics<Renderer::Font>,
+        position: Point,
+    ) -> Option<Target> {
+        if geometry
+            .overflow
+            .is_some_and(|overflow| overflow.contains(position))
+        {
+            return Some(Target::Overflow);
+        }
+
+        let index = geometry.index_at(position)?;
+        let tab = geometry.tab(index);
+
+        if self.on_close.is_some()
+            && self.tabs[index].is_closable
+            && self.close_button(tab, metrics).contains(position)
+        {
+            Some(Target::Close(index))
+        } else {
+            Some(Target::Tab(index))
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Metrics<Font> {
+    size: Pixels,
+    line_height: f32,
+    font: Font,
+    hint_factor: Option<f32>,
+}
+
+impl<Font: Copy> Metrics<Font> {
+    fn text(
+        &self,
+        content: &str,
+        bounds: Size,
+        align_x: text::Alignment,
+    ) -> text::Text<String, Font> {
+        text::Text {
+            content: content.to_owned(),
+            bounds,
+            size: self.size,
+            line_height: text::LineHeight::default(),
+            font: self.font,
+            align_x,
+            align_y: alignment::Vertical::Center,
+            shaping: text::Shaping::Advanced,
+            wrapping: text::Wrapping::None,
+            hint_factor: self.hint_factor,
+        }
+    }
+}
+
+struct Geometry {
+    bar: Rectangle,
+    tab_width: f32,
+    visible: std::ops::Range<usize>,
+    overflow: Option<Rectangle>,
+}
+
+impl Geometry {
+    fn slot(&self, slot: usize) -> Rectangle {
+        Rectangle {
+            x: self.bar.x + slot as f32 * self.tab_width,
+            width: self.tab_width,
+            ..self.bar
+        }
+    }
+
+    fn tab(&self, index: usize) -> Rectangle {
+        self.slot(index - self.visible.start)
+    }
+
+    fn slot_at(&self, x: f32) -> usize {
+        (((x - self.bar.x) / self.tab_width).floor().max(0.0) as usize)
+            .min(self.visible.len().saturating_sub(1))
+    }
+
+    fn index_at(&self, position: Point) -> Option<usize> {
+        let slot = ((position.x - self.bar.x) / self.tab_width).floor();
+
+        (self.bar.contains(position) && slot >= 0.0 && (slot as usize) < self.visible.len())
+            .then(|| self.visible.start + slot as usize)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Target {
+    Tab(usize),
+    Close(usize),
+    Overflow,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Drag {
+    index: usize,
+    origin: f32,
+    grab: f32,
+    cursor: f32,
+    is_dragging: bool,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    offset: usize,
+    last_selected: Option<usize>,
+    hovered: Option<Target>,
+    drag: Option<Drag>,
+    is_menu_open: bool,
+    menu_hovered: Option<usize>,
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for TabBar<'_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, Length::Shrink)
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let metrics = self.metrics(renderer);
+        let height = metrics.line_height + self.padding.y();
+        let size = limits
+            .height(height)
+            .resolve(self.width, height, Size::new(0.0, height));
+
+        let state = tree.state.downcast_mut::<State>();
+
+        // Scroll the selected tab into view when it changes
+        if state.last_selected != self.selected {
+            state.last_selected = self.selected;
+
+            if let Some(selected) = self.selected {
+                let geometry = self.geometry(Rectangle::with_size(size), state.offset);
+
+                if selected < geometry.visible.start {
+                    state.offset = selected;
+                } else if selected >= geometry.visible.end {
+                    state.offset = selected + 1 - geometry.visible.len();
+                }
+            }
+        }
+
+        state.offset = state.offset.min(self.tabs.len().saturating_sub(1));
+
+        layout::Node::new(size)
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+        let metrics = self.metrics(renderer);
+        let geometry = self.geometry(layout.bounds(), state.offset);
+
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { position }) => {
+                let hovered = cursor
+                    .position_over(layout.bounds())
+                    .and_then(|position| self.target(&geometry, &metrics, position));
+
+                if hovered != state.hovered {
+                    state.hovered = hovered;
+                    shell.request_redraw();
+                }
+
+                if let Some(drag) = &mut state.drag {
+                    drag.cursor = position.x;
+
+                    if !drag.is_dragging
+                        && self.on_reorder.is_some()
+                        && (drag.cursor - drag.origin).abs() > DRAG_THRESHOLD
+                    {
+                        drag.is_dragging = true;
+                    }
+
+                    if drag.is_dragging {
+                        shell.request_redraw();
+                    }
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(button)) => {
+                let Some(target) = cursor
+                    .position_over(layout.bounds())
+                    .and_then(|position| self.target(&geometry, &metrics, position))
+                else {
+                    return;
+                };
+
+                match (button, target) {
+                    (mouse::Button::Left, Target::Overflow) => {
+                        state.is_menu_open = !state.is_menu_open;
+                        state.menu_hovered = None;
+                    }
+                    (mouse::Button::Left, Target::Close(index))
+                    | (mouse::Button::Middle, Target::Tab(index) | Target::Close(index)) => {
+                        if let Some(on_close) = self
+                            .on_close
+                            .as_ref()
+                            .filter(|_| self.tabs[index].is_closable)
+                        {
+                            shell.publish(on_close(index));
+                        }
+                    }
+                    (mouse::Button::Left, Target::Tab(index)) => {
+                        if self.selected != Some(index) {
+                            if let Some(on_select) = &self.on_select {
+                                shell.publish(on_select(index));
+                            }
+                        }
+
+                        let position = cursor.position().unwrap_or_default();
+
+                        state.drag = Some(Drag {
+                            index,
+                            origin: position.x,
+                            grab: position.x - geometry.tab(index).x,
+                            cursor: position.x,
+                            is_dragging: false,
+                        });
+                    }
+                    _ => return,
+                }
+
+                shell.capture_event();
+                shell.request_redraw();
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                let Some(drag) = state.drag.take() else {
+                    return;
+                };
+
+                if drag.is_dragging {
+                    let target = geometry.visible.start
+                        + geometry.slot_at(drag.cursor - drag.grab + geometry.tab_width / 2.0);
+
+                    if target != drag.index {
+                        if let Some(on_reorder) = &self.on_reorder {
+                            shell.publish(on_reorder(drag.index, target));
+                        }
+                    }
+
+                    shell.capture_event();
+                    shell.request_redraw();
+                }
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) if geometry.overflow.is_some() => {
+                if !cursor.is_over(layout.bounds()) {
+                    return;
+                }
+
+                let (x, y) = match *delta {
+                    mouse::ScrollDelta::Lines { x, y } | mouse::ScrollDelta::Pixels { x, y } => {
+                        (x, y)
+                    }
+                };
+
+                let delta = if x.abs() > y.abs() { x } else { y };
+                let first = geometry.visible.start;
+
+                state.offset = if delta > 0.0 {
+                    first.saturating_sub(1)
+                } else {
+                    (first + 1).min(self.tabs.len() - geometry.visible.len())
+                };
+
+                shell.capture_event();
+                shell.request_redraw();
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key::Named::Escape),
+                ..
+            }) if state.drag.is_some_and(|drag| drag.is_dragging) => {
+                state.drag = None;
+                shell.capture_event();
+                shell.request_redraw();
+            }
+            _ => {}
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        _layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+
+        if state.drag.is_some_and(|drag| drag.is_dragging) {
+            return mouse::Interaction::Grabbing;
+        }
+
+        match state.hovered {
+            Some(Target::Close(_) | Target::Overflow) => mouse::Interaction::Pointer,
+            Some(Target::Tab(_)) if self.on_select.is_some() => mouse::Interaction::Pointer,
+            _ => mouse::Interaction::None,
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _defaults: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let style = theme.style(&self.class);
+        let metrics = self.metrics(renderer);
+        let bounds = layout.bounds();
+        let geometry = self.geometry(bounds, state.offset);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                ..renderer::Quad::default()
+            },
+            style.background,
+        );
+
+        let drag = state
+            .drag
+            .filter(|drag| drag.is_dragging && geometry.visible.contains(&drag.index));
+
+        // The order of the visible tabs, with the dragged one at its target
+        let mut order: Vec<usize> = geometry.visible.clone().collect();
+
+        if let Some(drag) = drag {
+            let target = geometry.slot_at(drag.cursor - drag.grab + geometry.tab_width / 2.0);
+
+            order.retain(|index| *index != drag.index);
+            order.insert(target.min(order.len()), drag.index);
+        }
+
+        let draw_tab = |renderer: &mut Renderer, index: usize, tab: Rectangle, shadow: Shadow| {
+            let is_selected = self.selected == Some(index);
+            let is_hovered = matches!(
+                state.hovered,
+                Some(Target::Tab(hovered) | Target::Close(hovered)) if hovered == index
+            );
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: tab,
+                    border: style.border,
+                    shadow,
+                    ..renderer::Quad::default()
+                },
+                if is_selected {
+                    style.selected_tab
+                } else if is_hovered {
+                    style.hovered_tab
+                } else {
+                    style.tab
+                },
+            );
+
+            if is_selected {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            y: tab.y + tab.height - 2.0,
+                            height: 2.0,
+                            ..tab
+                        },
+                        ..renderer::Quad::default()
+                    },
+                    style.indicator,
+                );
+            }
+
+            let close = self.close_button(tab, &metrics);
+            let has_close = self.on_close.is_some() && self.tabs[index].is_closable;
+            let label = Rectangle {
+                x: tab.x + self.padding.left,
+                width: (close.x - tab.x - self.padding.left).max(0.0),
+                ..tab
+            };
+
+            let text_color = if is_selected {
+                style.selected_text_color
+            } else {
+                style.text_color
+            };
+
+            renderer.fill_text(
+                metrics.text(
+                    &self.tabs[index].label,
+                    label.size(),
+                    text::Alignment::Default,
+                ),
+                Point::new(label.x, label.center_y()),
+                text_color,
+                label,
+            );
+
+            let show_close = has_close && (is_selected || is_hovered);
+
+            if self.tabs[index].is_dirty && !(show_close && is_hovered) {
+                let radius = metrics.line_height / 6.0;
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: close.center_x() - radius,
+                            y: close.center_y() - radius,
+                            width: radius * 2.0,
+                            height: radius * 2.0,
+                        },
+                        border: Border::default().rounded(radius),
+                        ..renderer::Quad::default()
+                    },
+                    style.dirty,
+                );
+            } else if show_close {
+                if state.hovered == Some(Target::Close(index)) {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: close,
+                            border: Border::default().rounded(4),
+                            ..renderer::Quad::default()
+                        },
+                        style.close_hovered,
+                    );
+                }
+
+                renderer.fill_text(
+                    metrics.text("×", close.size(), text::Alignment::Center),
+                    close.center(),
+                    text_color,
+                    close,
+                );
+            }
+        };
+
+        let Some(clip) = bounds.intersection(viewport) else {
+            return;
+        };
+
+        renderer.with_layer(clip, |renderer| {
+            for (slot, index) in order.iter().copied().enumerate() {
+                if drag.is_some_and(|drag| drag.index == index) {
+                    continue;
+                }
+
+                draw_tab(renderer, index, geometry.slot(slot), Shadow::default());
+            }
+
+            if let Some(drag) = drag {
+                let x = (drag.cursor - drag.grab)
+                    .clamp(bounds.x, bounds.x + bounds.width - geometry.tab_width);
+
+                draw_tab(
+                    renderer,
+                    drag.index,
+                    Rectangle {
+                        x,
+                        ..geometry.tab(drag.index)
+                    },
+                    style.dragged_shadow,
+                );
+            }
+        });
+
+        if let Some(overflow) = geometry.overflow {
+            if state.is_menu_open || state.hovered == Some(Target::Overflow) {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: overflow,
+                        ..renderer::Quad::default()
+                    },
+                    style.hovered_tab,
+                );
+            }
+
+            renderer.fill_text(
+                metrics.text("⌄", overflow.size(), text::Alignment::Center),
+                overflow.center(),
+                style.text_color,
+                overflow,
+            );
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        _viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let state = tree.state.downcast_mut::<State>();
+        let anchor = self.geometry(layout.bounds(), state.offset).overflow?;
+
+        if !state.is_menu_open {
+            return None;
+        }
+
+        Some(overlay::Element::new(Box::new(Menu {
+            tabs: &self.tabs,
+            selected: self.selected,
+            on_select: self.on_select.as_deref(),
+            state,
+            anchor: anchor + translation,
+            width: self.max_tab_width,
+            padding: self.padding,
+            metrics: self.metrics(renderer),
+            class: &self.class,
+        })))
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<TabBar<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(tab_bar: TabBar<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(tab_bar)
+    }
+}
+
+/// The overflow menu of a [`TabBar`], listing all of its tabs.
+struct Menu<'a, 'b, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    tabs: &'b [Tab<'a>],
+    selected: Option<usize>,
+    on_select: Option<&'b (dyn Fn(usize) -> Message + 'a)>,
+    state: &'b mut State,
+    anchor: Rectangle,
+    width: f32,
+    padding: Padding,
+    metrics: Metrics<Renderer::Font>,
+    class: &'b Theme::Class<'a>,
+}
+
+impl<Message, Theme, Renderer> Menu<'_, '_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn item_height(&self) -> f32 {
+        self.metrics.line_height + self.padding.y()
+    }
+
+    fn item_at(&self, bounds: Rectangle, position: Point) -> Option<usize> {
+        let index = ((position.y - bounds.y - 4.0) / self.item_height()).floor();
+
+        (bounds.contains(position) && index >= 0.0 && (index as usize) < self.tabs.len())
+            .then_some(index as usize)
+    }
+}
+
+impl<Message, Theme, Renderer> overlay::Overlay<Message, Theme, Renderer>
+    for Menu<'_, '_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn layout(&mut self, _renderer: &Renderer, bounds: Size) -> layout::Node {
+        let size = Size::new(
+            self.width.min(bounds.width),
+            (self.item_height() * self.tabs.len() as f32 + 8.0).min(bounds.height),
+        );
+
+        let x = (self.anchor.x + self.anchor.width - size.width).max(0.0);
+        let below = self.anchor.y + self.anchor.height;
+        let y = if below + size.height > bounds.height {
+            (self.anchor.y - size.height).max(0.0)
+        } else {
+            below
+        };
+
+        layout::Node::new(size).move_to(Point::new(x, y))
+    }
+
+    fn update(
+        &mut self,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { position }) => {
+                let hovered = self.item_at(bounds, *position);
+
+                if hovered != self.state.menu_hovered {
+                    self.state.menu_hovered = hovered;
+                    shell.request_redraw();
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                let Some(position) = cursor.position() else {
+                    return;
+                };
+
+                // The overflow button toggles the menu by itself
+                if self.anchor.contains(position) {
+                    return;
+                }
+
+                if let Some(index) = self.item_at(bounds, position) {
+                    if let Some(on_select) = self.on_select {
+                        shell.publish(on_select(index));
+                    }
+
+                    shell.capture_event();
+                }
+
+                self.state.is_menu_open = false;
+                shell.request_redraw();
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key::Named::Escape),
+                ..
+            }) => {
+                self.state.is_menu_open = false;
+                shell.capture_event();
+                shell.request_redraw();
+            }
+            _ => {}
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::None
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+    ) {
+        let style = theme.style(self.class);
+        let bounds = layout.bounds();
+        let item_height = self.item_height();
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: style.menu_border,
+                shadow: style.dragged_shadow,
+                ..renderer::Quad::default()
+            },
+            style.menu_background,
+        );
+
+        renderer.with_layer(bounds, |renderer| {
+            for (index, tab) in self.tabs.iter().enumerate() {
+                let item = Rectangle {
+                    x: bounds.x + 4.0,
+                    y: bounds.y + 4.0 + index as f32 * item_height,
+                    width: bounds.width - 8.0,
+                    height: item_height,
+                };
+
+                if self.state.menu_hovered == Some(index) {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: item,
+                            border: Border::default().rounded(4),
+                            ..renderer::Quad::default()
+                        },
+                        style.hovered_tab,
+                    );
+                }
+
+                let label = Rectangle {
+                    x: item.x + self.padding.left,
+                    width: (item.width - self.padding.x()).max(0.0),
+                    ..item
+                };
+
+                renderer.fill_text(
+                    self.metrics
+                        .text(&tab.label, label.size(), text::Alignment::Default),
+                    Point::new(label.x, label.center_y()),
+                    if self.selected == Some(index) {
+                        style.selected_text_color
+                    } else {
+                        style.text_color
+                    },
+                    label,
+                );
+
+                if tab.is_dirty {
+                    let radius = self.metrics.line_height / 6.0;
+
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: Rectangle {
+                                x: item.x + item.width - self.padding.right / 2.0 - radius * 2.0,
+                                y: item.center_y() - radius,
+                                width: radius * 2.0,
+                                height: radius * 2.0,
+                            },
+                            border: Border::default().rounded(radius),
+                            ..renderer::Quad::default()
+                        },
+                        style.dirty,
+                    );
+                }
+            }
+        });
+    }
+}
+
+/// The appearance of a [`TabBar`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Background`] of the bar.
+    pub background: Background,
+    /// The [`Background`] of a tab.
+    pub tab: Background,
+    /// The [`Background`] of a hovered tab.
+    pub hovered_tab: Background,
+    /// The [`Background`] of the selected tab.
+    pub selected_tab: Background,
+    /// The [`Border`] of a tab.
+    pub border: Border,
+    /// The [`Color`] of the line under the selected tab.
+    pub indicator: Color,
+    /// The text [`Color`] of a tab.
+    pub text_color: Color,
+    /// The text [`Color`] of the selected tab.
+    pub selected_text_color: Color,
+    /// The [`Color`] of the dot of a tab with unsaved changes.
+    pub dirty: Color,
+    /// The [`Background`] of a hovered close button.
+    pub close_hovered: Background,
+    /// The [`Shadow`] of a dragged tab and the overflow menu.
+    pub dragged_shadow: Shadow,
+    /// The [`Background`] of the overflow menu.
+    pub menu_background: Background,
+    /// The [`Border`] of the overflow menu.
+    pub menu_border: Border,
+}
+
+/// The theme catalog of a [`TabBar`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`TabBar`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`TabBar`].
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        background: palette.background.weak.color.into(),
+        tab: Color::TRANSPARENT.into(),
+        hovered_tab: palette.background.strong.color.scale_alpha(0.5).into(),
+        selected_tab: palette.background.base.color.into(),
+        border: Border::default(),
+        indicator: palette.primary.base.color,
+        text_color: palette.background.weak.text.scale_alpha(0.7),
+        selected_text_color: palette.background.base.text,
+        dirty: palette.primary.base.color,
+        close_hovered: palette.background.strong.color.into(),
+        dragged_shadow: Shadow {
+            color: Color::BLACK.scale_alpha(0.25),
+            offset: Vector::new(0.0, 2.0),
+            blur_radius: 8.0,
+        },
+        menu_background: palette.background.base.color.into(),
+        menu_border: Border::default()
+            .rounded(4)
+            .width(1)
+            .color(palette.background.strong.color),
+    }
+}
@@ -0,0 +1,613 @@
+use crate::core::animation::{Animation, Easing};
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::time::{Duration, Instant, milliseconds};
+use crate::core::touch;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::{Operation, Widget};
+use crate::core::window;
+use crate::core::{
+    Background, Border, Clipboard, Color, Element, Event, Length, Padding, Point, Rectangle,
+    Shadow, Shell, Size, Theme, Vector,
+};
+
+use std::f32::consts::TAU;
+
+/// The amount of dots in the spinner of a running [`TaskButton`].
+const SPINNER_DOTS: usize = 8;
+
+/// A button that runs a task, showing its progress until it finishes.
+///
+/// Presses are ignored while the task is running.
+pub struct TaskButton<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    content: Element<'a, Message, Theme, Renderer>,
+    progress: Progress,
+    on_press: Option<Message>,
+    width: Length,
+    height: Length,
+    padding: Padding,
+    duration: Duration,
+    class: Theme::Class<'a>,
+}
+
+/// The progress of the task of a [`TaskButton`].
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Progress {
+    /// The task has not started yet.
+    #[default]
+    Idle,
+    /// The task is running; with its completion from `0.0` to `1.0`, if known.
+    Running(Option<f32>),
+    /// The task has finished successfully.
+    Success,
+    /// The task has failed.
+    Error,
+}
+
+impl Progress {
+    fn is_running(self) -> bool {
+        matches!(self, Self::Running(_))
+    }
+
+    fn is_finished(self) -> bool {
+        matches!(self, Self::Success | Self::Error)
+    }
+}
+
+impl<'a, Message, Theme, Renderer> TaskButton<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    pub fn new(content: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        Self {
+            content: content.into(),
+            progress: Progress::Idle,
+            on_press: None,
+            width: Length::Shrink,
+            height: Length::Shrink,
+            padding: Padding::from([5, 10]),
+            duration: milliseconds(200),
+            class: Theme::default(),
+        }
+    }
+
+    pub fn progress(mut self, progress: Progress) -> Self {
+        self.progress = progress;
+        self
+    }
+
+    pub fn on_press(mut self, on_press: Message) -> Self {
+        self.on_press = Some(on_press);
+        self
+    }
+
+    pub fn on_press_maybe(mut self, on_press: Option<Message>) -> Self {
+        self.on_press = on_press;
+        self
+    }
+
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    pub fn padding(mut self, padding: impl Into<Padding>) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the duration of the transitions between each [`Progress`].
+    pub fn duration(mut self, duration: impl Into<Duration>) -> Self {
+        self.duration = duration.into();
+        self
+    }
+
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+}
+
+struct State {
+    progress: Progress,
+    /// The last finished [`Progress`], kept around while fading out.
+    outcome: Progress,
+    running: Animation<bool>,
+    finished: Animation<bool>,
+    /// Whether the animations start on the next redraw.
+    is_pending: bool,
+    started_at: Option<Instant>,
+    now: Instant,
+    is_pressed: bool,
+}
+
+impl State {
+    fn is_animating(&self) -> bool {
+        self.progress.is_running()
+            || self.is_pending
+            || self.running.is_animating(self.now)
+            || self.finished.is_animating(self.now)
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for TaskButton<'_, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: renderer::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            progress: self.progress,
+            outcome: self.progress,
+            running: Animation::new(self.progress.is_running()),
+            finished: Animation::new(self.progress.is_finished()),
+            is_pending: false,
+            started_at: None,
+            now: Instant::now(),
+            is_pressed: false,
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, self.height)
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let state = tree.state.downcast_mut::<State>();
+
+        if state.progress != self.progress {
+            if !state.progress.is_running() && self.progress.is_running() {
+                state.started_at = None;
+            }
+
+            if self.progress.is_finished() {
+                state.outcome = self.progress;
+            }
+
+            state.running = state
+                .running
+                .clone()
+                .duration(self.duration)
+                .easing(Easing::EaseOut);
+            state.finished = state
+                .finished
+                .clone()
+                .duration(self.duration)
+                .easing(Easing::EaseOut);
+            state.progress = self.progress;
+            state.is_pending = true;
+        }
+
+        layout::positioned(
+            limits,
+            self.width,
+            self.height,
+            self.padding,
+            |limits| {
+                self.content
+                    .as_widget_mut()
+                    .layout(&mut tree.children[0], renderer, limits)
+            },
+            |content, size| {
+                let content_size = content.size();
+
+                content.move_to(Point::new(
+                    (size.width - content_size.width) / 2.0,
+                    (size.height - content_size.height) / 2.0,
+                ))
+            },
+        )
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        operation.container(None, layout.bounds());
+        operation.traverse(&mut |operation| {
+            self.content.as_widget_mut().operate(
+                &mut tree.children[0],
+                layout.children().next().unwrap(),
+                renderer,
+                operation,
+            );
+        });
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget_mut().update(
+            &mut tree.children[0],
+            event,
+            layout.children().next().unwrap(),
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        let state = tree.state.downcast_mut::<State>();
+
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            state.now = *now;
+            let _ = state.started_at.get_or_insert(*now);
+
+            if std::mem::take(&mut state.is_pending) {
+                state.running.go_mut(state.progress.is_running(), *now);
+                state.finished.go_mut(state.progress.is_finished(), *now);
+            }
+
+            if state.is_animating() {
+                shell.request_redraw();
+            }
+
+            return;
+        }
+
+        if shell.is_event_captured() {
+            return;
+        }
+
+        let bounds = layout.bounds();
+        let is_enabled = self.on_press.is_some() && !self.progress.is_running();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. })
+                if is_enabled && cursor.is_over(bounds) =>
+            {
+                state.is_pressed = true;
+
+                shell.request_redraw();
+                shell.capture_event();
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+                if state.is_pressed =>
+            {
+                state.is_pressed = false;
+
+                if let Some(on_press) = self.on_press.clone().filter(|_| cursor.is_over(bounds)) {
+                    shell.publish(on_press);
+                }
+
+                shell.request_redraw();
+                shell.capture_event();
+            }
+            Event::Touch(touch::Event::FingerLost { .. }) => {
+                state.is_pressed = false;
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. }) if is_enabled => {
+                shell.request_redraw();
+            }
+            _ => {}
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+
+        if !cursor.is_over(layout.bounds()) {
+            mouse::Interaction::None
+        } else if state.progress.is_running() {
+            mouse::Interaction::Progress
+        } else if self.on_press.is_some() {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::NotAllowed
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _defaults: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+
+        let status = if self.on_press.is_none() {
+            Status::Disabled
+        } else if state.is_pressed {
+            Status::Pressed
+        } else if cursor.is_over(bounds) && !state.progress.is_running() {
+            Status::Hovered
+        } else {
+            Status::Active
+        };
+
+        let style = theme.style(&self.class, status);
+
+        let running = state.running.interpolate(0.0, 1.0, state.now);
+        let finished = state.finished.interpolate(0.0, 1.0, state.now);
+
+        let background = match (style.background, state.outcome) {
+            (Background::Color(color), Progress::Success) => {
+                Background::Color(mix(color, style.success, finished))
+            }
+            (Background::Color(color), Progress::Error) => {
+                Background::Color(mix(color, style.error, finished))
+            }
+            (background, _) => background,
+        };
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: style.border,
+                shadow: style.shadow,
+                ..renderer::Quad::default()
+            },
+            background,
+        );
+
+        let completion = match state.progress {
+            Progress::Running(Some(completion)) => Some(completion.clamp(0.0, 1.0)),
+            _ => None,
+        };
+
+        if let Some(completion) = completion {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        width: bounds.width * completion,
+                        ..bounds
+                    },
+                    border: Border {
+                        color: Color::TRANSPARENT,
+                        width: 0.0,
+                        ..style.border
+                    },
+                    ..renderer::Quad::default()
+                },
+                style.indicator.scale_alpha(0.3 * running),
+            );
+        }
+
+        // The label gives way to the spinner, unless the completion is known
+        let label_alpha = if completion.is_some() {
+            1.0
+        } else {
+            1.0 - running
+        };
+
+        if label_alpha > 0.0 {
+            self.content.as_widget().draw(
+                &tree.children[0],
+                renderer,
+                theme,
+                &renderer::Style {
+                    text_color: style.text_color.scale_alpha(label_alpha),
+                },
+                layout.children().next().unwrap(),
+                cursor,
+                viewport,
+            );
+        }
+
+        if completion.is_none() && running > 0.0 {
+            draw_spinner(
+                renderer,
+                bounds,
+                style.indicator.scale_alpha(running),
+                state
+                    .now
+                    .saturating_duration_since(state.started_at.unwrap_or(state.now))
+                    .as_secs_f32(),
+            );
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout.children().next()?,
+            renderer,
+            viewport,
+            translation,
+        )
+    }
+}
+
+/// Draws a ring of dots, fading behind the one leading each turn.
+fn draw_spinner<Renderer>(renderer: &mut Renderer, bounds: Rectangle, color: Color, elapsed: f32)
+where
+    Renderer: renderer::Renderer,
+{
+    let radius = (bounds.height.min(bounds.width) * 0.3).max(4.0);
+    let dot = (radius * 0.4).max(2.0);
+    let center = bounds.center();
+    let lead = (elapsed.fract() * SPINNER_DOTS as f32).floor();
+
+    for i in 0..SPINNER_DOTS {
+        let angle = TAU * i as f32 / SPINNER_DOTS as f32;
+        let behind = (lead - i as f32).rem_euclid(SPINNER_DOTS as f32);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    x: center.x + radius * angle.sin() - dot / 2.0,
+                    y: center.y - radius * angle.cos() - dot / 2.0,
+                    width: dot,
+                    height: dot,
+                },
+                border: Border::default().rounded(dot / 2.0),
+                ..renderer::Quad::default()
+            },
+            color.scale_alpha(1.0 - behind / SPINNER_DOTS as f32),
+        );
+    }
+}
+
+fn mix(a: Color, b: Color, t: f32) -> Color {
+    Color {
+        r: a.r + (b.r - a.r) * t,
+        g: a.g + (b.g - a.g) * t,
+        b: a.b + (b.b - a.b) * t,
+        a: a.a + (b.a - a.a) * t,
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<TaskButton<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(task_button: TaskButton<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(task_button)
+    }
+}
+
+/// The possible status of a [`TaskButton`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The [`TaskButton`] can be pressed.
+    Active,
+    /// The [`TaskButton`] is being hovered.
+    Hovered,
+    /// The [`TaskButton`] is being pressed.
+    Pressed,
+    /// The [`TaskButton`] has no message to produce.
+    Disabled,
+}
+
+/// The appearance of a [`TaskButton`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Background`] of the button.
+    pub background: Background,
+    /// The text [`Color`] of the label.
+    pub text_color: Color,
+    /// The [`Border`] of the button.
+    pub border: Border,
+    /// The [`Shadow`] of the button.
+    pub shadow: Shadow,
+    /// The [`Color`] of the spinner and the progress fill.
+    pub indicator: Color,
+    /// The [`Color`] the background turns into after a success.
+    pub success: Color,
+    /// The [`Color`] the background turns into after an error.
+    pub error: Color,
+}
+
+/// The theme catalog of a [`TaskButton`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class with the given status.
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style;
+}
+
+/// A styling function for a [`TaskButton`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
+        class(self, status)
+    }
+}
+
+/// The default style of a [`TaskButton`].
+pub fn default(theme: &Theme, status: Status) -> Style {
+    let palette = theme.extended_palette();
+
+    let base = Style {
+        background: palette.primary.base.color.into(),
+        text_color: palette.primary.base.text,
+        border: Border::default().rounded(2),
+        shadow: Shadow::default(),
+        indicator: palette.primary.base.text,
+        success: palette.success.base.color,
+        error: palette.danger.base.color,
+    };
+
+    match status {
+        Status::Active | Status::Pressed => base,
+        Status::Hovered => Style {
+            background: palette.primary.strong.color.into(),
+            ..base
+        },
+        Status::Disabled => Style {
+            background: base.background.scale_alpha(0.5),
+            text_color: base.text_color.scale_alpha(0.5),
+            ..base
+        },
+    }
+}
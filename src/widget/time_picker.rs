@@ -0,0 +1,665 @@
+use crate::core::alignment;
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::text;
+use crate::core::touch;
+use crate::core::widget::operation::{self, Operation};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::{Id, Widget};
+use crate::core::{
+    Background, Border, Clipboard, Color, Element, Event, Length, Padding, Pixels, Point,
+    Rectangle, Shell, Size, Theme,
+};
+
+/// A time of the day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Time {
+    /// The hour, from 0 to 23.
+    pub hour: u8,
+    /// The minute, from 0 to 59.
+    pub minute: u8,
+    /// The second, from 0 to 59.
+    pub second: u8,
+}
+
+impl Time {
+    pub fn new(hour: u8, minute: u8, second: u8) -> Self {
+        Self {
+            hour: hour % 24,
+            minute: minute % 60,
+            second: second % 60,
+        }
+    }
+}
+
+/// The clock convention used to display hours.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Clock {
+    /// Hours from 1 to 12, with an AM/PM column.
+    H12,
+    /// Hours from 0 to 23.
+    #[default]
+    H24,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Column {
+    Hour,
+    Minute,
+    Second,
+    Period,
+}
+
+impl Column {
+    fn label(self, time: Time, clock: Clock) -> String {
+        match (self, clock) {
+            (Column::Hour, Clock::H24) => format!("{:02}", time.hour),
+            (Column::Hour, Clock::H12) => format!("{:02}", (time.hour + 11) % 12 + 1),
+            (Column::Minute, _) => format!("{:02}", time.minute),
+            (Column::Second, _) => format!("{:02}", time.second),
+            (Column::Period, _) => String::from(if time.hour < 12 { "AM" } else { "PM" }),
+        }
+    }
+
+    fn step(self, time: Time, delta: i32) -> Time {
+        fn wrap(value: u8, delta: i32, max: i32) -> u8 {
+            (i32::from(value) + delta).rem_euclid(max) as u8
+        }
+
+        match self {
+            Column::Hour => Time {
+                hour: wrap(time.hour, delta, 24),
+                ..time
+            },
+            Column::Minute => Time {
+                minute: wrap(time.minute, delta, 60),
+                ..time
+            },
+            Column::Second => Time {
+                second: wrap(time.second, delta, 60),
+                ..time
+            },
+            Column::Period => Time {
+                hour: (time.hour + 12) % 24,
+                ..time
+            },
+        }
+    }
+
+    fn max(self, clock: Clock) -> u8 {
+        match (self, clock) {
+            (Column::Hour, Clock::H24) => 23,
+            (Column::Hour, Clock::H12) => 12,
+            (Column::Minute | Column::Second, _) => 59,
+            (Column::Period, _) => 1,
+        }
+    }
+
+    fn set(self, time: Time, value: u8, clock: Clock) -> Option<Time> {
+        match (self, clock) {
+            (Column::Hour, Clock::H24) if value < 24 => Some(Time {
+                hour: value,
+                ..time
+            }),
+            (Column::Hour, Clock::H12) if (1..=12).contains(&value) => Some(Time {
+                hour: value % 12 + if time.hour < 12 { 0 } else { 12 },
+                ..time
+            }),
+            (Column::Minute, _) if value < 60 => Some(Time {
+                minute: value,
+                ..time
+            }),
+            (Column::Second, _) if value < 60 => Some(Time {
+                second: value,
+                ..time
+            }),
+            _ => None,
+        }
+    }
+}
+
+pub struct TimePicker<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    id: Option<Id>,
+    time: Time,
+    on_select: Option<Box<dyn Fn(Time) -> Message + 'a>>,
+    clock: Clock,
+    show_seconds: bool,
+    padding: Padding,
+    text_size: Option<Pixels>,
+    font: Option<Renderer::Font>,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> TimePicker<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    pub fn new(time: Time) -> Self {
+        Self {
+            id: None,
+            time,
+            on_select: None,
+            clock: Clock::default(),
+            show_seconds: true,
+            padding: Padding::new(6.0),
+            text_size: None,
+            font: None,
+            class: Theme::default(),
+        }
+    }
+
+    pub fn id(mut self, id: impl Into<Id>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    pub fn on_select(mut self, on_select: impl Fn(Time) -> Message + 'a) -> Self {
+        self.on_select = Some(Box::new(on_select));
+        self
+    }
+
+    pub fn clock(mut self, clock: Clock) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    pub fn show_seconds(mut self, show_seconds: bool) -> Self {
+        self.show_seconds = show_seconds;
+        self
+    }
+
+    pub fn padding(mut self, padding: impl Into<Padding>) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    pub fn text_size(mut self, text_size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(text_size.into());
+        self
+    }
+
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    fn columns(&self) -> Vec<Column> {
+        let mut columns = vec![Column::Hour, Column::Minute];
+
+        if self.show_seconds {
+            columns.push(Column::Second);
+        }
+
+        if self.clock == Clock::H12 {
+            columns.push(Column::Period);
+        }
+
+        columns
+    }
+
+    fn cell(&self, renderer: &Renderer) -> Size {
+        let size = self.text_size.unwrap_or_else(|| renderer.default_size());
+
+        Size::new(
+            size.0 * 2.5,
+            text::LineHeight::default().to_absolute(size).0 * 1.5,
+        )
+    }
+
+    fn hit(&self, bounds: Rectangle, renderer: &Renderer, position: Point) -> Option<(usize, i32)> {
+        let cell = self.cell(renderer);
+        let content = bounds.shrink(self.padding);
+
+        if !content.contains(position) {
+            return None;
+        }
+
+        let column = ((position.x - content.x) / cell.width) as usize;
+        let row = ((position.y - content.y) / cell.height) as i32 - 1;
+
+        (column < self.columns().len()).then_some((column, row.clamp(-1, 1)))
+    }
+}
+
+#[derive(Debug, Default)]
+struct State {
+    focus: Option<usize>,
+    typed: String,
+    scroll: f32,
+}
+
+impl operation::Focusable for State {
+    fn is_focused(&self) -> bool {
+        self.focus.is_some()
+    }
+
+    fn focus(&mut self) {
+        self.focus = Some(self.focus.unwrap_or(0));
+    }
+
+    fn unfocus(&mut self) {
+        self.focus = None;
+        self.typed.clear();
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for TimePicker<'_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: Length::Shrink,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        _tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let cell = self.cell(renderer);
+        let columns = self.columns().len() as f32;
+
+        layout::Node::new(limits.resolve(
+            Length::Shrink,
+            Length::Shrink,
+            Size::new(
+                cell.width * columns + self.padding.x(),
+                cell.height * 3.0 + self.padding.y(),
+            ),
+        ))
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+
+        operation.focusable(self.id.as_ref(), layout.bounds(), state);
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let Some(on_select) = &self.on_select else {
+            return;
+        };
+
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+        let columns = self.columns();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let hit = cursor
+                    .position()
+                    .and_then(|position| self.hit(bounds, renderer, position));
+
+                state.typed.clear();
+
+                let Some((column, row)) = hit else {
+                    if state.focus.take().is_some() {
+                        shell.request_redraw();
+                    }
+
+                    return;
+                };
+
+                state.focus = Some(column);
+
+                if row != 0 {
+                    shell.publish(on_select(columns[column].step(self.time, row)));
+                }
+
+                shell.capture_event();
+                shell.request_redraw();
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
+                let Some((column, _)) = cursor
+                    .position()
+                    .and_then(|position| self.hit(bounds, renderer, position))
+                else {
+                    return;
+                };
+
+                state.scroll += match delta {
+                    mouse::ScrollDelta::Lines { y, .. } => *y,
+                    mouse::ScrollDelta::Pixels { y, .. } => *y / self.cell(renderer).height,
+                };
+
+                let steps = state.scroll.trunc();
+
+                if steps != 0.0 {
+                    state.scroll -= steps;
+
+                    shell.publish(on_select(columns[column].step(self.time, -steps as i32)));
+                }
+
+                shell.capture_event();
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed { key, text, .. }) => {
+                let Some(focus) = state.focus else {
+                    return;
+                };
+
+                let column = columns[focus.min(columns.len() - 1)];
+
+                match key.as_ref() {
+                    keyboard::Key::Named(key::Named::ArrowUp) => {
+                        shell.publish(on_select(column.step(self.time, -1)));
+                    }
+                    keyboard::Key::Named(key::Named::ArrowDown) => {
+                        shell.publish(on_select(column.step(self.time, 1)));
+                    }
+                    keyboard::Key::Named(key::Named::ArrowLeft) => {
+                        state.focus = Some(focus.saturating_sub(1));
+                        state.typed.clear();
+                    }
+                    keyboard::Key::Named(key::Named::ArrowRight | key::Named::Tab) => {
+                        state.focus = Some((focus + 1).min(columns.len() - 1));
+                        state.typed.clear();
+                    }
+                    keyboard::Key::Named(key::Named::Escape | key::Named::Enter) => {
+                        state.focus = None;
+                        state.typed.clear();
+                    }
+                    _ => {
+                        let Some(c) = text.as_ref().and_then(|text| text.chars().next()) else {
+                            return;
+                        };
+
+                        if column == Column::Period {
+                            let is_pm = self.time.hour >= 12;
+
+                            if matches!(c, 'a' | 'A') && is_pm || matches!(c, 'p' | 'P') && !is_pm {
+                                shell.publish(on_select(column.step(self.time, 1)));
+                            }
+                        } else if c.is_ascii_digit() {
+                            state.typed.push(c);
+
+                            let value: u8 = state.typed.parse().unwrap_or_default();
+
+                            if let Some(time) = column.set(self.time, value, self.clock) {
+                                shell.publish(on_select(time));
+                            }
+
+                            if state.typed.len() >= 2 || value * 10 > column.max(self.clock) {
+                                state.typed.clear();
+                                state.focus = Some((focus + 1).min(columns.len() - 1));
+                            }
+                        } else {
+                            return;
+                        }
+                    }
+                }
+
+                shell.capture_event();
+                shell.request_redraw();
+            }
+            _ => {}
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let hit = cursor
+            .position()
+            .and_then(|position| self.hit(layout.bounds(), renderer, position));
+
+        match hit {
+            Some((_, row)) if row != 0 && self.on_select.is_some() => mouse::Interaction::Pointer,
+            _ => mouse::Interaction::None,
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _defaults: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+
+        let status = if self.on_select.is_none() {
+            Status::Disabled
+        } else if state.focus.is_some() {
+            Status::Focused
+        } else if cursor.is_over(bounds) {
+            Status::Hovered
+        } else {
+            Status::Active
+        };
+
+        let style = theme.style(&self.class, status);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: style.border,
+                ..renderer::Quad::default()
+            },
+            style.background,
+        );
+
+        let cell = self.cell(renderer);
+        let content = bounds.shrink(self.padding);
+        let columns = self.columns();
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    x: content.x,
+                    y: content.y + cell.height,
+                    width: cell.width * columns.len() as f32,
+                    height: cell.height,
+                },
+                border: Border::default().rounded(style.border.radius),
+                ..renderer::Quad::default()
+            },
+            style.selection,
+        );
+
+        let size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
+
+        for (i, column) in columns.iter().enumerate() {
+            let x = content.x + cell.width * i as f32;
+
+            if state.focus == Some(i) {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x,
+                            y: content.y + cell.height,
+                            width: cell.width,
+                            height: cell.height,
+                        },
+                        border: Border::default().rounded(style.border.radius),
+                        ..renderer::Quad::default()
+                    },
+                    style.focus,
+                );
+            }
+
+            for row in -1..=1 {
+                let time = column.step(self.time, row);
+
+                if *column == Column::Period && row != 0 {
+                    continue;
+                }
+
+                renderer.fill_text(
+                    text::Text {
+                        content: column.label(time, self.clock),
+                        bounds: cell,
+                        size,
+                        line_height: text::LineHeight::default(),
+                        font,
+                        align_x: text::Alignment::Center,
+                        align_y: alignment::Vertical::Center,
+                        shaping: text::Shaping::Basic,
+                        wrapping: text::Wrapping::None,
+                        hint_factor: renderer.scale_factor(),
+                    },
+                    Point::new(
+                        x + cell.width / 2.0,
+                        content.y + cell.height * (row + 1) as f32 + cell.height / 2.0,
+                    ),
+                    if row == 0 {
+                        style.text_color
+                    } else {
+                        style.secondary_text_color
+                    },
+                    *viewport,
+                );
+            }
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<TimePicker<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(time_picker: TimePicker<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(time_picker)
+    }
+}
+
+/// The possible status of a [`TimePicker`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The [`TimePicker`] can be interacted with.
+    Active,
+    /// The [`TimePicker`] is being hovered.
+    Hovered,
+    /// The [`TimePicker`] is focused and accepts keyboard input.
+    Focused,
+    /// The [`TimePicker`] cannot be interacted with.
+    Disabled,
+}
+
+/// The appearance of a [`TimePicker`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Background`] of the picker.
+    pub background: Background,
+    /// The [`Border`] of the picker.
+    pub border: Border,
+    /// The [`Background`] of the row with the selected time.
+    pub selection: Background,
+    /// The [`Background`] of the focused value.
+    pub focus: Background,
+    /// The [`Color`] of the selected values.
+    pub text_color: Color,
+    /// The [`Color`] of the adjacent values.
+    pub secondary_text_color: Color,
+}
+
+/// The theme catalog of a [`TimePicker`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class with the given status.
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style;
+}
+
+/// A styling function for a [`TimePicker`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
+        class(self, status)
+    }
+}
+
+/// The default style of a [`TimePicker`].
+pub fn default(theme: &Theme, status: Status) -> Style {
+    let palette = theme.extended_palette();
+
+    let border_color = match status {
+        Status::Active | Status::Disabled => palette.background.strong.color,
+        Status::Hovered => palette.background.strongest.color,
+        Status::Focused => palette.primary.strong.color,
+    };
+
+    Style {
+        background: palette.background.base.color.into(),
+        border: Border::default().rounded(4).width(1).color(border_color),
+        selection: palette.background.weak.color.into(),
+        focus: palette.primary.weak.color.into(),
+        text_color: if status == Status::Disabled {
+            palette.background.strongest.color
+        } else {
+            palette.background.base.text
+        },
+        secondary_text_color: palette.background.strong.color,
+    }
+}
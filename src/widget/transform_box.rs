@@ -0,0 +1,630 @@
+use crate::core::keyboard;
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::{Operation, Widget};
+use crate::core::{
+    Background, Border, Clipboard, Color, Element, Event, Length, Point, Radians, Rectangle, Shell,
+    Size, Theme, Vector,
+};
+
+use std::f32::consts::PI;
+
+/// The size of the scale handles.
+const HANDLE_SIZE: f32 = 8.0;
+
+/// The distance between the top edge and the rotation handle.
+const ROTATION_OFFSET: f32 = 24.0;
+
+/// The angle that rotations snap to while holding Shift.
+const ROTATION_SNAP: f32 = PI / 12.0;
+
+/// The smallest scale a handle can produce, avoiding flips.
+const MIN_SCALE: f32 = 0.05;
+
+/// A change to the placement of a 2D item.
+///
+/// The scale and rotation are applied around the center of the item,
+/// before the translation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Transformation {
+    /// How much the center of the item moved.
+    pub translation: Vector,
+    /// The horizontal and vertical scale factors.
+    pub scale: Vector,
+    /// The clockwise rotation.
+    pub rotation: Radians,
+}
+
+impl Transformation {
+    /// The [`Transformation`] that leaves items untouched.
+    pub const IDENTITY: Self = Self {
+        translation: Vector::ZERO,
+        scale: Vector::new(1.0, 1.0),
+        rotation: Radians(0.0),
+    };
+
+    /// Returns the change needed to go from `self` to `other`.
+    fn to(self, other: Self) -> Self {
+        Self {
+            translation: other.translation - self.translation,
+            scale: Vector::new(other.scale.x / self.scale.x, other.scale.y / self.scale.y),
+            rotation: other.rotation - self.rotation,
+        }
+    }
+}
+
+impl Default for Transformation {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+/// A wrapper that shows handles to move, scale and rotate its content.
+///
+/// Dragging the content moves it, the handles on the edges scale it and
+/// the handle above rotates it. While dragging:
+///
+/// - Shift locks moves to an axis, keeps the aspect ratio when scaling
+///   from a corner, and snaps rotations to 15°.
+/// - Alt scales around the center, instead of the opposite edge.
+///
+/// Every [`Transformation`] produced carries the change since the previous
+/// one, so it can be applied as it arrives.
+pub struct TransformBox<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    content: Element<'a, Message, Theme, Renderer>,
+    on_transform: Option<Box<dyn Fn(Transformation) -> Message + 'a>>,
+    on_release: Option<Message>,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> TransformBox<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    pub fn new(content: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        Self {
+            content: content.into(),
+            on_transform: None,
+            on_release: None,
+            class: Theme::default(),
+        }
+    }
+
+    /// Sets the message produced while the content is transformed.
+    ///
+    /// No handles are shown if unset.
+    pub fn on_transform(mut self, on_transform: impl Fn(Transformation) -> Message + 'a) -> Self {
+        self.on_transform = Some(Box::new(on_transform));
+        self
+    }
+
+    /// Sets the message produced when a transformation ends.
+    pub fn on_release(mut self, on_release: Message) -> Self {
+        self.on_release = Some(on_release);
+        self
+    }
+
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+}
+
+/// A part of the gizmo that can be grabbed.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Grip {
+    Move,
+    /// The sides of the handle, from `-1` to `1` in each axis.
+    Scale(f32, f32),
+    Rotate,
+}
+
+impl Grip {
+    const HANDLES: [Grip; 9] = [
+        Grip::Scale(-1.0, -1.0),
+        Grip::Scale(0.0, -1.0),
+        Grip::Scale(1.0, -1.0),
+        Grip::Scale(1.0, 0.0),
+        Grip::Scale(1.0, 1.0),
+        Grip::Scale(0.0, 1.0),
+        Grip::Scale(-1.0, 1.0),
+        Grip::Scale(-1.0, 0.0),
+        Grip::Rotate,
+    ];
+
+    fn center(self, bounds: Rectangle) -> Point {
+        let center = bounds.center();
+
+        match self {
+            Grip::Move => center,
+            Grip::Scale(x, y) => Point::new(
+                center.x + x * bounds.width / 2.0,
+                center.y + y * bounds.height / 2.0,
+            ),
+            Grip::Rotate => Point::new(center.x, bounds.y - ROTATION_OFFSET),
+        }
+    }
+
+    fn bounds(self, bounds: Rectangle) -> Rectangle {
+        let center = self.center(bounds);
+
+        Rectangle {
+            x: center.x - HANDLE_SIZE / 2.0,
+            y: center.y - HANDLE_SIZE / 2.0,
+            width: HANDLE_SIZE,
+            height: HANDLE_SIZE,
+        }
+    }
+
+    fn interaction(self) -> mouse::Interaction {
+        match self {
+            Grip::Move => mouse::Interaction::Move,
+            Grip::Scale(x, y) if x * y > 0.0 => mouse::Interaction::ResizingDiagonallyDown,
+            Grip::Scale(x, y) if x * y < 0.0 => mouse::Interaction::ResizingDiagonallyUp,
+            Grip::Scale(x, _) if x != 0.0 => mouse::Interaction::ResizingHorizontally,
+            Grip::Scale(..) => mouse::Interaction::ResizingVertically,
+            Grip::Rotate => mouse::Interaction::Grab,
+        }
+    }
+}
+
+/// Returns the handle under the given position, if any.
+fn grip_at(bounds: Rectangle, position: Point) -> Option<Grip> {
+    Grip::HANDLES
+        .into_iter()
+        .find(|grip| grip.bounds(bounds).expand(2.0).contains(position))
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Drag {
+    grip: Grip,
+    origin: Point,
+    bounds: Rectangle,
+    current: Transformation,
+}
+
+impl Drag {
+    /// Returns the [`Transformation`] of the whole drag, up to the given
+    /// cursor position.
+    fn transformation(&self, position: Point, modifiers: keyboard::Modifiers) -> Transformation {
+        let center = self.bounds.center();
+
+        match self.grip {
+            Grip::Move => {
+                let mut translation = position - self.origin;
+
+                if modifiers.shift() {
+                    if translation.x.abs() > translation.y.abs() {
+                        translation.y = 0.0;
+                    } else {
+                        translation.x = 0.0;
+                    }
+                }
+
+                Transformation {
+                    translation,
+                    ..Transformation::IDENTITY
+                }
+            }
+            Grip::Scale(x, y) => {
+                let handle = self.grip.center(self.bounds);
+
+                let anchor = if modifiers.alt() {
+                    center
+                } else {
+                    Grip::Scale(-x, -y).center(self.bounds)
+                };
+
+                let factor = |side: f32, position: f32, handle: f32, anchor: f32| {
+                    if side == 0.0 || handle == anchor {
+                        1.0
+                    } else {
+                        ((position - anchor) / (handle - anchor)).max(MIN_SCALE)
+                    }
+                };
+
+                let mut scale = Vector::new(
+                    factor(x, position.x, handle.x, anchor.x),
+                    factor(y, position.y, handle.y, anchor.y),
+                );
+
+                if modifiers.shift() && x != 0.0 && y != 0.0 {
+                    let uniform = scale.x.max(scale.y);
+
+                    scale = Vector::new(uniform, uniform);
+                }
+
+                let offset = center - anchor;
+                let moved = Vector::new(offset.x * scale.x, offset.y * scale.y);
+
+                Transformation {
+                    translation: moved - offset,
+                    scale,
+                    ..Transformation::IDENTITY
+                }
+            }
+            Grip::Rotate => {
+                let angle = |point: Point| {
+                    let offset = point - center;
+
+                    offset.y.atan2(offset.x)
+                };
+
+                let mut rotation = angle(position) - angle(self.origin);
+
+                if modifiers.shift() {
+                    rotation = (rotation / ROTATION_SNAP).round() * ROTATION_SNAP;
+                }
+
+                Transformation {
+                    rotation: Radians(rotation),
+                    ..Transformation::IDENTITY
+                }
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct State {
+    drag: Option<Drag>,
+    modifiers: keyboard::Modifiers,
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for TransformBox<'_, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: renderer::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.content.as_widget().size_hint()
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget_mut()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content
+            .as_widget_mut()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                state.modifiers = *modifiers;
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                if self.on_transform.is_some() =>
+            {
+                if let Some((grip, position)) = cursor
+                    .position()
+                    .and_then(|position| Some((grip_at(bounds, position)?, position)))
+                {
+                    state.drag = Some(Drag {
+                        grip,
+                        origin: position,
+                        bounds,
+                        current: Transformation::IDENTITY,
+                    });
+
+                    shell.capture_event();
+                    return;
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position }) => {
+                if let Some((drag, on_transform)) =
+                    state.drag.as_mut().zip(self.on_transform.as_ref())
+                {
+                    let transformation = drag.transformation(*position, state.modifiers);
+                    let change = drag.current.to(transformation);
+
+                    if change != Transformation::IDENTITY {
+                        drag.current = transformation;
+                        shell.publish(on_transform(change));
+                    }
+
+                    shell.capture_event();
+                    return;
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+                if state.drag.is_some() =>
+            {
+                state.drag = None;
+
+                if let Some(on_release) = self.on_release.clone() {
+                    shell.publish(on_release);
+                }
+
+                shell.capture_event();
+                return;
+            }
+            _ => {}
+        }
+
+        self.content.as_widget_mut().update(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        if shell.is_event_captured() || self.on_transform.is_none() {
+            return;
+        }
+
+        let is_press = matches!(
+            event,
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+        );
+
+        // Presses the content ignores start moving it
+        if let Some(position) = cursor.position_over(bounds).filter(|_| is_press) {
+            state.drag = Some(Drag {
+                grip: Grip::Move,
+                origin: position,
+                bounds,
+                current: Transformation::IDENTITY,
+            });
+
+            shell.capture_event();
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+
+        if let Some(drag) = state.drag {
+            return match drag.grip {
+                Grip::Rotate => mouse::Interaction::Grabbing,
+                grip => grip.interaction(),
+            };
+        }
+
+        if let Some(grip) = cursor
+            .position()
+            .filter(|_| self.on_transform.is_some())
+            .and_then(|position| grip_at(bounds, position))
+        {
+            return grip.interaction();
+        }
+
+        let interaction = self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        );
+
+        if interaction == mouse::Interaction::None
+            && self.on_transform.is_some()
+            && cursor.is_over(bounds)
+        {
+            mouse::Interaction::Move
+        } else {
+            interaction
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        defaults: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            defaults,
+            layout,
+            cursor,
+            viewport,
+        );
+
+        if self.on_transform.is_none() {
+            return;
+        }
+
+        let bounds = layout.bounds();
+        let style = theme.style(&self.class);
+
+        renderer.with_layer(bounds.expand(ROTATION_OFFSET + HANDLE_SIZE), |renderer| {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border: style.outline,
+                    ..renderer::Quad::default()
+                },
+                Color::TRANSPARENT,
+            );
+
+            let top = Grip::Scale(0.0, -1.0).center(bounds);
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: top.x - style.outline.width / 2.0,
+                        y: bounds.y - ROTATION_OFFSET,
+                        width: style.outline.width,
+                        height: ROTATION_OFFSET,
+                    },
+                    ..renderer::Quad::default()
+                },
+                style.outline.color,
+            );
+
+            for grip in Grip::HANDLES {
+                let radius = if grip == Grip::Rotate {
+                    HANDLE_SIZE / 2.0
+                } else {
+                    1.0
+                };
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: grip.bounds(bounds),
+                        border: style.handle_border.rounded(radius),
+                        ..renderer::Quad::default()
+                    },
+                    style.handle,
+                );
+            }
+        });
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            viewport,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<TransformBox<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(transform_box: TransformBox<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(transform_box)
+    }
+}
+
+/// The appearance of a [`TransformBox`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Border`] drawn around the content.
+    pub outline: Border,
+    /// The [`Background`] of the handles.
+    pub handle: Background,
+    /// The [`Border`] of the handles.
+    pub handle_border: Border,
+}
+
+/// The theme catalog of a [`TransformBox`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`TransformBox`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`TransformBox`].
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        outline: Border::default().width(1).color(palette.primary.base.color),
+        handle: palette.background.base.color.into(),
+        handle_border: Border::default().width(1).color(palette.primary.base.color),
+    }
+}
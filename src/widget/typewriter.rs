@@ -6,13 +6,18 @@ use crate::core::renderer;
 use crate::core::text::{self, Fragment, Paragraph, Text};
 use crate::core::time::{Duration, Instant, milliseconds};
 use crate::core::widget;
+use crate::core::widget::Id;
+use crate::core::widget::operation::{self, Operation};
 use crate::core::widget::text::Format;
 use crate::core::widget::tree::{self, Tree};
 use crate::core::window;
 use crate::core::{
-    Alignment, Clipboard, Color, Element, Event, Length, Pixels, Rectangle, Shell, Size, Widget,
+    Alignment, Clipboard, Color, Element, Event, Length, Pixels, Point, Rectangle, Shell, Size,
+    Widget,
 };
 
+use std::any::Any;
+
 #[derive(Debug)]
 pub struct Typewriter<'a, Theme, Renderer>
 where
@@ -22,7 +27,78 @@ where
     fragment: Fragment<'a>,
     format: Format<Renderer::Font>,
     class: Theme::Class<'a>,
+    id: Option<Id>,
+    reveal_class: Option<Theme::Class<'a>>,
+    reveal_length: usize,
     speed: Duration,
+    stable: bool,
+    reserve_space: bool,
+    repeat: Repeat,
+    pause_when_hidden: bool,
+}
+
+/// The playback mode of a [`Typewriter`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Repeat {
+    /// Types the text once.
+    #[default]
+    Never,
+    /// Types the text, waits for the given pause, and types it again
+    /// from scratch.
+    Loop { pause: Duration },
+    /// Types the text forward and then deletes it backward, waiting for
+    /// the given pause at each end.
+    PingPong { pause: Duration },
+}
+
+impl Repeat {
+    /// Returns the amount of revealed characters once the given time has
+    /// elapsed, alongside the time until it changes; or `None` if done.
+    fn reveal(self, elapsed: Duration, speed: Duration, total: usize) -> Option<(usize, Duration)> {
+        let speed = speed.max(Duration::from_millis(1));
+        let typing = speed * total as u32;
+
+        let step = |t: Duration| (t.as_nanos() / speed.as_nanos()) as usize;
+        let next = |t: Duration| speed - nanos(t.as_nanos() % speed.as_nanos());
+        let cycle = |period: Duration| nanos(elapsed.as_nanos() % period.as_nanos().max(1));
+
+        match self {
+            Repeat::Never => {
+                let tick = step(elapsed);
+
+                (tick < total).then(|| (tick, next(elapsed)))
+            }
+            _ if total == 0 => None,
+            Repeat::Loop { pause } => {
+                let t = cycle(typing + pause);
+
+                Some(if t < typing {
+                    (step(t), next(t))
+                } else {
+                    (total, typing + pause - t)
+                })
+            }
+            Repeat::PingPong { pause } => {
+                let t = cycle((typing + pause) * 2);
+
+                Some(if t < typing {
+                    (step(t), next(t))
+                } else if t < typing + pause {
+                    (total, typing + pause - t)
+                } else if t < typing * 2 + pause {
+                    let t = t - typing - pause;
+
+                    (total - step(t), next(t))
+                } else {
+                    (0, (typing + pause) * 2 - t)
+                })
+            }
+        }
+    }
+}
+
+fn nanos(nanos: u128) -> Duration {
+    Duration::from_nanos(nanos as u64)
 }
 
 impl<'a, Theme, Renderer> Typewriter<'a, Theme, Renderer>
@@ -35,10 +111,24 @@ where
             fragment: fragment.into_fragment(),
             format: Format::default(),
             class: Theme::default(),
+            id: None,
+            reveal_class: None,
+            reveal_length: 4,
             speed: Duration::from_millis(20),
+            stable: true,
+            reserve_space: true,
+            repeat: Repeat::Never,
+            pause_when_hidden: true,
         }
     }
 
+    /// Sets the [`Id`] of the [`Typewriter`], used to query its
+    /// [`Progress`].
+    pub fn id(mut self, id: impl Into<Id>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
     pub fn size(mut self, size: impl Into<Pixels>) -> Self {
         self.format.size = Some(size.into());
         self
@@ -113,6 +203,26 @@ where
         self.style(move |_theme| widget::text::Style { color })
     }
 
+    /// Sets the style of the most recently revealed characters, which fade
+    /// into the regular style as typing goes on.
+    ///
+    /// It only applies to [`stable`](Self::stable) typing.
+    #[must_use]
+    pub fn reveal_style(mut self, style: impl Fn(&Theme) -> widget::text::Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<widget::text::StyleFn<'a, Theme>>,
+    {
+        self.reveal_class = Some((Box::new(style) as widget::text::StyleFn<'a, Theme>).into());
+        self
+    }
+
+    /// Sets the amount of characters styled by the
+    /// [`reveal_style`](Self::reveal_style); 4 by default.
+    pub fn reveal_length(mut self, length: usize) -> Self {
+        self.reveal_length = length;
+        self
+    }
+
     pub fn very_quick(self) -> Self {
         self.speed(milliseconds(10))
     }
@@ -133,16 +243,111 @@ where
         self.speed = char_rate.into();
         self
     }
+
+    /// Reveals the text within the layout of the final fragment, keeping
+    /// aligned text in place while typing. Enabled by default.
+    pub fn stable(mut self, stable: bool) -> Self {
+        self.stable = stable;
+        self
+    }
+
+    /// Sizes the layout for the complete fragment from the start, so
+    /// containers do not resize while typing. Enabled by default.
+    ///
+    /// When disabled, the layout grows with the revealed text instead.
+    pub fn reserve_space(mut self, reserve_space: bool) -> Self {
+        self.reserve_space = reserve_space;
+        self
+    }
+
+    /// Sets the [`Repeat`] mode of the [`Typewriter`]; types the text
+    /// only once by default.
+    pub fn repeat(mut self, repeat: Repeat) -> Self {
+        self.repeat = repeat;
+        self
+    }
+
+    /// Pauses typing while the window is unfocused or the [`Typewriter`]
+    /// is out of sight, resuming where it left off. Enabled by default.
+    pub fn pause_when_hidden(mut self, pause_when_hidden: bool) -> Self {
+        self.pause_when_hidden = pause_when_hidden;
+        self
+    }
+
+    /// Returns the amount of characters fading into the regular style.
+    fn trail(&self) -> usize {
+        if self.stable && self.reveal_class.is_some() {
+            self.reveal_length
+        } else {
+            0
+        }
+    }
+}
+
+/// The typing progress of a [`Typewriter`], as obtained by [`progress`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Progress {
+    /// The amount of characters revealed so far.
+    pub revealed: usize,
+    /// The amount of characters of the fragment.
+    pub total: usize,
+}
+
+impl Progress {
+    /// Returns true if the whole fragment has been revealed.
+    pub fn is_done(&self) -> bool {
+        self.revealed >= self.total
+    }
+}
+
+/// Produces the [`Progress`] of the [`Typewriter`] with the given [`Id`].
+pub fn progress(id: impl Into<Id>) -> impl Operation<Progress> {
+    struct Find {
+        id: Id,
+        progress: Option<Progress>,
+    }
+
+    impl Operation<Progress> for Find {
+        fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation<Progress>)) {
+            operate(self);
+        }
+
+        fn custom(&mut self, id: Option<&Id>, _bounds: Rectangle, state: &mut dyn Any) {
+            if id == Some(&self.id) {
+                self.progress = state.downcast_ref::<Progress>().copied();
+            }
+        }
+
+        fn finish(&self) -> operation::Outcome<Progress> {
+            match self.progress {
+                Some(progress) => operation::Outcome::Some(progress),
+                None => operation::Outcome::None,
+            }
+        }
+    }
+
+    Find {
+        id: id.into(),
+        progress: None,
+    }
 }
 
 /// The internal state of a [`Text`] widget.
 pub struct State<P: text::Paragraph> {
     text: text::paragraph::Plain<P>,
+    glyphs: Vec<Rectangle>,
+    glyphs_bounds: Size,
     animation: Animation<P>,
+    is_window_focused: bool,
+    paused_at: Option<Instant>,
 }
 
 enum Animation<P: text::Paragraph> {
-    Ticking { text: P, start: Option<Instant> },
+    Ticking {
+        revealed: usize,
+        partial: P,
+        start: Option<Instant>,
+    },
     Done,
 }
 
@@ -159,8 +364,13 @@ where
     fn state(&self) -> tree::State {
         tree::State::new(State {
             text: text::paragraph::Plain::<Renderer::Paragraph>::default(),
+            glyphs: Vec::new(),
+            glyphs_bounds: Size::ZERO,
+            is_window_focused: true,
+            paused_at: None,
             animation: Animation::Ticking {
-                text: Renderer::Paragraph::default(),
+                revealed: 0,
+                partial: Renderer::Paragraph::default(),
                 start: None,
             },
         })
@@ -191,19 +401,74 @@ where
             self.format,
         );
 
-        if has_changed {
+        let is_restarting =
+            matches!(state.animation, Animation::Done) && self.repeat != Repeat::Never;
+
+        if has_changed || is_restarting {
             let text = Text {
                 content: "",
                 ..state.text.as_text()
             };
 
             state.animation = Animation::Ticking {
-                text: Renderer::Paragraph::with_text(text),
+                revealed: 0,
+                partial: Renderer::Paragraph::with_text(text),
                 start: None,
             };
         }
 
-        node
+        if self.stable && matches!(state.animation, Animation::Ticking { .. }) {
+            let bounds = state.text.raw().bounds();
+
+            if has_changed || is_restarting || state.glyphs_bounds != bounds {
+                state.glyphs = glyphs::<Renderer::Paragraph>(&self.fragment, state.text.as_text());
+                state.glyphs_bounds = bounds;
+            }
+        }
+
+        if self.reserve_space {
+            return node;
+        }
+
+        let revealed = match &state.animation {
+            Animation::Ticking { revealed, .. } if self.stable => state.glyphs
+                [..(*revealed).min(state.glyphs.len())]
+                .iter()
+                .filter(|glyph| glyph.width > 0.0)
+                .fold(Size::ZERO, |size, glyph| {
+                    Size::new(
+                        size.width.max(glyph.x + glyph.width),
+                        size.height.max(glyph.y + glyph.height),
+                    )
+                }),
+            Animation::Ticking { partial, .. } => partial.min_bounds(),
+            Animation::Done => return node,
+        };
+
+        layout::Node::new(limits.resolve(self.format.width, self.format.height, revealed))
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+        let bounds = layout.bounds();
+        let total = self.fragment.chars().count();
+
+        let mut progress = Progress {
+            revealed: match &state.animation {
+                Animation::Ticking { revealed, .. } => (*revealed).min(total),
+                Animation::Done => total,
+            },
+            total,
+        };
+
+        operation.text(self.id.as_ref(), bounds, &self.fragment);
+        operation.custom(self.id.as_ref(), bounds, &mut progress);
     }
 
     fn draw(
@@ -219,23 +484,78 @@ where
         let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
         let style = theme.style(&self.class);
 
+        let color = style.color.unwrap_or(defaults.text_color);
+
         let paragraph = match &state.animation {
-            Animation::Ticking { text, .. } => text,
+            Animation::Ticking { revealed, .. } if self.stable => {
+                let bounds = layout.bounds();
+
+                let position = bounds.anchor(
+                    state.text.min_bounds(),
+                    self.format.align_x,
+                    self.format.align_y,
+                );
+
+                // Without reserved space, the bounds may be smaller than the
+                // final paragraph
+                let position = Point::new(position.x.max(bounds.x), position.y.max(bounds.y));
+
+                // The revealed count runs ahead of the glyphs while the
+                // trail fades out
+                let trail = self.trail();
+                let shown = (*revealed).min(state.glyphs.len());
+                let settled = revealed.saturating_sub(trail).min(shown);
+
+                for line in lines(&state.glyphs[..settled]) {
+                    let Some(clip) = (line + (position - Point::ORIGIN)).intersection(viewport)
+                    else {
+                        continue;
+                    };
+
+                    renderer.with_layer(clip, |renderer| {
+                        renderer.fill_paragraph(state.text.raw(), position, color, *viewport);
+                    });
+                }
+
+                let reveal_color = self
+                    .reveal_class
+                    .as_ref()
+                    .and_then(|class| theme.style(class).color)
+                    .unwrap_or(color);
+
+                for (i, glyph) in state.glyphs.iter().enumerate().take(shown).skip(settled) {
+                    let Some(clip) = (*glyph + (position - Point::ORIGIN)).intersection(viewport)
+                    else {
+                        continue;
+                    };
+
+                    let age = revealed - 1 - i;
+                    let color = mix(color, reveal_color, 1.0 - age as f32 / trail as f32);
+
+                    renderer.with_layer(clip, |renderer| {
+                        renderer.fill_paragraph(state.text.raw(), position, color, *viewport);
+                    });
+                }
+
+                return;
+            }
+            Animation::Ticking { partial, .. } => partial,
             Animation::Done => state.text.raw(),
         };
 
+        let height = if self.reserve_space {
+            state.text.min_height()
+        } else {
+            paragraph.min_height()
+        };
+
         let position = layout.bounds().anchor(
-            Size::new(paragraph.min_width(), state.text.min_height()),
+            Size::new(paragraph.min_width(), height),
             self.format.align_x,
             self.format.align_y,
         );
 
-        renderer.fill_paragraph(
-            paragraph,
-            position,
-            style.color.unwrap_or(defaults.text_color),
-            *viewport,
-        );
+        renderer.fill_paragraph(paragraph, position, color, *viewport);
     }
 
     fn update(
@@ -249,15 +569,57 @@ where
         shell: &mut Shell<'_, Message>,
         viewport: &Rectangle,
     ) {
-        if layout.bounds().intersection(viewport).is_none() {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        match event {
+            Event::Window(window::Event::Unfocused) => {
+                state.is_window_focused = false;
+
+                return;
+            }
+            Event::Window(window::Event::Focused) => {
+                state.is_window_focused = true;
+                shell.request_redraw();
+
+                return;
+            }
+            _ => {}
+        }
+
+        let bounds = layout.bounds();
+
+        // Without reserved space, the bounds start empty
+        let is_visible =
+            bounds.intersection(viewport).is_some() || viewport.contains(bounds.position());
+
+        if !is_visible && !self.pause_when_hidden {
             return;
         }
 
         if let Event::Window(window::Event::RedrawRequested(now)) = event {
-            let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+            if self.pause_when_hidden && (!is_visible || !state.is_window_focused) {
+                state.paused_at.get_or_insert(*now);
+
+                return;
+            }
+
+            // Resume where the animation left off
+            if let (
+                Some(paused_at),
+                Animation::Ticking {
+                    start: Some(start), ..
+                },
+            ) = (state.paused_at.take(), &mut state.animation)
+            {
+                *start += now.saturating_duration_since(paused_at);
+            }
 
             match &mut state.animation {
-                Animation::Ticking { text, start } => {
+                Animation::Ticking {
+                    revealed,
+                    partial,
+                    start,
+                } => {
                     let start = match start {
                         Some(start) => *start,
                         None => {
@@ -266,22 +628,36 @@ where
                         }
                     };
 
-                    let tick_rate = self.speed.as_millis() as f32;
-                    let tick = ((*now - start).as_millis() as f32 / tick_rate) as usize;
+                    let total_chars = self.fragment.chars().count() + self.trail();
 
-                    let total_chars = self.fragment.chars().count();
+                    match self.repeat.reveal(*now - start, self.speed, total_chars) {
+                        None => {
+                            state.animation = Animation::Done;
+                            state.glyphs = Vec::new();
+                            state.glyphs_bounds = Size::ZERO;
+
+                            if !self.reserve_space {
+                                shell.invalidate_layout();
+                            }
+                        }
+                        Some((tick, next)) => {
+                            if !self.stable && tick != *revealed {
+                                let truncated: String = self.fragment.chars().take(tick).collect();
 
-                    if tick >= total_chars {
-                        state.animation = Animation::Done;
-                    } else {
-                        let truncated: String = self.fragment.chars().take(tick).collect();
+                                *partial = Renderer::Paragraph::with_text(Text {
+                                    content: truncated.trim(),
+                                    ..state.text.as_text()
+                                });
+                            }
 
-                        *text = Renderer::Paragraph::with_text(Text {
-                            content: truncated.trim(),
-                            ..state.text.as_text()
-                        });
+                            if !self.reserve_space && tick != *revealed {
+                                shell.invalidate_layout();
+                            }
 
-                        shell.request_redraw_at(*now + Duration::from_millis(tick_rate as u64));
+                            *revealed = tick;
+
+                            shell.request_redraw_at(*now + next);
+                        }
                     }
                 }
                 Animation::Done => {}
@@ -301,3 +677,106 @@ where
         Element::new(text)
     }
 }
+
+/// Computes the bounds of every character of the fragment, as laid out
+/// in its final paragraph.
+fn glyphs<P: text::Paragraph>(fragment: &str, text: Text<&str, P::Font>) -> Vec<Rectangle> {
+    let spans: Vec<text::Span<'_, (), P::Font>> = fragment
+        .char_indices()
+        .map(|(i, c)| text::Span::new(&fragment[i..i + c.len_utf8()]))
+        .collect();
+
+    let paragraph = P::with_spans(text.with_content(spans.as_slice()));
+
+    (0..spans.len())
+        .map(|i| {
+            paragraph
+                .span_bounds(i)
+                .into_iter()
+                .reduce(|a, b| a.union(&b))
+                .unwrap_or_default()
+        })
+        .collect()
+}
+
+fn mix(a: Color, b: Color, t: f32) -> Color {
+    Color {
+        r: a.r + (b.r - a.r) * t,
+        g: a.g + (b.g - a.g) * t,
+        b: a.b + (b.b - a.b) * t,
+        a: a.a + (b.a - a.a) * t,
+    }
+}
+
+/// Merges the bounds of consecutive glyphs into the lines they occupy.
+fn lines(glyphs: &[Rectangle]) -> Vec<Rectangle> {
+    let mut lines: Vec<Rectangle> = Vec::new();
+
+    for glyph in glyphs.iter().filter(|glyph| glyph.width > 0.0) {
+        match lines.last_mut() {
+            Some(line) if (line.y - glyph.y).abs() < 1.0 => {
+                *line = line.union(glyph);
+            }
+            _ => {
+                lines.push(*glyph);
+            }
+        }
+    }
+
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::Harness;
+
+    fn revealed(harness: &mut Harness<'_, ()>) -> String {
+        let progress = harness
+            .query(progress("typewriter"))
+            .expect("typewriter is found");
+
+        "Hello".chars().take(progress.revealed).collect()
+    }
+
+    #[test]
+    fn reveals_characters_over_time() {
+        let mut harness = Harness::<()>::new(
+            Typewriter::new("Hello")
+                .id("typewriter")
+                .speed(milliseconds(100)),
+        );
+
+        harness.frame();
+        assert_eq!(revealed(&mut harness), "");
+
+        harness.advance(milliseconds(250));
+        assert_eq!(revealed(&mut harness), "He");
+
+        assert!(harness.settle(Duration::from_secs(1)));
+        assert_eq!(revealed(&mut harness), "Hello");
+    }
+
+    #[test]
+    fn pauses_while_unfocused() {
+        let mut harness = Harness::<()>::new(
+            Typewriter::new("Hello")
+                .id("typewriter")
+                .speed(milliseconds(100))
+                .pause_when_hidden(true),
+        );
+
+        harness.frame();
+        harness.advance(milliseconds(150));
+        assert_eq!(revealed(&mut harness), "H");
+
+        let _ = harness.event(Event::Window(window::Event::Unfocused));
+        harness.advance(milliseconds(50));
+        harness.advance(Duration::from_secs(1));
+        assert_eq!(revealed(&mut harness), "H");
+
+        let _ = harness.event(Event::Window(window::Event::Focused));
+        harness.advance(milliseconds(100));
+        assert_eq!(revealed(&mut harness), "He");
+    }
+}
@@ -0,0 +1,570 @@
+use crate::core::keyboard;
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::widget::Widget;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::{
+    Background, Border, Clipboard, Color, Element, Event, Length, Point, Rectangle, Shell, Size,
+    Theme,
+};
+
+use iced_widget::canvas;
+use iced_widget::graphics::geometry;
+
+use std::cell::Cell;
+use std::ops::Range;
+
+/// The smallest distance, in pixels, a drag needs to become a selection.
+const DRAG_THRESHOLD: f32 = 3.0;
+
+/// The maximum zoom, in pixels per sample.
+const MAX_ZOOM: f32 = 32.0;
+
+/// A widget that draws the peaks of an audio buffer.
+///
+/// Every pixel column shows the minimum and maximum of the samples it
+/// covers. The mouse wheel zooms around the cursor, while horizontal
+/// scrolling—or Shift with the wheel—scrolls through the buffer.
+///
+/// The peaks are cached until the samples, the zoom or the bounds change.
+/// Samples are compared by address and length, so mutating a buffer in
+/// place will not be noticed.
+pub struct Waveform<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    samples: &'a [f32],
+    playhead: Option<usize>,
+    selection: Option<Range<usize>>,
+    on_seek: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    on_select: Option<Box<dyn Fn(Range<usize>) -> Message + 'a>>,
+    width: Length,
+    height: Length,
+    class: Theme::Class<'a>,
+    renderer: std::marker::PhantomData<Renderer>,
+}
+
+impl<'a, Message, Theme, Renderer> Waveform<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    pub fn new(samples: &'a [f32]) -> Self {
+        Self {
+            samples,
+            playhead: None,
+            selection: None,
+            on_seek: None,
+            on_select: None,
+            width: Length::Fill,
+            height: Length::Fixed(100.0),
+            class: Theme::default(),
+            renderer: std::marker::PhantomData,
+        }
+    }
+
+    /// Sets the sample where the playhead marker is drawn.
+    pub fn playhead(mut self, sample: usize) -> Self {
+        self.playhead = Some(sample);
+        self
+    }
+
+    pub fn playhead_maybe(mut self, sample: Option<usize>) -> Self {
+        self.playhead = sample;
+        self
+    }
+
+    /// Sets the selected range of samples.
+    pub fn selection(mut self, selection: Range<usize>) -> Self {
+        self.selection = Some(selection);
+        self
+    }
+
+    pub fn selection_maybe(mut self, selection: Option<Range<usize>>) -> Self {
+        self.selection = selection;
+        self
+    }
+
+    /// Sets the message produced when the waveform is clicked, given the
+    /// sample under the cursor.
+    pub fn on_seek(mut self, on_seek: impl Fn(usize) -> Message + 'a) -> Self {
+        self.on_seek = Some(Box::new(on_seek));
+        self
+    }
+
+    /// Sets the message produced when a range of samples is selected by
+    /// dragging.
+    pub fn on_select(mut self, on_select: impl Fn(Range<usize>) -> Message + 'a) -> Self {
+        self.on_select = Some(Box::new(on_select));
+        self
+    }
+
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+}
+
+/// The visible part of the samples.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct View {
+    /// The first visible sample, which may be fractional.
+    offset: f32,
+    samples_per_pixel: f32,
+}
+
+impl View {
+    fn sample_at(&self, x: f32, len: usize) -> usize {
+        ((self.offset + x * self.samples_per_pixel).max(0.0) as usize).min(len)
+    }
+
+    fn x_of(&self, sample: usize) -> f32 {
+        (sample as f32 - self.offset) / self.samples_per_pixel
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Key {
+    samples: (usize, usize),
+    view: View,
+    size: Size,
+}
+
+struct State<Renderer>
+where
+    Renderer: geometry::Renderer,
+{
+    zoom: Option<View>,
+    drag: Option<(f32, f32)>,
+    modifiers: keyboard::Modifiers,
+    cache: canvas::Cache<Renderer>,
+    cached: Cell<Option<Key>>,
+}
+
+impl<Renderer> State<Renderer>
+where
+    Renderer: geometry::Renderer,
+{
+    /// Returns the current [`View`], fitting all the samples by default.
+    fn view(&self, len: usize, width: f32) -> View {
+        let fit = len as f32 / width.max(1.0);
+
+        // Short buffers may not even fill the bounds at maximum zoom
+        let min = (1.0 / MAX_ZOOM).min(fit);
+
+        match self.zoom {
+            Some(view) => {
+                let samples_per_pixel = view.samples_per_pixel.clamp(min, fit.max(min));
+
+                View {
+                    offset: view
+                        .offset
+                        .clamp(0.0, (len as f32 - width * samples_per_pixel).max(0.0)),
+                    samples_per_pixel,
+                }
+            }
+            None => View {
+                offset: 0.0,
+                samples_per_pixel: fit,
+            },
+        }
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Waveform<'_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: geometry::Renderer + 'static,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<Renderer>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::<Renderer> {
+            zoom: None,
+            drag: None,
+            modifiers: keyboard::Modifiers::default(),
+            cache: canvas::Cache::new(),
+            cached: Cell::new(None),
+        })
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        layout::atomic(limits, self.width, self.height)
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<State<Renderer>>();
+        let bounds = layout.bounds();
+        let len = self.samples.len();
+        let view = state.view(len, bounds.width);
+
+        match event {
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
+                if self.samples.is_empty() {
+                    return;
+                }
+
+                let Some(position) = cursor.position_in(bounds) else {
+                    return;
+                };
+
+                let (x, y) = match *delta {
+                    mouse::ScrollDelta::Lines { x, y } => (x * 60.0, y),
+                    mouse::ScrollDelta::Pixels { x, y } => (x, y / 60.0),
+                };
+
+                let (x, y) = if state.modifiers.shift() {
+                    (x - y * 60.0, 0.0)
+                } else {
+                    (x, y)
+                };
+
+                let mut zoomed = view;
+
+                if y != 0.0 {
+                    let anchor = view.offset + position.x * view.samples_per_pixel;
+
+                    zoomed.samples_per_pixel /= 1.2f32.powf(y);
+                    zoomed.offset = anchor - position.x * zoomed.samples_per_pixel;
+                }
+
+                zoomed.offset -= x * zoomed.samples_per_pixel;
+
+                state.zoom = Some(zoomed);
+
+                if state.view(len, bounds.width) != view {
+                    shell.request_redraw();
+                }
+
+                shell.capture_event();
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                if self.on_seek.is_none() && self.on_select.is_none() {
+                    return;
+                }
+
+                let Some(position) = cursor.position_in(bounds) else {
+                    return;
+                };
+
+                state.drag = Some((position.x, position.x));
+                shell.capture_event();
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position }) => {
+                let Some((_, end)) = &mut state.drag else {
+                    return;
+                };
+
+                *end = (position.x - bounds.x).clamp(0.0, bounds.width);
+
+                if self.on_select.is_some() {
+                    shell.request_redraw();
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                let Some((start, end)) = state.drag.take() else {
+                    return;
+                };
+
+                if (end - start).abs() < DRAG_THRESHOLD {
+                    if let Some(on_seek) = &self.on_seek {
+                        shell.publish(on_seek(view.sample_at(start, len)));
+                    }
+                } else if let Some(on_select) = &self.on_select {
+                    let first = view.sample_at(start.min(end), len);
+                    let last = view.sample_at(start.max(end), len);
+
+                    shell.publish(on_select(first..last));
+                }
+
+                shell.request_redraw();
+            }
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                state.modifiers = *modifiers;
+            }
+            _ => {}
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if (self.on_seek.is_some() || self.on_select.is_some()) && cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Text
+        } else {
+            mouse::Interaction::None
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _defaults: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+
+        if !bounds.intersects(viewport) {
+            return;
+        }
+
+        let state = tree.state.downcast_ref::<State<Renderer>>();
+        let style = theme.style(&self.class);
+        let len = self.samples.len();
+        let view = state.view(len, bounds.width);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: style.border,
+                ..renderer::Quad::default()
+            },
+            style.background,
+        );
+
+        let selection = match state.drag {
+            Some((start, end))
+                if self.on_select.is_some() && (end - start).abs() >= DRAG_THRESHOLD =>
+            {
+                Some((start.min(end), start.max(end)))
+            }
+            _ => self
+                .selection
+                .as_ref()
+                .map(|selection| (view.x_of(selection.start), view.x_of(selection.end))),
+        };
+
+        if let Some((start, end)) = selection {
+            let start = start.clamp(0.0, bounds.width);
+            let end = end.clamp(0.0, bounds.width);
+
+            if end > start {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: bounds.x + start,
+                            width: end - start,
+                            ..bounds
+                        },
+                        ..renderer::Quad::default()
+                    },
+                    style.selection,
+                );
+            }
+        }
+
+        let key = Key {
+            samples: (self.samples.as_ptr() as usize, len),
+            view,
+            size: bounds.size(),
+        };
+
+        if state.cached.replace(Some(key)) != Some(key) {
+            state.cache.clear();
+        }
+
+        let peaks = state.cache.draw(renderer, bounds.size(), |frame| {
+            let middle = frame.height() / 2.0;
+            let columns = frame.width().ceil() as usize;
+
+            let path = canvas::Path::new(|builder| {
+                for column in 0..columns {
+                    let x = column as f32;
+                    let start = view.offset + x * view.samples_per_pixel;
+                    let end = start + view.samples_per_pixel;
+
+                    let first = start.floor().max(0.0) as usize;
+                    let last = (end.ceil() as usize).clamp(first + 1, len.max(first + 1));
+
+                    let Some(samples) = self.samples.get(first..last.min(len)) else {
+                        break;
+                    };
+
+                    let (min, max) = samples
+                        .iter()
+                        .fold((f32::INFINITY, f32::NEG_INFINITY), |(min, max), sample| {
+                            (min.min(*sample), max.max(*sample))
+                        });
+
+                    if min > max {
+                        break;
+                    }
+
+                    let top = middle - max.clamp(-1.0, 1.0) * middle;
+                    let bottom = middle - min.clamp(-1.0, 1.0) * middle;
+
+                    builder.rectangle(Point::new(x, top), Size::new(1.0, (bottom - top).max(1.0)));
+                }
+            });
+
+            frame.fill(&path, style.wave);
+        });
+
+        renderer.with_translation(bounds.position() - Point::ORIGIN, |renderer| {
+            renderer.draw_geometry(peaks);
+        });
+
+        if let Some(playhead) = self.playhead {
+            let x = view.x_of(playhead);
+
+            if (0.0..=bounds.width).contains(&x) {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: bounds.x + x - 1.0,
+                            width: 2.0,
+                            ..bounds
+                        },
+                        ..renderer::Quad::default()
+                    },
+                    style.playhead,
+                );
+            }
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Waveform<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: geometry::Renderer + 'static,
+{
+    fn from(waveform: Waveform<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(waveform)
+    }
+}
+
+/// The appearance of a [`Waveform`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Background`] of the waveform.
+    pub background: Background,
+    /// The [`Border`] of the waveform.
+    pub border: Border,
+    /// The [`Color`] of the peaks.
+    pub wave: Color,
+    /// The [`Color`] of the playhead marker.
+    pub playhead: Color,
+    /// The [`Background`] of the selected range.
+    pub selection: Background,
+}
+
+/// The theme catalog of a [`Waveform`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`Waveform`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`Waveform`].
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        background: palette.background.weakest.color.into(),
+        border: Border::default().rounded(4),
+        wave: palette.primary.base.color,
+        playhead: palette.danger.base.color,
+        selection: palette.primary.weak.color.scale_alpha(0.4).into(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::Harness;
+
+    fn scroll(samples: &[f32]) {
+        let mut harness = Harness::<()>::new(Waveform::new(samples));
+
+        let _ = harness.move_cursor(Point::new(100.0, 50.0));
+
+        for y in [1.0, 5.0, -3.0] {
+            let _ = harness.event(Event::Mouse(mouse::Event::WheelScrolled {
+                delta: mouse::ScrollDelta::Lines { x: 0.0, y },
+            }));
+        }
+
+        harness.draw(&Theme::Light);
+    }
+
+    #[test]
+    fn scrolls_over_an_empty_buffer() {
+        scroll(&[]);
+    }
+
+    #[test]
+    fn scrolls_over_a_short_buffer() {
+        scroll(&[0.0, 0.5, -0.5, 1.0]);
+    }
+}
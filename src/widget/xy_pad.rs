@@ -0,0 +1,587 @@
+use crate::core::keyboard;
+use crate::core::keyboard::key::{self, Key};
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::touch;
+use crate::core::widget::Widget;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::window;
+use crate::core::{
+    self, Background, Border, Clipboard, Color, Element, Event, Length, Pixels, Point, Rectangle,
+    Shell, Size, Theme, Vector,
+};
+
+use std::ops::RangeInclusive;
+
+/// The distance, in logical pixels, the puck snaps to the center from.
+const SNAP_DISTANCE: f32 = 6.0;
+
+type OnChange<'a, Message> = Box<dyn Fn((f32, f32)) -> Message + 'a>;
+
+/// A pad that controls two values at once by dragging a puck.
+///
+/// The horizontal axis goes from the start to the end of its range, left
+/// to right; the vertical one, bottom to top.
+///
+/// Holding Shift while dragging moves the puck at a tenth of the speed of
+/// the cursor. Double clicking the pad moves the puck to its center.
+pub struct XyPad<'a, Message, Theme = core::Theme>
+where
+    Theme: Catalog,
+{
+    range_x: RangeInclusive<f32>,
+    range_y: RangeInclusive<f32>,
+    value: (f32, f32),
+    on_change: Option<OnChange<'a, Message>>,
+    on_release: Option<Message>,
+    center: Option<(f32, f32)>,
+    fine_factor: f32,
+    width: Length,
+    height: Length,
+    class: Theme::Class<'a>,
+    status: Option<Status>,
+}
+
+impl<'a, Message, Theme> XyPad<'a, Message, Theme>
+where
+    Theme: Catalog,
+{
+    pub fn new(
+        range_x: RangeInclusive<f32>,
+        range_y: RangeInclusive<f32>,
+        (x, y): (f32, f32),
+    ) -> Self {
+        let center = (
+            (range_x.start() + range_x.end()) / 2.0,
+            (range_y.start() + range_y.end()) / 2.0,
+        );
+
+        Self {
+            value: (clamp(x, &range_x), clamp(y, &range_y)),
+            range_x,
+            range_y,
+            on_change: None,
+            on_release: None,
+            center: Some(center),
+            fine_factor: 0.1,
+            width: Length::Fixed(200.0),
+            height: Length::Fixed(200.0),
+            class: Theme::default(),
+            status: None,
+        }
+    }
+
+    /// Sets the message produced when the puck moves.
+    pub fn on_change(mut self, on_change: impl Fn((f32, f32)) -> Message + 'a) -> Self {
+        self.on_change = Some(Box::new(on_change));
+        self
+    }
+
+    /// Sets the message produced when the puck is released.
+    pub fn on_release(mut self, on_release: Message) -> Self {
+        self.on_release = Some(on_release);
+        self
+    }
+
+    /// Sets the center the puck snaps to, or disables snapping with `None`.
+    ///
+    /// By default, the puck snaps to the middle of both ranges.
+    pub fn center(mut self, center: Option<(f32, f32)>) -> Self {
+        self.center = center;
+        self
+    }
+
+    /// Sets the speed of the puck, relative to the cursor, while holding
+    /// Shift.
+    pub fn fine_factor(mut self, fine_factor: f32) -> Self {
+        self.fine_factor = fine_factor;
+        self
+    }
+
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the width and the height of the pad.
+    pub fn size(self, size: impl Into<Pixels>) -> Self {
+        let size = size.into();
+
+        self.width(size).height(size)
+    }
+
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Returns the position of the given value inside the bounds.
+    fn project(&self, bounds: Rectangle, (x, y): (f32, f32)) -> Point {
+        Point::new(
+            bounds.x + bounds.width * ratio(x, &self.range_x),
+            bounds.y + bounds.height * (1.0 - ratio(y, &self.range_y)),
+        )
+    }
+
+    /// Returns the value at the given position inside the bounds.
+    fn locate(&self, bounds: Rectangle, position: Point) -> (f32, f32) {
+        let x = if bounds.width > 0.0 {
+            (position.x - bounds.x) / bounds.width
+        } else {
+            0.0
+        };
+
+        let y = if bounds.height > 0.0 {
+            1.0 - (position.y - bounds.y) / bounds.height
+        } else {
+            0.0
+        };
+
+        (
+            clamp(lerp(x, &self.range_x), &self.range_x),
+            clamp(lerp(y, &self.range_y), &self.range_y),
+        )
+    }
+
+    /// Snaps the value to the center when the puck lands close to it.
+    fn snap(&self, bounds: Rectangle, value: (f32, f32)) -> (f32, f32) {
+        let Some(center) = self.center else {
+            return value;
+        };
+
+        let point = self.project(bounds, value);
+        let target = self.project(bounds, center);
+
+        let x = if (point.x - target.x).abs() <= SNAP_DISTANCE {
+            center.0
+        } else {
+            value.0
+        };
+
+        let y = if (point.y - target.y).abs() <= SNAP_DISTANCE {
+            center.1
+        } else {
+            value.1
+        };
+
+        (x, y)
+    }
+
+    fn change(&mut self, value: (f32, f32), shell: &mut Shell<'_, Message>) {
+        let Some(on_change) = &self.on_change else {
+            return;
+        };
+
+        if value != self.value {
+            self.value = value;
+            shell.publish(on_change(value));
+        }
+    }
+}
+
+fn ratio(value: f32, range: &RangeInclusive<f32>) -> f32 {
+    let (start, end) = (*range.start(), *range.end());
+
+    if start == end {
+        0.0
+    } else {
+        ((value - start) / (end - start)).clamp(0.0, 1.0)
+    }
+}
+
+fn lerp(ratio: f32, range: &RangeInclusive<f32>) -> f32 {
+    range.start() + (range.end() - range.start()) * ratio
+}
+
+fn clamp(value: f32, range: &RangeInclusive<f32>) -> f32 {
+    value.clamp(
+        range.start().min(*range.end()),
+        range.start().max(*range.end()),
+    )
+}
+
+/// The cursor position and value a drag is measured from.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Drag {
+    origin: Point,
+    value: (f32, f32),
+}
+
+#[derive(Debug, Clone, Default)]
+struct State {
+    drag: Option<Drag>,
+    modifiers: keyboard::Modifiers,
+    last_click: Option<mouse::Click>,
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for XyPad<'_, Message, Theme>
+where
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: core::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        layout::atomic(limits, self.width, self.height)
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        if self.on_change.is_none() {
+            return;
+        }
+
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some(position) = cursor.position_over(bounds) else {
+                    return;
+                };
+
+                let click = mouse::Click::new(position, mouse::Button::Left, state.last_click);
+                state.last_click = Some(click);
+
+                let value = match self.center {
+                    Some(center) if click.kind() == mouse::click::Kind::Double => center,
+                    // Fine adjustments start from the puck, not the cursor
+                    _ if state.modifiers.shift() => self.value,
+                    _ => self.snap(bounds, self.locate(bounds, position)),
+                };
+
+                state.drag = Some(Drag {
+                    origin: position,
+                    value,
+                });
+
+                self.change(value, shell);
+
+                shell.capture_event();
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                let Some(drag) = state.drag else {
+                    return;
+                };
+
+                if let Some(position) = cursor.land().position() {
+                    let value = if state.modifiers.shift() {
+                        let delta = (position - drag.origin) * self.fine_factor;
+                        let origin = self.project(bounds, drag.value);
+
+                        self.locate(bounds, origin + delta)
+                    } else {
+                        self.snap(bounds, self.locate(bounds, position))
+                    };
+
+                    self.change(value, shell);
+                }
+
+                shell.capture_event();
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. }) => {
+                let was_dragging = state.drag.take().is_some();
+
+                if let Some(on_release) = self.on_release.clone().filter(|_| was_dragging) {
+                    shell.publish(on_release);
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed { key, .. }) if cursor.is_over(bounds) => {
+                let direction = match key {
+                    Key::Named(key::Named::ArrowLeft) => Vector::new(-1.0, 0.0),
+                    Key::Named(key::Named::ArrowRight) => Vector::new(1.0, 0.0),
+                    Key::Named(key::Named::ArrowUp) => Vector::new(0.0, -1.0),
+                    Key::Named(key::Named::ArrowDown) => Vector::new(0.0, 1.0),
+                    _ => return,
+                };
+
+                // A hundredth of the pad, or a thousandth for fine adjustments
+                let step = if state.modifiers.shift() {
+                    self.fine_factor / 100.0
+                } else {
+                    0.01
+                };
+
+                let delta = Vector::new(
+                    direction.x * bounds.width * step,
+                    direction.y * bounds.height * step,
+                );
+
+                let value = self.locate(bounds, self.project(bounds, self.value) + delta);
+
+                self.change(value, shell);
+
+                shell.capture_event();
+            }
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                // Dragging continues from the puck when switching speeds
+                if let Some(position) = cursor.position().filter(|_| state.drag.is_some()) {
+                    state.drag = Some(Drag {
+                        origin: position,
+                        value: self.value,
+                    });
+                }
+
+                state.modifiers = *modifiers;
+            }
+            _ => {}
+        }
+
+        let status = if state.drag.is_some() {
+            Status::Dragged
+        } else if cursor.is_over(bounds) {
+            Status::Hovered
+        } else {
+            Status::Active
+        };
+
+        if let Event::Window(window::Event::RedrawRequested(_)) = event {
+            self.status = Some(status);
+        } else if self.status.is_some_and(|current| current != status) {
+            shell.request_redraw();
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+
+        if self.on_change.is_none() {
+            mouse::Interaction::None
+        } else if state.drag.is_some() {
+            mouse::Interaction::Grabbing
+        } else if cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Crosshair
+        } else {
+            mouse::Interaction::None
+        }
+    }
+
+    fn draw(
+        &self,
+        _tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _defaults: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+
+        let status = if self.on_change.is_none() {
+            Status::Disabled
+        } else {
+            self.status.unwrap_or(Status::Active)
+        };
+
+        let style = theme.style(&self.class, status);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: style.border,
+                ..renderer::Quad::default()
+            },
+            style.background,
+        );
+
+        let line = |renderer: &mut Renderer, point: Point, color: Color| {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: bounds.x,
+                        y: point.y - 0.5,
+                        width: bounds.width,
+                        height: 1.0,
+                    },
+                    ..renderer::Quad::default()
+                },
+                color,
+            );
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: point.x - 0.5,
+                        y: bounds.y,
+                        width: 1.0,
+                        height: bounds.height,
+                    },
+                    ..renderer::Quad::default()
+                },
+                color,
+            );
+        };
+
+        renderer.with_layer(bounds, |renderer| {
+            if let Some(center) = self.center {
+                line(renderer, self.project(bounds, center), style.center);
+            }
+
+            let puck = self.project(bounds, self.value);
+            let radius = style.puck_radius;
+
+            line(renderer, puck, style.guide);
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: puck.x - radius,
+                        y: puck.y - radius,
+                        width: radius * 2.0,
+                        height: radius * 2.0,
+                    },
+                    border: style.puck_border.rounded(radius),
+                    ..renderer::Quad::default()
+                },
+                style.puck,
+            );
+        });
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<XyPad<'a, Message, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: core::Renderer + 'a,
+{
+    fn from(xy_pad: XyPad<'a, Message, Theme>) -> Self {
+        Element::new(xy_pad)
+    }
+}
+
+/// The possible status of an [`XyPad`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The [`XyPad`] can be interacted with.
+    Active,
+    /// The [`XyPad`] is being hovered.
+    Hovered,
+    /// The puck is being dragged.
+    Dragged,
+    /// The [`XyPad`] cannot be interacted with.
+    Disabled,
+}
+
+/// The appearance of an [`XyPad`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Background`] of the pad.
+    pub background: Background,
+    /// The [`Border`] of the pad.
+    pub border: Border,
+    /// The [`Color`] of the crosshair through the puck.
+    pub guide: Color,
+    /// The [`Color`] of the crosshair through the center.
+    pub center: Color,
+    /// The [`Background`] of the puck.
+    pub puck: Background,
+    /// The radius of the puck.
+    pub puck_radius: f32,
+    /// The [`Border`] of the puck.
+    pub puck_border: Border,
+}
+
+/// The theme catalog of an [`XyPad`].
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class with the given status.
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style;
+}
+
+/// A styling function for an [`XyPad`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
+        class(self, status)
+    }
+}
+
+/// The default style of an [`XyPad`].
+pub fn default(theme: &Theme, status: Status) -> Style {
+    let palette = theme.extended_palette();
+
+    let color = match status {
+        Status::Active => palette.primary.base.color,
+        Status::Hovered => palette.primary.strong.color,
+        Status::Dragged => palette.primary.weak.color,
+        Status::Disabled => palette.background.strong.color,
+    };
+
+    Style {
+        background: palette.background.weak.color.into(),
+        border: Border::default()
+            .rounded(4)
+            .width(1)
+            .color(palette.background.strong.color),
+        guide: color.scale_alpha(0.5),
+        center: palette.background.strong.color,
+        puck: color.into(),
+        puck_radius: 7.0,
+        puck_border: Border::default()
+            .width(2)
+            .color(palette.background.base.color),
+    }
+}